@@ -0,0 +1,29 @@
+use num_traits::Float;
+
+// Column major 4x4 matrices, the layout `Mat4::from_cols_array_2d` expects on both sides
+
+pub fn perspective(fov: f32, near: f32, far: f32) -> [[f32; 4]; 4] {
+    let inv_tan = 1.0 / (0.5 * fov).tan();
+    let f_n = far / (far - near);
+
+    [[inv_tan, 0.0, 0.0, 0.0], [0.0, inv_tan, 0.0, 0.0], [0.0, 0.0, f_n, 1.0], [0.0, 0.0, -near * f_n, 0.0]]
+}
+
+pub fn orthographic(near: f32, far: f32) -> [[f32; 4]; 4] {
+    let scale = 1.0 / (far - near);
+
+    [[1.0, 0.0, 0.0, 0.0], [0.0, 1.0, 0.0, 0.0], [0.0, 0.0, scale, 0.0], [0.0, 0.0, -near * scale, 1.0]]
+}
+
+// Maps the screen window to pixels, with the top of the window on the first row
+pub fn raster_from_screen(window_min: [f32; 2], window_max: [f32; 2], resolution: [u32; 2]) -> [[f32; 4]; 4] {
+    let scale_x = resolution[0] as f32 / (window_max[0] - window_min[0]);
+    let scale_y = resolution[1] as f32 / (window_min[1] - window_max[1]);
+
+    [
+        [scale_x, 0.0, 0.0, 0.0],
+        [0.0, scale_y, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [-window_min[0] * scale_x, -window_max[1] * scale_y, 0.0, 1.0],
+    ]
+}
@@ -0,0 +1,136 @@
+// The canonical tone curves and display encoding, the host `output::display` and the shader display pass wrap these.
+// Matrices are column major like `Mat3::from_cols_array_2d` expects
+use num_traits::Float;
+
+#[inline]
+fn dot(a: [f32; 3], b: [f32; 3]) -> f32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+#[inline]
+fn mul(m: &[[f32; 3]; 3], v: [f32; 3]) -> [f32; 3] {
+    [
+        m[0][0] * v[0] + m[1][0] * v[1] + m[2][0] * v[2],
+        m[0][1] * v[0] + m[1][1] * v[1] + m[2][1] * v[2],
+        m[0][2] * v[0] + m[1][2] * v[1] + m[2][2] * v[2],
+    ]
+}
+
+#[inline]
+fn per_channel(v: [f32; 3], f: impl Fn(f32) -> f32) -> [f32; 3] {
+    [f(v[0]), f(v[1]), f(v[2])]
+}
+
+#[inline]
+pub fn clamp_unit(rgb: [f32; 3]) -> [f32; 3] {
+    per_channel(rgb, |value| value.clamp(0.0, 1.0))
+}
+
+// Extended Reinhard on luminance, so saturated highlights keep their hue
+pub fn reinhard(rgb: [f32; 3], luminance: [f32; 3], white_point: f32) -> [f32; 3] {
+    let y = dot(luminance, rgb);
+
+    if y <= 0.0 {
+        return [0.0; 3];
+    }
+
+    let white_term = if white_point.is_finite() { y / (white_point * white_point) } else { 0.0 };
+    let mapped = y * (1.0 + white_term) / (1.0 + y);
+
+    per_channel(rgb, |value| value * (mapped / y))
+}
+
+// Stephen Hill's fit of the ACES RRT and sRGB ODT, expects linear Rec.709 input
+pub fn aces_fitted(rgb: [f32; 3]) -> [f32; 3] {
+    const ACES_INPUT: [[f32; 3]; 3] = [[0.59719, 0.076, 0.0284], [0.35458, 0.90834, 0.13383], [0.04823, 0.01566, 0.83777]];
+    const ACES_OUTPUT: [[f32; 3]; 3] = [[1.60475, -0.10208, -0.00327], [-0.53108, 1.10813, -0.07276], [-0.07367, -0.00605, 1.07602]];
+
+    let v = mul(&ACES_INPUT, rgb);
+    let fitted = per_channel(v, |v| (v * (v + 0.0245786) - 0.000090537) / (v * (0.983729 * v + 0.432951) + 0.238081));
+
+    clamp_unit(mul(&ACES_OUTPUT, fitted))
+}
+
+// Minimal AgX with the default contrast curve, expects linear Rec.709 input
+pub fn agx(rgb: [f32; 3]) -> [f32; 3] {
+    const MIN_EV: f32 = -12.47393;
+    const MAX_EV: f32 = 4.026069;
+
+    const AGX_INSET: [[f32; 3]; 3] = [
+        [0.842479062253094, 0.0423282422610123, 0.0423756549057051],
+        [0.0784335999999992, 0.878468636469772, 0.0784336],
+        [0.0792237451477643, 0.0791661274605434, 0.879142973793104],
+    ];
+    const AGX_OUTSET: [[f32; 3]; 3] = [
+        [1.19687900512017, -0.0528968517574562, -0.0529716355144438],
+        [-0.0980208811401368, 1.15190312990417, -0.0980434501171241],
+        [-0.0990297440797205, -0.0989611768448433, 1.15107367264116],
+    ];
+
+    let v = per_channel(mul(&AGX_INSET, rgb), |v| (v.max(1e-10).log2().clamp(MIN_EV, MAX_EV) - MIN_EV) / (MAX_EV - MIN_EV));
+
+    let v = per_channel(v, |v| {
+        let x2 = v * v;
+        let x4 = x2 * x2;
+        15.5 * x4 * x2 - 40.14 * x4 * v + 31.96 * x4 - 6.868 * x2 * v + 0.4298 * x2 + 0.1191 * v - 0.00232
+    });
+
+    // The curve output is display encoded with a 2.2 power
+    per_channel(clamp_unit(mul(&AGX_OUTSET, v)), |v| v.powf(2.2))
+}
+
+fn hable_partial(x: f32) -> f32 {
+    const A: f32 = 0.15;
+    const B: f32 = 0.5;
+    const C: f32 = 0.1;
+    const D: f32 = 0.2;
+    const E: f32 = 0.02;
+    const F: f32 = 0.3;
+
+    (x * (A * x + C * B) + D * E) / (x * (A * x + B) + D * F) - E / F
+}
+
+pub fn hable(rgb: [f32; 3], white_point: f32) -> [f32; 3] {
+    let white = hable_partial(white_point);
+    per_channel(rgb, |value| (hable_partial(value.max(0.0)) / white).clamp(0.0, 1.0))
+}
+
+// Display P3 shares the sRGB transfer function
+pub fn srgb_oetf(value: f32) -> f32 {
+    if value <= 0.0031308 {
+        12.92 * value
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+// Desaturates towards the luminance of the color until it fits the unit cube, keeping its hue
+pub fn gamut_map(rgb: [f32; 3], luminance: [f32; 3]) -> [f32; 3] {
+    let y = dot(luminance, rgb).clamp(0.0, 1.0);
+    let mut rgb = rgb;
+
+    let min = rgb[0].min(rgb[1]).min(rgb[2]);
+    if min < 0.0 {
+        rgb = per_channel(rgb, |value| y + (value - y) * (y / (y - min)));
+    }
+
+    let max = rgb[0].max(rgb[1]).max(rgb[2]);
+    if max > 1.0 {
+        rgb = per_channel(rgb, |value| y + (value - y) * ((1.0 - y) / (max - y)));
+    }
+
+    clamp_unit(rgb)
+}
+
+fn hash(mut state: u32) -> f32 {
+    state = state.wrapping_mul(747796405).wrapping_add(2891336453);
+    let word = ((state >> ((state >> 28) + 4)) ^ state).wrapping_mul(277803737);
+    ((word >> 22) ^ word) as f32 / u32::MAX as f32
+}
+
+// Triangular noise of one quantization step, decorrelated per pixel and channel
+pub fn dither_noise(pixel: [u32; 2], channel: u32) -> f32 {
+    let seed = pixel[0].wrapping_mul(1973).wrapping_add(pixel[1].wrapping_mul(9277)).wrapping_add(channel.wrapping_mul(26699));
+
+    hash(seed) + hash(seed ^ 0x68bc21eb) - 1.0
+}
@@ -0,0 +1,87 @@
+use num_traits::Float;
+
+// Radius of the default box filter of the film the volumetric kernels record into
+pub const FILTER_RADIUS: f32 = 0.5;
+
+// Filters without a closed form sample are tabulated on the host over this many cells per axis and sampled from the distribution buffer
+pub const FILTER_SAMPLER_RESOLUTION: usize = 32;
+
+#[inline]
+fn gaussian(x: f32, sigma: f32) -> f32 {
+    1.0 / (2.0 * core::f32::consts::PI * sigma * sigma).sqrt() * (-(x * x) / (2.0 * sigma * sigma)).exp()
+}
+
+#[inline]
+fn erf(x: f32) -> f32 {
+    let t = 1.0 / (1.0 + 0.3275911 * x.abs());
+    let polynomial = t * (0.254829592 + t * (-0.284496736 + t * (1.421413741 + t * (-1.453152027 + t * 1.061405429))));
+
+    (1.0 - polynomial * (-x * x).exp()).copysign(x)
+}
+
+// Gaussian shifted down to reach zero at the radius
+pub fn gaussian_filter(point: [f32; 2], radius: [f32; 2], sigma: f32) -> f32 {
+    (gaussian(point[0], sigma) - gaussian(radius[0], sigma)).max(0.0) * (gaussian(point[1], sigma) - gaussian(radius[1], sigma)).max(0.0)
+}
+
+pub fn gaussian_filter_integral(radius: [f32; 2], sigma: f32) -> f32 {
+    let integral_1d = |radius: f32| erf(radius / (sigma * core::f32::consts::SQRT_2)) - 2.0 * radius * gaussian(radius, sigma);
+    integral_1d(radius[0]) * integral_1d(radius[1])
+}
+
+fn mitchell_1d(x: f32, b: f32, c: f32) -> f32 {
+    let x = x.abs();
+
+    if x <= 1.0 {
+        ((12.0 - 9.0 * b - 6.0 * c) * x * x * x + (-18.0 + 12.0 * b + 6.0 * c) * x * x + (6.0 - 2.0 * b)) / 6.0
+    } else if x <= 2.0 {
+        ((-b - 6.0 * c) * x * x * x + (6.0 * b + 30.0 * c) * x * x + (-12.0 * b - 48.0 * c) * x + (8.0 * b + 24.0 * c)) / 6.0
+    } else {
+        0.0
+    }
+}
+
+pub fn mitchell_filter(point: [f32; 2], radius: [f32; 2], b: f32, c: f32) -> f32 {
+    mitchell_1d(2.0 * point[0] / radius[0], b, c) * mitchell_1d(2.0 * point[1] / radius[1], b, c)
+}
+
+#[inline]
+fn sinc(x: f32) -> f32 {
+    if 1.0 - x * x == 1.0 {
+        1.0
+    } else {
+        (core::f32::consts::PI * x).sin() / (core::f32::consts::PI * x)
+    }
+}
+
+#[inline]
+fn windowed_sinc(x: f32, radius: f32, tau: f32) -> f32 {
+    if x.abs() > radius {
+        0.0
+    } else {
+        sinc(x) * sinc(x / tau)
+    }
+}
+
+pub fn lanczos_sinc_filter(point: [f32; 2], radius: [f32; 2], tau: f32) -> f32 {
+    windowed_sinc(point[0], radius[0], tau) * windowed_sinc(point[1], radius[1], tau)
+}
+
+// Splats from different invocations land on the same pixels and are summed with 32-bit integer image atomics, so every
+// channel holds a fixed-point value with a resolution of 1 / SPLAT_FIXED_POINT_SCALE (about 6e-5). The running sum of a
+// channel has to stay within ±i32::MAX / SPLAT_FIXED_POINT_SCALE (about ±131000), past that the atomic add wraps around
+pub const SPLAT_FIXED_POINT_SCALE: f32 = 16384.0;
+
+// Largest f32 that still converts to an i32, float to int conversion is undefined past it on the GPU
+const SPLAT_FIXED_POINT_MAX: f32 = 2147483520.0;
+
+// Rounds a single contribution to the nearest fixed-point step, saturating it to the representable range
+#[inline]
+pub fn to_splat_fixed_point(value: f32) -> i32 {
+    (value * SPLAT_FIXED_POINT_SCALE).round().clamp(-SPLAT_FIXED_POINT_MAX, SPLAT_FIXED_POINT_MAX) as i32
+}
+
+#[inline]
+pub fn from_splat_fixed_point(value: i32) -> f32 {
+    value as f32 / SPLAT_FIXED_POINT_SCALE
+}
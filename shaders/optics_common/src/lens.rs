@@ -0,0 +1,238 @@
+use num_traits::Float;
+
+pub const MAX_LENS_ELEMENTS: usize = 32;
+pub const EXIT_PUPIL_BOUND_COUNT: usize = 64;
+
+// A lens is flattened into the distribution buffer as u32 bits:
+//   element count, dispersive flag, film diagonal, physical extent[4], exit pupil bounds[EXIT_PUPIL_BOUND_COUNT * 4], elements[count * 5]
+pub const LENS_HEADER_LEN: usize = 7;
+pub const LENS_ELEMENTS_OFFSET: usize = LENS_HEADER_LEN + 4 * EXIT_PUPIL_BOUND_COUNT;
+pub const LENS_ELEMENT_LEN: usize = 5;
+
+// Fraunhofer d, F and C lines in µm, the refractive index and Abbe number of a glass are given at these
+const LAMBDA_D: f32 = 0.5876;
+const LAMBDA_F: f32 = 0.4861;
+const LAMBDA_C: f32 = 0.6563;
+
+// Wavelength in nm at which a dispersive element has its nominal refractive index
+pub const LAMBDA_NOMINAL: f32 = LAMBDA_D * 1000.0;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct LensElementInterface {
+    pub curvature_radius: f32,
+    pub thickness: f32,
+    pub eta: f32,
+    pub abbe_number: f32,
+    pub aperture_radius: f32,
+}
+
+impl LensElementInterface {
+    pub fn from_array(values: [f32; LENS_ELEMENT_LEN]) -> Self {
+        Self {
+            curvature_radius: values[0],
+            thickness: values[1],
+            eta: values[2],
+            abbe_number: values[3],
+            aperture_radius: values[4],
+        }
+    }
+
+    pub fn to_array(&self) -> [f32; LENS_ELEMENT_LEN] {
+        [self.curvature_radius, self.thickness, self.eta, self.abbe_number, self.aperture_radius]
+    }
+
+    pub fn is_stop(&self) -> bool {
+        self.curvature_radius == 0.0
+    }
+
+    pub fn is_dispersive(&self) -> bool {
+        self.eta != 0.0 && self.abbe_number > 0.0
+    }
+
+    // Cauchy's equation fitted to the index at the d line and the Abbe number, air has an eta of 0 in prescriptions
+    pub fn eta_at(&self, lambda: f32) -> f32 {
+        if self.eta == 0.0 {
+            return 1.0;
+        }
+
+        if !self.is_dispersive() {
+            return self.eta;
+        }
+
+        let b = (self.eta - 1.0) / (self.abbe_number * (1.0 / (LAMBDA_F * LAMBDA_F) - 1.0 / (LAMBDA_C * LAMBDA_C)));
+        let a = self.eta - b / (LAMBDA_D * LAMBDA_D);
+        let lambda = lambda * 1.0e-3;
+
+        a + b / (lambda * lambda)
+    }
+}
+
+#[inline]
+fn dot(a: [f32; 3], b: [f32; 3]) -> f32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+#[inline]
+fn normalize(a: [f32; 3]) -> [f32; 3] {
+    let length = dot(a, a).sqrt();
+    a.map(|value| value / length)
+}
+
+#[inline]
+fn flip_z(a: [f32; 3]) -> [f32; 3] {
+    [a[0], a[1], -a[2]]
+}
+
+// Intersects the element's surface at `element_z` along the optical axis, clips against its aperture and refracts from `eta_i` into `eta_t`
+fn trace_element(element: LensElementInterface, element_z: f32, eta_i: f32, eta_t: f32, origin: &mut [f32; 3], direction: &mut [f32; 3]) -> Option<()> {
+    let (t, normal) = if element.is_stop() {
+        ((element_z - origin[2]) / direction[2], [0.0; 3])
+    } else {
+        intersect_spherical_element(element.curvature_radius, element_z + element.curvature_radius, *origin, *direction)?
+    };
+
+    let point_hit = [origin[0] + direction[0] * t, origin[1] + direction[1] * t, origin[2] + direction[2] * t];
+
+    if point_hit[0] * point_hit[0] + point_hit[1] * point_hit[1] > element.aperture_radius * element.aperture_radius {
+        return None;
+    }
+
+    *origin = point_hit;
+
+    if !element.is_stop() {
+        let (_, transmitted) = refract(direction.map(|value| -value), normal, eta_t / eta_i)?;
+        *direction = transmitted;
+    }
+
+    Some(())
+}
+
+// Traces a camera space ray from the film at z = 0 out of the front element, `element` looks up the prescription from the front.
+// Lens space mirrors z so the elements sit at negative z. Returns None when an element or the stop blocks the ray
+pub fn trace_lenses_from_film(element_count: usize, element: impl Fn(usize) -> LensElementInterface, origin: [f32; 3], direction: [f32; 3], lambda: f32) -> Option<([f32; 3], [f32; 3])> {
+    let mut element_z = 0.0;
+    let mut origin = flip_z(origin);
+    let mut direction = normalize(flip_z(direction));
+
+    for i in (0..element_count).rev() {
+        let interface = element(i);
+        element_z -= interface.thickness;
+
+        if interface.is_stop() && direction[2] >= 0.0 {
+            return None;
+        }
+
+        let eta_t = if i > 0 { element(i - 1).eta_at(lambda) } else { 1.0 };
+        trace_element(interface, element_z, interface.eta_at(lambda), eta_t, &mut origin, &mut direction)?;
+    }
+
+    Some((flip_z(origin), flip_z(direction)))
+}
+
+// Traces a camera space ray from the scene through the front element onto the film side of the lens
+pub fn trace_lenses_from_scene(element_count: usize, element: impl Fn(usize) -> LensElementInterface, origin: [f32; 3], direction: [f32; 3], lambda: f32) -> Option<([f32; 3], [f32; 3])> {
+    let mut element_z = -(0..element_count).map(|i| element(i).thickness).sum::<f32>();
+    let mut origin = flip_z(origin);
+    let mut direction = normalize(flip_z(direction));
+
+    for i in 0..element_count {
+        let interface = element(i);
+
+        if interface.is_stop() && direction[2] <= 0.0 {
+            return None;
+        }
+
+        let eta_i = if i == 0 { 1.0 } else { element(i - 1).eta_at(lambda) };
+        trace_element(interface, element_z, eta_i, interface.eta_at(lambda), &mut origin, &mut direction)?;
+
+        element_z += interface.thickness;
+    }
+
+    Some((flip_z(origin), flip_z(direction)))
+}
+
+#[inline]
+pub fn lerp(t: f32, a: f32, b: f32) -> f32 {
+    a * (1.0 - t) + b * t
+}
+
+// Distance along the ray to a spherical lens element centered on the optical axis, with the normal facing the ray
+pub fn intersect_spherical_element(radius: f32, z_center: f32, origin: [f32; 3], direction: [f32; 3]) -> Option<(f32, [f32; 3])> {
+    let origin = [origin[0], origin[1], origin[2] - z_center];
+
+    let a = dot(direction, direction);
+    let b = 2.0 * dot(direction, origin);
+    let c = dot(origin, origin) - radius * radius;
+
+    let discriminant = b * b - 4.0 * a * c;
+
+    if discriminant < 0.0 {
+        return None;
+    }
+
+    let root = discriminant.sqrt();
+    let q = if b < 0.0 { -0.5 * (b - root) } else { -0.5 * (b + root) };
+    let (t0, t1) = (q / a, c / q);
+
+    let use_closer_t = (direction[2] > 0.0) ^ (radius < 0.0);
+    let t = if use_closer_t { t0.min(t1) } else { t0.max(t1) };
+
+    if t < 0.0 {
+        return None;
+    }
+
+    let point = [origin[0] + direction[0] * t, origin[1] + direction[1] * t, origin[2] + direction[2] * t];
+    let length = dot(point, point).sqrt();
+    let sign = if dot(point, direction) > 0.0 { -1.0 } else { 1.0 };
+
+    Some((t, point.map(|value| sign * value / length)))
+}
+
+// Refracts the direction pointing away from the surface, returns the relative eta actually used and the transmitted direction
+pub fn refract(input_direction: [f32; 3], normal: [f32; 3], eta: f32) -> Option<(f32, [f32; 3])> {
+    let mut normal = normal;
+    let mut eta = eta;
+    let mut cos_theta_i = dot(normal, input_direction);
+
+    if cos_theta_i < 0.0 {
+        eta = 1.0 / eta;
+        cos_theta_i = -cos_theta_i;
+        normal = normal.map(|value| -value);
+    }
+
+    let sin_theta_t_sqr = (1.0 - cos_theta_i * cos_theta_i).max(0.0) / (eta * eta);
+
+    if sin_theta_t_sqr >= 1.0 {
+        return None;
+    }
+
+    let cos_theta_t = (1.0 - sin_theta_t_sqr).sqrt();
+    let scale = cos_theta_i / eta - cos_theta_t;
+
+    Some((
+        eta,
+        [
+            -input_direction[0] / eta + scale * normal[0],
+            -input_direction[1] / eta + scale * normal[1],
+            -input_direction[2] / eta + scale * normal[2],
+        ],
+    ))
+}
+
+// Mirrors the digits of `index` in `base` around the radix point, stops before the reversed digits overflow
+pub fn radical_inverse(base: u64, mut index: u64) -> f32 {
+    let limit = u64::MAX / base - base;
+    let inv_base = 1.0 / base as f32;
+    let mut inv_base_m = 1.0;
+    let mut reversed_digits = 0u64;
+
+    while index != 0 && reversed_digits < limit {
+        let next = index / base;
+        reversed_digits = reversed_digits * base + (index - next * base);
+        inv_base_m *= inv_base;
+        index = next;
+    }
+
+    (reversed_digits as f32 * inv_base_m).min(0.99999994)
+}
@@ -0,0 +1,9 @@
+// Plain math shared by the host and the shaders. The two sides link different glam versions, so vectors cross this crate as arrays
+#![no_std]
+
+pub mod calibration;
+pub mod camera;
+pub mod display;
+pub mod film;
+pub mod lens;
+pub mod sampler;
@@ -0,0 +1,20 @@
+// Shape of the PMJ02BN tables: written by the `pmj02bn_table` generator, uploaded by the host and indexed by `sampler::Pmj02bnSampler`
+pub const PMJ02BN_SET_COUNT: u32 = 5;
+pub const PMJ02BN_SAMPLE_COUNT: u32 = 1024;
+
+// Z-order index of a pixel or tile, x in the even bits. `ZSobolSampler` walks the image along it and the host's Morton tile order sorts by it
+pub fn encode_morton2(x: u32, y: u32) -> u64 {
+    let spread = |value: u32| {
+        let mut value = value as u64;
+
+        value = (value | (value << 16)) & 0x0000_ffff_0000_ffff;
+        value = (value | (value << 8)) & 0x00ff_00ff_00ff_00ff;
+        value = (value | (value << 4)) & 0x0f0f_0f0f_0f0f_0f0f;
+        value = (value | (value << 2)) & 0x3333_3333_3333_3333;
+        value = (value | (value << 1)) & 0x5555_5555_5555_5555;
+
+        value
+    };
+
+    spread(x) | (spread(y) << 1)
+}
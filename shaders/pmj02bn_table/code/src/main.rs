@@ -0,0 +1,38 @@
+use optics_common::sampler::{PMJ02BN_SAMPLE_COUNT, PMJ02BN_SET_COUNT};
+use std::fs::File;
+use std::io::Write;
+
+fn main() {
+    let samples = pmj02bn_table::generate_pmj02bn_sets(PMJ02BN_SET_COUNT as usize, PMJ02BN_SAMPLE_COUNT as usize);
+
+    // Verified after the conversion to fixed point, which is what the shaders see
+    for set in samples.chunks(PMJ02BN_SAMPLE_COUNT as usize) {
+        let set = set.iter().map(|sample| sample.map(|value| value as f64 / 4294967296.0)).collect::<Vec<_>>();
+        assert!(pmj02bn_table::is_progressive_02_sequence(&set), "Generated set is not a progressive (0,2) sequence");
+    }
+
+    write_to_file("src/pmj02bn_table.rs", &samples).unwrap();
+}
+
+fn write_to_file(path: &str, samples: &[[u32; 2]]) -> std::io::Result<()> {
+    let mut file = File::create(path)?;
+
+    writeln!(file, "// Generated by shaders/pmj02bn_table/code, do not edit")?;
+    writeln!(file)?;
+    writeln!(file, "use optics_common::sampler::{{PMJ02BN_SAMPLE_COUNT, PMJ02BN_SET_COUNT}};")?;
+    writeln!(file)?;
+    writeln!(file, "#[rustfmt::skip]")?;
+    writeln!(file, "pub const PMJ02BN_SAMPLES: [[u32; 2]; (PMJ02BN_SET_COUNT * PMJ02BN_SAMPLE_COUNT) as usize] = [")?;
+
+    for line in samples.chunks(8) {
+        file.write_all(b"   ")?;
+        for sample in line {
+            write!(file, " [0x{:08x}, 0x{:08x}],", sample[0], sample[1])?;
+        }
+        file.write_all(b"\n")?;
+    }
+
+    file.write_all(b"];\n")?;
+
+    Ok(())
+}
@@ -0,0 +1,238 @@
+// Progressive multi-jittered (0,2) sequences with blue noise candidate selection, after Christensen, Kensler and Kilpatrick,
+// "Progressive Multi-Jittered Sample Sequences". Every power of two prefix of a sequence is a (0,2) net in base 2.
+
+// Each new sample is the best of this many random valid positions
+const CANDIDATE_COUNT: usize = 16;
+
+// Small splitmix64 generator so the tables only depend on the seed
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+
+        z ^ (z >> 31)
+    }
+
+    pub fn uniform(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+}
+
+// Occupancy of every elementary interval shape 2^a x 2^(k - a) for 2^k samples
+struct Strata {
+    log2_count: u32,
+    occupied: Vec<Vec<bool>>,
+}
+
+impl Strata {
+    fn new(count: usize) -> Self {
+        let log2_count = count.trailing_zeros();
+
+        Self {
+            log2_count,
+            occupied: (0..=log2_count).map(|_| vec![false; count]).collect(),
+        }
+    }
+
+    fn count(&self) -> usize {
+        1 << self.log2_count
+    }
+
+    fn index(&self, shape: u32, x_cell: usize, y_cell: usize) -> usize {
+        ((x_cell >> (self.log2_count - shape)) << (self.log2_count - shape)) | (y_cell >> shape)
+    }
+
+    // Cells are the finest 1 / count strips in each axis
+    fn is_free(&self, x_cell: usize, y_cell: usize) -> bool {
+        (0..=self.log2_count).all(|shape| !self.occupied[shape as usize][self.index(shape, x_cell, y_cell)])
+    }
+
+    fn mark(&mut self, point: [f64; 2]) {
+        let x_cell = ((point[0] * self.count() as f64) as usize).min(self.count() - 1);
+        let y_cell = ((point[1] * self.count() as f64) as usize).min(self.count() - 1);
+
+        for shape in 0..=self.log2_count {
+            let index = self.index(shape, x_cell, y_cell);
+            self.occupied[shape as usize][index] = true;
+        }
+    }
+}
+
+fn toroidal_distance_squared(a: [f64; 2], b: [f64; 2]) -> f64 {
+    let dx = (a[0] - b[0]).abs();
+    let dy = (a[1] - b[1]).abs();
+    let dx = dx.min(1.0 - dx);
+    let dy = dy.min(1.0 - dy);
+
+    dx * dx + dy * dy
+}
+
+// Picks a point in the given subquadrant of grid cell (i, j) that keeps the samples a (0,2) net, or None if every position is taken
+fn generate_sample_point(samples: &[[f64; 2]], strata: &Strata, cell: [usize; 2], half: [usize; 2], grid_size: usize, rng: &mut Rng) -> Option<[f64; 2]> {
+    let strips = strata.count() / (2 * grid_size);
+    let x_first = (2 * cell[0] + half[0]) * strips;
+    let y_first = (2 * cell[1] + half[1]) * strips;
+
+    let mut free_cells = (x_first..x_first + strips)
+        .flat_map(|x| (y_first..y_first + strips).map(move |y| (x, y)))
+        .filter(|&(x, y)| strata.is_free(x, y))
+        .collect::<Vec<_>>();
+
+    if free_cells.is_empty() {
+        return None;
+    }
+
+    let mut best = None;
+    let mut best_distance = -1.0;
+
+    for _ in 0..CANDIDATE_COUNT.min(free_cells.len()) {
+        let (x, y) = free_cells.swap_remove(rng.below(free_cells.len()));
+        let candidate = [(x as f64 + rng.uniform()) / strata.count() as f64, (y as f64 + rng.uniform()) / strata.count() as f64];
+
+        let distance = samples.iter().map(|&sample| toroidal_distance_squared(sample, candidate)).fold(f64::MAX, f64::min);
+
+        if distance > best_distance {
+            best_distance = distance;
+            best = Some(candidate);
+        }
+    }
+
+    best
+}
+
+fn subquadrant(point: [f64; 2], grid_size: usize) -> ([usize; 2], [usize; 2]) {
+    let scaled = [point[0] * grid_size as f64, point[1] * grid_size as f64];
+    let cell = [scaled[0] as usize, scaled[1] as usize];
+    let half = [(2.0 * (scaled[0] - cell[0] as f64)) as usize, (2.0 * (scaled[1] - cell[1] as f64)) as usize];
+
+    (cell, half)
+}
+
+// Doubles a sequence of 4^k samples, every new sample goes in the subquadrant diagonally opposite of an old one
+fn extend_even(samples: &mut Vec<[f64; 2]>, rng: &mut Rng) -> Option<()> {
+    let count = samples.len();
+    let grid_size = (count as f64).sqrt() as usize;
+
+    let mut strata = Strata::new(2 * count);
+    samples.iter().for_each(|&sample| strata.mark(sample));
+
+    for s in 0..count {
+        let (cell, half) = subquadrant(samples[s], grid_size);
+        let point = generate_sample_point(samples, &strata, cell, [1 - half[0], 1 - half[1]], grid_size, rng)?;
+
+        strata.mark(point);
+        samples.push(point);
+    }
+
+    Some(())
+}
+
+// Doubles a sequence of 2 * 4^k samples by filling the two empty subquadrants of each grid cell
+fn extend_odd(samples: &mut Vec<[f64; 2]>, rng: &mut Rng) -> Option<()> {
+    let count = samples.len();
+    let grid_size = ((count / 2) as f64).sqrt() as usize;
+
+    let mut strata = Strata::new(2 * count);
+    samples.iter().for_each(|&sample| strata.mark(sample));
+
+    let mut chosen = Vec::with_capacity(count / 2);
+
+    for s in 0..count / 2 {
+        let (cell, half) = subquadrant(samples[s], grid_size);
+        let flips = if rng.below(2) == 0 {
+            [[1 - half[0], half[1]], [half[0], 1 - half[1]]]
+        } else {
+            [[half[0], 1 - half[1]], [1 - half[0], half[1]]]
+        };
+
+        // Falls back to the other free subquadrant when the first choice has no valid position left
+        let (point, flip) = flips
+            .iter()
+            .find_map(|&flip| generate_sample_point(samples, &strata, cell, flip, grid_size, rng).map(|point| (point, flip)))?;
+
+        strata.mark(point);
+        samples.push(point);
+        chosen.push((cell, flip));
+    }
+
+    for (cell, flip) in chosen {
+        let point = generate_sample_point(samples, &strata, cell, [1 - flip[0], 1 - flip[1]], grid_size, rng)?;
+
+        strata.mark(point);
+        samples.push(point);
+    }
+
+    Some(())
+}
+
+fn try_generate_pmj02bn(sample_count: usize, rng: &mut Rng) -> Option<Vec<[f64; 2]>> {
+    let mut samples = Vec::with_capacity(sample_count);
+    samples.push([rng.uniform(), rng.uniform()]);
+
+    while samples.len() < sample_count {
+        extend_even(&mut samples, rng)?;
+
+        if samples.len() < sample_count {
+            extend_odd(&mut samples, rng)?;
+        }
+    }
+
+    Some(samples)
+}
+
+// `sample_count` must be a power of two, generation restarts with a fresh stream in the rare case a subquadrant runs out of valid positions
+pub fn generate_pmj02bn(sample_count: usize, seed: u64) -> Vec<[f64; 2]> {
+    assert!(sample_count.is_power_of_two(), "PMJ02BN sample count must be a power of two");
+
+    let mut rng = Rng::new(seed);
+
+    loop {
+        if let Some(samples) = try_generate_pmj02bn(sample_count, &mut rng) {
+            return samples;
+        }
+    }
+}
+
+// Checks that every power of two prefix puts exactly one sample in each elementary interval
+pub fn is_progressive_02_sequence(samples: &[[f64; 2]]) -> bool {
+    let mut count = 1;
+
+    while count <= samples.len() {
+        let mut strata = Strata::new(count);
+
+        for &sample in &samples[..count] {
+            strata.mark(sample);
+        }
+
+        if strata.occupied.iter().any(|shape| shape.iter().any(|&occupied| !occupied)) {
+            return false;
+        }
+
+        count *= 2;
+    }
+
+    true
+}
+
+// Samples in 0.32 fixed point, set after set, as stored in the shipped table and read by the shaders
+pub fn generate_pmj02bn_sets(set_count: usize, sample_count: usize) -> Vec<[u32; 2]> {
+    (0..set_count)
+        .flat_map(|set| generate_pmj02bn(sample_count, set as u64))
+        .map(|sample| sample.map(|value| (value * 4294967296.0).min(u32::MAX as f64) as u32))
+        .collect()
+}
@@ -266,6 +266,20 @@ fn cie_interp(data: &[f64; CIE_SAMPLES], mut x: f64) -> f64 {
     (1.0 - weight) * data[offset] + weight * data[offset + 1]
 }
 
+pub fn cie_xyz(lambda: f64) -> [f64; 3] {
+    let lambda = lambda.clamp(CIE_LAMBDA_MIN, CIE_LAMBDA_MAX);
+
+    [cie_interp(&CIE_X, lambda), cie_interp(&CIE_Y, lambda), cie_interp(&CIE_Z, lambda)]
+}
+
+pub fn cie_d65(lambda: f64) -> f64 {
+    cie_interp(&CIE_D65, lambda.clamp(CIE_LAMBDA_MIN, CIE_LAMBDA_MAX))
+}
+
+pub fn cie_d50(lambda: f64) -> f64 {
+    cie_interp(&CIE_D50, lambda.clamp(CIE_LAMBDA_MIN, CIE_LAMBDA_MAX))
+}
+
 fn lup_decompose<const N: usize>(a: &mut [[f64; N]; N], p: &mut [usize], tol: f64) -> Option<()> {
     assert_eq!(p.len(), N + 1);
 
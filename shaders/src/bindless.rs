@@ -1,11 +1,25 @@
+use crate::camera::aperture::RenderAperture;
+use crate::camera::film::Film;
+use crate::camera::realistic::{LensSlice, RealisticCamera};
+use crate::camera::transform::{AnimatedTransform, CameraTransform};
+use crate::camera::{self, Camera, CameraBaseParameters, OrthographicCamera, PerspectiveCamera, SphericalCamera, SphericalMapping};
+use crate::light::medium::{BlackBodyEmission, GridMedium, HomogeneousMedium, Medium};
+use crate::spectrum::color::RgbToSpectrumTable;
+use crate::spectrum::{RgbUnboundedSpectrum, SampledSpectrum, SampledWavelengths};
+use crate::util::grid::SampledGrid;
+use crate::util::math;
 use core::ops::Index;
-use spirv_std::glam::{Mat4, Vec2, Vec3, Vec4};
+use spirv_std::glam::{Mat4, UVec2, UVec4, Vec2, Vec3, Vec4, Vec4Swizzles};
+
+pub const NO_MEDIUM: u32 = u32::MAX;
 
 #[derive(Default, Clone, Copy)]
 #[repr(C)]
 pub struct InstanceMetadata {
     pub transform: Mat4,
     pub index_offset: u32,
+    pub medium_inside: u32,
+    pub medium_outside: u32,
 }
 
 #[derive(Default, Clone, Copy)]
@@ -23,6 +37,8 @@ pub enum MaterialType {
     Lambertian,
     Metal,
     Dielectric,
+    Interface,
+    Subsurface,
 }
 
 impl From<u32> for MaterialType {
@@ -31,6 +47,8 @@ impl From<u32> for MaterialType {
             0 => MaterialType::Lambertian,
             1 => MaterialType::Metal,
             2 => MaterialType::Dielectric,
+            3 => MaterialType::Interface,
+            4 => MaterialType::Subsurface,
             _ => panic!("Invalid material type"),
         }
     }
@@ -50,6 +68,83 @@ pub struct RenderMaterial {
 
     pub material_type: u32,
     pub material_property: f32,
+
+    pub mean_free_path: Vec4,
+}
+
+#[derive(Copy, Clone, Debug)]
+pub enum MediumType {
+    Homogeneous,
+    Grid,
+}
+
+impl From<u32> for MediumType {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => MediumType::Homogeneous,
+            1 => MediumType::Grid,
+            _ => panic!("Invalid medium type"),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct RenderMedium {
+    pub medium_from_render: Mat4,
+    pub bounds_min: Vec4,
+    pub bounds_max: Vec4,
+    pub sigma_a: Vec4,
+    pub sigma_s: Vec4,
+    pub emitted: Vec4,
+    pub density_grid: UVec4,
+    pub temperature_grid: UVec4,
+
+    pub scale: f32,
+    pub g: f32,
+    pub emission_scale: f32,
+    pub temperature_scale: f32,
+    pub temperature_offset: f32,
+    pub density_max: f32,
+
+    pub medium_type: u32,
+}
+
+impl RenderMedium {
+    pub fn to_medium(&self, table: &RgbToSpectrumTable) -> Medium {
+        let sigma_a = RgbUnboundedSpectrum::new(table, self.sigma_a.x, self.sigma_a.y, self.sigma_a.z);
+        let sigma_s = RgbUnboundedSpectrum::new(table, self.sigma_s.x, self.sigma_s.y, self.sigma_s.z);
+
+        match MediumType::from(self.medium_type) {
+            MediumType::Homogeneous => {
+                let emitted = RgbUnboundedSpectrum::new(table, self.emitted.x, self.emitted.y, self.emitted.z);
+                Medium::Homogeneous(HomogeneousMedium::new(sigma_a, sigma_s, self.scale, emitted, self.emission_scale, self.g))
+            }
+            MediumType::Grid => {
+                let density_grid = SampledGrid::new(self.density_grid.xyz(), self.density_grid.w);
+
+                let emission = if self.emission_scale > 0.0 {
+                    let temperature_grid = SampledGrid::new(self.temperature_grid.xyz(), self.temperature_grid.w);
+                    Some(BlackBodyEmission::new(temperature_grid, self.temperature_scale, self.temperature_offset, self.emission_scale))
+                } else {
+                    None
+                };
+
+                Medium::Grid(GridMedium::new(
+                    self.medium_from_render,
+                    self.bounds_min.xyz(),
+                    self.bounds_max.xyz(),
+                    sigma_a,
+                    sigma_s,
+                    self.scale,
+                    self.g,
+                    density_grid,
+                    emission,
+                    self.density_max,
+                ))
+            }
+        }
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -60,6 +155,140 @@ pub struct Light {
     // TODO: extend this from point light into full light struct
 }
 
+#[derive(Copy, Clone, Debug)]
+pub enum CameraType {
+    Perspective,
+    Orthographic,
+    Spherical,
+    Realistic,
+}
+
+impl From<u32> for CameraType {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => CameraType::Perspective,
+            1 => CameraType::Orthographic,
+            2 => CameraType::Spherical,
+            3 => CameraType::Realistic,
+            _ => panic!("Invalid camera type"),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct RenderCamera {
+    pub camera_transform: Mat4,
+    pub camera_transform_end: Mat4,
+    pub camera_from_raster: Mat4,
+    pub window: Vec4,
+    pub resolution: UVec2,
+    pub fov: f32,
+    pub lens_radius: f32,
+    pub focal_distance: f32,
+    pub shutter_open: f32,
+    pub shutter_close: f32,
+    pub camera_type: u32,
+    pub spherical_mapping: u32,
+    // Offset of the lens in the flattened distribution buffer, only read for `CameraType::Realistic`
+    pub lens_offset: u32,
+    pub aperture: RenderAperture,
+}
+
+impl RenderCamera {
+    pub fn transform(&self) -> CameraTransform {
+        CameraTransform::new(AnimatedTransform::new(self.camera_transform, self.camera_transform_end, self.shutter_open, self.shutter_close))
+    }
+
+    pub fn sample_time(&self, u: f32) -> f32 {
+        math::lerp(u, self.shutter_open, self.shutter_close)
+    }
+
+    fn thin_lens_ray(&self, distributions: &[u32], origin: Vec3, direction: Vec3, point_film: Vec2, point_lens: Vec2) -> Option<(Vec3, Vec3)> {
+        camera::thin_lens_ray(
+            origin,
+            direction,
+            self.lens_radius,
+            self.focal_distance,
+            &self.aperture,
+            distributions,
+            point_film,
+            point_lens,
+            self.resolution,
+        )
+    }
+
+    // Render space ray with its weight, which is only below one where a realistic lens vignettes the film
+    pub fn gen_ray(&self, distributions: &[u32], point_film: Vec2, point_lens: Vec2, time: f32, lambda: &SampledWavelengths) -> Option<(Vec3, Vec3, SampledSpectrum)> {
+        let unit_weight = SampledSpectrum::uniform(1.0);
+
+        let (origin, direction, weight) = match CameraType::from(self.camera_type) {
+            CameraType::Perspective => {
+                let (origin, direction) = self.thin_lens_ray(distributions, Vec3::ZERO, camera::perspective_direction(&self.camera_from_raster, point_film), point_film, point_lens)?;
+                (origin, direction, unit_weight)
+            }
+            CameraType::Orthographic => {
+                let (origin, direction) = self.thin_lens_ray(distributions, camera::orthographic_origin(&self.camera_from_raster, point_film), Vec3::Z, point_film, point_lens)?;
+                (origin, direction, unit_weight)
+            }
+            CameraType::Spherical => (
+                Vec3::ZERO,
+                camera::spherical_direction(SphericalMapping::from(self.spherical_mapping), point_film, self.resolution),
+                unit_weight,
+            ),
+            CameraType::Realistic => LensSlice::new(distributions, self.lens_offset).gen_ray(point_film, self.resolution, point_lens, lambda)?,
+        };
+
+        let render_from_camera = if self.camera_transform == self.camera_transform_end {
+            self.camera_transform
+        } else {
+            self.transform().render_from_camera(time)
+        };
+
+        Some((render_from_camera.transform_point3(origin), render_from_camera.transform_vector3(direction).normalize(), weight))
+    }
+
+    pub fn base_parameters(&self, film: Film, medium: Option<Medium>) -> CameraBaseParameters {
+        CameraBaseParameters {
+            camera_transform: self.transform(),
+            shutter_open: self.shutter_open,
+            shutter_close: self.shutter_close,
+            film,
+            medium,
+        }
+    }
+
+    pub fn to_camera<'a>(&self, film: Film, medium: Option<Medium>, distributions: &'a [u32]) -> Camera<'a> {
+        let params = self.base_parameters(film, medium);
+        let window_min = self.window.xy();
+        let window_max = self.window.zw();
+
+        match CameraType::from(self.camera_type) {
+            CameraType::Perspective => Camera::Perspective(PerspectiveCamera::new(
+                params,
+                self.fov,
+                window_min,
+                window_max,
+                self.lens_radius,
+                self.focal_distance,
+                self.aperture,
+                distributions,
+            )),
+            CameraType::Orthographic => Camera::Orthographic(OrthographicCamera::new(
+                params,
+                window_min,
+                window_max,
+                self.lens_radius,
+                self.focal_distance,
+                self.aperture,
+                distributions,
+            )),
+            CameraType::Spherical => Camera::Spherical(SphericalCamera::new(params, SphericalMapping::from(self.spherical_mapping))),
+            CameraType::Realistic => Camera::Realistic(RealisticCamera::new(params, LensSlice::new(distributions, self.lens_offset))),
+        }
+    }
+}
+
 #[inline]
 pub fn get_instance_metadata(metadata: &[InstanceMetadata], instance_custom_index: u32) -> InstanceMetadata {
     metadata[instance_custom_index as usize]
@@ -71,6 +300,15 @@ pub fn get_global_index_offset(metadata: &[InstanceMetadata], instance_custom_in
     (instance_index_offset + 3 * primitive_id) as _
 }
 
+#[inline]
+pub fn get_medium(media: &[RenderMedium], medium_index: u32, table: &RgbToSpectrumTable) -> Option<Medium> {
+    if medium_index == NO_MEDIUM {
+        None
+    } else {
+        Some(media[medium_index as usize].to_medium(table))
+    }
+}
+
 #[inline]
 pub fn get_instance_material(materials: &[RenderMaterial], instance_custom_index: u32) -> &RenderMaterial {
     materials.index(instance_custom_index as usize)
@@ -0,0 +1,222 @@
+use crate::bsdf::fresnel::{self, TrowbridgeReitzDistribution};
+use crate::bsdf::{Bsdf, BsdfFlags, BsdfReflTransFlags, BsdfSample, TransportMode};
+use crate::spectrum::SampledSpectrum;
+use crate::util::math;
+use crate::util::vector::BasicVecOperation;
+use spirv_std::glam::{Vec2, Vec3};
+use spirv_std::num_traits::Float;
+
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct DielectricBsdf {
+    eta: f32,
+    roughness: TrowbridgeReitzDistribution,
+}
+
+impl DielectricBsdf {
+    pub fn new(eta: f32, roughness: TrowbridgeReitzDistribution) -> Self {
+        Self { eta, roughness }
+    }
+
+    fn reflection_probability(&self, reflectance: f32, sample_flags: BsdfReflTransFlags) -> Option<f32> {
+        let pr = if sample_flags.contains(BsdfReflTransFlags::REFLECTION) { reflectance } else { 0.0 };
+        let pt = if sample_flags.contains(BsdfReflTransFlags::TRANSMISSION) { 1.0 - reflectance } else { 0.0 };
+
+        if pr == 0.0 && pt == 0.0 {
+            None
+        } else {
+            Some(pr / (pr + pt))
+        }
+    }
+
+    fn generalized_half_vector(&self, output_direction: Vec3, input_direction: Vec3) -> Option<(Vec3, f32, bool)> {
+        let cos_theta_o = output_direction.cos_theta();
+        let cos_theta_i = input_direction.cos_theta();
+        let reflect = cos_theta_i * cos_theta_o > 0.0;
+
+        let etap = if reflect {
+            1.0
+        } else if cos_theta_o > 0.0 {
+            self.eta
+        } else {
+            1.0 / self.eta
+        };
+
+        let sub_normal = input_direction * etap + output_direction;
+
+        if cos_theta_i == 0.0 || cos_theta_o == 0.0 || sub_normal.length_squared() == 0.0 {
+            return None;
+        }
+
+        let sub_normal = sub_normal.normalize();
+        let sub_normal = if sub_normal.z < 0.0 { -sub_normal } else { sub_normal };
+
+        if sub_normal.dot(input_direction) * cos_theta_i < 0.0 || sub_normal.dot(output_direction) * cos_theta_o < 0.0 {
+            None
+        } else {
+            Some((sub_normal, etap, reflect))
+        }
+    }
+}
+
+impl Bsdf for DielectricBsdf {
+    fn flags(&self) -> BsdfFlags {
+        let flags = if self.eta == 1.0 {
+            BsdfFlags::TRANSMISSION
+        } else {
+            BsdfFlags::REFLECTION | BsdfFlags::TRANSMISSION
+        };
+
+        if self.roughness.effectively_smooth() {
+            flags | BsdfFlags::SPECULAR
+        } else {
+            flags | BsdfFlags::GLOSSY
+        }
+    }
+
+    fn bsdf_func(&self, output_direction: Vec3, input_direction: Vec3, mode: TransportMode) -> SampledSpectrum {
+        if self.eta == 1.0 || self.roughness.effectively_smooth() {
+            return SampledSpectrum::trivial();
+        }
+
+        let (sub_normal, etap, reflect) = match self.generalized_half_vector(output_direction, input_direction) {
+            Some(half_vector) => half_vector,
+            None => return SampledSpectrum::trivial(),
+        };
+
+        let fresnel = fresnel::fresnel_real(output_direction.dot(sub_normal), self.eta);
+        let distribution = self.roughness.distribution(sub_normal);
+        let masking = self.roughness.masking_shadowing_func(output_direction, input_direction);
+
+        if reflect {
+            SampledSpectrum::uniform(distribution * masking * fresnel / (4.0 * input_direction.cos_theta() * output_direction.cos_theta()).abs())
+        } else {
+            let denom = math::sqr(input_direction.dot(sub_normal) + output_direction.dot(sub_normal) / etap) * input_direction.cos_theta() * output_direction.cos_theta();
+            let mut func = distribution * (1.0 - fresnel) * masking * (input_direction.dot(sub_normal) * output_direction.dot(sub_normal) / denom).abs();
+
+            if mode == TransportMode::Radiance {
+                func /= math::sqr(etap);
+            }
+
+            SampledSpectrum::uniform(func)
+        }
+    }
+
+    fn sample(&self, output_direction: Vec3, uc: f32, u: Vec2, mode: TransportMode, sample_flags: BsdfReflTransFlags) -> Option<BsdfSample> {
+        if self.eta == 1.0 || self.roughness.effectively_smooth() {
+            let reflectance = fresnel::fresnel_real(output_direction.cos_theta(), self.eta);
+            let reflection_probability = self.reflection_probability(reflectance, sample_flags)?;
+
+            if uc < reflection_probability {
+                let input_direction = Vec3::new(-output_direction.x, -output_direction.y, output_direction.z);
+
+                Some(BsdfSample {
+                    sampled_func: SampledSpectrum::uniform(reflectance / input_direction.cos_theta().abs()),
+                    input_direction,
+                    pdf: reflection_probability,
+                    flags: BsdfFlags::SPECULAR_REFLECTION,
+                    eta: 1.0,
+                    pdf_is_proportional: false,
+                })
+            } else {
+                let (etap, input_direction) = fresnel::refract(output_direction, Vec3::Z, self.eta)?;
+                let mut func = (1.0 - reflectance) / input_direction.cos_theta().abs();
+
+                if mode == TransportMode::Radiance {
+                    func /= math::sqr(etap);
+                }
+
+                Some(BsdfSample {
+                    sampled_func: SampledSpectrum::uniform(func),
+                    input_direction,
+                    pdf: 1.0 - reflection_probability,
+                    flags: BsdfFlags::SPECULAR_TRANSMISSION,
+                    eta: etap,
+                    pdf_is_proportional: false,
+                })
+            }
+        } else {
+            let sub_normal = self.roughness.sample(output_direction, u);
+            let reflectance = fresnel::fresnel_real(output_direction.dot(sub_normal), self.eta);
+            let reflection_probability = self.reflection_probability(reflectance, sample_flags)?;
+
+            if uc < reflection_probability {
+                let input_direction = -output_direction + 2.0 * output_direction.dot(sub_normal) * sub_normal;
+
+                if output_direction.z * input_direction.z <= 0.0 {
+                    return None;
+                }
+
+                let pdf = self.roughness.pdf(output_direction, sub_normal) / (4.0 * output_direction.dot(sub_normal).abs()) * reflection_probability;
+                let func = self.roughness.distribution(sub_normal) * self.roughness.masking_shadowing_func(output_direction, input_direction) * reflectance
+                    / (4.0 * input_direction.cos_theta() * output_direction.cos_theta());
+
+                Some(BsdfSample {
+                    sampled_func: SampledSpectrum::uniform(func),
+                    input_direction,
+                    pdf,
+                    flags: BsdfFlags::GLOSSY_REFLECTION,
+                    eta: 1.0,
+                    pdf_is_proportional: false,
+                })
+            } else {
+                let (etap, input_direction) = fresnel::refract(output_direction, sub_normal, self.eta)?;
+
+                if output_direction.z * input_direction.z > 0.0 || input_direction.z == 0.0 {
+                    return None;
+                }
+
+                let denom = math::sqr(input_direction.dot(sub_normal) + output_direction.dot(sub_normal) / etap);
+                let dwm_dwi = input_direction.dot(sub_normal).abs() / denom;
+                let pdf = self.roughness.pdf(output_direction, sub_normal) * dwm_dwi * (1.0 - reflection_probability);
+
+                let mut func = (1.0 - reflectance)
+                    * self.roughness.distribution(sub_normal)
+                    * self.roughness.masking_shadowing_func(output_direction, input_direction)
+                    * (input_direction.dot(sub_normal) * output_direction.dot(sub_normal) / (input_direction.cos_theta() * output_direction.cos_theta() * denom)).abs();
+
+                if mode == TransportMode::Radiance {
+                    func /= math::sqr(etap);
+                }
+
+                Some(BsdfSample {
+                    sampled_func: SampledSpectrum::uniform(func),
+                    input_direction,
+                    pdf,
+                    flags: BsdfFlags::GLOSSY_TRANSMISSION,
+                    eta: etap,
+                    pdf_is_proportional: false,
+                })
+            }
+        }
+    }
+
+    fn pdf(&self, output_direction: Vec3, input_direction: Vec3, _: TransportMode, sample_flags: BsdfReflTransFlags) -> f32 {
+        if self.eta == 1.0 || self.roughness.effectively_smooth() {
+            return 0.0;
+        }
+
+        let (sub_normal, etap, reflect) = match self.generalized_half_vector(output_direction, input_direction) {
+            Some(half_vector) => half_vector,
+            None => return 0.0,
+        };
+
+        let reflectance = fresnel::fresnel_real(output_direction.dot(sub_normal), self.eta);
+        let reflection_probability = match self.reflection_probability(reflectance, sample_flags) {
+            Some(probability) => probability,
+            None => return 0.0,
+        };
+
+        if reflect {
+            self.roughness.pdf(output_direction, sub_normal) / (4.0 * output_direction.dot(sub_normal).abs()) * reflection_probability
+        } else {
+            let denom = math::sqr(input_direction.dot(sub_normal) + output_direction.dot(sub_normal) / etap);
+            let dwm_dwi = input_direction.dot(sub_normal).abs() / denom;
+            self.roughness.pdf(output_direction, sub_normal) * dwm_dwi * (1.0 - reflection_probability)
+        }
+    }
+
+    fn regularize(&mut self) {
+        self.roughness.regularize()
+    }
+}
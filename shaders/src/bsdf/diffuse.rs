@@ -10,6 +10,12 @@ pub struct LambertianBsdf {
     reflect: SampledSpectrum,
 }
 
+impl LambertianBsdf {
+    pub fn new(reflect: SampledSpectrum) -> Self {
+        Self { reflect }
+    }
+}
+
 impl Bsdf for LambertianBsdf {
     fn flags(&self) -> BsdfFlags {
         if self.reflect.is_nontrivial() {
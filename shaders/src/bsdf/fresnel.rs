@@ -9,25 +9,9 @@ use spirv_std::num_traits::Float;
 use spirv_std::glam::{Vec2, Vec3};
 
 #[inline]
-pub fn refract(input_direction: Vec3, mut normal: Vec3, mut eta: f32) -> Option<(f32, Vec3)> {
-    let mut cos_theta_i = normal.dot(input_direction);
-
-    if cos_theta_i < 0.0 {
-        eta = 1.0 / eta;
-        cos_theta_i = -cos_theta_i;
-        normal = -normal;
-    }
-
-    let sin_theta_i_sqr = (1.0 - cos_theta_i * cos_theta_i).max(0.0);
-    let sin_theta_t_sqr = sin_theta_i_sqr / (eta * eta);
-
-    if sin_theta_t_sqr >= 1.0 {
-        None
-    } else {
-        let cos_theta_t = (1.0 - sin_theta_t_sqr).sqrt();
-
-        Some((eta, -input_direction / eta + (cos_theta_i / eta - cos_theta_t) * normal))
-    }
+pub fn refract(input_direction: Vec3, normal: Vec3, eta: f32) -> Option<(f32, Vec3)> {
+    let (eta, transmitted) = optics_common::lens::refract(input_direction.to_array(), normal.to_array(), eta)?;
+    Some((eta, Vec3::from_array(transmitted)))
 }
 
 #[inline]
@@ -65,7 +49,7 @@ pub fn fresnel_complex(mut cos_theta_i: f32, eta: Complex32) -> f32 {
     let r_parallel = (eta * cos_theta_i - cos_theta_t) / (eta * cos_theta_i + cos_theta_t);
     let r_perpendicular = (cos_theta_i - eta * cos_theta_t) / (cos_theta_i + eta * cos_theta_t);
 
-    (r_parallel.norm() + r_perpendicular.norm()) * 0.5
+    (r_parallel.norm_sqr() + r_perpendicular.norm_sqr()) * 0.5
 }
 
 #[inline]
@@ -112,6 +96,10 @@ impl TrowbridgeReitzDistribution {
         }
     }
 
+    pub fn roughness_to_alpha(roughness: f32) -> f32 {
+        roughness.sqrt()
+    }
+
     pub fn effectively_smooth(&self) -> bool {
         self.alpha_x.max(self.alpha_y) < 1.0e-3
     }
@@ -173,6 +161,7 @@ impl TrowbridgeReitzDistribution {
     }
 }
 
+#[derive(Clone, Copy)]
 #[repr(C)]
 pub struct ConductorBsdf {
     eta_re: SampledSpectrum,
@@ -180,6 +169,22 @@ pub struct ConductorBsdf {
     roughness: TrowbridgeReitzDistribution,
 }
 
+impl ConductorBsdf {
+    pub fn new(eta_re: SampledSpectrum, eta_im: SampledSpectrum, roughness: TrowbridgeReitzDistribution) -> Self {
+        Self { eta_re, eta_im, roughness }
+    }
+
+    // Picks eta = 1 and the absorption k that give `reflectance` at normal incidence, so an artist color can drive a conductor
+    pub fn from_reflectance(reflectance: SampledSpectrum, roughness: TrowbridgeReitzDistribution) -> Self {
+        let eta_im: [f32; N_SAMPLES] = array::from_fn(|i| {
+            let r = reflectance[i].clamp(0.0, 0.9999);
+            2.0 * r.sqrt() / (1.0 - r).sqrt()
+        });
+
+        Self::new(SampledSpectrum::uniform(1.0), SampledSpectrum::from_array(eta_im), roughness)
+    }
+}
+
 impl Bsdf for ConductorBsdf {
     fn flags(&self) -> BsdfFlags {
         if self.roughness.effectively_smooth() {
@@ -213,7 +218,7 @@ impl Bsdf for ConductorBsdf {
     }
 
     fn sample(&self, output_direction: Vec3, _: f32, u: Vec2, _: TransportMode, sample_flags: BsdfReflTransFlags) -> Option<BsdfSample> {
-        if !sample_flags.contains(BsdfReflTransFlags::REFLECTION) {
+        if !sample_flags.contains(BsdfReflTransFlags::REFLECTION) || output_direction.z == 0.0 {
             return None;
         }
 
@@ -222,7 +227,7 @@ impl Bsdf for ConductorBsdf {
             let fresnel = fresnel_complex_sampled(input_direction.cos_theta().abs(), self.eta_re, self.eta_im);
 
             return Some(BsdfSample {
-                sampled_func: fresnel,
+                sampled_func: fresnel / input_direction.cos_theta().abs(),
                 input_direction,
                 pdf: 1.0,
                 flags: BsdfFlags::SPECULAR_REFLECTION,
@@ -231,12 +236,8 @@ impl Bsdf for ConductorBsdf {
             });
         }
 
-        if output_direction.z == 0.0 {
-            return None;
-        }
-
         let sub_normal = self.roughness.sample(output_direction, u);
-        let input_direction = output_direction.reflect(sub_normal);
+        let input_direction = -output_direction.reflect(sub_normal);
 
         if input_direction.z * output_direction.z <= 0.0 {
             return None;
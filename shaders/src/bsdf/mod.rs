@@ -1,3 +1,4 @@
+pub mod dielectric;
 pub mod diffuse;
 pub mod fresnel;
 
@@ -39,6 +40,7 @@ bitflags! {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TransportMode {
     Radiance,
     Importance,
@@ -47,12 +49,12 @@ pub enum TransportMode {
 #[derive(Clone, Copy)]
 #[repr(C)]
 pub struct BsdfSample {
-    sampled_func: SampledSpectrum,
-    input_direction: Vec3,
-    pdf: f32,
-    flags: BsdfFlags,
-    eta: f32,
-    pdf_is_proportional: bool,
+    pub sampled_func: SampledSpectrum,
+    pub input_direction: Vec3,
+    pub pdf: f32,
+    pub flags: BsdfFlags,
+    pub eta: f32,
+    pub pdf_is_proportional: bool,
 }
 
 pub trait Bsdf {
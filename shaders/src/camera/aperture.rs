@@ -0,0 +1,62 @@
+use crate::util::sampling;
+use crate::util::sampling::PiecewiseConstant2DSlice;
+use spirv_std::glam::{UVec2, Vec2};
+
+#[derive(Copy, Clone, PartialEq)]
+pub enum ApertureType {
+    Circular,
+    Polygon,
+    Image,
+}
+
+impl From<u32> for ApertureType {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => ApertureType::Circular,
+            1 => ApertureType::Polygon,
+            2 => ApertureType::Image,
+            _ => panic!(),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct RenderAperture {
+    pub aperture_type: u32,
+    pub blade_count: u32,
+    pub rotation: f32,
+    pub cats_eye: f32,
+    // Offset of the image distribution in the flattened distribution buffer, only read for `ApertureType::Image`
+    pub image_offset: u32,
+}
+
+impl RenderAperture {
+    // Returns a point on the aperture scaled to the unit disk, or None when the sample is clipped by cat's-eye vignetting
+    pub fn sample(&self, distributions: &[u32], u: Vec2, point_film: Vec2, resolution: UVec2) -> Option<Vec2> {
+        let point_lens = match ApertureType::from(self.aperture_type) {
+            ApertureType::Circular => sampling::sample_uniform_disk_concentric(u),
+            ApertureType::Polygon => sampling::sample_regular_polygon(self.blade_count, self.rotation, u),
+            ApertureType::Image => {
+                let (point, pdf) = PiecewiseConstant2DSlice::new(distributions, self.image_offset).sample(u);
+
+                if pdf == 0.0 {
+                    return None;
+                }
+
+                point * 2.0 - Vec2::ONE
+            }
+        };
+
+        if self.cats_eye > 0.0 {
+            let half_resolution = 0.5 * Vec2::new(resolution.x as f32, resolution.y as f32);
+            let offset = self.cats_eye * (point_film - half_resolution) / half_resolution.length();
+
+            if (point_lens - offset).length_squared() > 1.0 {
+                return None;
+            }
+        }
+
+        Some(point_lens)
+    }
+}
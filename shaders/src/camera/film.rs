@@ -2,137 +2,67 @@ use crate::camera::filter::FilmFilter;
 use crate::spectrum::color::RgbColor;
 use crate::spectrum::color_space::RgbColorSpace;
 use crate::spectrum::{DenselySampledSpectrum, DiscreteSpectrum, ISpectrum, SampledSpectrum, SampledWavelengths, CIE_X_SPECTRUM, CIE_Y_SPECTRUM, CIE_Z_SPECTRUM, LAMBDA_DENSELY_COUNT, LAMBDA_MIN};
+#[cfg(target_arch = "spirv")]
+use core::arch::asm;
 use core::array;
 use core::ops::Deref;
-use spirv_std::glam::{Mat3, UVec2, Vec2, Vec3, Vec4, Vec4Swizzles};
+use optics_common::calibration::{self, SWATCH_COUNT};
+use optics_common::film::from_splat_fixed_point;
+#[cfg(target_arch = "spirv")]
+use optics_common::film::to_splat_fixed_point;
+use spirv_std::glam::{Mat3, Mat4, UVec2, Vec2, Vec3, Vec4, Vec4Swizzles};
+use spirv_std::num_traits::Float;
 use spirv_std::Image;
 
-pub const SWATCH_REFLECTANCE_COUNT: usize = 24;
-
-pub const SWATCH_REFLECTANCES: [DiscreteSpectrum; SWATCH_REFLECTANCE_COUNT] = unsafe {
-    [
-        DiscreteSpectrum::from_interleaved::<36, 72>([
-            380.0, 0.055, 390.0, 0.058, 400.0, 0.061, 410.0, 0.062, 420.0, 0.062, 430.0, 0.062, 440.0, 0.062, 450.0, 0.062, 460.0, 0.062, 470.0, 0.062, 480.0, 0.062, 490.0, 0.063, 500.0, 0.065,
-            510.0, 0.070, 520.0, 0.076, 530.0, 0.079, 540.0, 0.081, 550.0, 0.084, 560.0, 0.091, 570.0, 0.103, 580.0, 0.119, 590.0, 0.134, 600.0, 0.143, 610.0, 0.147, 620.0, 0.151, 630.0, 0.158,
-            640.0, 0.168, 650.0, 0.179, 660.0, 0.188, 670.0, 0.190, 680.0, 0.186, 690.0, 0.181, 700.0, 0.182, 710.0, 0.187, 720.0, 0.196, 730.0, 0.209,
-        ]),
-        DiscreteSpectrum::from_interleaved::<36, 72>([
-            380.0, 0.117, 390.0, 0.143, 400.0, 0.175, 410.0, 0.191, 420.0, 0.196, 430.0, 0.199, 440.0, 0.204, 450.0, 0.213, 460.0, 0.228, 470.0, 0.251, 480.0, 0.280, 490.0, 0.309, 500.0, 0.329,
-            510.0, 0.333, 520.0, 0.315, 530.0, 0.286, 540.0, 0.273, 550.0, 0.276, 560.0, 0.277, 570.0, 0.289, 580.0, 0.339, 590.0, 0.420, 600.0, 0.488, 610.0, 0.525, 620.0, 0.546, 630.0, 0.562,
-            640.0, 0.578, 650.0, 0.595, 660.0, 0.612, 670.0, 0.625, 680.0, 0.638, 690.0, 0.656, 700.0, 0.678, 710.0, 0.700, 720.0, 0.717, 730.0, 0.734,
-        ]),
-        DiscreteSpectrum::from_interleaved::<36, 72>([
-            380.0, 0.130, 390.0, 0.177, 400.0, 0.251, 410.0, 0.306, 420.0, 0.324, 430.0, 0.330, 440.0, 0.333, 450.0, 0.331, 460.0, 0.323, 470.0, 0.311, 480.0, 0.298, 490.0, 0.285, 500.0, 0.269,
-            510.0, 0.250, 520.0, 0.231, 530.0, 0.214, 540.0, 0.199, 550.0, 0.185, 560.0, 0.169, 570.0, 0.157, 580.0, 0.149, 590.0, 0.145, 600.0, 0.142, 610.0, 0.141, 620.0, 0.141, 630.0, 0.141,
-            640.0, 0.143, 650.0, 0.147, 660.0, 0.152, 670.0, 0.154, 680.0, 0.150, 690.0, 0.144, 700.0, 0.136, 710.0, 0.132, 720.0, 0.135, 730.0, 0.147,
-        ]),
-        DiscreteSpectrum::from_interleaved::<36, 72>([
-            380.0, 0.051, 390.0, 0.054, 400.0, 0.056, 410.0, 0.057, 420.0, 0.058, 430.0, 0.059, 440.0, 0.060, 450.0, 0.061, 460.0, 0.062, 470.0, 0.063, 480.0, 0.065, 490.0, 0.067, 500.0, 0.075,
-            510.0, 0.101, 520.0, 0.145, 530.0, 0.178, 540.0, 0.184, 550.0, 0.170, 560.0, 0.149, 570.0, 0.133, 580.0, 0.122, 590.0, 0.115, 600.0, 0.109, 610.0, 0.105, 620.0, 0.104, 630.0, 0.106,
-            640.0, 0.109, 650.0, 0.112, 660.0, 0.114, 670.0, 0.114, 680.0, 0.112, 690.0, 0.112, 700.0, 0.115, 710.0, 0.120, 720.0, 0.125, 730.0, 0.130,
-        ]),
-        DiscreteSpectrum::from_interleaved::<36, 72>([
-            380.0, 0.144, 390.0, 0.198, 400.0, 0.294, 410.0, 0.375, 420.0, 0.408, 430.0, 0.421, 440.0, 0.426, 450.0, 0.426, 460.0, 0.419, 470.0, 0.403, 480.0, 0.379, 490.0, 0.346, 500.0, 0.311,
-            510.0, 0.281, 520.0, 0.254, 530.0, 0.229, 540.0, 0.214, 550.0, 0.208, 560.0, 0.202, 570.0, 0.194, 580.0, 0.193, 590.0, 0.200, 600.0, 0.214, 610.0, 0.230, 620.0, 0.241, 630.0, 0.254,
-            640.0, 0.279, 650.0, 0.313, 660.0, 0.348, 670.0, 0.366, 680.0, 0.366, 690.0, 0.359, 700.0, 0.358, 710.0, 0.365, 720.0, 0.377, 730.0, 0.398,
-        ]),
-        DiscreteSpectrum::from_interleaved::<36, 72>([
-            380.0, 0.136, 390.0, 0.179, 400.0, 0.247, 410.0, 0.297, 420.0, 0.320, 430.0, 0.337, 440.0, 0.355, 450.0, 0.381, 460.0, 0.419, 470.0, 0.466, 480.0, 0.510, 490.0, 0.546, 500.0, 0.567,
-            510.0, 0.574, 520.0, 0.569, 530.0, 0.551, 540.0, 0.524, 550.0, 0.488, 560.0, 0.445, 570.0, 0.400, 580.0, 0.350, 590.0, 0.299, 600.0, 0.252, 610.0, 0.221, 620.0, 0.204, 630.0, 0.196,
-            640.0, 0.191, 650.0, 0.188, 660.0, 0.191, 670.0, 0.199, 680.0, 0.212, 690.0, 0.223, 700.0, 0.232, 710.0, 0.233, 720.0, 0.229, 730.0, 0.229,
-        ]),
-        DiscreteSpectrum::from_interleaved::<36, 72>([
-            380.0, 0.054, 390.0, 0.054, 400.0, 0.053, 410.0, 0.054, 420.0, 0.054, 430.0, 0.055, 440.0, 0.055, 450.0, 0.055, 460.0, 0.056, 470.0, 0.057, 480.0, 0.058, 490.0, 0.061, 500.0, 0.068,
-            510.0, 0.089, 520.0, 0.125, 530.0, 0.154, 540.0, 0.174, 550.0, 0.199, 560.0, 0.248, 570.0, 0.335, 580.0, 0.444, 590.0, 0.538, 600.0, 0.587, 610.0, 0.595, 620.0, 0.591, 630.0, 0.587,
-            640.0, 0.584, 650.0, 0.584, 660.0, 0.590, 670.0, 0.603, 680.0, 0.620, 690.0, 0.639, 700.0, 0.655, 710.0, 0.663, 720.0, 0.663, 730.0, 0.667,
-        ]),
-        DiscreteSpectrum::from_interleaved::<36, 72>([
-            380.0, 0.122, 390.0, 0.164, 400.0, 0.229, 410.0, 0.286, 420.0, 0.327, 430.0, 0.361, 440.0, 0.388, 450.0, 0.400, 460.0, 0.392, 470.0, 0.362, 480.0, 0.316, 490.0, 0.260, 500.0, 0.209,
-            510.0, 0.168, 520.0, 0.138, 530.0, 0.117, 540.0, 0.104, 550.0, 0.096, 560.0, 0.090, 570.0, 0.086, 580.0, 0.084, 590.0, 0.084, 600.0, 0.084, 610.0, 0.084, 620.0, 0.084, 630.0, 0.085,
-            640.0, 0.090, 650.0, 0.098, 660.0, 0.109, 670.0, 0.123, 680.0, 0.143, 690.0, 0.169, 700.0, 0.205, 710.0, 0.244, 720.0, 0.287, 730.0, 0.332,
-        ]),
-        DiscreteSpectrum::from_interleaved::<36, 72>([
-            380.0, 0.096, 390.0, 0.115, 400.0, 0.131, 410.0, 0.135, 420.0, 0.133, 430.0, 0.132, 440.0, 0.130, 450.0, 0.128, 460.0, 0.125, 470.0, 0.120, 480.0, 0.115, 490.0, 0.110, 500.0, 0.105,
-            510.0, 0.100, 520.0, 0.095, 530.0, 0.093, 540.0, 0.092, 550.0, 0.093, 560.0, 0.096, 570.0, 0.108, 580.0, 0.156, 590.0, 0.265, 600.0, 0.399, 610.0, 0.500, 620.0, 0.556, 630.0, 0.579,
-            640.0, 0.588, 650.0, 0.591, 660.0, 0.593, 670.0, 0.594, 680.0, 0.598, 690.0, 0.602, 700.0, 0.607, 710.0, 0.609, 720.0, 0.609, 730.0, 0.610,
-        ]),
-        DiscreteSpectrum::from_interleaved::<36, 72>([
-            380.0, 0.092, 390.0, 0.116, 400.0, 0.146, 410.0, 0.169, 420.0, 0.178, 430.0, 0.173, 440.0, 0.158, 450.0, 0.139, 460.0, 0.119, 470.0, 0.101, 480.0, 0.087, 490.0, 0.075, 500.0, 0.066,
-            510.0, 0.060, 520.0, 0.056, 530.0, 0.053, 540.0, 0.051, 550.0, 0.051, 560.0, 0.052, 570.0, 0.052, 580.0, 0.051, 590.0, 0.052, 600.0, 0.058, 610.0, 0.073, 620.0, 0.096, 630.0, 0.119,
-            640.0, 0.141, 650.0, 0.166, 660.0, 0.194, 670.0, 0.227, 680.0, 0.265, 690.0, 0.309, 700.0, 0.355, 710.0, 0.396, 720.0, 0.436, 730.0, 0.478,
-        ]),
-        DiscreteSpectrum::from_interleaved::<36, 72>([
-            380.0, 0.061, 390.0, 0.061, 400.0, 0.062, 410.0, 0.063, 420.0, 0.064, 430.0, 0.066, 440.0, 0.069, 450.0, 0.075, 460.0, 0.085, 470.0, 0.105, 480.0, 0.139, 490.0, 0.192, 500.0, 0.271,
-            510.0, 0.376, 520.0, 0.476, 530.0, 0.531, 540.0, 0.549, 550.0, 0.546, 560.0, 0.528, 570.0, 0.504, 580.0, 0.471, 590.0, 0.428, 600.0, 0.381, 610.0, 0.347, 620.0, 0.327, 630.0, 0.318,
-            640.0, 0.312, 650.0, 0.310, 660.0, 0.314, 670.0, 0.327, 680.0, 0.345, 690.0, 0.363, 700.0, 0.376, 710.0, 0.381, 720.0, 0.378, 730.0, 0.379,
-        ]),
-        DiscreteSpectrum::from_interleaved::<36, 72>([
-            380.0, 0.063, 390.0, 0.063, 400.0, 0.063, 410.0, 0.064, 420.0, 0.064, 430.0, 0.064, 440.0, 0.065, 450.0, 0.066, 460.0, 0.067, 470.0, 0.068, 480.0, 0.071, 490.0, 0.076, 500.0, 0.087,
-            510.0, 0.125, 520.0, 0.206, 530.0, 0.305, 540.0, 0.383, 550.0, 0.431, 560.0, 0.469, 570.0, 0.518, 580.0, 0.568, 590.0, 0.607, 600.0, 0.628, 610.0, 0.637, 620.0, 0.640, 630.0, 0.642,
-            640.0, 0.645, 650.0, 0.648, 660.0, 0.651, 670.0, 0.653, 680.0, 0.657, 690.0, 0.664, 700.0, 0.673, 710.0, 0.680, 720.0, 0.684, 730.0, 0.688,
-        ]),
-        DiscreteSpectrum::from_interleaved::<36, 72>([
-            380.0, 0.066, 390.0, 0.079, 400.0, 0.102, 410.0, 0.146, 420.0, 0.200, 430.0, 0.244, 440.0, 0.282, 450.0, 0.309, 460.0, 0.308, 470.0, 0.278, 480.0, 0.231, 490.0, 0.178, 500.0, 0.130,
-            510.0, 0.094, 520.0, 0.070, 530.0, 0.054, 540.0, 0.046, 550.0, 0.042, 560.0, 0.039, 570.0, 0.038, 580.0, 0.038, 590.0, 0.038, 600.0, 0.038, 610.0, 0.039, 620.0, 0.039, 630.0, 0.040,
-            640.0, 0.041, 650.0, 0.042, 660.0, 0.044, 670.0, 0.045, 680.0, 0.046, 690.0, 0.046, 700.0, 0.048, 710.0, 0.052, 720.0, 0.057, 730.0, 0.065,
-        ]),
-        DiscreteSpectrum::from_interleaved::<36, 72>([
-            380.0, 0.052, 390.0, 0.053, 400.0, 0.054, 410.0, 0.055, 420.0, 0.057, 430.0, 0.059, 440.0, 0.061, 450.0, 0.066, 460.0, 0.075, 470.0, 0.093, 480.0, 0.125, 490.0, 0.178, 500.0, 0.246,
-            510.0, 0.307, 520.0, 0.337, 530.0, 0.334, 540.0, 0.317, 550.0, 0.293, 560.0, 0.262, 570.0, 0.230, 580.0, 0.198, 590.0, 0.165, 600.0, 0.135, 610.0, 0.115, 620.0, 0.104, 630.0, 0.098,
-            640.0, 0.094, 650.0, 0.092, 660.0, 0.093, 670.0, 0.097, 680.0, 0.102, 690.0, 0.108, 700.0, 0.113, 710.0, 0.115, 720.0, 0.114, 730.0, 0.114,
-        ]),
-        DiscreteSpectrum::from_interleaved::<36, 72>([
-            380.0, 0.050, 390.0, 0.049, 400.0, 0.048, 410.0, 0.047, 420.0, 0.047, 430.0, 0.047, 440.0, 0.047, 450.0, 0.047, 460.0, 0.046, 470.0, 0.045, 480.0, 0.044, 490.0, 0.044, 500.0, 0.045,
-            510.0, 0.046, 520.0, 0.047, 530.0, 0.048, 540.0, 0.049, 550.0, 0.050, 560.0, 0.054, 570.0, 0.060, 580.0, 0.072, 590.0, 0.104, 600.0, 0.178, 610.0, 0.312, 620.0, 0.467, 630.0, 0.581,
-            640.0, 0.644, 650.0, 0.675, 660.0, 0.690, 670.0, 0.698, 680.0, 0.706, 690.0, 0.715, 700.0, 0.724, 710.0, 0.730, 720.0, 0.734, 730.0, 0.738,
-        ]),
-        DiscreteSpectrum::from_interleaved::<36, 72>([
-            380.0, 0.058, 390.0, 0.054, 400.0, 0.052, 410.0, 0.052, 420.0, 0.053, 430.0, 0.054, 440.0, 0.056, 450.0, 0.059, 460.0, 0.067, 470.0, 0.081, 480.0, 0.107, 490.0, 0.152, 500.0, 0.225,
-            510.0, 0.336, 520.0, 0.462, 530.0, 0.559, 540.0, 0.616, 550.0, 0.650, 560.0, 0.672, 570.0, 0.694, 580.0, 0.710, 590.0, 0.723, 600.0, 0.731, 610.0, 0.739, 620.0, 0.746, 630.0, 0.752,
-            640.0, 0.758, 650.0, 0.764, 660.0, 0.769, 670.0, 0.771, 680.0, 0.776, 690.0, 0.782, 700.0, 0.790, 710.0, 0.796, 720.0, 0.799, 730.0, 0.804,
-        ]),
-        DiscreteSpectrum::from_interleaved::<36, 72>([
-            380.0, 0.145, 390.0, 0.195, 400.0, 0.283, 410.0, 0.346, 420.0, 0.362, 430.0, 0.354, 440.0, 0.334, 450.0, 0.306, 460.0, 0.276, 470.0, 0.248, 480.0, 0.218, 490.0, 0.190, 500.0, 0.168,
-            510.0, 0.149, 520.0, 0.127, 530.0, 0.107, 540.0, 0.100, 550.0, 0.102, 560.0, 0.104, 570.0, 0.109, 580.0, 0.137, 590.0, 0.200, 600.0, 0.290, 610.0, 0.400, 620.0, 0.516, 630.0, 0.615,
-            640.0, 0.687, 650.0, 0.732, 660.0, 0.760, 670.0, 0.774, 680.0, 0.783, 690.0, 0.793, 700.0, 0.803, 710.0, 0.812, 720.0, 0.817, 730.0, 0.825,
-        ]),
-        DiscreteSpectrum::from_interleaved::<36, 72>([
-            380.0, 0.108, 390.0, 0.141, 400.0, 0.192, 410.0, 0.236, 420.0, 0.261, 430.0, 0.286, 440.0, 0.317, 450.0, 0.353, 460.0, 0.390, 470.0, 0.426, 480.0, 0.446, 490.0, 0.444, 500.0, 0.423,
-            510.0, 0.385, 520.0, 0.337, 530.0, 0.283, 540.0, 0.231, 550.0, 0.185, 560.0, 0.146, 570.0, 0.118, 580.0, 0.101, 590.0, 0.090, 600.0, 0.082, 610.0, 0.076, 620.0, 0.074, 630.0, 0.073,
-            640.0, 0.073, 650.0, 0.074, 660.0, 0.076, 670.0, 0.077, 680.0, 0.076, 690.0, 0.075, 700.0, 0.073, 710.0, 0.072, 720.0, 0.074, 730.0, 0.079,
-        ]),
-        DiscreteSpectrum::from_interleaved::<36, 72>([
-            380.0, 0.189, 390.0, 0.255, 400.0, 0.423, 410.0, 0.660, 420.0, 0.811, 430.0, 0.862, 440.0, 0.877, 450.0, 0.884, 460.0, 0.891, 470.0, 0.896, 480.0, 0.899, 490.0, 0.904, 500.0, 0.907,
-            510.0, 0.909, 520.0, 0.911, 530.0, 0.910, 540.0, 0.911, 550.0, 0.914, 560.0, 0.913, 570.0, 0.916, 580.0, 0.915, 590.0, 0.916, 600.0, 0.914, 610.0, 0.915, 620.0, 0.918, 630.0, 0.919,
-            640.0, 0.921, 650.0, 0.923, 660.0, 0.924, 670.0, 0.922, 680.0, 0.922, 690.0, 0.925, 700.0, 0.927, 710.0, 0.930, 720.0, 0.930, 730.0, 0.933,
-        ]),
-        DiscreteSpectrum::from_interleaved::<36, 72>([
-            380.0, 0.171, 390.0, 0.232, 400.0, 0.365, 410.0, 0.507, 420.0, 0.567, 430.0, 0.583, 440.0, 0.588, 450.0, 0.590, 460.0, 0.591, 470.0, 0.590, 480.0, 0.588, 490.0, 0.588, 500.0, 0.589,
-            510.0, 0.589, 520.0, 0.591, 530.0, 0.590, 540.0, 0.590, 550.0, 0.590, 560.0, 0.589, 570.0, 0.591, 580.0, 0.590, 590.0, 0.590, 600.0, 0.587, 610.0, 0.585, 620.0, 0.583, 630.0, 0.580,
-            640.0, 0.578, 650.0, 0.576, 660.0, 0.574, 670.0, 0.572, 680.0, 0.571, 690.0, 0.569, 700.0, 0.568, 710.0, 0.568, 720.0, 0.566, 730.0, 0.566,
-        ]),
-        DiscreteSpectrum::from_interleaved::<36, 72>([
-            380.0, 0.144, 390.0, 0.192, 400.0, 0.272, 410.0, 0.331, 420.0, 0.350, 430.0, 0.357, 440.0, 0.361, 450.0, 0.363, 460.0, 0.363, 470.0, 0.361, 480.0, 0.359, 490.0, 0.358, 500.0, 0.358,
-            510.0, 0.359, 520.0, 0.360, 530.0, 0.360, 540.0, 0.361, 550.0, 0.361, 560.0, 0.360, 570.0, 0.362, 580.0, 0.362, 590.0, 0.361, 600.0, 0.359, 610.0, 0.358, 620.0, 0.355, 630.0, 0.352,
-            640.0, 0.350, 650.0, 0.348, 660.0, 0.345, 670.0, 0.343, 680.0, 0.340, 690.0, 0.338, 700.0, 0.335, 710.0, 0.334, 720.0, 0.332, 730.0, 0.331,
-        ]),
-        DiscreteSpectrum::from_interleaved::<36, 72>([
-            380.0, 0.105, 390.0, 0.131, 400.0, 0.163, 410.0, 0.180, 420.0, 0.186, 430.0, 0.190, 440.0, 0.193, 450.0, 0.194, 460.0, 0.194, 470.0, 0.192, 480.0, 0.191, 490.0, 0.191, 500.0, 0.191,
-            510.0, 0.192, 520.0, 0.192, 530.0, 0.192, 540.0, 0.192, 550.0, 0.192, 560.0, 0.192, 570.0, 0.193, 580.0, 0.192, 590.0, 0.192, 600.0, 0.191, 610.0, 0.189, 620.0, 0.188, 630.0, 0.186,
-            640.0, 0.184, 650.0, 0.182, 660.0, 0.181, 670.0, 0.179, 680.0, 0.178, 690.0, 0.176, 700.0, 0.174, 710.0, 0.173, 720.0, 0.172, 730.0, 0.171,
-        ]),
-        DiscreteSpectrum::from_interleaved::<36, 72>([
-            380.0, 0.068, 390.0, 0.077, 400.0, 0.084, 410.0, 0.087, 420.0, 0.089, 430.0, 0.090, 440.0, 0.092, 450.0, 0.092, 460.0, 0.091, 470.0, 0.090, 480.0, 0.090, 490.0, 0.090, 500.0, 0.090,
-            510.0, 0.090, 520.0, 0.090, 530.0, 0.090, 540.0, 0.090, 550.0, 0.090, 560.0, 0.090, 570.0, 0.090, 580.0, 0.090, 590.0, 0.089, 600.0, 0.089, 610.0, 0.088, 620.0, 0.087, 630.0, 0.086,
-            640.0, 0.086, 650.0, 0.085, 660.0, 0.084, 670.0, 0.084, 680.0, 0.083, 690.0, 0.083, 700.0, 0.082, 710.0, 0.081, 720.0, 0.081, 730.0, 0.081,
-        ]),
-        DiscreteSpectrum::from_interleaved::<36, 72>([
-            380.0, 0.031, 390.0, 0.032, 400.0, 0.032, 410.0, 0.033, 420.0, 0.033, 430.0, 0.033, 440.0, 0.033, 450.0, 0.033, 460.0, 0.032, 470.0, 0.032, 480.0, 0.032, 490.0, 0.032, 500.0, 0.032,
-            510.0, 0.032, 520.0, 0.032, 530.0, 0.032, 540.0, 0.032, 550.0, 0.032, 560.0, 0.032, 570.0, 0.032, 580.0, 0.032, 590.0, 0.032, 600.0, 0.032, 610.0, 0.032, 620.0, 0.032, 630.0, 0.032,
-            640.0, 0.032, 650.0, 0.032, 660.0, 0.032, 670.0, 0.032, 680.0, 0.032, 690.0, 0.032, 700.0, 0.032, 710.0, 0.032, 720.0, 0.032, 730.0, 0.033,
-        ]),
-    ]
-};
+// Keeps the relative error of black pixels finite
+pub const RELATIVE_ERROR_EPSILON: f32 = 1.0e-3;
+
+pub type SplatImage = Image!(2D, format = r32i, sampled = false);
+
+// The shared chart table, as spectra the sensor fit can project against illuminants and curves
+pub const SWATCH_REFLECTANCES: [DiscreteSpectrum; SWATCH_COUNT] = swatch_reflectances();
+
+const fn swatch_reflectances() -> [DiscreteSpectrum; SWATCH_COUNT] {
+    let lambdas = calibration::SWATCH_LAMBDAS;
+    let mut spectra = [unsafe { DiscreteSpectrum::new_unchecked(lambdas, calibration::SWATCH_REFLECTANCES[0]) }; SWATCH_COUNT];
+    let mut i = 1;
+
+    while i < SWATCH_COUNT {
+        spectra[i] = unsafe { DiscreteSpectrum::new_unchecked(lambdas, calibration::SWATCH_REFLECTANCES[i]) };
+        i += 1;
+    }
+
+    spectra
+}
+
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct ExposureSettings {
+    pub iso: f32,
+    pub shutter_time: f32,
+    pub f_number: f32,
+    pub compensation: f32,
+}
+
+impl ExposureSettings {
+    pub fn ev100(&self) -> f32 {
+        (self.f_number * self.f_number / self.shutter_time * 100.0 / self.iso).log2()
+    }
+
+    // t * S / (100 * N^2), which reduces to pbrt's exposure time * ISO / 100 at f/1
+    pub fn image_ratio(&self) -> f32 {
+        (self.compensation - self.ev100()).exp2()
+    }
+}
+
+// Mirrors `RenderSensor` of the host's sensor module
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct RenderSensor {
+    pub xyz_from_sensor_rgb: Mat4,
+    pub image_ratio: f32,
+    pub curves: [[f32; LAMBDA_DENSELY_COUNT]; 3],
+}
 
 #[derive(Clone, Copy)]
 #[repr(C)]
@@ -150,12 +80,12 @@ impl PixelSensor {
         let curve_g = DenselySampledSpectrum::new::<LAMBDA_DENSELY_COUNT>(LAMBDA_MIN as _, g);
         let curve_b = DenselySampledSpectrum::new::<LAMBDA_DENSELY_COUNT>(LAMBDA_MIN as _, b);
 
-        let rgb_camera: [Vec3; SWATCH_REFLECTANCE_COUNT] = array::from_fn(|i| Self::project_reflectance(&SWATCH_REFLECTANCES[i], sensor_illuminant, &curve_r, &curve_g, &curve_b));
+        let rgb_camera: [Vec3; SWATCH_COUNT] = array::from_fn(|i| Self::project_reflectance(&SWATCH_REFLECTANCES[i], sensor_illuminant, &curve_r, &curve_g, &curve_b));
 
         let sensor_white_g = sensor_illuminant.inner_product_densely(&curve_g);
         let sensor_white_y = sensor_illuminant.inner_product_densely(&CIE_Y_SPECTRUM);
 
-        let xyz_output: [Vec3; SWATCH_REFLECTANCE_COUNT] =
+        let xyz_output: [Vec3; SWATCH_COUNT] =
             array::from_fn(|i| sensor_white_y / sensor_white_g * Self::project_reflectance(&SWATCH_REFLECTANCES[i], &color_space.illuminant, &CIE_X_SPECTRUM, &CIE_Y_SPECTRUM, &CIE_Z_SPECTRUM));
 
         let xyz_from_sensor_rgb = Self::linear_least_square(&rgb_camera, &xyz_output);
@@ -169,6 +99,32 @@ impl PixelSensor {
         }
     }
 
+    // Uses a matrix fitted on the host from a photographed color checker instead of fitting one against the swatches
+    pub fn from_profile(xyz_from_sensor_rgb: Mat3, r: &dyn ISpectrum, g: &dyn ISpectrum, b: &dyn ISpectrum, image_ratio: f32) -> Self {
+        Self {
+            xyz_from_sensor_rgb,
+            curve_r: DenselySampledSpectrum::new::<LAMBDA_DENSELY_COUNT>(LAMBDA_MIN as _, r),
+            curve_g: DenselySampledSpectrum::new::<LAMBDA_DENSELY_COUNT>(LAMBDA_MIN as _, g),
+            curve_b: DenselySampledSpectrum::new::<LAMBDA_DENSELY_COUNT>(LAMBDA_MIN as _, b),
+            image_ratio,
+        }
+    }
+
+    // A profile loaded on the host, whose curves are already resampled to the dense grid
+    pub fn from_render_sensor(sensor: &RenderSensor) -> Self {
+        Self {
+            xyz_from_sensor_rgb: Mat3::from_mat4(sensor.xyz_from_sensor_rgb),
+            curve_r: DenselySampledSpectrum::from_values(LAMBDA_MIN as _, sensor.curves[0]),
+            curve_g: DenselySampledSpectrum::from_values(LAMBDA_MIN as _, sensor.curves[1]),
+            curve_b: DenselySampledSpectrum::from_values(LAMBDA_MIN as _, sensor.curves[2]),
+            image_ratio: sensor.image_ratio,
+        }
+    }
+
+    pub fn with_exposure(r: &dyn ISpectrum, g: &dyn ISpectrum, b: &dyn ISpectrum, color_space: &RgbColorSpace, sensor_illuminant: &dyn ISpectrum, exposure: &ExposureSettings) -> Self {
+        Self::new(r, g, b, color_space, sensor_illuminant, exposure.image_ratio())
+    }
+
     pub fn sensor_rgb(&self, mut radiance: SampledSpectrum, lambda: &SampledWavelengths) -> RgbColor {
         radiance = radiance.safe_div(lambda.pdf_spectrum());
 
@@ -219,14 +175,14 @@ impl PixelSensor {
 #[derive(Clone, Copy)]
 #[repr(C)]
 pub struct VisibleSurface {
-    albedo: SampledSpectrum,
-    point: Vec3,
-    partial_point_x: Vec3,
-    partial_point_y: Vec3,
-    geometry_normal: Vec3,
-    shading_normal: Vec3,
-    uv: Vec2,
-    time: f32,
+    pub albedo: SampledSpectrum,
+    pub point: Vec3,
+    pub partial_point_x: Vec3,
+    pub partial_point_y: Vec3,
+    pub geometry_normal: Vec3,
+    pub shading_normal: Vec3,
+    pub uv: Vec2,
+    pub time: f32,
 }
 
 pub trait IFilm {
@@ -245,11 +201,16 @@ pub trait IFilm {
     fn get_pixel_rgb(&self, point_film: UVec2, splat_scale: f32) -> RgbColor;
 
     fn get_filter(&self) -> FilmFilter;
+
+    fn relative_error(&self, point_film: UVec2) -> f32;
+
+    fn sample_count(&self, point_film: UVec2) -> u32;
 }
 
 #[derive(Clone)]
 pub enum Film {
     Rgb(RgbFilm),
+    GBuffer(GBufferFilm),
 }
 
 impl Deref for Film {
@@ -258,6 +219,7 @@ impl Deref for Film {
     fn deref(&self) -> &Self::Target {
         match self {
             Film::Rgb(film) => film,
+            Film::GBuffer(film) => film,
         }
     }
 }
@@ -273,16 +235,48 @@ pub struct FilmBase {
     pub sensor: PixelSensor,
 }
 
+impl FilmBase {
+    pub fn new(full_resolution: UVec2, pixel_bounds_min: UVec2, pixel_bounds_max: UVec2, filter: FilmFilter, diagonal: f32, sensor: PixelSensor) -> Self {
+        Self {
+            full_resolution,
+            pixel_bounds_min: pixel_bounds_min.as_vec2(),
+            pixel_bounds_max: pixel_bounds_max.as_vec2(),
+            filter,
+            diagonal,
+            sensor,
+        }
+    }
+
+    pub fn contains(&self, point_film: UVec2) -> bool {
+        let point = point_film.as_vec2();
+        point.cmpge(self.pixel_bounds_min).all() && point.cmplt(self.pixel_bounds_max).all()
+    }
+
+    // Sensor RGB of the radiance converted to the output color space, with its largest component clamped to `max_component_value`
+    fn output_rgb(&self, output_rgb_from_sensor_rgb: Mat3, max_component_value: f32, radiance: SampledSpectrum, lambda: &SampledWavelengths) -> Vec3 {
+        let sensor_rgb: Vec3 = self.sensor.sensor_rgb(radiance, lambda).into();
+        let rgb = output_rgb_from_sensor_rgb * sensor_rgb;
+
+        let m = rgb.max_element();
+        if m > max_component_value {
+            rgb * (max_component_value / m)
+        } else {
+            rgb
+        }
+    }
+}
+
 #[derive(Clone)]
 #[repr(C)]
 pub struct RgbFilm {
     base: FilmBase,
-    color_space: RgbColorSpace,
     max_component_value: f32,
     filter_integral: f32,
     output_rgb_from_sensor_rgb: Mat3,
     pixels_packed_rgb_weight_sum: Image!(2D, format = rgba32f, sampled = false),
-    pixels_rgb_splat: Image!(2D, format = rgba32f, sampled = false),
+    pixels_rgb_splat: SplatImage,
+    pixels_variance_mean: Image!(2D, format = rgba32f, sampled = false),
+    pixels_variance_m2: Image!(2D, format = rgba32f, sampled = false),
 }
 
 impl Deref for RgbFilm {
@@ -293,23 +287,45 @@ impl Deref for RgbFilm {
     }
 }
 
-impl IFilm for RgbFilm {
-    fn add_sample(&mut self, point_film: UVec2, radiance: SampledSpectrum, lambda: &SampledWavelengths, _: Option<VisibleSurface>, weight: f32) {
-        let mut rgb = self.sensor.sensor_rgb(radiance, &lambda);
-
-        let m = rgb.r.max(rgb.g).max(rgb.b);
-        if m > self.max_component_value {
-            rgb *= self.max_component_value / m;
+impl RgbFilm {
+    // `rgb_from_xyz` converts to the output color space, the images are the ones of the host `film::RgbFilmImages`
+    pub fn new(
+        base: FilmBase,
+        rgb_from_xyz: Mat3,
+        max_component_value: f32,
+        pixels_packed_rgb_weight_sum: Image!(2D, format = rgba32f, sampled = false),
+        pixels_rgb_splat: SplatImage,
+        pixels_variance_mean: Image!(2D, format = rgba32f, sampled = false),
+        pixels_variance_m2: Image!(2D, format = rgba32f, sampled = false),
+    ) -> Self {
+        Self {
+            filter_integral: base.filter.integral(),
+            output_rgb_from_sensor_rgb: rgb_from_xyz * base.sensor.xyz_from_sensor_rgb,
+            base,
+            max_component_value,
+            pixels_packed_rgb_weight_sum,
+            pixels_rgb_splat,
+            pixels_variance_mean,
+            pixels_variance_m2,
         }
+    }
 
-        let mut rgb_weight: Vec4 = self.pixels_packed_rgb_weight_sum.read(point_film);
+    fn output_rgb(&self, radiance: SampledSpectrum, lambda: &SampledWavelengths) -> Vec3 {
+        self.base.output_rgb(self.output_rgb_from_sensor_rgb, self.max_component_value, radiance, lambda)
+    }
+}
 
-        for i in 0..3 {
-            rgb_weight[i] += weight * rgb[i];
+impl IFilm for RgbFilm {
+    fn add_sample(&mut self, point_film: UVec2, radiance: SampledSpectrum, lambda: &SampledWavelengths, _: Option<VisibleSurface>, weight: f32) {
+        // Pixels of the filter footprint around the film bounds are only sampled for their splats
+        if !self.contains(point_film) {
+            return;
         }
-        rgb_weight[3] += weight;
 
-        unsafe { self.pixels_packed_rgb_weight_sum.write(point_film, rgb_weight) };
+        let rgb = self.output_rgb(radiance, lambda);
+
+        accumulate(&self.pixels_packed_rgb_weight_sum, point_film, (weight * rgb).extend(weight));
+        add_variance_sample(&self.pixels_variance_mean, &self.pixels_variance_m2, point_film, rgb);
     }
 
     fn sample_bounds(&self) -> (Vec2, Vec2) {
@@ -325,30 +341,154 @@ impl IFilm for RgbFilm {
         false
     }
 
+    #[cfg_attr(not(target_arch = "spirv"), allow(unused_variables))]
     fn add_splat(&mut self, point: Vec2, radiance: SampledSpectrum, lambda: &SampledWavelengths) {
-        let mut rgb = self.sensor.sensor_rgb(radiance, &lambda);
+        // Image atomics only exist on the GPU, the host build of the shader crate never splats
+        #[cfg(target_arch = "spirv")]
+        splat_footprint(&self.base, &self.pixels_rgb_splat, point, self.output_rgb(radiance, lambda));
+    }
+
+    fn sample_wavelengths(&self, u: f32) -> SampledWavelengths {
+        SampledWavelengths::sample_visible(u)
+    }
 
-        let m = rgb.r.max(rgb.g).max(rgb.b);
-        if m > self.max_component_value {
-            rgb *= self.max_component_value / m;
+    fn full_resolution(&self) -> UVec2 {
+        self.full_resolution
+    }
+
+    fn get_pixel_rgb(&self, point_film: UVec2, splat_scale: f32) -> RgbColor {
+        resolve_pixel_rgb(&self.pixels_packed_rgb_weight_sum, &self.pixels_rgb_splat, point_film, splat_scale, self.filter_integral)
+    }
+
+    fn get_filter(&self) -> FilmFilter {
+        self.filter
+    }
+
+    fn relative_error(&self, point_film: UVec2) -> f32 {
+        let m2: Vec4 = self.pixels_variance_m2.read(point_film);
+        relative_error(self.pixels_variance_mean.read(point_film), m2.xyz())
+    }
+
+    fn sample_count(&self, point_film: UVec2) -> u32 {
+        let mean: Vec4 = self.pixels_variance_mean.read(point_film);
+        mean.w as u32
+    }
+}
+
+#[derive(Clone)]
+#[repr(C)]
+pub struct GBufferFilm {
+    base: FilmBase,
+    max_component_value: f32,
+    filter_integral: f32,
+    output_rgb_from_sensor_rgb: Mat3,
+    output_from_render: Mat4,
+    pixels_packed_rgb_weight_sum: Image!(2D, format = rgba32f, sampled = false),
+    pixels_rgb_splat: SplatImage,
+    pixels_variance_mean: Image!(2D, format = rgba32f, sampled = false),
+    pixels_variance_m2: Image!(2D, format = rgba32f, sampled = false),
+    pixels_albedo: Image!(2D, format = rgba32f, sampled = false),
+    pixels_position_depth: Image!(2D, format = rgba32f, sampled = false),
+    pixels_normal: Image!(2D, format = rgba32f, sampled = false),
+    pixels_uv_depth_derivatives: Image!(2D, format = rgba32f, sampled = false),
+}
+
+impl Deref for GBufferFilm {
+    type Target = FilmBase;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl GBufferFilm {
+    // The surface layers are stored in the space of `output_from_render`, the images are the ones of the host `film::GBufferImages`
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        base: FilmBase,
+        rgb_from_xyz: Mat3,
+        output_from_render: Mat4,
+        max_component_value: f32,
+        pixels_packed_rgb_weight_sum: Image!(2D, format = rgba32f, sampled = false),
+        pixels_rgb_splat: SplatImage,
+        pixels_variance_mean: Image!(2D, format = rgba32f, sampled = false),
+        pixels_variance_m2: Image!(2D, format = rgba32f, sampled = false),
+        pixels_albedo: Image!(2D, format = rgba32f, sampled = false),
+        pixels_position_depth: Image!(2D, format = rgba32f, sampled = false),
+        pixels_normal: Image!(2D, format = rgba32f, sampled = false),
+        pixels_uv_depth_derivatives: Image!(2D, format = rgba32f, sampled = false),
+    ) -> Self {
+        Self {
+            filter_integral: base.filter.integral(),
+            output_rgb_from_sensor_rgb: rgb_from_xyz * base.sensor.xyz_from_sensor_rgb,
+            base,
+            max_component_value,
+            output_from_render,
+            pixels_packed_rgb_weight_sum,
+            pixels_rgb_splat,
+            pixels_variance_mean,
+            pixels_variance_m2,
+            pixels_albedo,
+            pixels_position_depth,
+            pixels_normal,
+            pixels_uv_depth_derivatives,
+        }
+    }
+
+    fn output_rgb(&self, radiance: SampledSpectrum, lambda: &SampledWavelengths) -> Vec3 {
+        self.base.output_rgb(self.output_rgb_from_sensor_rgb, self.max_component_value, radiance, lambda)
+    }
+}
+
+impl IFilm for GBufferFilm {
+    fn add_sample(&mut self, point_film: UVec2, radiance: SampledSpectrum, lambda: &SampledWavelengths, surface: Option<VisibleSurface>, weight: f32) {
+        if !self.contains(point_film) {
+            return;
+        }
+
+        let rgb = self.output_rgb(radiance, lambda);
+
+        accumulate(&self.pixels_packed_rgb_weight_sum, point_film, (weight * rgb).extend(weight));
+        add_variance_sample(&self.pixels_variance_mean, &self.pixels_variance_m2, point_film, rgb);
+
+        if let Some(surface) = surface {
+            let point = self.output_from_render.transform_point3(surface.point);
+            let partial_point_x = self.output_from_render.transform_vector3(surface.partial_point_x);
+            let partial_point_y = self.output_from_render.transform_vector3(surface.partial_point_y);
+            let shading_normal = self.output_from_render.transform_vector3(surface.shading_normal).normalize_or_zero();
+
+            let albedo: Vec3 = self.sensor.sensor_rgb(surface.albedo, lambda).into();
+            let albedo = self.output_rgb_from_sensor_rgb * albedo;
+
+            accumulate(&self.pixels_albedo, point_film, (weight * albedo).extend(weight));
+            accumulate(&self.pixels_position_depth, point_film, (weight * point).extend(weight * point.z));
+            accumulate(&self.pixels_normal, point_film, (weight * shading_normal).extend(weight));
+            accumulate(
+                &self.pixels_uv_depth_derivatives,
+                point_film,
+                Vec4::new(surface.uv.x, surface.uv.y, partial_point_x.z, partial_point_y.z) * weight,
+            );
         }
+    }
 
-        let point_discrete = point + Vec2::new(0.5, 0.5);
+    fn sample_bounds(&self) -> (Vec2, Vec2) {
         let radius = self.filter.radius();
 
-        let mut splat_bound_min = (point_discrete - radius).floor();
-        let mut splat_bound_max = (point_discrete + radius).floor() + Vec2::new(1.0, 1.0);
+        let min = self.pixel_bounds_min - radius + Vec2::new(0.5, 0.5);
+        let max = self.pixel_bounds_max + radius - Vec2::new(0.5, 0.5);
 
-        splat_bound_min = splat_bound_min.max(self.pixel_bounds_min);
-        splat_bound_max = splat_bound_max.min(self.pixel_bounds_max);
+        (min, max)
+    }
 
-        [splat_bound_min, splat_bound_max].iter().for_each(|&pi| {
-            let wt = self.filter.evaluate(point - pi - Vec2::new(0.5, 0.5));
+    fn use_visible_surface(&self) -> bool {
+        true
+    }
 
-            if wt != 0.0 {
-                todo!()
-            }
-        })
+    #[cfg_attr(not(target_arch = "spirv"), allow(unused_variables))]
+    fn add_splat(&mut self, point: Vec2, radiance: SampledSpectrum, lambda: &SampledWavelengths) {
+        // Image atomics only exist on the GPU, the host build of the shader crate never splats
+        #[cfg(target_arch = "spirv")]
+        splat_footprint(&self.base, &self.pixels_rgb_splat, point, self.output_rgb(radiance, lambda));
     }
 
     fn sample_wavelengths(&self, u: f32) -> SampledWavelengths {
@@ -360,25 +500,133 @@ impl IFilm for RgbFilm {
     }
 
     fn get_pixel_rgb(&self, point_film: UVec2, splat_scale: f32) -> RgbColor {
-        let rgb_weight: Vec4 = self.pixels_packed_rgb_weight_sum.read(point_film);
-        let rgb_splat: Vec4 = self.pixels_rgb_splat.read(point_film);
-        let rgb_splat = rgb_splat.xyz();
+        resolve_pixel_rgb(&self.pixels_packed_rgb_weight_sum, &self.pixels_rgb_splat, point_film, splat_scale, self.filter_integral)
+    }
+
+    fn get_filter(&self) -> FilmFilter {
+        self.filter
+    }
 
-        let mut rgb = RgbColor::new(rgb_weight.x, rgb_weight.y, rgb_weight.z);
-        let weight_sum = rgb_weight.w;
+    fn relative_error(&self, point_film: UVec2) -> f32 {
+        let m2: Vec4 = self.pixels_variance_m2.read(point_film);
+        relative_error(self.pixels_variance_mean.read(point_film), m2.xyz())
+    }
 
-        if weight_sum != 0.0 {
-            rgb /= weight_sum;
-        }
+    fn sample_count(&self, point_film: UVec2) -> u32 {
+        let mean: Vec4 = self.pixels_variance_mean.read(point_film);
+        mean.w as u32
+    }
+}
+
+// One step of Welford's running mean and sum of squared differences, kept per channel with the sample count in `mean.w`
+fn welford_update(mean: Vec4, m2: Vec3, value: Vec3) -> (Vec4, Vec3) {
+    let count = mean.w + 1.0;
+    let delta = value - mean.xyz();
+    let new_mean = mean.xyz() + delta / count;
+
+    (new_mean.extend(count), m2 + delta * (value - new_mean))
+}
+
+// Largest relative standard error of the pixel mean over the channels, infinite until there are two samples
+fn relative_error(mean: Vec4, m2: Vec3) -> f32 {
+    let count = mean.w;
+
+    if count < 2.0 {
+        return f32::INFINITY;
+    }
+
+    let mut error: f32 = 0.0;
+
+    for c in 0..3 {
+        let standard_error = (m2[c] / ((count - 1.0) * count)).max(0.0).sqrt();
+        error = error.max(standard_error / (mean[c].abs() + RELATIVE_ERROR_EPSILON));
+    }
+
+    error
+}
+
+// Tracks the clamped sensor RGB of every sample, the estimate adaptive sampling stops on
+fn add_variance_sample(mean_image: &Image!(2D, format = rgba32f, sampled = false), m2_image: &Image!(2D, format = rgba32f, sampled = false), point_film: UVec2, rgb: Vec3) {
+    let mean: Vec4 = mean_image.read(point_film);
+    let m2: Vec4 = m2_image.read(point_film);
+    let (mean, m2) = welford_update(mean, m2.xyz(), rgb);
+
+    unsafe {
+        mean_image.write(point_film, mean);
+        m2_image.write(point_film, m2.extend(0.0));
+    }
+}
 
+fn accumulate(image: &Image!(2D, format = rgba32f, sampled = false), point_film: UVec2, value: Vec4) {
+    let previous: Vec4 = image.read(point_film);
+    unsafe { image.write(point_film, previous + value) };
+}
+
+// Weighted average of the samples plus the splats, the same resolve the host does in `film::RgbFilmImages::read_rgb`
+fn resolve_pixel_rgb(weight_image: &Image!(2D, format = rgba32f, sampled = false), splat_image: &SplatImage, point_film: UVec2, splat_scale: f32, filter_integral: f32) -> RgbColor {
+    let rgb_weight: Vec4 = weight_image.read(point_film);
+    let rgb_splat = Vec3::from_array(array::from_fn(|c| {
+        let splat: i32 = splat_image.read(UVec2::new(3 * point_film.x + c as u32, point_film.y));
+        from_splat_fixed_point(splat)
+    }));
+
+    let mut rgb = RgbColor::new(rgb_weight.x, rgb_weight.y, rgb_weight.z);
+    let weight_sum = rgb_weight.w;
+
+    if weight_sum != 0.0 {
+        rgb /= weight_sum;
+    }
+
+    if filter_integral != 0.0 {
         for i in 0..3 {
-            rgb[i] += splat_scale / self.filter_integral * rgb_splat[i];
+            rgb[i] += splat_scale / filter_integral * rgb_splat[i];
         }
+    }
+
+    rgb
+}
+
+// Adds the filtered contribution to every pixel of the filter footprint around `point`
+#[cfg(target_arch = "spirv")]
+fn splat_footprint(film: &FilmBase, splat_image: &SplatImage, point: Vec2, rgb: Vec3) {
+    let point_discrete = point + Vec2::new(0.5, 0.5);
+    let radius = film.filter.radius();
 
-        rgb
+    let splat_bound_min = (point_discrete - radius).floor().max(film.pixel_bounds_min);
+    let splat_bound_max = ((point_discrete + radius).floor() + Vec2::new(1.0, 1.0)).min(film.pixel_bounds_max);
+
+    for y in splat_bound_min.y as u32..splat_bound_max.y as u32 {
+        for x in splat_bound_min.x as u32..splat_bound_max.x as u32 {
+            let wt = film.filter.evaluate(point - Vec2::new(x as f32, y as f32) - Vec2::new(0.5, 0.5));
+
+            if wt != 0.0 {
+                for c in 0..3 {
+                    atomic_add_splat(splat_image, UVec2::new(3 * x + c, y), to_splat_fixed_point(wt * rgb[c as usize]));
+                }
+            }
+        }
     }
+}
 
-    fn get_filter(&self) -> FilmFilter {
-        self.filter
+// Splats from different invocations land on the same pixels, so they are accumulated as fixed-point integers with image atomics,
+// see `optics_common::film` for the representable range
+#[cfg(target_arch = "spirv")]
+fn atomic_add_splat(image: &SplatImage, coordinate: UVec2, value: i32) {
+    unsafe {
+        asm! {
+            "%int = OpTypeInt 32 1",
+            "%uint = OpTypeInt 32 0",
+            "%texel_pointer_type = OpTypePointer Image %int",
+            "%sample = OpConstant %uint 0",
+            "%scope = OpConstant %uint 1",
+            "%semantics = OpConstant %uint 0",
+            "%coordinate = OpLoad _ {coordinate}",
+            "%value = OpLoad _ {value}",
+            "%texel = OpImageTexelPointer %texel_pointer_type {image} %coordinate %sample",
+            "%previous = OpAtomicIAdd %int %texel %scope %semantics %value",
+            image = in(reg) image,
+            coordinate = in(reg) &coordinate,
+            value = in(reg) &value,
+        }
     }
 }
@@ -1,5 +1,9 @@
+use crate::util::sampling::PiecewiseConstant2DSlice;
+use crate::util::{math, sampling};
 use core::ops::Deref;
+use optics_common::film;
 use spirv_std::glam::Vec2;
+use spirv_std::num_traits::Float;
 
 pub struct FilterSample {
     pub point: Vec2,
@@ -13,22 +17,270 @@ pub trait IFilmFilter {
 
     fn integral(&self) -> f32;
 
-    fn sample(&self, u: Vec2) -> FilterSample;
+    // Tabulated filters read their sampling distribution from the flattened distribution buffer
+    fn sample(&self, distributions: &[u32], u: Vec2) -> FilterSample;
+}
+
+#[derive(Copy, Clone, PartialEq)]
+pub enum FilterType {
+    Box,
+    Gaussian,
+    Mitchell,
+    LanczosSinc,
+    Triangle,
+}
+
+impl From<u32> for FilterType {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => FilterType::Box,
+            1 => FilterType::Gaussian,
+            2 => FilterType::Mitchell,
+            3 => FilterType::LanczosSinc,
+            4 => FilterType::Triangle,
+            _ => panic!("Invalid filter type"),
+        }
+    }
+}
+
+// The host's choice of film filter, tabulated into the distribution buffer when it has no closed form sample
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct RenderFilter {
+    pub radius: Vec2,
+    // Sigma for the Gaussian, B and C for Mitchell, tau for Lanczos
+    pub parameters: Vec2,
+    pub filter_type: u32,
+    pub sampler_offset: u32,
+    pub integral: f32,
+}
+
+impl RenderFilter {
+    pub fn to_filter(&self) -> FilmFilter {
+        let sampler = FilterSampler {
+            radius: self.radius,
+            offset: self.sampler_offset,
+            integral: self.integral,
+        };
+
+        match FilterType::from(self.filter_type) {
+            FilterType::Box => FilmFilter::Box(BoxFilter::new(self.radius)),
+            FilterType::Gaussian => FilmFilter::Gaussian(GaussianFilter::new(self.parameters.x, sampler)),
+            FilterType::Mitchell => FilmFilter::Mitchell(MitchellFilter::new(self.parameters.x, self.parameters.y, sampler)),
+            FilterType::LanczosSinc => FilmFilter::LanczosSinc(LanczosSincFilter::new(self.parameters.x, sampler)),
+            FilterType::Triangle => FilmFilter::Triangle(TriangleFilter::new(self.radius)),
+        }
+    }
 }
 
 #[derive(Copy, Clone)]
 pub enum FilmFilter {
-    BoxFilter,
-    GaussianFilter,
-    MitchellFilter,
-    LanczosSincFilter,
-    TriangleFilter,
+    Box(BoxFilter),
+    Gaussian(GaussianFilter),
+    Mitchell(MitchellFilter),
+    LanczosSinc(LanczosSincFilter),
+    Triangle(TriangleFilter),
 }
 
 impl Deref for FilmFilter {
     type Target = dyn IFilmFilter;
 
     fn deref(&self) -> &Self::Target {
-        todo!()
+        match self {
+            FilmFilter::Box(filter) => filter,
+            FilmFilter::Gaussian(filter) => filter,
+            FilmFilter::Mitchell(filter) => filter,
+            FilmFilter::LanczosSinc(filter) => filter,
+            FilmFilter::Triangle(filter) => filter,
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct FilterSampler {
+    pub radius: Vec2,
+    // Offset of the filter tabulated over the `[-radius, radius]` domain by the host, read through `PiecewiseConstant2DSlice`
+    pub offset: u32,
+    pub integral: f32,
+}
+
+impl FilterSampler {
+    // Returns a point in the filter domain with its density; the caller divides the signed filter value by it
+    pub fn sample(&self, distributions: &[u32], u: Vec2) -> (Vec2, f32) {
+        let (point, pdf) = PiecewiseConstant2DSlice::new(distributions, self.offset).sample(u);
+        let point = -self.radius + 2.0 * self.radius * point;
+
+        (point, pdf / (4.0 * self.radius.x * self.radius.y))
+    }
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct BoxFilter {
+    radius: Vec2,
+}
+
+impl BoxFilter {
+    pub fn new(radius: Vec2) -> Self {
+        Self { radius }
+    }
+}
+
+impl IFilmFilter for BoxFilter {
+    fn radius(&self) -> Vec2 {
+        self.radius
+    }
+
+    fn evaluate(&self, point: Vec2) -> f32 {
+        if point.x.abs() <= self.radius.x && point.y.abs() <= self.radius.y {
+            1.0
+        } else {
+            0.0
+        }
+    }
+
+    fn integral(&self) -> f32 {
+        4.0 * self.radius.x * self.radius.y
+    }
+
+    fn sample(&self, _: &[u32], u: Vec2) -> FilterSample {
+        let point = Vec2::new(math::lerp(u.x, -self.radius.x, self.radius.x), math::lerp(u.y, -self.radius.y, self.radius.y));
+
+        FilterSample { point, weight: 1.0 }
+    }
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct GaussianFilter {
+    sigma: f32,
+    sampler: FilterSampler,
+}
+
+impl GaussianFilter {
+    pub fn new(sigma: f32, sampler: FilterSampler) -> Self {
+        Self { sigma, sampler }
+    }
+}
+
+impl IFilmFilter for GaussianFilter {
+    fn radius(&self) -> Vec2 {
+        self.sampler.radius
+    }
+
+    fn evaluate(&self, point: Vec2) -> f32 {
+        film::gaussian_filter(point.to_array(), self.sampler.radius.to_array(), self.sigma)
+    }
+
+    fn integral(&self) -> f32 {
+        self.sampler.integral
+    }
+
+    fn sample(&self, distributions: &[u32], u: Vec2) -> FilterSample {
+        sample_tabulated(self, &self.sampler, distributions, u)
     }
 }
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct MitchellFilter {
+    b: f32,
+    c: f32,
+    sampler: FilterSampler,
+}
+
+impl MitchellFilter {
+    pub fn new(b: f32, c: f32, sampler: FilterSampler) -> Self {
+        Self { b, c, sampler }
+    }
+}
+
+impl IFilmFilter for MitchellFilter {
+    fn radius(&self) -> Vec2 {
+        self.sampler.radius
+    }
+
+    fn evaluate(&self, point: Vec2) -> f32 {
+        film::mitchell_filter(point.to_array(), self.sampler.radius.to_array(), self.b, self.c)
+    }
+
+    fn integral(&self) -> f32 {
+        self.sampler.integral
+    }
+
+    fn sample(&self, distributions: &[u32], u: Vec2) -> FilterSample {
+        sample_tabulated(self, &self.sampler, distributions, u)
+    }
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct LanczosSincFilter {
+    tau: f32,
+    sampler: FilterSampler,
+}
+
+impl LanczosSincFilter {
+    pub fn new(tau: f32, sampler: FilterSampler) -> Self {
+        Self { tau, sampler }
+    }
+}
+
+impl IFilmFilter for LanczosSincFilter {
+    fn radius(&self) -> Vec2 {
+        self.sampler.radius
+    }
+
+    fn evaluate(&self, point: Vec2) -> f32 {
+        film::lanczos_sinc_filter(point.to_array(), self.sampler.radius.to_array(), self.tau)
+    }
+
+    fn integral(&self) -> f32 {
+        self.sampler.integral
+    }
+
+    fn sample(&self, distributions: &[u32], u: Vec2) -> FilterSample {
+        sample_tabulated(self, &self.sampler, distributions, u)
+    }
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct TriangleFilter {
+    radius: Vec2,
+}
+
+impl TriangleFilter {
+    pub fn new(radius: Vec2) -> Self {
+        Self { radius }
+    }
+}
+
+impl IFilmFilter for TriangleFilter {
+    fn radius(&self) -> Vec2 {
+        self.radius
+    }
+
+    fn evaluate(&self, point: Vec2) -> f32 {
+        (self.radius.x - point.x.abs()).max(0.0) * (self.radius.y - point.y.abs()).max(0.0)
+    }
+
+    fn integral(&self) -> f32 {
+        math::sqr(self.radius.x) * math::sqr(self.radius.y)
+    }
+
+    fn sample(&self, _: &[u32], u: Vec2) -> FilterSample {
+        let point = Vec2::new(sampling::sample_tent(u.x, self.radius.x), sampling::sample_tent(u.y, self.radius.y));
+
+        FilterSample { point, weight: 1.0 }
+    }
+}
+
+fn sample_tabulated(filter: &dyn IFilmFilter, sampler: &FilterSampler, distributions: &[u32], u: Vec2) -> FilterSample {
+    let (point, pdf) = sampler.sample(distributions, u);
+
+    let weight = if pdf > 0.0 { filter.evaluate(point) / pdf } else { 0.0 };
+
+    FilterSample { point, weight }
+}
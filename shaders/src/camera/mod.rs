@@ -1,14 +1,20 @@
+use crate::camera::aperture::RenderAperture;
 use crate::camera::film::Film;
+use crate::camera::filter::IFilmFilter;
+use crate::camera::realistic::RealisticCamera;
+use crate::camera::transform::CameraTransform;
 use crate::light::medium::Medium;
 use crate::light::ray::{Ray, RayDifferential};
 use crate::spectrum::{SampledSpectrum, SampledWavelengths};
 use crate::util::frame::Frame;
-use crate::util::{math, sampling};
+use crate::util::{math, vector};
 use core::ops::Deref;
-use spirv_std::glam::{Mat4, Quat, Vec2, Vec3};
+use spirv_std::glam::{Mat4, UVec2, Vec2, Vec3};
 
+pub mod aperture;
 pub mod film;
 pub mod filter;
+pub mod realistic;
 pub mod transform;
 
 #[derive(Clone, Copy)]
@@ -20,6 +26,19 @@ pub struct CameraSample {
     pub filer_weight: f32,
 }
 
+impl CameraSample {
+    pub fn new(point_pixel: UVec2, filter: &dyn IFilmFilter, distributions: &[u32], u_filter: Vec2, u_lens: Vec2, time: f32) -> Self {
+        let filter_sample = filter.sample(distributions, u_filter);
+
+        Self {
+            point_film: point_pixel.as_vec2() + filter_sample.point + Vec2::new(0.5, 0.5),
+            point_lens: u_lens,
+            time,
+            filer_weight: filter_sample.weight,
+        }
+    }
+}
+
 #[derive(Clone, Copy)]
 #[repr(C)]
 pub struct CameraRay {
@@ -98,16 +117,22 @@ pub trait ICamera {
 }
 
 #[derive(Clone)]
-pub enum Camera {
-    Perspective(PerspectiveCamera),
+pub enum Camera<'a> {
+    Perspective(PerspectiveCamera<'a>),
+    Orthographic(OrthographicCamera<'a>),
+    Spherical(SphericalCamera),
+    Realistic(RealisticCamera<'a>),
 }
 
-impl Deref for Camera {
-    type Target = dyn ICamera;
+impl<'a> Deref for Camera<'a> {
+    type Target = dyn ICamera + 'a;
 
     fn deref(&self) -> &Self::Target {
         match self {
             Camera::Perspective(camera) => camera,
+            Camera::Orthographic(camera) => camera,
+            Camera::Spherical(camera) => camera,
+            Camera::Realistic(camera) => camera,
         }
     }
 }
@@ -115,13 +140,19 @@ impl Deref for Camera {
 #[derive(Clone)]
 #[repr(C)]
 pub struct CameraBaseParameters {
-    pub camera_transform: Mat4,
+    pub camera_transform: CameraTransform,
     pub shutter_open: f32,
     pub shutter_close: f32,
     pub film: Film,
     pub medium: Option<Medium>,
 }
 
+impl CameraBaseParameters {
+    pub fn render_from_camera(&self, time: f32) -> Mat4 {
+        self.camera_transform.render_from_camera(time)
+    }
+}
+
 #[derive(Copy, Clone)]
 #[repr(C)]
 pub struct MinDifferentials {
@@ -193,30 +224,80 @@ impl MinDifferentials {
     }
 }
 
+// Camera space direction of a perspective projection through raster position `point_film`
+pub fn perspective_direction(camera_from_raster: &Mat4, point_film: Vec2) -> Vec3 {
+    camera_from_raster.project_point3(Vec3::new(point_film.x, point_film.y, 0.0)).normalize()
+}
+
+// Camera space origin of an orthographic projection through raster position `point_film`, the ray points along +z
+pub fn orthographic_origin(camera_from_raster: &Mat4, point_film: Vec2) -> Vec3 {
+    camera_from_raster.project_point3(Vec3::new(point_film.x, point_film.y, 0.0))
+}
+
+// Camera space direction of raster position `point_film` on a panorama covering the whole sphere, with +y up
+pub fn spherical_direction(mapping: SphericalMapping, point_film: Vec2, resolution: UVec2) -> Vec3 {
+    let uv = point_film / resolution.as_vec2();
+
+    let direction = match mapping {
+        SphericalMapping::EquiRectangular => vector::equirectangular_square_to_sphere(uv),
+        SphericalMapping::EqualArea => vector::equal_area_square_to_sphere(vector::wrap_equal_area_square(uv)),
+    };
+
+    Vec3::new(direction.x, direction.z, direction.y)
+}
+
+// Moves the ray origin onto the lens and aims it at the point in focus, rays are unchanged for a pinhole
+pub fn thin_lens_ray(
+    origin: Vec3,
+    direction: Vec3,
+    lens_radius: f32,
+    focal_distance: f32,
+    aperture: &RenderAperture,
+    distributions: &[u32],
+    point_film: Vec2,
+    u_lens: Vec2,
+    resolution: UVec2,
+) -> Option<(Vec3, Vec3)> {
+    if lens_radius <= 0.0 {
+        return Some((origin, direction));
+    }
+
+    let point_lens = lens_radius * aperture.sample(distributions, u_lens, point_film, resolution)?;
+    let point_focus = origin + direction * (focal_distance / direction.z);
+    let origin = Vec3::new(origin.x + point_lens.x, origin.y + point_lens.y, 0.0);
+
+    Some((origin, (point_focus - origin).normalize()))
+}
+
 #[derive(Copy, Clone)]
 #[repr(C)]
-pub struct ProjectiveCamera {
+pub struct ProjectiveCamera<'a> {
     pub screen_from_camera: Mat4,
     pub camera_from_raster: Mat4,
     pub raster_from_screen: Mat4,
     pub screen_from_raster: Mat4,
     pub lens_radius: f32,
     pub focal_distance: f32,
+    pub aperture: RenderAperture,
+    pub distributions: &'a [u32],
 }
 
-impl ProjectiveCamera {
-    pub fn new(params: &CameraBaseParameters, screen_from_camera: Mat4, window_min: Vec2, window_max: Vec2, lens_radius: f32, focal_distance: f32) -> Self {
-        let ndc_from_screen = Mat4::from_scale_rotation_translation(
-            Vec3::new(1.0 / (window_max.x - window_min.x), 1.0 / (window_max.y - window_min.y), 1.0),
-            Quat::IDENTITY,
-            Vec3::new(-window_min.x, -window_max.y, 0.0),
-        );
-
-        let full_resolution = params.film.full_resolution();
-
-        let raster_from_ndc = Mat4::from_scale(Vec3::new(full_resolution.x as f32, -(full_resolution.y as f32), 1.0));
-
-        let raster_from_screen = raster_from_ndc * ndc_from_screen;
+impl<'a> ProjectiveCamera<'a> {
+    pub fn new(
+        params: &CameraBaseParameters,
+        screen_from_camera: Mat4,
+        window_min: Vec2,
+        window_max: Vec2,
+        lens_radius: f32,
+        focal_distance: f32,
+        aperture: RenderAperture,
+        distributions: &'a [u32],
+    ) -> Self {
+        let raster_from_screen = Mat4::from_cols_array_2d(&optics_common::camera::raster_from_screen(
+            window_min.to_array(),
+            window_max.to_array(),
+            params.film.full_resolution().to_array(),
+        ));
         let screen_from_raster = raster_from_screen.inverse();
 
         let camera_from_raster = screen_from_camera.inverse() * screen_from_raster;
@@ -228,15 +309,17 @@ impl ProjectiveCamera {
             screen_from_raster,
             lens_radius,
             focal_distance,
+            aperture,
+            distributions,
         }
     }
 }
 
 #[derive(Clone)]
 #[repr(C)]
-pub struct PerspectiveCamera {
+pub struct PerspectiveCamera<'a> {
     pub params: CameraBaseParameters,
-    pub base: ProjectiveCamera,
+    pub base: ProjectiveCamera<'a>,
     pub dx_camera: Vec3,
     pub dy_camera: Vec3,
     pub cos_total_width: f32,
@@ -244,9 +327,18 @@ pub struct PerspectiveCamera {
     pub min_differentials: Option<MinDifferentials>,
 }
 
-impl PerspectiveCamera {
-    pub fn new(params: CameraBaseParameters, fov: f32, window_min: Vec2, window_max: Vec2, lens_radius: f32, focal_distance: f32) -> Self {
-        let base = ProjectiveCamera::new(&params, math::perspective(fov, 0.01, 1000.0), window_min, window_max, lens_radius, focal_distance);
+impl<'a> PerspectiveCamera<'a> {
+    pub fn new(params: CameraBaseParameters, fov: f32, window_min: Vec2, window_max: Vec2, lens_radius: f32, focal_distance: f32, aperture: RenderAperture, distributions: &'a [u32]) -> Self {
+        let base = ProjectiveCamera::new(
+            &params,
+            math::perspective(fov, 0.01, 1000.0),
+            window_min,
+            window_max,
+            lens_radius,
+            focal_distance,
+            aperture,
+            distributions,
+        );
 
         let dx_camera = base.camera_from_raster.transform_point3(Vec3::new(1.0, 0.0, 0.0)) - base.camera_from_raster.transform_point3(Vec3::ZERO);
         let dy_camera = base.camera_from_raster.transform_point3(Vec3::new(0.0, 1.0, 0.0)) - base.camera_from_raster.transform_point3(Vec3::ZERO);
@@ -282,33 +374,32 @@ impl PerspectiveCamera {
     }
 }
 
-impl Deref for PerspectiveCamera {
-    type Target = ProjectiveCamera;
+impl<'a> Deref for PerspectiveCamera<'a> {
+    type Target = ProjectiveCamera<'a>;
 
     fn deref(&self) -> &Self::Target {
         &self.base
     }
 }
 
-impl ICamera for PerspectiveCamera {
+impl ICamera for PerspectiveCamera<'_> {
     fn gen_ray(&self, sample: CameraSample, _: SampledWavelengths) -> Option<CameraRay> {
-        let point_film = Vec3::new(sample.point_film.x, sample.point_film.y, 0.0);
-        let point_camera = self.camera_from_raster.transform_point3(point_film).normalize();
-
-        let mut ray = Ray::new(Vec3::ZERO, point_camera, sample.time, self.params.medium);
-
-        if self.lens_radius > 0.0 {
-            let point_lens = self.lens_radius * sampling::sample_uniform_disk_concentric(sample.point_lens);
-
-            let focal_t = self.focal_distance / ray.direction.z;
-            let point_focus = ray.at(focal_t);
-
-            ray.origin = Vec3::new(point_lens.x, point_lens.y, 0.0);
-            ray.direction = (point_focus - ray.origin).normalize();
-        }
+        let (origin, direction) = thin_lens_ray(
+            Vec3::ZERO,
+            perspective_direction(&self.camera_from_raster, sample.point_film),
+            self.lens_radius,
+            self.focal_distance,
+            &self.aperture,
+            self.distributions,
+            sample.point_film,
+            sample.point_lens,
+            self.params.film.full_resolution(),
+        )?;
+
+        let ray = Ray::new(origin, direction, sample.time, self.params.medium);
 
         Some(CameraRay {
-            ray: ray.transform(self.get_camera_transform()),
+            ray: ray.transform(self.params.render_from_camera(sample.time)),
             weight: SampledSpectrum::uniform(1.0),
         })
     }
@@ -320,7 +411,7 @@ impl ICamera for PerspectiveCamera {
         let mut ray: RayDifferential = Ray::new(Vec3::ZERO, point_camera, sample.time, self.params.medium).into();
 
         if self.lens_radius > 0.0 {
-            let point_lens = self.lens_radius * sampling::sample_uniform_disk_concentric(sample.point_lens);
+            let point_lens = self.lens_radius * self.aperture.sample(self.distributions, sample.point_lens, sample.point_film, self.params.film.full_resolution())?;
 
             let focal_t = self.focal_distance / ray.direction.z;
             let point_focus = ray.at(focal_t);
@@ -348,7 +439,172 @@ impl ICamera for PerspectiveCamera {
         ray.has_differentials = true;
 
         Some(CameraRayDifferential {
-            ray,
+            ray: ray.transform(self.params.render_from_camera(sample.time)),
+            weight: SampledSpectrum::uniform(1.0),
+        })
+    }
+
+    fn get_film(&self) -> &Film {
+        &self.params.film
+    }
+
+    fn get_camera_transform(&self) -> Mat4 {
+        self.params.render_from_camera(self.params.shutter_open)
+    }
+
+    fn sample_time(&self, u: f32) -> f32 {
+        math::lerp(u, self.params.shutter_open, self.params.shutter_close)
+    }
+}
+
+#[derive(Clone)]
+#[repr(C)]
+pub struct OrthographicCamera<'a> {
+    pub params: CameraBaseParameters,
+    pub base: ProjectiveCamera<'a>,
+    pub dx_camera: Vec3,
+    pub dy_camera: Vec3,
+    pub min_differentials: Option<MinDifferentials>,
+}
+
+impl<'a> OrthographicCamera<'a> {
+    pub fn new(params: CameraBaseParameters, window_min: Vec2, window_max: Vec2, lens_radius: f32, focal_distance: f32, aperture: RenderAperture, distributions: &'a [u32]) -> Self {
+        let base = ProjectiveCamera::new(&params, math::orthographic(0.0, 1.0), window_min, window_max, lens_radius, focal_distance, aperture, distributions);
+
+        let dx_camera = base.camera_from_raster.transform_vector3(Vec3::new(1.0, 0.0, 0.0));
+        let dy_camera = base.camera_from_raster.transform_vector3(Vec3::new(0.0, 1.0, 0.0));
+
+        let mut camera = Self {
+            params,
+            base,
+            dx_camera,
+            dy_camera,
+            min_differentials: None,
+        };
+
+        let min_differentials = MinDifferentials::new(&camera, &camera.params.film);
+        camera.min_differentials = Some(min_differentials);
+
+        camera
+    }
+}
+
+impl<'a> Deref for OrthographicCamera<'a> {
+    type Target = ProjectiveCamera<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl ICamera for OrthographicCamera<'_> {
+    fn gen_ray(&self, sample: CameraSample, _: SampledWavelengths) -> Option<CameraRay> {
+        let (origin, direction) = thin_lens_ray(
+            orthographic_origin(&self.camera_from_raster, sample.point_film),
+            Vec3::Z,
+            self.lens_radius,
+            self.focal_distance,
+            &self.aperture,
+            self.distributions,
+            sample.point_film,
+            sample.point_lens,
+            self.params.film.full_resolution(),
+        )?;
+
+        let ray = Ray::new(origin, direction, sample.time, self.params.medium);
+
+        Some(CameraRay {
+            ray: ray.transform(self.params.render_from_camera(sample.time)),
+            weight: SampledSpectrum::uniform(1.0),
+        })
+    }
+
+    fn gen_ray_differential(&self, sample: CameraSample, _: SampledWavelengths) -> Option<CameraRayDifferential> {
+        let point_film = Vec3::new(sample.point_film.x, sample.point_film.y, 0.0);
+        let point_camera = self.camera_from_raster.transform_point3(point_film);
+
+        let mut ray: RayDifferential = Ray::new(point_camera, Vec3::Z, sample.time, self.params.medium).into();
+
+        if self.lens_radius > 0.0 {
+            let point_lens = self.lens_radius * self.aperture.sample(self.distributions, sample.point_lens, sample.point_film, self.params.film.full_resolution())?;
+
+            let focal_t = self.focal_distance / ray.direction.z;
+            let point_focus = ray.at(focal_t);
+            ray.base.origin = Vec3::new(point_camera.x + point_lens.x, point_camera.y + point_lens.y, 0.0);
+            ray.base.direction = (point_focus - ray.origin).normalize();
+
+            let point_focus = point_camera + self.dx_camera + Vec3::Z * focal_t;
+            ray.rx_origin = Vec3::new(point_camera.x + self.dx_camera.x + point_lens.x, point_camera.y + self.dx_camera.y + point_lens.y, 0.0);
+            ray.rx_direction = (point_focus - ray.rx_origin).normalize();
+
+            let point_focus = point_camera + self.dy_camera + Vec3::Z * focal_t;
+            ray.ry_origin = Vec3::new(point_camera.x + self.dy_camera.x + point_lens.x, point_camera.y + self.dy_camera.y + point_lens.y, 0.0);
+            ray.ry_direction = (point_focus - ray.ry_origin).normalize();
+        } else {
+            ray.rx_origin = ray.origin + self.dx_camera;
+            ray.ry_origin = ray.origin + self.dy_camera;
+            ray.rx_direction = ray.direction;
+            ray.ry_direction = ray.direction;
+        }
+
+        ray.has_differentials = true;
+
+        Some(CameraRayDifferential {
+            ray: ray.transform(self.params.render_from_camera(sample.time)),
+            weight: SampledSpectrum::uniform(1.0),
+        })
+    }
+
+    fn get_film(&self) -> &Film {
+        &self.params.film
+    }
+
+    fn get_camera_transform(&self) -> Mat4 {
+        self.params.render_from_camera(self.params.shutter_open)
+    }
+
+    fn sample_time(&self, u: f32) -> f32 {
+        math::lerp(u, self.params.shutter_open, self.params.shutter_close)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum SphericalMapping {
+    EquiRectangular,
+    EqualArea,
+}
+
+impl From<u32> for SphericalMapping {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => SphericalMapping::EquiRectangular,
+            1 => SphericalMapping::EqualArea,
+            _ => panic!("Invalid spherical mapping"),
+        }
+    }
+}
+
+#[derive(Clone)]
+#[repr(C)]
+pub struct SphericalCamera {
+    pub params: CameraBaseParameters,
+    pub mapping: SphericalMapping,
+}
+
+impl SphericalCamera {
+    pub fn new(params: CameraBaseParameters, mapping: SphericalMapping) -> Self {
+        Self { params, mapping }
+    }
+}
+
+impl ICamera for SphericalCamera {
+    fn gen_ray(&self, sample: CameraSample, _: SampledWavelengths) -> Option<CameraRay> {
+        let direction = spherical_direction(self.mapping, sample.point_film, self.params.film.full_resolution());
+        let ray = Ray::new(Vec3::ZERO, direction, sample.time, self.params.medium);
+
+        Some(CameraRay {
+            ray: ray.transform(self.params.render_from_camera(sample.time)),
             weight: SampledSpectrum::uniform(1.0),
         })
     }
@@ -358,7 +614,7 @@ impl ICamera for PerspectiveCamera {
     }
 
     fn get_camera_transform(&self) -> Mat4 {
-        self.params.camera_transform
+        self.params.render_from_camera(self.params.shutter_open)
     }
 
     fn sample_time(&self, u: f32) -> f32 {
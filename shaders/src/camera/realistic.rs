@@ -0,0 +1,170 @@
+use crate::camera::film::Film;
+use crate::camera::{CameraBaseParameters, CameraRay, CameraSample, ICamera};
+use crate::light::ray::Ray;
+use crate::spectrum::{SampledSpectrum, SampledWavelengths, N_SAMPLES};
+use crate::util::math;
+use optics_common::lens::{self, LensElementInterface, EXIT_PUPIL_BOUND_COUNT, LENS_ELEMENTS_OFFSET, LENS_ELEMENT_LEN, LENS_HEADER_LEN};
+use spirv_std::glam::{Mat4, UVec2, Vec2, Vec3, Vec4, Vec4Swizzles};
+
+// View of a lens flattened into the distribution buffer by the host `RenderLens`, the layout is described in `optics_common::lens`
+#[derive(Clone, Copy)]
+pub struct LensSlice<'a> {
+    data: &'a [u32],
+    offset: usize,
+}
+
+impl<'a> LensSlice<'a> {
+    pub fn new(data: &'a [u32], offset: u32) -> Self {
+        Self { data, offset: offset as usize }
+    }
+
+    fn float_at(&self, index: usize) -> f32 {
+        f32::from_bits(self.data[self.offset + index])
+    }
+
+    fn vec4_at(&self, index: usize) -> Vec4 {
+        Vec4::new(self.float_at(index), self.float_at(index + 1), self.float_at(index + 2), self.float_at(index + 3))
+    }
+
+    pub fn element_count(&self) -> usize {
+        self.data[self.offset] as usize
+    }
+
+    pub fn is_dispersive(&self) -> bool {
+        self.data[self.offset + 1] != 0
+    }
+
+    pub fn film_diagonal(&self) -> f32 {
+        self.float_at(2)
+    }
+
+    pub fn physical_extent(&self) -> Vec4 {
+        self.vec4_at(3)
+    }
+
+    pub fn exit_pupil_bounds(&self, index: usize) -> Vec4 {
+        self.vec4_at(LENS_HEADER_LEN + 4 * index)
+    }
+
+    pub fn element(&self, index: usize) -> LensElementInterface {
+        let start = LENS_ELEMENTS_OFFSET + LENS_ELEMENT_LEN * index;
+        LensElementInterface::from_array([
+            self.float_at(start),
+            self.float_at(start + 1),
+            self.float_at(start + 2),
+            self.float_at(start + 3),
+            self.float_at(start + 4),
+        ])
+    }
+
+    pub fn lens_rear_z(&self) -> f32 {
+        self.element(self.element_count() - 1).thickness
+    }
+
+    pub fn lens_front_z(&self) -> f32 {
+        (0..self.element_count()).map(|i| self.element(i).thickness).sum()
+    }
+
+    pub fn rear_element_radius(&self) -> f32 {
+        self.element(self.element_count() - 1).aperture_radius
+    }
+
+    pub fn trace_lenses_from_film(&self, origin: Vec3, direction: Vec3, lambda: f32) -> Option<(Vec3, Vec3)> {
+        let (origin, direction) = lens::trace_lenses_from_film(self.element_count(), |i| self.element(i), origin.to_array(), direction.to_array(), lambda)?;
+        Some((Vec3::from_array(origin), Vec3::from_array(direction)))
+    }
+
+    pub fn sample_exit_pupil(&self, point_film: Vec2, u_lens: Vec2) -> Option<(Vec3, f32)> {
+        let radius_film = point_film.length();
+        let index = ((radius_film / (self.film_diagonal() / 2.0) * EXIT_PUPIL_BOUND_COUNT as f32) as usize).min(EXIT_PUPIL_BOUND_COUNT - 1);
+
+        let bounds = self.exit_pupil_bounds(index);
+        let bounds_min = bounds.xy();
+        let bounds_max = bounds.zw();
+        let area = (bounds_max.x - bounds_min.x) * (bounds_max.y - bounds_min.y);
+
+        if area <= 0.0 {
+            return None;
+        }
+
+        let point_lens = Vec2::new(math::lerp(u_lens.x, bounds_min.x, bounds_max.x), math::lerp(u_lens.y, bounds_min.y, bounds_max.y));
+
+        let (sin_theta, cos_theta) = if radius_film != 0.0 {
+            (point_film.y / radius_film, point_film.x / radius_film)
+        } else {
+            (0.0, 1.0)
+        };
+
+        let point_pupil = Vec3::new(
+            cos_theta * point_lens.x - sin_theta * point_lens.y,
+            sin_theta * point_lens.x + cos_theta * point_lens.y,
+            self.lens_rear_z(),
+        );
+
+        Some((point_pupil, 1.0 / area))
+    }
+
+    // Camera space ray through the lens system for a raster position, with its weight. A dispersive lens traces the hero wavelength only,
+    // so the weight is carried by the first wavelength alone
+    pub fn gen_ray(&self, point_raster: Vec2, resolution: UVec2, u_lens: Vec2, lambda: &SampledWavelengths) -> Option<(Vec3, Vec3, SampledSpectrum)> {
+        let s = Vec2::new(point_raster.x / resolution.x as f32, point_raster.y / resolution.y as f32);
+
+        let extent = self.physical_extent();
+        let point_film = Vec3::new(-math::lerp(s.x, extent.x, extent.z), math::lerp(s.y, extent.y, extent.w), 0.0);
+
+        let (point_pupil, pdf) = self.sample_exit_pupil(Vec2::new(point_film.x, point_film.y), u_lens)?;
+
+        let direction_film = (point_pupil - point_film).normalize();
+        let (origin, direction) = self.trace_lenses_from_film(point_film, direction_film, lambda[0])?;
+
+        let weight = math::powi(direction_film.z, 4) / (pdf * math::sqr(self.lens_rear_z()));
+
+        let weight = if self.is_dispersive() {
+            let mut hero = [0.0; N_SAMPLES];
+            hero[0] = weight * N_SAMPLES as f32;
+            SampledSpectrum::from_array(hero)
+        } else {
+            SampledSpectrum::uniform(weight)
+        };
+
+        Some((origin, direction, weight))
+    }
+}
+
+#[derive(Clone)]
+pub struct RealisticCamera<'a> {
+    pub params: CameraBaseParameters,
+    pub lens: LensSlice<'a>,
+}
+
+impl<'a> RealisticCamera<'a> {
+    pub fn new(params: CameraBaseParameters, lens: LensSlice<'a>) -> Self {
+        Self { params, lens }
+    }
+}
+
+impl ICamera for RealisticCamera<'_> {
+    fn gen_ray(&self, sample: CameraSample, lambda: SampledWavelengths) -> Option<CameraRay> {
+        let resolution = self.params.film.full_resolution();
+        let (origin, direction, weight) = self.lens.gen_ray(sample.point_film, resolution, sample.point_lens, &lambda)?;
+
+        let ray = Ray::new(origin, direction, sample.time, self.params.medium);
+
+        Some(CameraRay {
+            ray: ray.transform(self.params.render_from_camera(sample.time)),
+            weight,
+        })
+    }
+
+    fn get_film(&self) -> &Film {
+        &self.params.film
+    }
+
+    fn get_camera_transform(&self) -> Mat4 {
+        self.params.render_from_camera(self.params.shutter_open)
+    }
+
+    fn sample_time(&self, u: f32) -> f32 {
+        math::lerp(u, self.params.shutter_open, self.params.shutter_close)
+    }
+}
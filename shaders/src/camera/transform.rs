@@ -33,6 +33,18 @@ impl AnimatedTransform {
         }
     }
 
+    pub fn fixed(transform: Mat4) -> Self {
+        Self::new(transform, transform, 0.0, 1.0)
+    }
+
+    pub fn is_animated(&self) -> bool {
+        self.actually_animated
+    }
+
+    pub fn start_transform(&self) -> Mat4 {
+        self.start_transform
+    }
+
     pub fn interpolate(&self, time: f32) -> Mat4 {
         if !self.actually_animated || time <= self.start_time {
             return self.start_transform;
@@ -55,6 +67,39 @@ impl AnimatedTransform {
 #[derive(Clone, Copy)]
 #[repr(C)]
 pub struct CameraTransform {
-    render_from_camera: Mat4,
-    world_from_render: AnimatedTransform,
+    render_from_camera: AnimatedTransform,
+    world_from_render: Mat4,
+}
+
+impl CameraTransform {
+    pub fn new(world_from_camera: AnimatedTransform) -> Self {
+        Self {
+            render_from_camera: world_from_camera,
+            world_from_render: Mat4::IDENTITY,
+        }
+    }
+
+    pub fn from_matrix(world_from_camera: Mat4) -> Self {
+        Self::new(AnimatedTransform::fixed(world_from_camera))
+    }
+
+    pub fn is_animated(&self) -> bool {
+        self.render_from_camera.is_animated()
+    }
+
+    pub fn render_from_camera(&self, time: f32) -> Mat4 {
+        self.render_from_camera.interpolate(time)
+    }
+
+    pub fn camera_from_render(&self, time: f32) -> Mat4 {
+        self.render_from_camera(time).inverse()
+    }
+
+    pub fn world_from_render(&self) -> Mat4 {
+        self.world_from_render
+    }
+
+    pub fn render_from_world(&self) -> Mat4 {
+        self.world_from_render.inverse()
+    }
 }
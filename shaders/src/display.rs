@@ -0,0 +1,90 @@
+use optics_common::display;
+use spirv_std::glam::{Mat3, Mat4, UVec2, UVec3, Vec3, Vec3Swizzles, Vec4, Vec4Swizzles};
+use spirv_std::{spirv, Image};
+
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct DisplayParameters {
+    pub tone_map_from_working: Mat4,
+    pub display_from_tone_map: Mat4,
+    pub tone_map_luminance: Vec3,
+    pub exposure_scale: f32,
+    pub display_luminance: Vec3,
+    pub white_point: f32,
+    pub resolution: UVec2,
+    pub operator: u32,
+    pub dither: u32,
+}
+
+#[derive(Clone, Copy)]
+pub enum ToneMapOperator {
+    Clamp,
+    Reinhard,
+    AcesFitted,
+    AgX,
+    Hable,
+}
+
+impl From<u32> for ToneMapOperator {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => ToneMapOperator::Clamp,
+            1 => ToneMapOperator::Reinhard,
+            2 => ToneMapOperator::AcesFitted,
+            3 => ToneMapOperator::AgX,
+            4 => ToneMapOperator::Hable,
+            _ => panic!(),
+        }
+    }
+}
+
+// The curves themselves are canonical in `optics_common::display`, shared with the host encoder
+pub fn tone_map(operator: ToneMapOperator, rgb: Vec3, luminance: Vec3, white_point: f32) -> Vec3 {
+    match operator {
+        ToneMapOperator::Clamp => rgb.clamp(Vec3::ZERO, Vec3::ONE),
+        ToneMapOperator::Reinhard => Vec3::from_array(display::reinhard(rgb.to_array(), luminance.to_array(), white_point)),
+        ToneMapOperator::AcesFitted => Vec3::from_array(display::aces_fitted(rgb.to_array())),
+        ToneMapOperator::AgX => Vec3::from_array(display::agx(rgb.to_array())),
+        ToneMapOperator::Hable => Vec3::from_array(display::hable(rgb.to_array(), white_point)),
+    }
+}
+
+fn gamut_map(rgb: Vec3, luminance: Vec3) -> Vec3 {
+    Vec3::from_array(display::gamut_map(rgb.to_array(), luminance.to_array()))
+}
+
+fn dither_noise(pixel: UVec2, channel: u32) -> f32 {
+    display::dither_noise(pixel.to_array(), channel)
+}
+
+#[spirv(compute(threads(16, 8, 1)))]
+pub fn main_cs(
+    #[spirv(global_invocation_id)] invocation_id: UVec3,
+    #[spirv(uniform, descriptor_set = 0, binding = 0)] params: &DisplayParameters,
+    #[spirv(descriptor_set = 0, binding = 1)] image_input: &Image!(2D, format = rgba32f, sampled = false),
+    #[spirv(descriptor_set = 0, binding = 2)] image_output: &Image!(2D, format = rgba8, sampled = false),
+) {
+    let pixel = invocation_id.xy();
+
+    if pixel.x >= params.resolution.x || pixel.y >= params.resolution.y {
+        return;
+    }
+
+    let value: Vec4 = image_input.read(pixel);
+    let rgb = if value.xyz().is_finite() { value.xyz() * params.exposure_scale } else { Vec3::ZERO };
+
+    let rgb = Mat3::from_mat4(params.tone_map_from_working) * rgb;
+    let mapped = tone_map(params.operator.into(), rgb, params.tone_map_luminance, params.white_point);
+    let mapped_display = gamut_map(Mat3::from_mat4(params.display_from_tone_map) * mapped, params.display_luminance);
+
+    let mut encoded = Vec3::new(display::srgb_oetf(mapped_display.x), display::srgb_oetf(mapped_display.y), display::srgb_oetf(mapped_display.z));
+
+    // The storage image quantizes to 8 bits on write, so the noise is added in units of one step beforehand
+    if params.dither != 0 {
+        encoded += Vec3::new(dither_noise(pixel, 0), dither_noise(pixel, 1), dither_noise(pixel, 2)) / 255.0;
+    }
+
+    let alpha = if value.w.is_finite() { value.w.clamp(0.0, 1.0) } else { 1.0 };
+
+    unsafe { image_output.write(pixel, encoded.clamp(Vec3::ZERO, Vec3::ONE).extend(alpha)) };
+}
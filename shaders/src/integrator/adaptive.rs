@@ -0,0 +1,107 @@
+use crate::bindless::{InstanceMetadata, Light, RenderCamera, RenderMaterial, RenderMedium, Vertex};
+use crate::camera::film::{IFilm, RenderSensor, RgbFilm, SplatImage};
+use crate::integrator::volumetric::{self, VolumetricParameters};
+use crate::integrator::{self, SceneData};
+use crate::sampler::{ISampler, IndependentSampler};
+use crate::spectrum::color::{RgbToSpectrumTable, LINEAR_SRGB_FROM_XYZ};
+use crate::spectrum::SampledSpectrum;
+use crate::util::math;
+use spirv_std::glam::{UVec3, Vec3Swizzles};
+use spirv_std::ray_tracing::AccelerationStructure;
+use spirv_std::{spirv, Image};
+
+// `volumetric.sample_count` is the number of samples a pixel takes in each pass it is still active, numbered from `first_sample_index`
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct AdaptiveParameters {
+    pub pass_index: u32,
+    pub first_sample_index: u32,
+    pub min_sample_count: u32,
+    pub max_sample_count: u32,
+    pub relative_error_threshold: f32,
+    pub volumetric: VolumetricParameters,
+}
+
+// Every pass is dispatched over the pixel bounds, pixels whose relative error is already below the threshold return right away.
+// The film images persist across passes, the variance images carry the sample count and error the stopping test reads
+#[spirv(compute(threads(16, 8, 1)))]
+pub fn main_cs(
+    #[spirv(global_invocation_id)] invocation_id: UVec3,
+    #[spirv(uniform, descriptor_set = 0, binding = 0)] params: &AdaptiveParameters,
+    #[spirv(descriptor_set = 0, binding = 1)] image_rgb_weight_sum: &Image!(2D, format = rgba32f, sampled = false),
+    #[spirv(descriptor_set = 0, binding = 2)] image_rgb_splat: &SplatImage,
+    #[spirv(descriptor_set = 0, binding = 3)] image_variance_mean: &Image!(2D, format = rgba32f, sampled = false),
+    #[spirv(descriptor_set = 0, binding = 4)] image_variance_m2: &Image!(2D, format = rgba32f, sampled = false),
+    #[spirv(descriptor_set = 0, binding = 5)] tlas: &AccelerationStructure,
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 6)] vertices: &[Vertex],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 7)] indices: &[u32],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 8)] metadata: &[InstanceMetadata],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 9)] materials: &[RenderMaterial],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 10)] media: &[RenderMedium],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 11)] grid_data: &[f32],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 12)] lights: &[Light],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 13)] rgb_to_spectrum_table: &RgbToSpectrumTable,
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 14)] camera: &RenderCamera,
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 15)] distributions: &[u32],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 16)] sensor: &RenderSensor,
+) {
+    let volumetric_params = &params.volumetric;
+    let pixel = invocation_id.xy() + volumetric_params.tile_offset;
+
+    if !volumetric_params.contains(pixel) {
+        return;
+    }
+
+    let mut film = RgbFilm::new(
+        integrator::film_base(volumetric_params, sensor),
+        LINEAR_SRGB_FROM_XYZ,
+        f32::INFINITY,
+        *image_rgb_weight_sum,
+        *image_rgb_splat,
+        *image_variance_mean,
+        *image_variance_m2,
+    );
+
+    let sample_count = film.sample_count(pixel);
+
+    if sample_count >= params.max_sample_count || (sample_count >= params.min_sample_count && film.relative_error(pixel) <= params.relative_error_threshold) {
+        return;
+    }
+
+    let scene = SceneData {
+        tlas,
+        vertices,
+        indices,
+        metadata,
+        materials,
+        media,
+        grid_data,
+        lights,
+        rgb_to_spectrum_table,
+    };
+
+    // The sample count of a pixel has no fixed bound, so its samples come from the sampler without a per-pixel sample budget.
+    // Every pass starts at the sample index the host gives it, a resumed render continues the sequence of each pixel
+    let filter = film.get_filter();
+    let mut sampler = IndependentSampler::new(params.max_sample_count, 0);
+
+    for i in 0..volumetric_params.sample_count.min(params.max_sample_count - sample_count) {
+        sampler.start_pixel_sample(pixel, params.first_sample_index + i, 0);
+
+        let mut sample = sampler.get_camera_sample(pixel, &*filter, distributions);
+        sample.time = math::lerp(sample.time, volumetric_params.time_min, volumetric_params.time_max);
+
+        let lambda = film.sample_wavelengths(sampler.get_1d());
+        let Some((ray_origin, ray_direction, weight)) = camera.gen_ray(distributions, sample.point_film, sample.point_lens, sample.time, &lambda) else {
+            // A sample clipped by the aperture is black, skipping it would let the weight normalization undo the vignetting
+            film.add_sample(pixel, SampledSpectrum::trivial(), &lambda, None, sample.filer_weight);
+            continue;
+        };
+
+        let radiance = volumetric::radiance(&scene, volumetric_params, ray_origin, ray_direction, lambda, &mut sampler) * weight;
+
+        if !radiance.has_nans() {
+            film.add_sample(pixel, radiance, &lambda, None, sample.filer_weight);
+        }
+    }
+}
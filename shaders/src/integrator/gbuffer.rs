@@ -0,0 +1,146 @@
+use crate::bindless::{InstanceMetadata, Light, RenderCamera, RenderMaterial, RenderMedium, Vertex};
+use crate::camera::film::{GBufferFilm, IFilm, RenderSensor, SplatImage, VisibleSurface};
+use crate::camera::CameraSample;
+use crate::integrator::volumetric::{self, VolumetricParameters};
+use crate::integrator::{self, SceneData, SurfaceHit, MAX_SKIPPED_INSTANCES};
+use crate::sampler::{ISampler, ZSobolSampler};
+use crate::spectrum::color::{RgbColor, RgbToSpectrumTable, LINEAR_SRGB_FROM_XYZ};
+use crate::spectrum::{ISpectrum, RgbAlbedoSpectrum, SampledSpectrum, SampledWavelengths};
+use crate::util::math;
+use spirv_std::glam::{UVec3, Vec2, Vec3, Vec3Swizzles, Vec4Swizzles};
+use spirv_std::num_traits::Float;
+use spirv_std::ray_tracing::AccelerationStructure;
+use spirv_std::{spirv, Image};
+
+// First surface along the camera ray, skipping the interfaces that only bound media
+fn first_surface(scene: &SceneData, origin: Vec3, direction: Vec3) -> Option<SurfaceHit> {
+    let mut origin = origin;
+
+    for _ in 0..MAX_SKIPPED_INSTANCES {
+        let hit = scene.intersect(origin, direction, f32::MAX)?;
+
+        if !scene.is_interface(hit.instance) {
+            return Some(hit);
+        }
+
+        origin = hit.spawn_origin(direction);
+    }
+
+    None
+}
+
+// Where the ray through a neighboring film position meets the tangent plane of the hit, for the position differentials
+fn tangent_plane_point(hit: &SurfaceHit, ray: Option<(Vec3, Vec3, SampledSpectrum)>) -> Vec3 {
+    match ray {
+        Some((origin, direction, _)) if hit.normal.dot(direction).abs() > 1.0e-6 => origin + direction * (hit.normal.dot(hit.position - origin) / hit.normal.dot(direction)),
+        _ => hit.position,
+    }
+}
+
+fn visible_surface(scene: &SceneData, camera: &RenderCamera, distributions: &[u32], sample: &CameraSample, origin: Vec3, direction: Vec3, lambda: &SampledWavelengths) -> Option<VisibleSurface> {
+    let hit = first_surface(scene, origin, direction)?;
+    let (shading_normal, uv) = scene.shading_attributes(&hit);
+
+    let base_color = scene.material(hit.instance).base_color.xyz();
+    let albedo = RgbAlbedoSpectrum::new(scene.rgb_to_spectrum_table, RgbColor::new(base_color.x, base_color.y, base_color.z)).sample(lambda);
+
+    let ray_x = camera.gen_ray(distributions, sample.point_film + Vec2::new(1.0, 0.0), sample.point_lens, sample.time, lambda);
+    let ray_y = camera.gen_ray(distributions, sample.point_film + Vec2::new(0.0, 1.0), sample.point_lens, sample.time, lambda);
+
+    Some(VisibleSurface {
+        albedo,
+        point: hit.position,
+        partial_point_x: tangent_plane_point(&hit, ray_x) - hit.position,
+        partial_point_y: tangent_plane_point(&hit, ray_y) - hit.position,
+        geometry_normal: hit.normal,
+        shading_normal,
+        uv,
+        time: sample.time,
+    })
+}
+
+// Renders the volumetric integrator into a `GBufferFilm`, the feature layers come from the first surface each camera ray sees
+#[spirv(compute(threads(16, 8, 1)))]
+pub fn main_cs(
+    #[spirv(global_invocation_id)] invocation_id: UVec3,
+    #[spirv(uniform, descriptor_set = 0, binding = 0)] params: &VolumetricParameters,
+    #[spirv(descriptor_set = 0, binding = 1)] image_rgb_weight_sum: &Image!(2D, format = rgba32f, sampled = false),
+    #[spirv(descriptor_set = 0, binding = 2)] image_rgb_splat: &SplatImage,
+    #[spirv(descriptor_set = 0, binding = 3)] image_variance_mean: &Image!(2D, format = rgba32f, sampled = false),
+    #[spirv(descriptor_set = 0, binding = 4)] image_variance_m2: &Image!(2D, format = rgba32f, sampled = false),
+    #[spirv(descriptor_set = 0, binding = 5)] image_albedo: &Image!(2D, format = rgba32f, sampled = false),
+    #[spirv(descriptor_set = 0, binding = 6)] image_position_depth: &Image!(2D, format = rgba32f, sampled = false),
+    #[spirv(descriptor_set = 0, binding = 7)] image_normal: &Image!(2D, format = rgba32f, sampled = false),
+    #[spirv(descriptor_set = 0, binding = 8)] image_uv_depth_derivatives: &Image!(2D, format = rgba32f, sampled = false),
+    #[spirv(descriptor_set = 0, binding = 9)] tlas: &AccelerationStructure,
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 10)] vertices: &[Vertex],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 11)] indices: &[u32],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 12)] metadata: &[InstanceMetadata],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 13)] materials: &[RenderMaterial],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 14)] media: &[RenderMedium],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 15)] grid_data: &[f32],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 16)] lights: &[Light],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 17)] rgb_to_spectrum_table: &RgbToSpectrumTable,
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 18)] camera: &RenderCamera,
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 19)] distributions: &[u32],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 20)] sensor: &RenderSensor,
+) {
+    let resolution = params.resolution;
+    let pixel = invocation_id.xy() + params.tile_offset;
+
+    if !params.contains(pixel) {
+        return;
+    }
+
+    let scene = SceneData {
+        tlas,
+        vertices,
+        indices,
+        metadata,
+        materials,
+        media,
+        grid_data,
+        lights,
+        rgb_to_spectrum_table,
+    };
+
+    let mut film = GBufferFilm::new(
+        integrator::film_base(params, sensor),
+        LINEAR_SRGB_FROM_XYZ,
+        camera.camera_transform.inverse(),
+        f32::INFINITY,
+        *image_rgb_weight_sum,
+        *image_rgb_splat,
+        *image_variance_mean,
+        *image_variance_m2,
+        *image_albedo,
+        *image_position_depth,
+        *image_normal,
+        *image_uv_depth_derivatives,
+    );
+
+    let filter = film.get_filter();
+    let mut sampler = ZSobolSampler::new(params.sample_count, resolution, params.time_slice);
+
+    for sample_index in 0..params.sample_count {
+        sampler.start_pixel_sample(pixel, sample_index, 0);
+
+        // The camera sample time is a fraction of the shutter interval
+        let mut sample = sampler.get_camera_sample(pixel, &*filter, distributions);
+        sample.time = math::lerp(sample.time, params.time_min, params.time_max);
+
+        let lambda = film.sample_wavelengths(sampler.get_1d());
+        let Some((ray_origin, ray_direction, weight)) = camera.gen_ray(distributions, sample.point_film, sample.point_lens, sample.time, &lambda) else {
+            // Clipped aperture samples still count as black, so the filter weight normalization keeps the vignetting
+            film.add_sample(pixel, SampledSpectrum::trivial(), &lambda, None, sample.filer_weight);
+            continue;
+        };
+
+        let radiance = volumetric::radiance(&scene, params, ray_origin, ray_direction, lambda, &mut sampler) * weight;
+
+        if !radiance.has_nans() {
+            let surface = visible_surface(&scene, camera, distributions, &sample, ray_origin, ray_direction, &lambda);
+            film.add_sample(pixel, radiance, &lambda, surface, sample.filer_weight);
+        }
+    }
+}
@@ -0,0 +1,290 @@
+use crate::bindless;
+use crate::bindless::{InstanceMetadata, Light, MaterialType, RenderMaterial, RenderMedium, Vertex, NO_MEDIUM};
+use crate::camera::film::{FilmBase, PixelSensor, RenderSensor};
+use crate::integrator::volumetric::VolumetricParameters;
+use crate::light::medium::{Medium, MediumProperties};
+use crate::light::ray::Ray;
+use crate::sampler::ISampler;
+use crate::spectrum::color::RgbToSpectrumTable;
+use crate::spectrum::{SampledSpectrum, SampledWavelengths};
+use crate::util::sampling;
+use spirv_std::glam::{Vec2, Vec3, Vec4Swizzles};
+use spirv_std::ray_tracing::{AccelerationStructure, CommittedIntersection, RayFlags, RayQuery};
+
+pub mod adaptive;
+pub mod gbuffer;
+pub mod subsurface;
+pub mod volumetric;
+
+pub const RAY_EPSILON: f32 = 1.0e-4;
+pub const SHADOW_EPSILON: f32 = 1.0e-4;
+pub const MAX_SKIPPED_INSTANCES: u32 = 16;
+
+// Diagonal of a full frame sensor in meters
+pub const FILM_DIAGONAL: f32 = 0.035;
+
+// The film the volumetric kernels record into: the host's filter over the pixel bounds of `params`, seen through the host's sensor profile
+pub fn film_base(params: &VolumetricParameters, sensor: &RenderSensor) -> FilmBase {
+    FilmBase::new(
+        params.resolution,
+        params.pixel_bounds_min,
+        params.pixel_bounds_max.min(params.resolution),
+        params.filter.to_filter(),
+        FILM_DIAGONAL,
+        PixelSensor::from_render_sensor(sensor),
+    )
+}
+
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct SurfaceHit {
+    pub t: f32,
+    pub position: Vec3,
+    pub normal: Vec3,
+    pub instance: u32,
+    pub primitive: u32,
+    pub barycentrics: Vec2,
+}
+
+impl SurfaceHit {
+    pub fn spawn_origin(&self, direction: Vec3) -> Vec3 {
+        if self.normal.dot(direction) > 0.0 {
+            self.position + RAY_EPSILON * self.normal
+        } else {
+            self.position - RAY_EPSILON * self.normal
+        }
+    }
+}
+
+pub struct SceneData<'a> {
+    pub tlas: &'a AccelerationStructure,
+    pub vertices: &'a [Vertex],
+    pub indices: &'a [u32],
+    pub metadata: &'a [InstanceMetadata],
+    pub materials: &'a [RenderMaterial],
+    pub media: &'a [RenderMedium],
+    pub grid_data: &'a [f32],
+    pub lights: &'a [Light],
+    pub rgb_to_spectrum_table: &'a RgbToSpectrumTable,
+}
+
+impl<'a> SceneData<'a> {
+    pub fn intersect(&self, origin: Vec3, direction: Vec3, t_max: f32) -> Option<SurfaceHit> {
+        let ray_query: &mut RayQuery = {
+            spirv_std::ray_query!(let mut ray_query);
+            ray_query
+        };
+
+        unsafe {
+            ray_query.initialize(self.tlas, RayFlags::OPAQUE, 0xff, origin, 0.0, direction, t_max);
+
+            while ray_query.proceed() {}
+
+            if let CommittedIntersection::Triangle = ray_query.get_committed_intersection_type() {
+                let instance = ray_query.get_committed_intersection_instance_custom_index();
+                let primitive_id = ray_query.get_committed_intersection_primitive_index();
+                let t = ray_query.get_committed_intersection_t();
+                let barycentrics: Vec2 = ray_query.get_committed_intersection_barycentrics();
+
+                let metadata = bindless::get_instance_metadata(self.metadata, instance);
+                let index_offset = bindless::get_global_index_offset(self.metadata, instance, primitive_id);
+
+                let v0 = (metadata.transform * self.vertices[self.indices[index_offset] as usize].pos).xyz();
+                let v1 = (metadata.transform * self.vertices[self.indices[index_offset + 1] as usize].pos).xyz();
+                let v2 = (metadata.transform * self.vertices[self.indices[index_offset + 2] as usize].pos).xyz();
+
+                Some(SurfaceHit {
+                    t,
+                    position: v0 * (1.0 - barycentrics.x - barycentrics.y) + v1 * barycentrics.x + v2 * barycentrics.y,
+                    normal: (v1 - v0).cross(v2 - v0).normalize(),
+                    instance,
+                    primitive: primitive_id,
+                    barycentrics,
+                })
+            } else {
+                None
+            }
+        }
+    }
+
+    pub fn intersect_instance(&self, origin: Vec3, direction: Vec3, t_max: f32, instance: u32) -> Option<SurfaceHit> {
+        let mut origin = origin;
+        let mut t_offset = 0.0;
+
+        for _ in 0..MAX_SKIPPED_INSTANCES {
+            let mut hit = self.intersect(origin, direction, t_max - t_offset)?;
+
+            if hit.instance == instance {
+                hit.t += t_offset;
+                return Some(hit);
+            }
+
+            t_offset += hit.t;
+            origin = hit.spawn_origin(direction);
+        }
+
+        None
+    }
+
+    // Interpolated vertex normal and texture coordinates of a hit, the normal falls back to the geometric one when the mesh has none
+    pub fn shading_attributes(&self, hit: &SurfaceHit) -> (Vec3, Vec2) {
+        let metadata = bindless::get_instance_metadata(self.metadata, hit.instance);
+        let index_offset = bindless::get_global_index_offset(self.metadata, hit.instance, hit.primitive);
+
+        let v0 = self.vertices[self.indices[index_offset] as usize];
+        let v1 = self.vertices[self.indices[index_offset + 1] as usize];
+        let v2 = self.vertices[self.indices[index_offset + 2] as usize];
+
+        let w0 = 1.0 - hit.barycentrics.x - hit.barycentrics.y;
+        let normal = v0.normal * w0 + v1.normal * hit.barycentrics.x + v2.normal * hit.barycentrics.y;
+        let uv = v0.uv * w0 + v1.uv * hit.barycentrics.x + v2.uv * hit.barycentrics.y;
+
+        let normal = (metadata.transform * normal.extend(0.0)).xyz().normalize_or_zero();
+
+        if normal == Vec3::ZERO {
+            (hit.normal, uv)
+        } else {
+            (normal, uv)
+        }
+    }
+
+    pub fn material(&self, instance: u32) -> &RenderMaterial {
+        bindless::get_instance_material(self.materials, instance)
+    }
+
+    pub fn is_interface(&self, instance: u32) -> bool {
+        matches!(MaterialType::from(self.material(instance).material_type), MaterialType::Interface)
+    }
+
+    pub fn medium(&self, medium_index: u32) -> Option<Medium> {
+        bindless::get_medium(self.media, medium_index, self.rgb_to_spectrum_table)
+    }
+
+    pub fn next_medium(&self, hit: &SurfaceHit, current_medium: u32, direction: Vec3) -> u32 {
+        let metadata = bindless::get_instance_metadata(self.metadata, hit.instance);
+
+        if metadata.medium_inside == NO_MEDIUM && metadata.medium_outside == NO_MEDIUM {
+            current_medium
+        } else if hit.normal.dot(direction) > 0.0 {
+            metadata.medium_outside
+        } else {
+            metadata.medium_inside
+        }
+    }
+
+    pub fn transmittance(&self, from: Vec3, to: Vec3, medium_index: u32, lambda: SampledWavelengths, sampler: &mut dyn ISampler) -> (SampledSpectrum, SampledSpectrum, SampledSpectrum) {
+        let mut t_ray = SampledSpectrum::uniform(1.0);
+        let mut r_u = SampledSpectrum::uniform(1.0);
+        let mut r_l = SampledSpectrum::uniform(1.0);
+
+        let mut origin = from;
+        let mut medium_index = medium_index;
+
+        loop {
+            let distance = (to - origin).length();
+            let direction = (to - origin) / distance;
+            let hit = self.intersect(origin, direction, distance * (1.0 - SHADOW_EPSILON));
+
+            if let Some(hit) = hit {
+                if !self.is_interface(hit.instance) {
+                    return (SampledSpectrum::trivial(), r_u, r_l);
+                }
+            }
+
+            if let Some(medium) = self.medium(medium_index) {
+                let t_max = hit.map_or(distance, |hit| hit.t);
+                let u = sampler.get_1d();
+
+                let t_maj = sample_majorant_transmittance(
+                    Ray::new(origin, direction, 0.0, None),
+                    t_max,
+                    u,
+                    &medium,
+                    lambda,
+                    self.grid_data,
+                    sampler,
+                    |_, properties, sigma_maj, t_maj, sampler| {
+                        let sigma_n = (sigma_maj - properties.sigma_a - properties.sigma_s).clamp_zero();
+
+                        let pdf = t_maj[0] * sigma_maj[0];
+                        t_ray *= t_maj * sigma_n / pdf;
+                        r_l *= t_maj * sigma_maj / pdf;
+                        r_u *= t_maj * sigma_n / pdf;
+
+                        let transmittance = t_ray / (r_l + r_u).average();
+
+                        if transmittance.max_component() < 0.05 {
+                            if sampler.get_1d() < 0.75 {
+                                t_ray = SampledSpectrum::trivial();
+                            } else {
+                                t_ray = t_ray / 0.25;
+                            }
+                        }
+
+                        t_ray.is_nontrivial()
+                    },
+                );
+
+                t_ray *= t_maj / t_maj[0];
+                r_l *= t_maj / t_maj[0];
+                r_u *= t_maj / t_maj[0];
+            }
+
+            if !t_ray.is_nontrivial() {
+                return (t_ray, r_u, r_l);
+            }
+
+            match hit {
+                Some(hit) => {
+                    medium_index = self.next_medium(&hit, medium_index, direction);
+                    origin = hit.spawn_origin(direction);
+                }
+                None => return (t_ray, r_u, r_l),
+            }
+        }
+    }
+}
+
+pub fn sample_majorant_transmittance<F>(ray: Ray, t_max: f32, u: f32, medium: &Medium, lambda: SampledWavelengths, grid_data: &[f32], sampler: &mut dyn ISampler, mut callback: F) -> SampledSpectrum
+where
+    F: FnMut(Vec3, &MediumProperties, SampledSpectrum, SampledSpectrum, &mut dyn ISampler) -> bool,
+{
+    let mut u = u;
+    let mut t_maj = SampledSpectrum::uniform(1.0);
+    let mut iter = medium.sample_ray(ray, t_max, lambda, grid_data);
+
+    while let Some(segment) = iter.next() {
+        if segment.sigma_majorant[0] == 0.0 {
+            let dt = (segment.t_max - segment.t_min).min(f32::MAX);
+            t_maj *= (-segment.sigma_majorant * dt).exp();
+            continue;
+        }
+
+        let mut t_min = segment.t_min;
+
+        loop {
+            let t = t_min + sampling::sample_exponential(u, segment.sigma_majorant[0]);
+            u = sampler.get_1d();
+
+            if t < segment.t_max {
+                t_maj *= (-segment.sigma_majorant * (t - t_min)).exp();
+
+                let point = ray.at(t);
+                let properties = medium.sample_point(point, lambda, grid_data);
+
+                if !callback(point, &properties, segment.sigma_majorant, t_maj, sampler) {
+                    return SampledSpectrum::uniform(1.0);
+                }
+
+                t_maj = SampledSpectrum::uniform(1.0);
+                t_min = t;
+            } else {
+                let dt = (segment.t_max - t_min).min(f32::MAX);
+                t_maj *= (-segment.sigma_majorant * dt).exp();
+                break;
+            }
+        }
+    }
+
+    t_maj
+}
@@ -0,0 +1,177 @@
+use crate::bindless::RenderMaterial;
+use crate::bsdf::dielectric::DielectricBsdf;
+use crate::bsdf::fresnel::TrowbridgeReitzDistribution;
+use crate::bsdf::{Bsdf, BsdfReflTransFlags, TransportMode};
+use crate::integrator::{SceneData, SurfaceHit};
+use crate::sampler::ISampler;
+use crate::spectrum::color::RgbColor;
+use crate::spectrum::{ISpectrum, RgbAlbedoSpectrum, RgbUnboundedSpectrum, SampledSpectrum, SampledWavelengths, N_SAMPLES};
+use crate::util::frame::Frame;
+use crate::util::{math, sampling};
+use core::array;
+use spirv_std::glam::{Vec3, Vec4Swizzles};
+use spirv_std::num_traits::Float;
+
+pub const MAX_WALK_STEPS: u32 = 256;
+
+#[inline]
+pub fn single_scattering_albedo(multiple_scattering_albedo: f32) -> f32 {
+    let albedo = multiple_scattering_albedo.clamp(0.0, 1.0);
+    1.0 - math::sqr(4.09712 + 4.20863 * albedo - (9.59217 + 41.6808 * albedo + 17.7126 * albedo * albedo).sqrt())
+}
+
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct SubsurfaceMaterial {
+    pub boundary: DielectricBsdf,
+    pub sigma_t: SampledSpectrum,
+    pub albedo: SampledSpectrum,
+}
+
+impl SubsurfaceMaterial {
+    pub fn new(scene: &SceneData, material: &RenderMaterial, lambda: &SampledWavelengths) -> Self {
+        let alpha = TrowbridgeReitzDistribution::roughness_to_alpha(material.roughness_factor);
+        let eta = if material.material_property > 0.0 { material.material_property } else { 1.33 };
+
+        let base_color = material.base_color.xyz();
+        let multiple_scattering_albedo = RgbAlbedoSpectrum::new(scene.rgb_to_spectrum_table, RgbColor::new(base_color.x, base_color.y, base_color.z)).sample(lambda);
+        let mean_free_path = RgbUnboundedSpectrum::new(scene.rgb_to_spectrum_table, material.mean_free_path.x, material.mean_free_path.y, material.mean_free_path.z).sample(lambda);
+
+        let sigma_t: [f32; N_SAMPLES] = array::from_fn(|i| if mean_free_path[i] > 0.0 { 1.0 / mean_free_path[i] } else { f32::MAX });
+        let albedo: [f32; N_SAMPLES] = array::from_fn(|i| single_scattering_albedo(multiple_scattering_albedo[i]));
+
+        Self {
+            boundary: DielectricBsdf::new(eta, TrowbridgeReitzDistribution::new(alpha, alpha)),
+            sigma_t: SampledSpectrum::from_array(sigma_t),
+            albedo: SampledSpectrum::from_array(albedo),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct SubsurfaceExit {
+    pub origin: Vec3,
+    pub direction: Vec3,
+    pub beta: SampledSpectrum,
+    pub r_u: SampledSpectrum,
+    pub r_l: SampledSpectrum,
+}
+
+// Samples the boundary at `hit`; a refracted path walks through the interior until it leaves again. `None` ends the path
+#[allow(clippy::too_many_arguments)]
+pub fn scatter(
+    scene: &SceneData,
+    hit: &SurfaceHit,
+    frame: &Frame,
+    output_direction: Vec3,
+    material: &SubsurfaceMaterial,
+    beta: SampledSpectrum,
+    r_u: SampledSpectrum,
+    sampler: &mut dyn ISampler,
+) -> Option<SubsurfaceExit> {
+    let uc = sampler.get_1d();
+    let u = sampler.get_2d();
+
+    let sample = match material.boundary.sample(output_direction, uc, u, TransportMode::Radiance, BsdfReflTransFlags::ALL) {
+        Some(sample) if sample.pdf > 0.0 => sample,
+        _ => return None,
+    };
+
+    let beta = beta * sample.sampled_func * sample.input_direction.z.abs() / sample.pdf;
+    let direction = frame.local_to_global(sample.input_direction);
+
+    if output_direction.z * sample.input_direction.z < 0.0 {
+        random_walk(scene, hit, direction, material, beta, r_u, sampler)
+    } else {
+        Some(SubsurfaceExit {
+            origin: hit.spawn_origin(direction),
+            direction,
+            beta,
+            r_u,
+            r_l: r_u / sample.pdf,
+        })
+    }
+}
+
+pub fn random_walk(
+    scene: &SceneData,
+    entry: &SurfaceHit,
+    direction: Vec3,
+    material: &SubsurfaceMaterial,
+    beta: SampledSpectrum,
+    r_u: SampledSpectrum,
+    sampler: &mut dyn ISampler,
+) -> Option<SubsurfaceExit> {
+    let mut origin = entry.spawn_origin(direction);
+    let mut direction = direction;
+    let mut beta = beta;
+    let mut r_u = r_u;
+
+    for _ in 0..MAX_WALK_STEPS {
+        let t = sampling::sample_exponential(sampler.get_1d(), material.sigma_t[0]);
+
+        match scene.intersect_instance(origin, direction, t, entry.instance) {
+            None => {
+                let transmittance = (-material.sigma_t * t).exp();
+                let pdf = transmittance[0] * material.sigma_t[0];
+
+                beta *= transmittance * material.sigma_t * material.albedo / pdf;
+                r_u *= transmittance * material.sigma_t / pdf;
+
+                if !beta.is_nontrivial() || !r_u.is_nontrivial() {
+                    return None;
+                }
+
+                origin += direction * t;
+                direction = sampling::sample_uniform_sphere(sampler.get_2d());
+            }
+            Some(hit) => {
+                let transmittance = (-material.sigma_t * hit.t).exp();
+                let pdf = transmittance[0];
+
+                beta *= transmittance / pdf;
+                r_u *= transmittance / pdf;
+
+                let frame = Frame::from_z(hit.normal);
+                let output_direction = frame.global_to_local(-direction);
+
+                let uc = sampler.get_1d();
+                let u = sampler.get_2d();
+
+                let sample = match material.boundary.sample(output_direction, uc, u, TransportMode::Radiance, BsdfReflTransFlags::ALL) {
+                    Some(sample) if sample.pdf > 0.0 => sample,
+                    _ => return None,
+                };
+
+                beta *= sample.sampled_func * sample.input_direction.z.abs() / sample.pdf;
+                direction = frame.local_to_global(sample.input_direction);
+                origin = hit.spawn_origin(direction);
+
+                if output_direction.z * sample.input_direction.z < 0.0 {
+                    return Some(SubsurfaceExit {
+                        origin,
+                        direction,
+                        beta,
+                        r_u,
+                        r_l: r_u / sample.pdf,
+                    });
+                }
+            }
+        }
+
+        let rr_beta = beta / r_u.average();
+
+        if rr_beta.max_component() < 0.25 {
+            let q = (1.0 - rr_beta.max_component()).max(0.0);
+
+            if sampler.get_1d() < q {
+                return None;
+            }
+
+            beta = beta / (1.0 - q);
+        }
+    }
+
+    None
+}
@@ -0,0 +1,501 @@
+use crate::bindless::{InstanceMetadata, Light, MaterialType, RenderCamera, RenderMaterial, RenderMedium, Vertex};
+use crate::bsdf::dielectric::DielectricBsdf;
+use crate::bsdf::diffuse::LambertianBsdf;
+use crate::bsdf::fresnel::{ConductorBsdf, TrowbridgeReitzDistribution};
+use crate::bsdf::{Bsdf, BsdfFlags, BsdfReflTransFlags, BsdfSample, TransportMode};
+use crate::camera::filter::RenderFilter;
+use crate::integrator;
+use crate::integrator::subsurface::{self, SubsurfaceMaterial};
+use crate::integrator::SceneData;
+use crate::light::ray::Ray;
+use crate::sampler::{ISampler, Pmj02bnSampler, ZSobolSampler};
+use crate::spectrum::color::{RgbColor, RgbToSpectrumTable, LINEAR_SRGB_FROM_XYZ};
+use crate::spectrum::{ISpectrum, RgbAlbedoSpectrum, SampledSpectrum, SampledWavelengths};
+use crate::util::frame::Frame;
+use crate::util::math;
+use spirv_std::glam::{UVec2, UVec3, Vec2, Vec3, Vec3Swizzles, Vec4, Vec4Swizzles};
+use spirv_std::num_traits::Float;
+use spirv_std::ray_tracing::AccelerationStructure;
+use spirv_std::{spirv, Image};
+
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct VolumetricParameters {
+    pub resolution: UVec2,
+    pub sample_count: u32,
+    pub max_depth: u32,
+    pub camera_medium: u32,
+    pub time_slice: u32,
+    pub time_slice_count: u32,
+    pub time_min: f32,
+    pub time_max: f32,
+    // Half-open bounds of the film, the crop window of the render
+    pub pixel_bounds_min: UVec2,
+    pub pixel_bounds_max: UVec2,
+    // The dispatch covers this tile, which may reach past the film bounds by the filter footprint
+    pub tile_offset: UVec2,
+    pub tile_size: UVec2,
+    pub filter: RenderFilter,
+}
+
+impl VolumetricParameters {
+    pub fn in_tile(&self, pixel: UVec2) -> bool {
+        pixel.cmpge(self.tile_offset).all() && pixel.cmplt((self.tile_offset + self.tile_size).min(self.resolution)).all()
+    }
+
+    pub fn contains(&self, pixel: UVec2) -> bool {
+        self.in_tile(pixel) && pixel.cmpge(self.pixel_bounds_min).all() && pixel.cmplt(self.pixel_bounds_max.min(self.resolution)).all()
+    }
+}
+
+// `u_light` picks the light, `sampler` drives the ratio tracking of the shadow ray
+#[allow(clippy::too_many_arguments)]
+fn sample_light<F>(
+    scene: &SceneData,
+    point: Vec3,
+    medium_index: u32,
+    scatter: F,
+    lambda: SampledWavelengths,
+    beta: SampledSpectrum,
+    r_p: SampledSpectrum,
+    u_light: f32,
+    sampler: &mut dyn ISampler,
+) -> SampledSpectrum
+where
+    F: Fn(Vec3) -> (SampledSpectrum, f32),
+{
+    let light_count = scene.lights.len();
+
+    if light_count == 0 {
+        return SampledSpectrum::trivial();
+    }
+
+    let light_index = ((u_light * light_count as f32) as usize).min(light_count - 1);
+    let light_pdf = 1.0 / light_count as f32;
+    let light = scene.lights[light_index];
+
+    let input_direction = (light.pos - point).normalize();
+    let (func, _) = scatter(input_direction);
+
+    if !func.is_nontrivial() {
+        return SampledSpectrum::trivial();
+    }
+
+    let radiance = SampledSpectrum::uniform(light.scale) / (light.pos - point).length_squared();
+
+    let (t_ray, _, r_l) = scene.transmittance(point, light.pos, medium_index, lambda, sampler);
+
+    if !t_ray.is_nontrivial() {
+        return SampledSpectrum::trivial();
+    }
+
+    let r_l = r_p * r_l * light_pdf;
+
+    beta * func * t_ray * radiance / r_l.average()
+}
+
+#[allow(clippy::too_many_arguments)]
+fn sample_surface_light<B: Bsdf>(
+    scene: &SceneData,
+    bsdf: &B,
+    frame: &Frame,
+    output_direction: Vec3,
+    point: Vec3,
+    medium_index: u32,
+    lambda: SampledWavelengths,
+    beta: SampledSpectrum,
+    r_u: SampledSpectrum,
+    u_light: f32,
+    sampler: &mut dyn ISampler,
+) -> SampledSpectrum {
+    sample_light(
+        scene,
+        point,
+        medium_index,
+        |input_direction| {
+            let input_direction = frame.global_to_local(input_direction);
+            let func = bsdf.bsdf_func(output_direction, input_direction, TransportMode::Radiance) * input_direction.z.abs();
+            (func, bsdf.pdf(output_direction, input_direction, TransportMode::Radiance, BsdfReflTransFlags::ALL))
+        },
+        lambda,
+        beta,
+        r_u,
+        u_light,
+        sampler,
+    )
+}
+
+// Light sampling plus one BSDF sample, returns the sample with the pdf to use for the light path ratio
+#[allow(clippy::too_many_arguments)]
+fn scatter_surface<B: Bsdf>(
+    scene: &SceneData,
+    bsdf: &B,
+    frame: &Frame,
+    output_direction: Vec3,
+    point: Vec3,
+    medium_index: u32,
+    lambda: SampledWavelengths,
+    beta: SampledSpectrum,
+    r_u: SampledSpectrum,
+    sampler: &mut dyn ISampler,
+) -> (SampledSpectrum, Option<(BsdfSample, f32)>) {
+    // Both dimensions are drawn either way, so the BSDF sample keeps its dimension whatever the lobes of the surface
+    let u_light = sampler.get_1d();
+
+    // Delta lobes never match a light direction, so only rough and diffuse surfaces sample lights
+    let light_radiance = if bsdf.flags().intersects(BsdfFlags::DIFFUSE | BsdfFlags::GLOSSY) {
+        sample_surface_light(scene, bsdf, frame, output_direction, point, medium_index, lambda, beta, r_u, u_light, sampler)
+    } else {
+        SampledSpectrum::trivial()
+    };
+
+    let uc = sampler.get_1d();
+    let u = sampler.get_2d();
+
+    let sample = match bsdf.sample(output_direction, uc, u, TransportMode::Radiance, BsdfReflTransFlags::ALL) {
+        Some(sample) if sample.pdf > 0.0 => sample,
+        _ => return (light_radiance, None),
+    };
+
+    let pdf = if sample.pdf_is_proportional {
+        bsdf.pdf(output_direction, sample.input_direction, TransportMode::Radiance, BsdfReflTransFlags::ALL)
+    } else {
+        sample.pdf
+    };
+
+    (light_radiance, Some((sample, pdf)))
+}
+
+// Every decision of the path takes its dimensions from `sampler`, including the loops without a bounded number of decisions
+// (majorant steps, ratio tracking and subsurface walks), as the subsurface random walk does
+pub fn radiance(scene: &SceneData, params: &VolumetricParameters, origin: Vec3, direction: Vec3, lambda: SampledWavelengths, sampler: &mut dyn ISampler) -> SampledSpectrum {
+    let mut radiance = SampledSpectrum::trivial();
+    let mut beta = SampledSpectrum::uniform(1.0);
+    let mut r_u = SampledSpectrum::uniform(1.0);
+    let mut r_l = SampledSpectrum::uniform(1.0);
+
+    let mut origin = origin;
+    let mut direction = direction;
+    let mut medium_index = params.camera_medium;
+    let mut depth = 0;
+
+    loop {
+        let hit = scene.intersect(origin, direction, f32::MAX);
+
+        if let Some(medium) = scene.medium(medium_index) {
+            let mut scattered = false;
+            let mut terminated = false;
+
+            let t_max = hit.map_or(f32::MAX, |hit| hit.t);
+            let u = sampler.get_1d();
+
+            let t_maj = integrator::sample_majorant_transmittance(
+                Ray::new(origin, direction, 0.0, None),
+                t_max,
+                u,
+                &medium,
+                lambda,
+                scene.grid_data,
+                sampler,
+                |point, properties, sigma_maj, t_maj, sampler| {
+                    if depth < params.max_depth && properties.emitted.is_nontrivial() {
+                        let pdf = sigma_maj[0] * t_maj[0];
+                        let beta_p = beta * t_maj / pdf;
+                        let r_e = r_u * sigma_maj * t_maj / pdf;
+
+                        if r_e.is_nontrivial() {
+                            radiance += beta_p * properties.sigma_a * properties.emitted / r_e.average();
+                        }
+                    }
+
+                    let p_absorb = properties.sigma_a[0] / sigma_maj[0];
+                    let p_scatter = properties.sigma_s[0] / sigma_maj[0];
+                    let u_mode = sampler.get_1d();
+
+                    if u_mode < p_absorb {
+                        terminated = true;
+                        false
+                    } else if u_mode < p_absorb + p_scatter {
+                        if depth >= params.max_depth {
+                            terminated = true;
+                            return false;
+                        }
+                        depth += 1;
+
+                        let pdf = t_maj[0] * properties.sigma_s[0];
+                        beta *= t_maj * properties.sigma_s / pdf;
+                        r_u *= t_maj * properties.sigma_s / pdf;
+
+                        if beta.is_nontrivial() && r_u.is_nontrivial() {
+                            let output_direction = -direction;
+                            let phase = properties.phase;
+
+                            radiance += sample_light(
+                                scene,
+                                point,
+                                medium_index,
+                                |input_direction| {
+                                    let func = phase.func_value(output_direction, input_direction);
+                                    (SampledSpectrum::uniform(func), phase.pdf(output_direction, input_direction))
+                                },
+                                lambda,
+                                beta,
+                                r_u,
+                                sampler.get_1d(),
+                                sampler,
+                            );
+
+                            match phase.sample(output_direction, sampler.get_2d()) {
+                                Some(sample) if sample.pdf > 0.0 => {
+                                    beta *= sample.val / sample.pdf;
+                                    r_l = r_u / sample.pdf;
+                                    origin = point;
+                                    direction = sample.input_direction;
+                                    scattered = true;
+                                }
+                                _ => terminated = true,
+                            }
+                        }
+
+                        false
+                    } else {
+                        let sigma_n = (sigma_maj - properties.sigma_a - properties.sigma_s).clamp_zero();
+                        let pdf = t_maj[0] * sigma_n[0];
+
+                        if pdf == 0.0 {
+                            beta = SampledSpectrum::trivial();
+                        } else {
+                            beta *= t_maj * sigma_n / pdf;
+                            r_u *= t_maj * sigma_n / pdf;
+                            r_l *= t_maj * sigma_maj / pdf;
+                        }
+
+                        beta.is_nontrivial() && r_u.is_nontrivial()
+                    }
+                },
+            );
+
+            if terminated || !beta.is_nontrivial() || !r_u.is_nontrivial() {
+                return radiance;
+            }
+
+            if scattered {
+                continue;
+            }
+
+            beta *= t_maj / t_maj[0];
+            r_u *= t_maj / t_maj[0];
+            r_l *= t_maj / t_maj[0];
+        }
+
+        let hit = match hit {
+            Some(hit) => hit,
+            None => return radiance,
+        };
+
+        if scene.is_interface(hit.instance) {
+            medium_index = scene.next_medium(&hit, medium_index, direction);
+            origin = hit.spawn_origin(direction);
+            continue;
+        }
+
+        if depth >= params.max_depth {
+            return radiance;
+        }
+        depth += 1;
+
+        let material = scene.material(hit.instance);
+        let frame = Frame::from_z(hit.normal);
+        let output_direction = frame.global_to_local(-direction);
+        let point = hit.spawn_origin(-direction);
+
+        match MaterialType::from(material.material_type) {
+            MaterialType::Subsurface => {
+                let subsurface = SubsurfaceMaterial::new(scene, material, &lambda);
+                let u_light = sampler.get_1d();
+                radiance += sample_surface_light(scene, &subsurface.boundary, &frame, output_direction, point, medium_index, lambda, beta, r_u, u_light, sampler);
+
+                match subsurface::scatter(scene, &hit, &frame, output_direction, &subsurface, beta, r_u, sampler) {
+                    Some(exit) => {
+                        beta = exit.beta;
+                        r_u = exit.r_u;
+                        r_l = exit.r_l;
+                        origin = exit.origin;
+                        direction = exit.direction;
+                    }
+                    None => return radiance,
+                }
+            }
+            material_type => {
+                let base_color = material.base_color.xyz();
+                let albedo = RgbAlbedoSpectrum::new(scene.rgb_to_spectrum_table, RgbColor::new(base_color.x, base_color.y, base_color.z)).sample(&lambda);
+                let alpha = TrowbridgeReitzDistribution::roughness_to_alpha(material.roughness_factor);
+                let roughness = TrowbridgeReitzDistribution::new(alpha, alpha);
+
+                let (light_radiance, scattered) = match material_type {
+                    MaterialType::Metal => {
+                        let bsdf = ConductorBsdf::from_reflectance(albedo, roughness);
+                        scatter_surface(scene, &bsdf, &frame, output_direction, point, medium_index, lambda, beta, r_u, sampler)
+                    }
+                    MaterialType::Dielectric => {
+                        let eta = if material.material_property > 0.0 { material.material_property } else { 1.5 };
+                        let bsdf = DielectricBsdf::new(eta, roughness);
+                        scatter_surface(scene, &bsdf, &frame, output_direction, point, medium_index, lambda, beta, r_u, sampler)
+                    }
+                    _ => {
+                        let bsdf = LambertianBsdf::new(albedo);
+                        scatter_surface(scene, &bsdf, &frame, output_direction, point, medium_index, lambda, beta, r_u, sampler)
+                    }
+                };
+
+                radiance += light_radiance;
+
+                let (sample, pdf) = match scattered {
+                    Some(scattered) => scattered,
+                    None => return radiance,
+                };
+
+                beta *= sample.sampled_func * sample.input_direction.z.abs() / sample.pdf;
+                r_l = r_u / pdf;
+
+                direction = frame.local_to_global(sample.input_direction);
+                medium_index = scene.next_medium(&hit, medium_index, direction);
+                origin = hit.spawn_origin(direction);
+            }
+        }
+
+        let rr_beta = beta / r_u.average();
+
+        if rr_beta.max_component() < 1.0 && depth > 1 {
+            let q = (1.0 - rr_beta.max_component()).max(0.0);
+
+            if sampler.get_1d() < q {
+                return radiance;
+            }
+
+            beta = beta / (1.0 - q);
+        }
+    }
+}
+
+// Average of this time slice's samples, weighted by its share of the shutter interval
+fn slice_color(scene: &SceneData, params: &VolumetricParameters, camera: &RenderCamera, distributions: &[u32], pixel: UVec2, sampler: &mut dyn ISampler) -> Vec3 {
+    let mut integrated_color = Vec3::ZERO;
+
+    // The time slices split one sample budget, so each slice continues the pixel's sample indices where the previous one stopped
+    for sample_index in 0..params.sample_count {
+        sampler.start_pixel_sample(pixel, params.time_slice * params.sample_count + sample_index, 0);
+
+        let pixel_center: Vec2 = pixel.as_vec2() + sampler.get_pixel_2d();
+        let time = math::lerp(sampler.get_1d(), params.time_min, params.time_max);
+        let u_lens = sampler.get_2d();
+        let lambda = SampledWavelengths::sample_visible(sampler.get_1d());
+        let Some((ray_origin, ray_direction, weight)) = camera.gen_ray(distributions, pixel_center, u_lens, time, &lambda) else {
+            continue;
+        };
+
+        let radiance = radiance(scene, params, ray_origin, ray_direction, lambda, sampler) * weight;
+
+        if !radiance.has_nans() {
+            let xyz = radiance.to_xyz(&lambda);
+            integrated_color += LINEAR_SRGB_FROM_XYZ * Vec3::new(xyz.x, xyz.y, xyz.z);
+        }
+    }
+
+    integrated_color / (params.sample_count * params.time_slice_count.max(1)) as f32
+}
+
+fn accumulate_slice(image_output: &Image!(2D, format = rgba32f, sampled = false), params: &VolumetricParameters, pixel: UVec2, color: Vec3) {
+    let mut color = color;
+
+    if params.time_slice > 0 {
+        let previous: Vec4 = image_output.read(pixel);
+        color += previous.xyz();
+    }
+
+    unsafe { image_output.write(pixel, Vec4::new(color.x, color.y, color.z, 1.0)) };
+}
+
+#[spirv(compute(threads(16, 8, 1)))]
+pub fn main_cs(
+    #[spirv(global_invocation_id)] invocation_id: UVec3,
+    #[spirv(uniform, descriptor_set = 0, binding = 0)] params: &VolumetricParameters,
+    #[spirv(descriptor_set = 0, binding = 1)] image_output: &Image!(2D, format = rgba32f, sampled = false),
+    #[spirv(descriptor_set = 0, binding = 2)] tlas: &AccelerationStructure,
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 3)] vertices: &[Vertex],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 4)] indices: &[u32],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 5)] metadata: &[InstanceMetadata],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 6)] materials: &[RenderMaterial],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 7)] media: &[RenderMedium],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 8)] grid_data: &[f32],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 9)] lights: &[Light],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 10)] rgb_to_spectrum_table: &RgbToSpectrumTable,
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 11)] camera: &RenderCamera,
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 12)] distributions: &[u32],
+) {
+    let pixel = invocation_id.xy() + params.tile_offset;
+
+    if !params.contains(pixel) {
+        return;
+    }
+
+    let scene = SceneData {
+        tlas,
+        vertices,
+        indices,
+        metadata,
+        materials,
+        media,
+        grid_data,
+        lights,
+        rgb_to_spectrum_table,
+    };
+
+    let mut sampler = ZSobolSampler::new(params.sample_count * params.time_slice_count.max(1), params.resolution, 0);
+    let color = slice_color(&scene, params, camera, distributions, pixel, &mut sampler);
+
+    accumulate_slice(image_output, params, pixel, color);
+}
+
+// `main_cs` with the blue noise PMJ02BN sets of `pmj02bn_table`, for previews of a few samples per pixel
+#[spirv(compute(threads(16, 8, 1)))]
+pub fn preview_cs(
+    #[spirv(global_invocation_id)] invocation_id: UVec3,
+    #[spirv(uniform, descriptor_set = 0, binding = 0)] params: &VolumetricParameters,
+    #[spirv(descriptor_set = 0, binding = 1)] image_output: &Image!(2D, format = rgba32f, sampled = false),
+    #[spirv(descriptor_set = 0, binding = 2)] tlas: &AccelerationStructure,
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 3)] vertices: &[Vertex],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 4)] indices: &[u32],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 5)] metadata: &[InstanceMetadata],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 6)] materials: &[RenderMaterial],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 7)] media: &[RenderMedium],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 8)] grid_data: &[f32],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 9)] lights: &[Light],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 10)] rgb_to_spectrum_table: &RgbToSpectrumTable,
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 11)] camera: &RenderCamera,
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 12)] distributions: &[u32],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 13)] pmj02bn_table: &[UVec2],
+) {
+    let pixel = invocation_id.xy() + params.tile_offset;
+
+    if !params.contains(pixel) {
+        return;
+    }
+
+    let scene = SceneData {
+        tlas,
+        vertices,
+        indices,
+        metadata,
+        materials,
+        media,
+        grid_data,
+        lights,
+        rgb_to_spectrum_table,
+    };
+
+    let mut sampler = Pmj02bnSampler::new(params.sample_count * params.time_slice_count.max(1), pmj02bn_table, 0);
+    let color = slice_color(&scene, params, camera, distributions, pixel, &mut sampler);
+
+    accumulate_slice(image_output, params, pixel, color);
+}
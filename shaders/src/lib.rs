@@ -3,7 +3,10 @@
 
 pub mod bsdf;
 pub mod camera;
+pub mod display;
 pub mod light;
+pub mod post;
+pub mod sampler;
 pub mod spectrum;
 pub mod test;
 pub mod util;
@@ -1,14 +1,20 @@
 use crate::light::ray::Ray;
-use crate::spectrum::{SampledSpectrum, SampledWavelengths};
-use core::ops::Deref;
-use spirv_std::glam::{Vec2, Vec3};
+use crate::spectrum::{BlackBodySpectrum, ISpectrum, RgbUnboundedSpectrum, SampledSpectrum, SampledWavelengths};
+use crate::util::frame::Frame;
+use crate::util::grid::SampledGrid;
+use crate::util::math;
+use crate::util::vector;
+use core::f32::consts;
+use core::ops::{Deref, DerefMut};
+use spirv_std::glam::{Mat4, Vec2, Vec3};
+use spirv_std::num_traits::Float;
 
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct PhaseFunctionSample {
-    val: f32,
-    pdf: f32,
-    input_direction: Vec3,
+    pub val: f32,
+    pub pdf: f32,
+    pub input_direction: Vec3,
 }
 
 pub trait IPhaseFunction {
@@ -19,25 +25,77 @@ pub trait IPhaseFunction {
     fn pdf(&self, output_direction: Vec3, input_direction: Vec3) -> f32;
 }
 
-#[derive(Clone, Copy)]
+#[inline]
+pub fn henyey_greenstein(cos_theta: f32, g: f32) -> f32 {
+    let denom = 1.0 + math::sqr(g) + 2.0 * g * cos_theta;
+    consts::FRAC_1_PI * 0.25 * (1.0 - math::sqr(g)) / (denom * denom.max(0.0).sqrt())
+}
+
+pub fn sample_henyey_greenstein(output_direction: Vec3, g: f32, u: Vec2) -> (Vec3, f32) {
+    let cos_theta = if g.abs() < 1.0e-3 {
+        1.0 - 2.0 * u.x
+    } else {
+        -1.0 / (2.0 * g) * (1.0 + math::sqr(g) - math::sqr((1.0 - math::sqr(g)) / (1.0 + g - 2.0 * g * u.x)))
+    };
+
+    let sin_theta = (1.0 - math::sqr(cos_theta)).max(0.0).sqrt();
+    let phi = consts::TAU * u.y;
+
+    let frame = Frame::from_z(output_direction);
+    let input_direction = frame.local_to_global(vector::spherical_direction(sin_theta, cos_theta, phi));
+
+    (input_direction, henyey_greenstein(cos_theta, g))
+}
+
+#[derive(Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct HgPhaseFunction {
+    g: f32,
+}
+
+impl HgPhaseFunction {
+    pub fn new(g: f32) -> Self {
+        Self { g }
+    }
+}
+
+impl IPhaseFunction for HgPhaseFunction {
+    fn func_value(&self, output_direction: Vec3, input_direction: Vec3) -> f32 {
+        henyey_greenstein(output_direction.dot(input_direction), self.g)
+    }
+
+    fn sample(&self, output_direction: Vec3, u: Vec2) -> Option<PhaseFunctionSample> {
+        let (input_direction, pdf) = sample_henyey_greenstein(output_direction, self.g, u);
+
+        Some(PhaseFunctionSample { val: pdf, pdf, input_direction })
+    }
+
+    fn pdf(&self, output_direction: Vec3, input_direction: Vec3) -> f32 {
+        self.func_value(output_direction, input_direction)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
 pub enum PhaseFunction {
-    Hg,
+    Hg(HgPhaseFunction),
 }
 
 impl Deref for PhaseFunction {
     type Target = dyn IPhaseFunction;
 
     fn deref(&self) -> &Self::Target {
-        todo!()
+        match self {
+            PhaseFunction::Hg(phase) => phase,
+        }
     }
 }
 
 #[repr(C)]
 #[derive(Clone, Copy)]
 pub struct RayMajorantSegment {
-    t_min: f32,
-    t_max: f32,
-    sigma_majorant: SampledSpectrum,
+    pub t_min: f32,
+    pub t_max: f32,
+    pub sigma_majorant: SampledSpectrum,
 }
 
 pub trait IRayMajorantIterator {
@@ -45,39 +103,258 @@ pub trait IRayMajorantIterator {
 }
 
 #[derive(Clone, Copy)]
-pub enum RayMajorantIterator {}
+#[repr(C)]
+pub struct HomogeneousMajorantIterator {
+    segment: Option<RayMajorantSegment>,
+}
+
+impl HomogeneousMajorantIterator {
+    pub fn new(t_min: f32, t_max: f32, sigma_majorant: SampledSpectrum) -> Self {
+        Self {
+            segment: Some(RayMajorantSegment { t_min, t_max, sigma_majorant }),
+        }
+    }
+
+    pub fn empty() -> Self {
+        Self { segment: None }
+    }
+}
+
+impl IRayMajorantIterator for HomogeneousMajorantIterator {
+    fn next(&mut self) -> Option<RayMajorantSegment> {
+        self.segment.take()
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum RayMajorantIterator {
+    Homogeneous(HomogeneousMajorantIterator),
+}
+
+impl Deref for RayMajorantIterator {
+    type Target = dyn IRayMajorantIterator;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            RayMajorantIterator::Homogeneous(iter) => iter,
+        }
+    }
+}
+
+impl DerefMut for RayMajorantIterator {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self {
+            RayMajorantIterator::Homogeneous(iter) => iter,
+        }
+    }
+}
 
 #[repr(C)]
 #[derive(Clone, Copy)]
 pub struct MediumProperties {
-    sigma_a: SampledSpectrum,
-    sigma_s: SampledSpectrum,
-    phase: PhaseFunction,
-    emitted: SampledSpectrum,
+    pub sigma_a: SampledSpectrum,
+    pub sigma_s: SampledSpectrum,
+    pub phase: PhaseFunction,
+    pub emitted: SampledSpectrum,
 }
 
 pub trait IMedium {
     fn is_emissive(&self) -> bool;
 
-    fn sample_point(&self, point: Vec3, lambda: SampledWavelengths) -> f32;
+    fn sample_point(&self, point: Vec3, lambda: SampledWavelengths, grid_data: &[f32]) -> MediumProperties;
 
-    fn sample_ray(&self, ray: Ray, t_max: f32, lambda: SampledWavelengths) -> RayMajorantIterator;
+    fn sample_ray(&self, ray: Ray, t_max: f32, lambda: SampledWavelengths, grid_data: &[f32]) -> RayMajorantIterator;
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq)]
 pub enum Medium {
-    Homogeneous,
-    Grid,
-    RgbGrid,
-    Cloud,
-    NanoVdb,
+    Homogeneous(HomogeneousMedium),
+    Grid(GridMedium),
 }
 
 impl Deref for Medium {
     type Target = dyn IMedium;
 
     fn deref(&self) -> &Self::Target {
-        todo!()
+        match self {
+            Medium::Homogeneous(medium) => medium,
+            Medium::Grid(medium) => medium,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct HomogeneousMedium {
+    sigma_a: RgbUnboundedSpectrum,
+    sigma_s: RgbUnboundedSpectrum,
+    scale: f32,
+    emitted: RgbUnboundedSpectrum,
+    emission_scale: f32,
+    phase: HgPhaseFunction,
+}
+
+impl HomogeneousMedium {
+    pub fn new(sigma_a: RgbUnboundedSpectrum, sigma_s: RgbUnboundedSpectrum, scale: f32, emitted: RgbUnboundedSpectrum, emission_scale: f32, g: f32) -> Self {
+        Self {
+            sigma_a,
+            sigma_s,
+            scale,
+            emitted,
+            emission_scale,
+            phase: HgPhaseFunction::new(g),
+        }
+    }
+}
+
+impl IMedium for HomogeneousMedium {
+    fn is_emissive(&self) -> bool {
+        self.emission_scale > 0.0 && self.emitted.max_value() > 0.0
+    }
+
+    fn sample_point(&self, _: Vec3, lambda: SampledWavelengths, _: &[f32]) -> MediumProperties {
+        MediumProperties {
+            sigma_a: self.sigma_a.sample(&lambda) * self.scale,
+            sigma_s: self.sigma_s.sample(&lambda) * self.scale,
+            phase: PhaseFunction::Hg(self.phase),
+            emitted: self.emitted.sample(&lambda) * self.emission_scale,
+        }
+    }
+
+    fn sample_ray(&self, _: Ray, t_max: f32, lambda: SampledWavelengths, _: &[f32]) -> RayMajorantIterator {
+        let sigma_majorant = (self.sigma_a.sample(&lambda) + self.sigma_s.sample(&lambda)) * self.scale;
+        RayMajorantIterator::Homogeneous(HomogeneousMajorantIterator::new(0.0, t_max, sigma_majorant))
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct BlackBodyEmission {
+    temperature_grid: SampledGrid,
+    temperature_scale: f32,
+    temperature_offset: f32,
+    emission_scale: f32,
+}
+
+impl BlackBodyEmission {
+    pub fn new(temperature_grid: SampledGrid, temperature_scale: f32, temperature_offset: f32, emission_scale: f32) -> Self {
+        Self {
+            temperature_grid,
+            temperature_scale,
+            temperature_offset,
+            emission_scale,
+        }
+    }
+
+    pub fn temperature(&self, point: Vec3, grid_data: &[f32]) -> f32 {
+        (self.temperature_grid.sample(grid_data, point) - self.temperature_offset) * self.temperature_scale
+    }
+
+    pub fn emitted(&self, point: Vec3, lambda: &SampledWavelengths, grid_data: &[f32]) -> SampledSpectrum {
+        let temp = self.temperature(point, grid_data);
+
+        if temp <= 100.0 || self.emission_scale <= 0.0 {
+            SampledSpectrum::trivial()
+        } else {
+            BlackBodySpectrum::new(temp).sample(lambda) * self.emission_scale
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct GridMedium {
+    bounds_min: Vec3,
+    bounds_max: Vec3,
+    medium_from_render: Mat4,
+    sigma_a: RgbUnboundedSpectrum,
+    sigma_s: RgbUnboundedSpectrum,
+    density_scale: f32,
+    density_grid: SampledGrid,
+    density_max: f32,
+    phase: HgPhaseFunction,
+    emission: Option<BlackBodyEmission>,
+}
+
+impl GridMedium {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        medium_from_render: Mat4,
+        bounds_min: Vec3,
+        bounds_max: Vec3,
+        sigma_a: RgbUnboundedSpectrum,
+        sigma_s: RgbUnboundedSpectrum,
+        density_scale: f32,
+        g: f32,
+        density_grid: SampledGrid,
+        emission: Option<BlackBodyEmission>,
+        density_max: f32,
+    ) -> Self {
+        Self {
+            bounds_min,
+            bounds_max,
+            medium_from_render,
+            sigma_a,
+            sigma_s,
+            density_scale,
+            density_grid,
+            density_max,
+            phase: HgPhaseFunction::new(g),
+            emission,
+        }
+    }
+
+    fn intersect_bounds(&self, origin: Vec3, direction: Vec3, t_max: f32) -> Option<(f32, f32)> {
+        let inv_direction = direction.recip();
+        let t_near = (self.bounds_min - origin) * inv_direction;
+        let t_far = (self.bounds_max - origin) * inv_direction;
+
+        let t0 = t_near.min(t_far).max_element().max(0.0);
+        let t1 = t_near.max(t_far).min_element().min(t_max);
+
+        if t0 > t1 {
+            None
+        } else {
+            Some((t0, t1))
+        }
+    }
+}
+
+impl IMedium for GridMedium {
+    fn is_emissive(&self) -> bool {
+        self.emission.is_some()
+    }
+
+    fn sample_point(&self, point: Vec3, lambda: SampledWavelengths, grid_data: &[f32]) -> MediumProperties {
+        let point = self.medium_from_render.transform_point3(point);
+        let point = (point - self.bounds_min) / (self.bounds_max - self.bounds_min);
+
+        let density = self.density_grid.sample(grid_data, point) * self.density_scale;
+
+        let emitted = match self.emission {
+            Some(emission) => emission.emitted(point, &lambda, grid_data),
+            None => SampledSpectrum::trivial(),
+        };
+
+        MediumProperties {
+            sigma_a: self.sigma_a.sample(&lambda) * density,
+            sigma_s: self.sigma_s.sample(&lambda) * density,
+            phase: PhaseFunction::Hg(self.phase),
+            emitted,
+        }
+    }
+
+    fn sample_ray(&self, ray: Ray, t_max: f32, lambda: SampledWavelengths, _: &[f32]) -> RayMajorantIterator {
+        let origin = self.medium_from_render.transform_point3(ray.origin);
+        let direction = self.medium_from_render.transform_vector3(ray.direction);
+
+        match self.intersect_bounds(origin, direction, t_max) {
+            Some((t_min, t_max)) => {
+                let sigma_majorant = (self.sigma_a.sample(&lambda) + self.sigma_s.sample(&lambda)) * self.density_scale * self.density_max;
+                RayMajorantIterator::Homogeneous(HomogeneousMajorantIterator::new(t_min, t_max, sigma_majorant))
+            }
+            None => RayMajorantIterator::Homogeneous(HomogeneousMajorantIterator::empty()),
+        }
     }
 }
 
@@ -94,6 +371,6 @@ impl MediumInterface {
     }
 
     pub fn is_medium_transition(&self) -> bool {
-        todo!()
+        self.inside != self.outside
     }
 }
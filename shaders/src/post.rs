@@ -0,0 +1,66 @@
+use spirv_std::glam::{UVec2, UVec3, Vec2, Vec3Swizzles, Vec4};
+use spirv_std::{spirv, Image};
+
+// Filled by `PostProcess::to_parameters` on the host, whose `PostParameters` must keep this field order
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct PostParameters {
+    pub resolution: UVec2,
+    pub vignetting_strength: f32,
+    pub vignetting_falloff_radius: f32,
+    pub chromatic_aberration: f32,
+}
+
+type PostImage = Image!(2D, format = rgba32f, sampled = false);
+
+fn vignetting_factor(radius: f32, strength: f32, falloff_radius: f32) -> f32 {
+    let ratio = radius / falloff_radius;
+    let cos2 = 1.0 / (1.0 + ratio * ratio);
+
+    1.0 + strength * (cos2 * cos2 - 1.0)
+}
+
+fn sample_bilinear(image: &PostImage, resolution: UVec2, point: Vec2, channel: usize) -> f32 {
+    let point = (point - 0.5).clamp(Vec2::ZERO, Vec2::new(resolution.x as f32 - 1.0, resolution.y as f32 - 1.0));
+
+    let x0 = point.x as u32;
+    let y0 = point.y as u32;
+    let x1 = (x0 + 1).min(resolution.x - 1);
+    let y1 = (y0 + 1).min(resolution.y - 1);
+    let t = point - Vec2::new(x0 as f32, y0 as f32);
+
+    let at = |x: u32, y: u32| {
+        let value: Vec4 = image.read(UVec2::new(x, y));
+        value[channel]
+    };
+
+    (at(x0, y0) * (1.0 - t.x) + at(x1, y0) * t.x) * (1.0 - t.y) + (at(x0, y1) * (1.0 - t.x) + at(x1, y1) * t.x) * t.y
+}
+
+#[spirv(compute(threads(16, 8, 1)))]
+pub fn main_cs(
+    #[spirv(global_invocation_id)] invocation_id: UVec3,
+    #[spirv(uniform, descriptor_set = 0, binding = 0)] params: &PostParameters,
+    #[spirv(descriptor_set = 0, binding = 1)] image_input: &PostImage,
+    #[spirv(descriptor_set = 0, binding = 2)] image_output: &PostImage,
+) {
+    let pixel = invocation_id.xy();
+
+    if pixel.x >= params.resolution.x || pixel.y >= params.resolution.y {
+        return;
+    }
+
+    let center = 0.5 * Vec2::new(params.resolution.x as f32, params.resolution.y as f32);
+    let point = Vec2::new(pixel.x as f32, pixel.y as f32) + 0.5;
+
+    let mut value: Vec4 = image_input.read(pixel);
+
+    if params.chromatic_aberration != 0.0 {
+        value.x = sample_bilinear(image_input, params.resolution, center + (point - center) / (1.0 + params.chromatic_aberration), 0);
+        value.z = sample_bilinear(image_input, params.resolution, center + (point - center) / (1.0 - params.chromatic_aberration), 2);
+    }
+
+    let factor = vignetting_factor((point - center).length() / center.length(), params.vignetting_strength, params.vignetting_falloff_radius);
+
+    unsafe { image_output.write(pixel, Vec4::new(value.x * factor, value.y * factor, value.z * factor, value.w)) };
+}
@@ -0,0 +1,168 @@
+use crate::sampler::{hash, permutation_element, ISampler, ONE_MINUS_EPSILON};
+use spirv_std::glam::{UVec2, Vec2};
+
+pub const PRIME_COUNT: usize = 64;
+
+pub const PRIMES: [u32; PRIME_COUNT] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97, 101, 103, 107, 109, 113, 127, 131, 137, 139, 149, 151, 157, 163, 167, 173, 179, 181, 191, 193, 197,
+    199, 211, 223, 227, 229, 233, 239, 241, 251, 257, 263, 269, 271, 277, 281, 283, 293, 307, 311,
+];
+
+// The first two dimensions repeat over tiles of at most this many pixels, so pixel samples are found with the inverse radical inverse instead of a search
+pub const MAX_HALTON_RESOLUTION: u32 = 128;
+
+pub fn radical_inverse(base_index: usize, a: u64) -> f32 {
+    optics_common::lens::radical_inverse(PRIMES[base_index] as u64, a)
+}
+
+// Index of the `digit_count` digit number whose radical inverse has the digits of `inverse`
+pub fn inverse_radical_inverse(mut inverse: u64, base: u64, digit_count: u32) -> u64 {
+    let mut index = 0;
+
+    for _ in 0..digit_count {
+        let digit = inverse % base;
+        inverse /= base;
+        index = index * base + digit;
+    }
+
+    index
+}
+
+// Radical inverse with every digit position shuffled by its own permutation, breaks up the correlation between dimensions with large bases
+pub fn permuted_radical_inverse(base_index: usize, mut a: u64, seed: u32) -> f32 {
+    let base = PRIMES[base_index];
+    let inv_base = 1.0 / base as f32;
+    let mut inv_base_m = 1.0;
+    let mut reversed_digits = 0u64;
+    let mut digit_index = 0;
+
+    // Trailing zero digits are permuted as well, until they no longer change the float result
+    while 1.0 - (base - 1) as f32 * inv_base_m < 1.0 {
+        let next = a / base as u64;
+        let digit = (a - next * base as u64) as u32;
+        let permutation_seed = hash([seed, base_index as u32, digit_index]) as u32;
+
+        reversed_digits = reversed_digits * base as u64 + permutation_element(digit, base, permutation_seed) as u64;
+        inv_base_m *= inv_base;
+        digit_index += 1;
+        a = next;
+    }
+
+    (reversed_digits as f32 * inv_base_m).min(ONE_MINUS_EPSILON)
+}
+
+// Inverse of `a` modulo `n` by the extended Euclidean algorithm
+pub fn multiplicative_inverse(a: i64, n: i64) -> u64 {
+    let (mut old_r, mut r) = (a, n);
+    let (mut old_x, mut x) = (1i64, 0i64);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+    }
+
+    old_x.rem_euclid(n) as u64
+}
+
+#[derive(Clone, Copy)]
+pub struct HaltonSampler {
+    pub samples_per_pixel: u32,
+    pub seed: u32,
+    base_scales: UVec2,
+    base_exponents: UVec2,
+    mult_inverse: [u64; 2],
+    halton_index: u64,
+    dimension: u32,
+}
+
+impl HaltonSampler {
+    pub fn new(samples_per_pixel: u32, resolution: UVec2, seed: u32) -> Self {
+        let mut base_scales = UVec2::ONE;
+        let mut base_exponents = UVec2::ZERO;
+
+        for i in 0..2 {
+            let base = PRIMES[i];
+
+            while base_scales[i] < resolution[i].min(MAX_HALTON_RESOLUTION) {
+                base_scales[i] *= base;
+                base_exponents[i] += 1;
+            }
+        }
+
+        let mult_inverse = [
+            multiplicative_inverse(base_scales.y as i64, base_scales.x as i64),
+            multiplicative_inverse(base_scales.x as i64, base_scales.y as i64),
+        ];
+
+        Self {
+            samples_per_pixel,
+            seed,
+            base_scales,
+            base_exponents,
+            mult_inverse,
+            halton_index: 0,
+            dimension: 0,
+        }
+    }
+
+    fn sample_dimension(&self, dimension: u32) -> f32 {
+        permuted_radical_inverse(dimension as usize, self.halton_index, self.seed)
+    }
+}
+
+impl ISampler for HaltonSampler {
+    fn samples_per_pixel(&self) -> u32 {
+        self.samples_per_pixel
+    }
+
+    // Finds the first Halton index that lands in the pixel, the following samples of the pixel are `sample_stride` apart
+    fn start_pixel_sample(&mut self, pixel: UVec2, sample_index: u32, dimension: u32) {
+        let sample_stride = (self.base_scales.x * self.base_scales.y) as u64;
+        self.halton_index = 0;
+
+        if sample_stride > 1 {
+            let pixel = pixel % MAX_HALTON_RESOLUTION;
+
+            for i in 0..2 {
+                let offset = inverse_radical_inverse(pixel[i] as u64, PRIMES[i] as u64, self.base_exponents[i]);
+                self.halton_index += offset * (sample_stride / self.base_scales[i] as u64) * self.mult_inverse[i];
+            }
+
+            self.halton_index %= sample_stride;
+        }
+
+        self.halton_index += sample_index as u64 * sample_stride;
+        self.dimension = dimension.max(2);
+    }
+
+    fn get_1d(&mut self) -> f32 {
+        if self.dimension as usize >= PRIME_COUNT {
+            self.dimension = 2;
+        }
+
+        let dimension = self.dimension;
+        self.dimension += 1;
+
+        self.sample_dimension(dimension)
+    }
+
+    fn get_2d(&mut self) -> Vec2 {
+        if self.dimension as usize + 1 >= PRIME_COUNT {
+            self.dimension = 2;
+        }
+
+        let dimension = self.dimension;
+        self.dimension += 2;
+
+        Vec2::new(self.sample_dimension(dimension), self.sample_dimension(dimension + 1))
+    }
+
+    // The first two dimensions are scaled up to the pixel grid, so they are left unpermuted to keep each sample inside its pixel
+    fn get_pixel_2d(&mut self) -> Vec2 {
+        Vec2::new(
+            radical_inverse(0, self.halton_index >> self.base_exponents.x),
+            radical_inverse(1, self.halton_index / self.base_scales.y as u64),
+        )
+    }
+}
@@ -0,0 +1,55 @@
+use crate::sampler::{hash, to_unit_float, ISampler};
+use spirv_std::glam::{UVec2, Vec2};
+
+// Uncorrelated uniform samples, the baseline the other samplers are measured against
+#[derive(Clone, Copy)]
+pub struct IndependentSampler {
+    pub samples_per_pixel: u32,
+    pub seed: u32,
+    pixel: UVec2,
+    sample_index: u32,
+    dimension: u32,
+}
+
+impl IndependentSampler {
+    pub fn new(samples_per_pixel: u32, seed: u32) -> Self {
+        Self {
+            samples_per_pixel,
+            seed,
+            pixel: UVec2::ZERO,
+            sample_index: 0,
+            dimension: 0,
+        }
+    }
+
+    fn next(&mut self) -> f32 {
+        let bits = hash([self.pixel.x, self.pixel.y, self.sample_index, self.dimension, self.seed]);
+        self.dimension += 1;
+
+        to_unit_float((bits >> 32) as u32)
+    }
+}
+
+impl ISampler for IndependentSampler {
+    fn samples_per_pixel(&self) -> u32 {
+        self.samples_per_pixel
+    }
+
+    fn start_pixel_sample(&mut self, pixel: UVec2, sample_index: u32, dimension: u32) {
+        self.pixel = pixel;
+        self.sample_index = sample_index;
+        self.dimension = dimension;
+    }
+
+    fn get_1d(&mut self) -> f32 {
+        self.next()
+    }
+
+    fn get_2d(&mut self) -> Vec2 {
+        Vec2::new(self.next(), self.next())
+    }
+
+    fn get_pixel_2d(&mut self) -> Vec2 {
+        self.get_2d()
+    }
+}
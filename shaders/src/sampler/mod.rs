@@ -0,0 +1,179 @@
+use crate::camera::filter::IFilmFilter;
+use crate::camera::CameraSample;
+use core::ops::{Deref, DerefMut};
+use spirv_std::glam::{UVec2, Vec2};
+
+pub mod halton;
+pub mod independent;
+pub mod pmj02bn;
+pub mod sobol;
+pub mod stratified;
+
+pub use halton::HaltonSampler;
+pub use independent::IndependentSampler;
+pub use pmj02bn::Pmj02bnSampler;
+pub use sobol::{PaddedSobolSampler, ZSobolSampler};
+pub use stratified::StratifiedSampler;
+
+// Largest float below one, samples are clamped to it so they stay in [0, 1)
+pub const ONE_MINUS_EPSILON: f32 = 0.99999994;
+
+// Every sample is a function of the pixel, the sample index and the dimension, so the same pixel sample gets the same values in any pass or tile
+pub trait ISampler {
+    fn samples_per_pixel(&self) -> u32;
+
+    fn start_pixel_sample(&mut self, pixel: UVec2, sample_index: u32, dimension: u32);
+
+    fn get_1d(&mut self) -> f32;
+
+    fn get_2d(&mut self) -> Vec2;
+
+    // Position inside the pixel, samplers that place samples over the whole image use this to keep the first two dimensions well distributed across pixels
+    fn get_pixel_2d(&mut self) -> Vec2;
+
+    fn get_camera_sample(&mut self, pixel: UVec2, filter: &dyn IFilmFilter, distributions: &[u32]) -> CameraSample {
+        let u_filter = self.get_pixel_2d();
+        let time = self.get_1d();
+        let u_lens = self.get_2d();
+
+        CameraSample::new(pixel, filter, distributions, u_filter, u_lens, time)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum SamplerType {
+    Independent,
+    Stratified,
+    Halton,
+    PaddedSobol,
+    ZSobol,
+    Pmj02bn,
+}
+
+impl From<u32> for SamplerType {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => SamplerType::Independent,
+            1 => SamplerType::Stratified,
+            2 => SamplerType::Halton,
+            3 => SamplerType::PaddedSobol,
+            4 => SamplerType::ZSobol,
+            5 => SamplerType::Pmj02bn,
+            _ => panic!("Invalid sampler type"),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum Sampler<'a> {
+    Independent(IndependentSampler),
+    Stratified(StratifiedSampler),
+    Halton(HaltonSampler),
+    PaddedSobol(PaddedSobolSampler),
+    ZSobol(ZSobolSampler),
+    Pmj02bn(Pmj02bnSampler<'a>),
+}
+
+impl<'a> Sampler<'a> {
+    // `pmj02bn_table` is only read by `SamplerType::Pmj02bn`
+    pub fn new(sampler_type: SamplerType, samples_per_pixel: u32, resolution: UVec2, pmj02bn_table: &'a [UVec2], seed: u32) -> Self {
+        match sampler_type {
+            SamplerType::Independent => Sampler::Independent(IndependentSampler::new(samples_per_pixel, seed)),
+            SamplerType::Stratified => Sampler::Stratified(StratifiedSampler::new(samples_per_pixel, true, seed)),
+            SamplerType::Halton => Sampler::Halton(HaltonSampler::new(samples_per_pixel, resolution, seed)),
+            SamplerType::PaddedSobol => Sampler::PaddedSobol(PaddedSobolSampler::new(samples_per_pixel, seed)),
+            SamplerType::ZSobol => Sampler::ZSobol(ZSobolSampler::new(samples_per_pixel, resolution, seed)),
+            SamplerType::Pmj02bn => Sampler::Pmj02bn(Pmj02bnSampler::new(samples_per_pixel, pmj02bn_table, seed)),
+        }
+    }
+}
+
+impl<'a> Deref for Sampler<'a> {
+    type Target = dyn ISampler + 'a;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Sampler::Independent(sampler) => sampler,
+            Sampler::Stratified(sampler) => sampler,
+            Sampler::Halton(sampler) => sampler,
+            Sampler::PaddedSobol(sampler) => sampler,
+            Sampler::ZSobol(sampler) => sampler,
+            Sampler::Pmj02bn(sampler) => sampler,
+        }
+    }
+}
+
+impl DerefMut for Sampler<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        match self {
+            Sampler::Independent(sampler) => sampler,
+            Sampler::Stratified(sampler) => sampler,
+            Sampler::Halton(sampler) => sampler,
+            Sampler::PaddedSobol(sampler) => sampler,
+            Sampler::ZSobol(sampler) => sampler,
+            Sampler::Pmj02bn(sampler) => sampler,
+        }
+    }
+}
+
+// 64-bit finalizer from splitmix, every input bit affects every output bit
+pub fn mix_bits(mut value: u64) -> u64 {
+    value ^= value >> 31;
+    value = value.wrapping_mul(0x7fb5_d329_728e_a185);
+    value ^= value >> 27;
+    value = value.wrapping_mul(0x81da_def4_bc2d_d44d);
+    value ^= value >> 33;
+
+    value
+}
+
+pub fn hash<const N: usize>(values: [u32; N]) -> u64 {
+    let mut hash = 0x9e37_79b9_7f4a_7c15;
+
+    for value in values {
+        hash = mix_bits(hash ^ value as u64).wrapping_add(0x9e37_79b9_7f4a_7c15);
+    }
+
+    hash
+}
+
+pub fn to_unit_float(bits: u32) -> f32 {
+    ((bits >> 8) as f32 * (1.0 / 16_777_216.0)).min(ONE_MINUS_EPSILON)
+}
+
+// Element `index` of a random permutation of 0..length selected by `seed`, without storing the permutation (Kensler, "Correlated Multi-Jittered Sampling")
+pub fn permutation_element(mut index: u32, length: u32, seed: u32) -> u32 {
+    let mut mask = length.wrapping_sub(1);
+    mask |= mask >> 1;
+    mask |= mask >> 2;
+    mask |= mask >> 4;
+    mask |= mask >> 8;
+    mask |= mask >> 16;
+
+    loop {
+        index ^= seed;
+        index = index.wrapping_mul(0xe170_893d);
+        index ^= seed >> 16;
+        index ^= (index & mask) >> 4;
+        index ^= seed >> 8;
+        index = index.wrapping_mul(0x0929_eb3f);
+        index ^= seed >> 23;
+        index ^= (index & mask) >> 1;
+        index = index.wrapping_mul(1 | seed >> 27);
+        index = index.wrapping_mul(0x6935_fa69);
+        index ^= (index & mask) >> 11;
+        index = index.wrapping_mul(0x74dc_b303);
+        index ^= (index & mask) >> 2;
+        index = index.wrapping_mul(0x9e50_1cc3);
+        index ^= (index & mask) >> 2;
+        index = index.wrapping_mul(0xc860_a3df);
+        index &= mask;
+        index ^= index >> 5;
+
+        if index < length {
+            break;
+        }
+    }
+
+    index.wrapping_add(seed) % length
+}
@@ -0,0 +1,75 @@
+use crate::sampler::{hash, mix_bits, permutation_element, to_unit_float, ISampler, ONE_MINUS_EPSILON};
+use optics_common::sampler::{PMJ02BN_SAMPLE_COUNT, PMJ02BN_SET_COUNT};
+use spirv_std::glam::{UVec2, Vec2};
+
+// Progressive multi-jittered (0,2) samples with blue noise spacing, read from precomputed sets in 0.32 fixed point.
+// Every power of two prefix of a set is well stratified, so the first few samples of a preview already converge quickly
+#[derive(Clone, Copy)]
+pub struct Pmj02bnSampler<'a> {
+    pub samples_per_pixel: u32,
+    pub seed: u32,
+    table: &'a [UVec2],
+    pixel: UVec2,
+    sample_index: u32,
+    dimension: u32,
+}
+
+impl<'a> Pmj02bnSampler<'a> {
+    // The sample count is rounded up to a power of two and capped at the size of a set
+    pub fn new(samples_per_pixel: u32, table: &'a [UVec2], seed: u32) -> Self {
+        Self {
+            samples_per_pixel: samples_per_pixel.max(1).next_power_of_two().min(PMJ02BN_SAMPLE_COUNT),
+            seed,
+            table,
+            pixel: UVec2::ZERO,
+            sample_index: 0,
+            dimension: 0,
+        }
+    }
+
+    // Xor with a random mask flips whole digits, which keeps every elementary interval stratified
+    fn sample(&self, set: u32, index: u32, scramble: u64) -> Vec2 {
+        let sample = self.table[(set * PMJ02BN_SAMPLE_COUNT + index % PMJ02BN_SAMPLE_COUNT) as usize];
+
+        Vec2::new(to_unit_float(sample.x ^ scramble as u32), to_unit_float(sample.y ^ (scramble >> 32) as u32))
+    }
+}
+
+impl ISampler for Pmj02bnSampler<'_> {
+    fn samples_per_pixel(&self) -> u32 {
+        self.samples_per_pixel
+    }
+
+    fn start_pixel_sample(&mut self, pixel: UVec2, sample_index: u32, dimension: u32) {
+        self.pixel = pixel;
+        self.sample_index = sample_index;
+        self.dimension = dimension;
+    }
+
+    fn get_1d(&mut self) -> f32 {
+        let hash = hash([self.pixel.x, self.pixel.y, self.dimension, self.seed]);
+        let index = permutation_element(self.sample_index % self.samples_per_pixel, self.samples_per_pixel, hash as u32);
+        let delta = to_unit_float((hash >> 32) as u32);
+        self.dimension += 1;
+
+        ((index as f32 + delta) / self.samples_per_pixel as f32).min(ONE_MINUS_EPSILON)
+    }
+
+    // Sets are picked per dimension pair and the sample order is shuffled per pixel, so dimensions do not correlate
+    fn get_2d(&mut self) -> Vec2 {
+        let hash = hash([self.pixel.x, self.pixel.y, self.dimension, self.seed]);
+        let index = permutation_element(self.sample_index % self.samples_per_pixel, self.samples_per_pixel, hash as u32);
+        let set = (self.dimension / 2) % PMJ02BN_SET_COUNT;
+        self.dimension += 2;
+
+        self.sample(set, index, mix_bits(hash))
+    }
+
+    // Unshuffled, so a pixel that keeps sampling past a preview still walks the set in its progressive order
+    fn get_pixel_2d(&mut self) -> Vec2 {
+        let hash = hash([self.pixel.x, self.pixel.y, self.seed]);
+        let set = (hash % PMJ02BN_SET_COUNT as u64) as u32;
+
+        self.sample(set, self.sample_index, mix_bits(hash))
+    }
+}
@@ -0,0 +1,207 @@
+use crate::sampler::{hash, mix_bits, permutation_element, to_unit_float, ISampler};
+use optics_common::sampler::encode_morton2;
+use spirv_std::glam::{UVec2, Vec2};
+
+// Only the first two Sobol dimensions are used, padded with independent scrambles, so their generator matrices are built on the fly instead of stored:
+// dimension 0 is the van der Corput sequence and the rows of dimension 1 are the binary Pascal matrix
+pub fn sobol_sample(mut a: u64, dimension: u32, seed: u32) -> f32 {
+    let mut row = 0x8000_0000u32;
+    let mut v = 0u32;
+
+    while a != 0 {
+        if a & 1 != 0 {
+            v ^= row;
+        }
+
+        row = if dimension == 0 { row >> 1 } else { row ^ (row >> 1) };
+        a >>= 1;
+    }
+
+    to_unit_float(fast_owen_scramble(v, seed))
+}
+
+// Nested uniform scrambling by hashing, flips each bit depending only on the bits above it (Burley, "Practical Hash-based Owen Scrambling")
+pub fn fast_owen_scramble(mut v: u32, seed: u32) -> u32 {
+    v = v.reverse_bits();
+    v ^= v.wrapping_mul(0x3d20_adea);
+    v = v.wrapping_add(seed);
+    v = v.wrapping_mul((seed >> 16) | 1);
+    v ^= v.wrapping_mul(0x0552_6c56);
+    v ^= v.wrapping_mul(0x53a2_2864);
+
+    v.reverse_bits()
+}
+
+// Each pixel and dimension pair gets its own shuffle of a scrambled 2D Sobol set, the sample count is rounded up to a power of two
+#[derive(Clone, Copy)]
+pub struct PaddedSobolSampler {
+    pub samples_per_pixel: u32,
+    pub seed: u32,
+    pixel: UVec2,
+    sample_index: u32,
+    dimension: u32,
+}
+
+impl PaddedSobolSampler {
+    pub fn new(samples_per_pixel: u32, seed: u32) -> Self {
+        Self {
+            samples_per_pixel: samples_per_pixel.max(1).next_power_of_two(),
+            seed,
+            pixel: UVec2::ZERO,
+            sample_index: 0,
+            dimension: 0,
+        }
+    }
+
+    fn index_and_hash(&self) -> (u64, u64) {
+        let hash = hash([self.pixel.x, self.pixel.y, self.dimension, self.seed]);
+        let index = permutation_element(self.sample_index % self.samples_per_pixel, self.samples_per_pixel, hash as u32);
+
+        (index as u64, hash)
+    }
+}
+
+impl ISampler for PaddedSobolSampler {
+    fn samples_per_pixel(&self) -> u32 {
+        self.samples_per_pixel
+    }
+
+    fn start_pixel_sample(&mut self, pixel: UVec2, sample_index: u32, dimension: u32) {
+        self.pixel = pixel;
+        self.sample_index = sample_index;
+        self.dimension = dimension;
+    }
+
+    fn get_1d(&mut self) -> f32 {
+        let (index, hash) = self.index_and_hash();
+        self.dimension += 1;
+
+        sobol_sample(index, 0, (hash >> 32) as u32)
+    }
+
+    fn get_2d(&mut self) -> Vec2 {
+        let (index, hash) = self.index_and_hash();
+        self.dimension += 2;
+
+        Vec2::new(sobol_sample(index, 0, hash as u32), sobol_sample(index, 1, (hash >> 32) as u32))
+    }
+
+    fn get_pixel_2d(&mut self) -> Vec2 {
+        self.get_2d()
+    }
+}
+
+// All 24 orderings of a base 4 digit
+const BASE4_PERMUTATIONS: [[u32; 4]; 24] = [
+    [0, 1, 2, 3],
+    [0, 1, 3, 2],
+    [0, 2, 1, 3],
+    [0, 2, 3, 1],
+    [0, 3, 2, 1],
+    [0, 3, 1, 2],
+    [1, 0, 2, 3],
+    [1, 0, 3, 2],
+    [1, 2, 0, 3],
+    [1, 2, 3, 0],
+    [1, 3, 2, 0],
+    [1, 3, 0, 2],
+    [2, 1, 0, 3],
+    [2, 1, 3, 0],
+    [2, 0, 1, 3],
+    [2, 0, 3, 1],
+    [2, 3, 0, 1],
+    [2, 3, 1, 0],
+    [3, 1, 2, 0],
+    [3, 1, 0, 2],
+    [3, 2, 1, 0],
+    [3, 2, 0, 1],
+    [3, 0, 2, 1],
+    [3, 0, 1, 2],
+];
+
+// One Sobol sequence over the whole image, indexed along a Z-order curve with shuffled base 4 digits, so neighbouring pixels get blue noise distributed error
+// (Ahmed and Wonka, "Screen-Space Blue-Noise Diffusion of Monte Carlo Sampling Error via Hierarchical Ordering of Pixels").
+// The sample index must stay below the power of two sample count, larger indices run into the next pixel's samples
+#[derive(Clone, Copy)]
+pub struct ZSobolSampler {
+    pub seed: u32,
+    log2_samples_per_pixel: u32,
+    base4_digit_count: u32,
+    morton_index: u64,
+    dimension: u32,
+}
+
+impl ZSobolSampler {
+    pub fn new(samples_per_pixel: u32, resolution: UVec2, seed: u32) -> Self {
+        let log2_samples_per_pixel = samples_per_pixel.max(1).next_power_of_two().trailing_zeros();
+        let resolution = resolution.max_element().max(1).next_power_of_two();
+        let log4_samples_per_pixel = (log2_samples_per_pixel + 1) / 2;
+
+        Self {
+            seed,
+            log2_samples_per_pixel,
+            base4_digit_count: resolution.trailing_zeros() + log4_samples_per_pixel,
+            morton_index: 0,
+            dimension: 0,
+        }
+    }
+
+    fn sample_index(&self) -> u64 {
+        let mut sample_index = 0;
+        let odd_exponent = self.log2_samples_per_pixel & 1;
+        let dimension_salt = 0x5555_5555u64.wrapping_mul(self.dimension as u64);
+
+        // An odd power of two leaves a base 2 digit at the bottom, it is scrambled on its own below
+        let mut i = self.base4_digit_count;
+        while i > odd_exponent {
+            i -= 1;
+
+            let digit_shift = 2 * i - odd_exponent;
+            let digit = (self.morton_index >> digit_shift) & 3;
+            let higher_digits = self.morton_index >> (digit_shift + 2);
+            let permutation = (mix_bits(higher_digits ^ dimension_salt) >> 24) % 24;
+
+            sample_index |= (BASE4_PERMUTATIONS[permutation as usize][digit as usize] as u64) << digit_shift;
+        }
+
+        if odd_exponent == 1 {
+            let digit = self.morton_index & 1;
+            sample_index |= digit ^ (mix_bits((self.morton_index >> 1) ^ dimension_salt) & 1);
+        }
+
+        sample_index
+    }
+}
+
+impl ISampler for ZSobolSampler {
+    fn samples_per_pixel(&self) -> u32 {
+        1 << self.log2_samples_per_pixel
+    }
+
+    fn start_pixel_sample(&mut self, pixel: UVec2, sample_index: u32, dimension: u32) {
+        self.morton_index = (encode_morton2(pixel.x, pixel.y) << self.log2_samples_per_pixel) | sample_index as u64;
+        self.dimension = dimension;
+    }
+
+    fn get_1d(&mut self) -> f32 {
+        let sample_index = self.sample_index();
+        self.dimension += 1;
+
+        let seed = hash([self.dimension, self.seed]) as u32;
+
+        sobol_sample(sample_index, 0, seed)
+    }
+
+    fn get_2d(&mut self) -> Vec2 {
+        let sample_index = self.sample_index();
+        self.dimension += 2;
+
+        let seed = hash([self.dimension, self.seed]);
+
+        Vec2::new(sobol_sample(sample_index, 0, seed as u32), sobol_sample(sample_index, 1, (seed >> 32) as u32))
+    }
+
+    fn get_pixel_2d(&mut self) -> Vec2 {
+        self.get_2d()
+    }
+}
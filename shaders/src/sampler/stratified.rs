@@ -0,0 +1,85 @@
+use crate::sampler::{hash, permutation_element, to_unit_float, ISampler};
+use spirv_std::glam::{UVec2, Vec2};
+use spirv_std::num_traits::Float;
+
+// One sample per stratum, the strata are visited in a different random order for every pixel and dimension so the dimensions do not correlate
+#[derive(Clone, Copy)]
+pub struct StratifiedSampler {
+    pub strata: UVec2,
+    pub jitter: bool,
+    pub seed: u32,
+    pixel: UVec2,
+    sample_index: u32,
+    dimension: u32,
+}
+
+impl StratifiedSampler {
+    // The sample count is split into the most square grid of strata that divides it
+    pub fn new(samples_per_pixel: u32, jitter: bool, seed: u32) -> Self {
+        let samples_per_pixel = samples_per_pixel.max(1);
+        let mut x_strata = (samples_per_pixel as f32).sqrt() as u32;
+
+        while x_strata > 1 && samples_per_pixel % x_strata != 0 {
+            x_strata -= 1;
+        }
+
+        Self {
+            strata: UVec2::new(x_strata.max(1), samples_per_pixel / x_strata.max(1)),
+            jitter,
+            seed,
+            pixel: UVec2::ZERO,
+            sample_index: 0,
+            dimension: 0,
+        }
+    }
+
+    fn stratum(&self) -> u32 {
+        let seed = hash([self.pixel.x, self.pixel.y, self.dimension, self.seed]) as u32;
+
+        permutation_element(self.sample_index % self.samples_per_pixel(), self.samples_per_pixel(), seed)
+    }
+
+    fn jitter(&self, offset: u32) -> f32 {
+        if self.jitter {
+            let bits = hash([self.pixel.x, self.pixel.y, self.sample_index, self.dimension + offset, !self.seed]);
+
+            to_unit_float((bits >> 32) as u32)
+        } else {
+            0.5
+        }
+    }
+}
+
+impl ISampler for StratifiedSampler {
+    fn samples_per_pixel(&self) -> u32 {
+        self.strata.x * self.strata.y
+    }
+
+    fn start_pixel_sample(&mut self, pixel: UVec2, sample_index: u32, dimension: u32) {
+        self.pixel = pixel;
+        self.sample_index = sample_index;
+        self.dimension = dimension;
+    }
+
+    fn get_1d(&mut self) -> f32 {
+        let stratum = self.stratum();
+        let delta = self.jitter(0);
+        self.dimension += 1;
+
+        (stratum as f32 + delta) / self.samples_per_pixel() as f32
+    }
+
+    fn get_2d(&mut self) -> Vec2 {
+        let stratum = self.stratum();
+        let delta = Vec2::new(self.jitter(0), self.jitter(1));
+        self.dimension += 2;
+
+        let cell = UVec2::new(stratum % self.strata.x, stratum / self.strata.x);
+
+        (cell.as_vec2() + delta) / self.strata.as_vec2()
+    }
+
+    fn get_pixel_2d(&mut self) -> Vec2 {
+        self.get_2d()
+    }
+}
@@ -43,6 +43,8 @@ pub const SRGB_TO_LINEAR_LUT: [f32; 256] = [
 
 const LMS_FROM_XYZ: Mat3 = Mat3::from_cols_array(&[0.8951, -0.7502, 0.0389, 0.2664, 1.7135, -0.0685, -0.1614, 0.0367, 1.0296]);
 
+pub const LINEAR_SRGB_FROM_XYZ: Mat3 = Mat3::from_cols_array(&[3.2404542, -0.969266, 0.0556434, -1.5371385, 1.8760108, -0.2040259, -0.4985314, 0.041556, 1.0572252]);
+
 const XYZ_FROM_LMS: Mat3 = Mat3::from_cols_array(&[0.986993, 0.432305, -0.00852866, -0.147054, 0.51836, 0.0400428, 0.159963, 0.0492912, 0.968487]);
 
 #[derive(Clone, Copy, PartialEq)]
@@ -350,7 +352,7 @@ impl DivAssign<f32> for XyzColor {
     }
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq)]
 #[repr(C)]
 pub struct RgbSigmoidPolynomial {
     c2: f32,
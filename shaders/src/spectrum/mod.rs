@@ -1,3 +1,4 @@
+use crate::spectrum::color::{RgbColor, RgbSigmoidPolynomial, RgbToSpectrumTable};
 use crate::spectrum::color_space::RgbColorSpace;
 use crate::util;
 use crate::util::math;
@@ -65,6 +66,8 @@ pub enum Spectrum {
     BlackBody(BlackBodySpectrum),
     Discrete(DiscreteSpectrum),
     DenselySampled(DenselySampledSpectrum),
+    RgbAlbedo(RgbAlbedoSpectrum),
+    RgbUnbounded(RgbUnboundedSpectrum),
 }
 
 impl Deref for Spectrum {
@@ -75,6 +78,8 @@ impl Deref for Spectrum {
             Spectrum::BlackBody(spectrum) => spectrum,
             Spectrum::Discrete(spectrum) => spectrum,
             Spectrum::DenselySampled(spectrum) => spectrum,
+            Spectrum::RgbAlbedo(spectrum) => spectrum,
+            Spectrum::RgbUnbounded(spectrum) => spectrum,
         }
     }
 }
@@ -210,6 +215,15 @@ impl DenselySampledSpectrum {
             }
         }
     }
+
+    pub fn from_values(lambda_min: usize, values: [f32; LAMBDA_DENSELY_COUNT]) -> Self {
+        DenselySampledSpectrum {
+            lambda_min,
+            lambda_max: lambda_min + LAMBDA_DENSELY_COUNT - 1,
+            size: LAMBDA_DENSELY_COUNT,
+            lambdas: values,
+        }
+    }
 }
 
 impl ISpectrum for DenselySampledSpectrum {
@@ -237,6 +251,77 @@ impl ISpectrum for DenselySampledSpectrum {
     }
 }
 
+#[derive(Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct RgbAlbedoSpectrum {
+    polynomial: RgbSigmoidPolynomial,
+}
+
+impl RgbAlbedoSpectrum {
+    pub fn new(table: &RgbToSpectrumTable, rgb: RgbColor) -> Self {
+        Self {
+            polynomial: table.color_to_polynomial(rgb),
+        }
+    }
+}
+
+impl ISpectrum for RgbAlbedoSpectrum {
+    fn max_value(&self) -> f32 {
+        [LAMBDA_MIN, LAMBDA_MAX, self.polynomial.max_value()]
+            .into_iter()
+            .map(|lambda| self.polynomial.get_value(lambda))
+            .fold(0.0, f32::max)
+    }
+
+    fn get_value(&self, lambda: f32) -> f32 {
+        self.polynomial.get_value(lambda)
+    }
+
+    fn sample(&self, wavelengths: &SampledWavelengths) -> SampledSpectrum {
+        let values: [f32; N_SAMPLES] = array::from_fn(|i| self.polynomial.get_value(wavelengths[i]));
+        SampledSpectrum::from_array(values)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct RgbUnboundedSpectrum {
+    scale: f32,
+    polynomial: RgbSigmoidPolynomial,
+}
+
+impl RgbUnboundedSpectrum {
+    pub fn new(table: &RgbToSpectrumTable, r: f32, g: f32, b: f32) -> Self {
+        let scale = 2.0 * r.max(g).max(b);
+
+        let polynomial = if scale > 0.0 {
+            table.color_to_polynomial(RgbColor::new(r / scale, g / scale, b / scale))
+        } else {
+            table.color_to_polynomial(RgbColor::new(0.0, 0.0, 0.0))
+        };
+
+        Self { scale, polynomial }
+    }
+}
+
+impl ISpectrum for RgbUnboundedSpectrum {
+    fn max_value(&self) -> f32 {
+        [LAMBDA_MIN, LAMBDA_MAX, self.polynomial.max_value()]
+            .into_iter()
+            .map(|lambda| self.scale * self.polynomial.get_value(lambda))
+            .fold(0.0, f32::max)
+    }
+
+    fn get_value(&self, lambda: f32) -> f32 {
+        self.scale * self.polynomial.get_value(lambda)
+    }
+
+    fn sample(&self, wavelengths: &SampledWavelengths) -> SampledSpectrum {
+        let values: [f32; N_SAMPLES] = array::from_fn(|i| self.scale * self.polynomial.get_value(wavelengths[i]));
+        SampledSpectrum::from_array(values)
+    }
+}
+
 #[derive(Clone, Copy, PartialEq)]
 #[repr(C)]
 pub struct SampledSpectrum {
@@ -266,6 +351,12 @@ impl SampledSpectrum {
         SampledSpectrum::from_array(values)
     }
 
+    pub fn clamp_zero(&self) -> Self {
+        let values: [f32; N_SAMPLES] = array::from_fn(|i| self.values[i].max(0.0));
+
+        SampledSpectrum::from_array(values)
+    }
+
     pub fn min_component(&self) -> f32 {
         self.values.iter().fold(f32::INFINITY, |min, &v| min.min(v))
     }
@@ -286,9 +377,9 @@ impl SampledSpectrum {
 
         let pdf = wavelengths.pdf_spectrum();
 
-        let x = ((*self) * x_weight).safe_div(pdf).average();
-        let y = ((*self) * y_weight).safe_div(pdf).average();
-        let z = ((*self) * z_weight).safe_div(pdf).average();
+        let x = ((*self) * x_weight).safe_div(pdf).average() / CIE_Y_INTEGRAL;
+        let y = ((*self) * y_weight).safe_div(pdf).average() / CIE_Y_INTEGRAL;
+        let z = ((*self) * z_weight).safe_div(pdf).average() / CIE_Y_INTEGRAL;
 
         XyzColor::new(x, y, z)
     }
@@ -302,6 +393,12 @@ impl SampledSpectrum {
         self.to_xyz(lambda).y
     }
 
+    pub fn exp(&self) -> Self {
+        let values: [f32; N_SAMPLES] = array::from_fn(|i| self.values[i].exp());
+
+        SampledSpectrum::from_array(values)
+    }
+
     pub fn is_nontrivial(&self) -> bool {
         for &value in self.values.iter() {
             if value != 0.0 {
@@ -411,6 +508,14 @@ impl Mul<f32> for SampledSpectrum {
     }
 }
 
+impl MulAssign<f32> for SampledSpectrum {
+    fn mul_assign(&mut self, rhs: f32) {
+        for i in 0..N_SAMPLES {
+            self.values[i] *= rhs;
+        }
+    }
+}
+
 impl Mul<SampledSpectrum> for f32 {
     type Output = SampledSpectrum;
     fn mul(self, rhs: SampledSpectrum) -> SampledSpectrum {
@@ -516,7 +621,7 @@ impl Index<usize> for SampledWavelengths {
 }
 
 const CIE_SAMPLES: usize = 471;
-const CIE_Y_INTEGRAL: f32 = 106.856895;
+pub const CIE_Y_INTEGRAL: f32 = 106.856895;
 
 #[rustfmt::skip]
 const CIE_LAMBDA: [f32; CIE_SAMPLES] = [
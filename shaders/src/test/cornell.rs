@@ -1,5 +1,7 @@
+use crate::bindless::RenderCamera;
+use crate::spectrum::SampledWavelengths;
 use crate::util::vector::BasicVecOperation;
-use spirv_std::glam::{UVec2, UVec3, Vec2, Vec3, Vec3Swizzles, Vec4, Vec4Swizzles};
+use spirv_std::glam::{UVec3, Vec2, Vec3, Vec3Swizzles, Vec4, Vec4Swizzles};
 use spirv_std::ray_tracing::{AccelerationStructure, CommittedIntersection, RayFlags, RayQuery};
 use spirv_std::spirv;
 
@@ -59,16 +61,16 @@ pub fn main_cs(
     #[spirv(descriptor_set = 0, binding = 1)] tlas: &AccelerationStructure,
     #[spirv(storage_buffer, descriptor_set = 0, binding = 2)] vertices: &[Vec4],
     #[spirv(storage_buffer, descriptor_set = 0, binding = 3)] indices: &[u32],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 4)] camera: &RenderCamera,
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 5)] distributions: &[u32],
 ) {
-    let resolution = UVec2::new(800, 600);
+    let resolution = camera.resolution;
     let pixel = invocation_id.xy();
 
     if pixel.x > resolution.x && pixel.y > resolution.y {
         return;
     }
 
-    let camera_origin = Vec3::new(-0.001, 1.0, 6.0);
-    let fov_vertical_slope: f32 = 1.0 / 5.0;
     let sample_level: u32 = 1024;
     let reflect_level: u32 = 32;
     let t_min: f32 = 0.0;
@@ -79,15 +81,15 @@ pub fn main_cs(
 
     for _ in 0..sample_level {
         let pixel_center: Vec2 = pixel.as_vec2() + Vec2::new(gen_rand(&mut rand_state), gen_rand(&mut rand_state));
-        let screen_uv: Vec2 = Vec2::new(
-            (2.0 * pixel_center.x - resolution.x as f32) / resolution.y as f32,
-            -(2.0 * pixel_center.y - resolution.y as f32) / resolution.y as f32,
-        );
-
-        let mut ray_origin = camera_origin;
-        let mut ray_direction = Vec3::new(fov_vertical_slope * screen_uv.x, fov_vertical_slope * screen_uv.y, -1.0).normalize();
-
-        let mut current_ray_color = Vec3::new(1.0, 1.0, 1.0);
+        let time = camera.sample_time(gen_rand(&mut rand_state));
+        let u_lens = Vec2::new(gen_rand(&mut rand_state), gen_rand(&mut rand_state));
+        let lambda = SampledWavelengths::sample_visible(gen_rand(&mut rand_state));
+        let Some((mut ray_origin, mut ray_direction, weight)) = camera.gen_ray(distributions, pixel_center, u_lens, time, &lambda) else {
+            continue;
+        };
+
+        // The test traces RGB, so only the lens vignetting of the camera weight is kept
+        let mut current_ray_color = Vec3::splat(weight.average());
 
         for _ in 0..reflect_level {
             let ray_query: &mut RayQuery = {
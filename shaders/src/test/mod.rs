@@ -1,3 +1,4 @@
 pub mod cornell;
 pub mod hello_world;
 pub mod rt_pipeline;
+pub mod subsurface_slab;
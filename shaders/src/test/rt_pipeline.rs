@@ -1,7 +1,8 @@
 use crate::bindless;
-use crate::bindless::{InstanceMetadata, RenderMaterial, Vertex};
+use crate::bindless::{InstanceMetadata, RenderCamera, RenderMaterial, Vertex};
+use crate::spectrum::SampledWavelengths;
 use crate::util::vector::BasicVecOperation;
-use spirv_std::glam::{UVec2, UVec3, Vec2, Vec3, Vec3Swizzles, Vec4, Vec4Swizzles};
+use spirv_std::glam::{UVec3, Vec2, Vec3, Vec3Swizzles, Vec4, Vec4Swizzles};
 use spirv_std::num_traits::Float;
 use spirv_std::ray_tracing::{AccelerationStructure, RayFlags};
 use spirv_std::{spirv, Image};
@@ -68,16 +69,16 @@ pub fn main_rgen(
     #[spirv(launch_id)] launch_id: UVec3,
     #[spirv(descriptor_set = 0, binding = 0)] tlas: &AccelerationStructure,
     #[spirv(descriptor_set = 0, binding = 1)] image_output: &Image!(2D, format = rgba32f, sampled = false),
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 6)] camera: &RenderCamera,
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 7)] distributions: &[u32],
 ) {
-    let resolution = UVec2::new(800, 600);
+    let resolution = camera.resolution;
     let pixel = launch_id.xy();
 
     if pixel.x > resolution.x && pixel.y > resolution.y {
         return;
     }
 
-    let camera_origin = Vec3::new(-0.001, 1.0, 6.0);
-    let fov_vertical_slope: f32 = 1.0 / 5.0;
     let sample_level: u32 = 1024;
     let reflect_level: u32 = 32;
     let t_min: f32 = 0.0;
@@ -88,15 +89,15 @@ pub fn main_rgen(
 
     for _ in 0..sample_level {
         let pixel_center: Vec2 = pixel.as_vec2() + Vec2::new(gen_rand(&mut payload.rand_state), gen_rand(&mut payload.rand_state));
-        let screen_uv: Vec2 = Vec2::new(
-            (2.0 * pixel_center.x - resolution.x as f32) / resolution.y as f32,
-            -(2.0 * pixel_center.y - resolution.y as f32) / resolution.y as f32,
-        );
-
-        let mut ray_origin = camera_origin;
-        let mut ray_direction = Vec3::new(fov_vertical_slope * screen_uv.x, fov_vertical_slope * screen_uv.y, -1.0).normalize();
-
-        let mut current_ray_color = Vec3::new(1.0, 1.0, 1.0);
+        let time = camera.sample_time(gen_rand(&mut payload.rand_state));
+        let u_lens = Vec2::new(gen_rand(&mut payload.rand_state), gen_rand(&mut payload.rand_state));
+        let lambda = SampledWavelengths::sample_visible(gen_rand(&mut payload.rand_state));
+        let Some((mut ray_origin, mut ray_direction, weight)) = camera.gen_ray(distributions, pixel_center, u_lens, time, &lambda) else {
+            continue;
+        };
+
+        // The pipeline traces RGB, so only the lens vignetting of the camera weight is kept
+        let mut current_ray_color = Vec3::splat(weight.average());
 
         for _ in 0..reflect_level {
             unsafe { tlas.trace_ray(RayFlags::OPAQUE, 0xff, 0, 0, 0, ray_origin, t_min, ray_direction, t_max, payload) };
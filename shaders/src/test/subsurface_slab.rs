@@ -0,0 +1,93 @@
+use crate::bindless::{InstanceMetadata, Light, RenderMaterial, RenderMedium, Vertex};
+use crate::integrator::subsurface::{self, SubsurfaceMaterial};
+use crate::integrator::SceneData;
+use crate::sampler::{ISampler, IndependentSampler};
+use crate::spectrum::color::RgbToSpectrumTable;
+use crate::spectrum::{SampledSpectrum, SampledWavelengths};
+use crate::util::frame::Frame;
+use spirv_std::glam::{UVec2, UVec3, Vec3, Vec3Swizzles, Vec4};
+use spirv_std::ray_tracing::AccelerationStructure;
+use spirv_std::spirv;
+
+// Mirrors `reference::subsurface::SlabWalkParameters` on the host
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct SlabWalkParameters {
+    pub thickness: f32,
+    pub walk_count: u32,
+    pub seed: u32,
+    pub grid_size: UVec2,
+}
+
+// Every invocation sends `walk_count` rays straight down onto the slab mesh, which spans [0, thickness] in y, and scatters them through the
+// integrator's subsurface boundary and random walk. Paths leaving upwards count as reflected, downwards as transmitted.
+// Writes the per invocation averages as (reflected, transmitted, 0, 0)
+#[spirv(compute(threads(16, 8, 1)))]
+pub fn main_cs(
+    #[spirv(global_invocation_id)] invocation_id: UVec3,
+    #[spirv(uniform, descriptor_set = 0, binding = 0)] params: &SlabWalkParameters,
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 1)] tallies: &mut [Vec4],
+    #[spirv(descriptor_set = 0, binding = 2)] tlas: &AccelerationStructure,
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 3)] vertices: &[Vertex],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 4)] indices: &[u32],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 5)] metadata: &[InstanceMetadata],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 6)] materials: &[RenderMaterial],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 7)] media: &[RenderMedium],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 8)] grid_data: &[f32],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 9)] lights: &[Light],
+    #[spirv(storage_buffer, descriptor_set = 0, binding = 10)] rgb_to_spectrum_table: &RgbToSpectrumTable,
+) {
+    let cell = invocation_id.xy();
+
+    if cell.x >= params.grid_size.x || cell.y >= params.grid_size.y {
+        return;
+    }
+
+    let scene = SceneData {
+        tlas,
+        vertices,
+        indices,
+        metadata,
+        materials,
+        media,
+        grid_data,
+        lights,
+        rgb_to_spectrum_table,
+    };
+
+    let origin = Vec3::new(0.0, params.thickness + 1.0, 0.0);
+    let direction = Vec3::new(0.0, -1.0, 0.0);
+
+    let mut sampler = IndependentSampler::new(params.walk_count, params.seed);
+    let mut reflected = 0.0;
+    let mut transmitted = 0.0;
+
+    for walk_index in 0..params.walk_count {
+        sampler.start_pixel_sample(cell, walk_index, 0);
+
+        let Some(hit) = scene.intersect(origin, direction, f32::MAX) else {
+            continue;
+        };
+
+        let lambda = SampledWavelengths::sample_visible(sampler.get_1d());
+        let material = SubsurfaceMaterial::new(&scene, scene.material(hit.instance), &lambda);
+        let frame = Frame::from_z(hit.normal);
+        let output_direction = frame.global_to_local(-direction);
+
+        let beta = SampledSpectrum::uniform(1.0);
+        let Some(exit) = subsurface::scatter(&scene, &hit, &frame, output_direction, &material, beta, beta, &mut sampler) else {
+            continue;
+        };
+
+        let weight = (exit.beta / exit.r_u.average()).average();
+
+        if exit.direction.y > 0.0 {
+            reflected += weight;
+        } else {
+            transmitted += weight;
+        }
+    }
+
+    let index = (params.grid_size.x * cell.y + cell.x) as usize;
+    tallies[index] = Vec4::new(reflected, transmitted, 0.0, 0.0) / params.walk_count as f32;
+}
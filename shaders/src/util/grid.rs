@@ -0,0 +1,48 @@
+use crate::util::math;
+use spirv_std::glam::{IVec3, UVec3, Vec3};
+
+#[derive(Clone, Copy, Default, PartialEq)]
+#[repr(C)]
+pub struct SampledGrid {
+    pub resolution: UVec3,
+    pub offset: u32,
+}
+
+impl SampledGrid {
+    pub fn new(resolution: UVec3, offset: u32) -> Self {
+        Self { resolution, offset }
+    }
+
+    pub fn len(&self) -> usize {
+        (self.resolution.x * self.resolution.y * self.resolution.z) as usize
+    }
+
+    pub fn lookup(&self, data: &[f32], point: IVec3) -> f32 {
+        if point.x < 0 || point.y < 0 || point.z < 0 {
+            return 0.0;
+        }
+
+        let point = point.as_uvec3();
+
+        if point.x >= self.resolution.x || point.y >= self.resolution.y || point.z >= self.resolution.z {
+            return 0.0;
+        }
+
+        let index = (point.z * self.resolution.y + point.y) * self.resolution.x + point.x;
+        data[(self.offset + index) as usize]
+    }
+
+    pub fn sample(&self, data: &[f32], point: Vec3) -> f32 {
+        let point_samples = point * self.resolution.as_vec3() - Vec3::splat(0.5);
+        let point_index = point_samples.floor();
+        let delta = point_samples - point_index;
+        let point_index = point_index.as_ivec3();
+
+        let d00 = math::lerp(delta.x, self.lookup(data, point_index), self.lookup(data, point_index + IVec3::new(1, 0, 0)));
+        let d10 = math::lerp(delta.x, self.lookup(data, point_index + IVec3::new(0, 1, 0)), self.lookup(data, point_index + IVec3::new(1, 1, 0)));
+        let d01 = math::lerp(delta.x, self.lookup(data, point_index + IVec3::new(0, 0, 1)), self.lookup(data, point_index + IVec3::new(1, 0, 1)));
+        let d11 = math::lerp(delta.x, self.lookup(data, point_index + IVec3::new(0, 1, 1)), self.lookup(data, point_index + IVec3::new(1, 1, 1)));
+
+        math::lerp(delta.z, math::lerp(delta.y, d00, d10), math::lerp(delta.y, d01, d11))
+    }
+}
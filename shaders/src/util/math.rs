@@ -27,10 +27,20 @@ pub fn clamp<T: PartialOrd>(value: T, min: T, max: T) -> T {
 }
 
 pub fn perspective(fov: f32, near: f32, far: f32) -> Mat4 {
-    let inv_tan = 1.0 / (0.5 * fov).tan();
-    let f_n = far / (far - near);
+    Mat4::from_cols_array_2d(&optics_common::camera::perspective(fov, near, far))
+}
+
+pub fn orthographic(near: f32, far: f32) -> Mat4 {
+    Mat4::from_cols_array_2d(&optics_common::camera::orthographic(near, far))
+}
 
-    Mat4::from_cols_array_2d(&[[inv_tan, 0.0, 0.0, 0.0], [0.0, inv_tan, 0.0, 0.0], [0.0, 0.0, f_n, 1.0], [0.0, 0.0, -near * f_n, 0.0]])
+#[inline]
+pub fn copysign(magnitude: f32, sign: f32) -> f32 {
+    if sign < 0.0 {
+        -magnitude.abs()
+    } else {
+        magnitude.abs()
+    }
 }
 
 #[inline]
@@ -1,4 +1,5 @@
 pub mod frame;
+pub mod grid;
 pub mod math;
 pub mod sampling;
 pub mod vector;
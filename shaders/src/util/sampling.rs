@@ -1,7 +1,11 @@
+use crate::util;
+use crate::util::math;
 use core::f32::consts;
 use spirv_std::num_traits::Float;
 use spirv_std::glam::{Vec2, Vec3};
 
+pub const ONE_MINUS_EPSILON: f32 = 1.0 - f32::EPSILON / 2.0;
+
 #[inline]
 pub fn sample_uniform_disk_polar(u: Vec2) -> Vec2 {
     let r = u.x.sqrt();
@@ -26,6 +30,36 @@ pub fn sample_uniform_disk_concentric(u: Vec2) -> Vec2 {
     }
 }
 
+#[inline]
+pub fn sample_uniform_triangle(u: Vec2) -> Vec3 {
+    let (b0, b1) = if u.x < u.y {
+        let b0 = u.x / 2.0;
+        (b0, u.y - b0)
+    } else {
+        let b1 = u.y / 2.0;
+        (u.x - b1, b1)
+    };
+
+    Vec3::new(b0, b1, 1.0 - b0 - b1)
+}
+
+#[inline]
+pub fn sample_regular_polygon(vertex_count: u32, rotation: f32, u: Vec2) -> Vec2 {
+    let vertex_count = vertex_count.max(3);
+
+    let scaled = u.x * vertex_count as f32;
+    let index = (scaled as u32).min(vertex_count - 1);
+    let u = Vec2::new((scaled - index as f32).min(ONE_MINUS_EPSILON), u.y);
+
+    let theta0 = rotation + consts::TAU * index as f32 / vertex_count as f32;
+    let theta1 = rotation + consts::TAU * (index + 1) as f32 / vertex_count as f32;
+    let v0 = Vec2::new(theta0.cos(), theta0.sin());
+    let v1 = Vec2::new(theta1.cos(), theta1.sin());
+
+    let b = sample_uniform_triangle(u);
+    v0 * b.y + v1 * b.z
+}
+
 #[inline]
 pub fn sample_uniform_sphere(u: Vec2) -> Vec3 {
     let z = 1.0 - 2.0 * u.x;
@@ -69,6 +103,20 @@ pub fn sample_cosine_hemisphere(u: Vec2) -> Vec3 {
     Vec3::new(d.x, d.y, z)
 }
 
+#[inline]
+pub fn sample_exponential(u: f32, a: f32) -> f32 {
+    -(1.0 - u).ln() / a
+}
+
+#[inline]
+pub fn sample_tent(u: f32, radius: f32) -> f32 {
+    if u < 0.5 {
+        -radius * (1.0 - (2.0 * u).sqrt())
+    } else {
+        radius * (1.0 - (2.0 - 2.0 * u).sqrt())
+    }
+}
+
 #[inline]
 pub fn uniform_sphere_pdf() -> f32 {
     consts::FRAC_1_PI * 0.25
@@ -83,3 +131,327 @@ pub fn uniform_hemisphere_pdf() -> f32 {
 pub fn cosine_hemisphere_pdf(cos_theta: f32) -> f32 {
     cos_theta * consts::FRAC_1_PI
 }
+
+// Sample in [0, 1) with density proportional to the line from `a` at 0 to `b` at 1
+#[inline]
+pub fn sample_linear(u: f32, a: f32, b: f32) -> f32 {
+    if u == 0.0 && a == 0.0 {
+        return 0.0;
+    }
+
+    let x = u * (a + b) / (a + math::lerp(u, a * a, b * b).sqrt());
+    x.min(ONE_MINUS_EPSILON)
+}
+
+#[inline]
+pub fn invert_linear_sample(x: f32, a: f32, b: f32) -> f32 {
+    if a + b == 0.0 {
+        x
+    } else {
+        x * (a * (2.0 - x) + b * x) / (a + b)
+    }
+}
+
+// Distributions built at runtime on the host by `sampling::DistributionBuffer` are flattened into one `&[u32]` storage buffer,
+// values are stored as their bits. Each starts with its size, so a view only needs the buffer and its offset:
+//   piecewise constant 1D: count, integral, func[count], cdf[count]
+//   piecewise constant 2D: nu, nv, marginal (1D over nv), nv conditionals (1D over nu)
+//   alias table: count, (q, p, alias)[count]
+//   piecewise linear: segment count, integral, values[count + 1], cdf[count]
+#[inline]
+fn count_at(data: &[u32], index: usize) -> usize {
+    data[index] as usize
+}
+
+#[inline]
+fn float_at(data: &[u32], index: usize) -> f32 {
+    f32::from_bits(data[index])
+}
+
+#[derive(Clone, Copy)]
+pub struct PiecewiseConstant1DSlice<'a> {
+    data: &'a [u32],
+    offset: usize,
+    count: usize,
+}
+
+impl<'a> PiecewiseConstant1DSlice<'a> {
+    pub fn new(data: &'a [u32], offset: u32) -> Self {
+        let offset = offset as usize;
+
+        Self {
+            data,
+            offset,
+            count: count_at(data, offset),
+        }
+    }
+
+    pub const fn flattened_len(count: usize) -> usize {
+        2 + 2 * count
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn func_integral(&self) -> f32 {
+        float_at(self.data, self.offset + 1)
+    }
+
+    #[inline]
+    fn func(&self, index: usize) -> f32 {
+        float_at(self.data, self.offset + 2 + index)
+    }
+
+    #[inline]
+    fn cdf_at(&self, index: usize) -> f32 {
+        if index == 0 {
+            0.0
+        } else {
+            float_at(self.data, self.offset + 1 + self.count + index)
+        }
+    }
+
+    pub fn sample(&self, u: f32) -> (f32, f32, usize) {
+        let count = self.count;
+        let func_integral = self.func_integral();
+
+        if func_integral == 0.0 {
+            return (u, 0.0, ((u * count as f32) as usize).min(count - 1));
+        }
+
+        let offset = util::find_interval(count + 1, |index| self.cdf_at(index) <= u);
+
+        let mut du = u - self.cdf_at(offset);
+        let width = self.cdf_at(offset + 1) - self.cdf_at(offset);
+
+        if width > 0.0 {
+            du /= width;
+        }
+
+        ((offset as f32 + du) / count as f32, self.func(offset) / func_integral, offset)
+    }
+
+    pub fn pdf(&self, x: f32) -> f32 {
+        let func_integral = self.func_integral();
+
+        if func_integral == 0.0 {
+            return 0.0;
+        }
+
+        let offset = ((x * self.count as f32) as usize).min(self.count - 1);
+        self.func(offset) / func_integral
+    }
+
+    pub fn invert(&self, x: f32) -> Option<f32> {
+        if !(0.0..=1.0).contains(&x) {
+            return None;
+        }
+
+        if self.func_integral() == 0.0 {
+            return Some(x);
+        }
+
+        let scaled = x * self.count as f32;
+        let offset = (scaled as usize).min(self.count - 1);
+
+        Some(math::lerp(scaled - offset as f32, self.cdf_at(offset), self.cdf_at(offset + 1)))
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct PiecewiseConstant2DSlice<'a> {
+    data: &'a [u32],
+    offset: usize,
+    nu: usize,
+    nv: usize,
+}
+
+impl<'a> PiecewiseConstant2DSlice<'a> {
+    pub fn new(data: &'a [u32], offset: u32) -> Self {
+        let offset = offset as usize;
+
+        Self {
+            data,
+            offset,
+            nu: count_at(data, offset),
+            nv: count_at(data, offset + 1),
+        }
+    }
+
+    pub fn marginal(&self) -> PiecewiseConstant1DSlice<'a> {
+        PiecewiseConstant1DSlice::new(self.data, (self.offset + 2) as u32)
+    }
+
+    pub fn conditional(&self, v: usize) -> PiecewiseConstant1DSlice<'a> {
+        let offset = self.offset + 2 + PiecewiseConstant1DSlice::flattened_len(self.nv) + v * PiecewiseConstant1DSlice::flattened_len(self.nu);
+        PiecewiseConstant1DSlice::new(self.data, offset as u32)
+    }
+
+    pub fn sample(&self, u: Vec2) -> (Vec2, f32) {
+        let (d1, pdf1, v) = self.marginal().sample(u.y);
+        let (d0, pdf0, _) = self.conditional(v).sample(u.x);
+
+        (Vec2::new(d0, d1), pdf0 * pdf1)
+    }
+
+    pub fn pdf(&self, point: Vec2) -> f32 {
+        let marginal_integral = self.marginal().func_integral();
+
+        if marginal_integral == 0.0 {
+            return 0.0;
+        }
+
+        let iu = ((point.x * self.nu as f32) as usize).min(self.nu - 1);
+        let iv = ((point.y * self.nv as f32) as usize).min(self.nv - 1);
+        let conditional = self.conditional(iv);
+
+        conditional.func(iu) / marginal_integral
+    }
+
+    pub fn invert(&self, point: Vec2) -> Option<Vec2> {
+        let v = self.marginal().invert(point.y)?;
+        let iv = ((point.y * self.nv as f32) as usize).min(self.nv - 1);
+        let u = self.conditional(iv).invert(point.x)?;
+
+        Some(Vec2::new(u, v))
+    }
+}
+
+// Walker's alias method, picks one of `count` discrete outcomes in constant time, e.g. a light by power
+#[derive(Clone, Copy)]
+pub struct AliasTableSlice<'a> {
+    data: &'a [u32],
+    offset: usize,
+    count: usize,
+}
+
+impl<'a> AliasTableSlice<'a> {
+    pub fn new(data: &'a [u32], offset: u32) -> Self {
+        let offset = offset as usize;
+
+        Self {
+            data,
+            offset,
+            count: count_at(data, offset),
+        }
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    #[inline]
+    fn bin(&self, index: usize) -> (f32, f32, usize) {
+        let start = self.offset + 1 + 3 * index;
+        (float_at(self.data, start), float_at(self.data, start + 1), count_at(self.data, start + 2))
+    }
+
+    pub fn pmf(&self, index: usize) -> f32 {
+        self.bin(index).1
+    }
+
+    // Returns the outcome, its probability and `u` remapped to [0, 1) for reuse
+    pub fn sample(&self, u: f32) -> (usize, f32, f32) {
+        let scaled = u * self.count as f32;
+        let offset = (scaled as usize).min(self.count - 1);
+        let up = (scaled - offset as f32).min(ONE_MINUS_EPSILON);
+
+        let (q, p, alias) = self.bin(offset);
+
+        if up < q {
+            (offset, p, (up / q).min(ONE_MINUS_EPSILON))
+        } else {
+            (alias, self.pmf(alias), ((up - q) / (1.0 - q)).min(ONE_MINUS_EPSILON))
+        }
+    }
+}
+
+// Density that interpolates linearly between values at evenly spaced points over [0, 1]
+#[derive(Clone, Copy)]
+pub struct PiecewiseLinearSlice<'a> {
+    data: &'a [u32],
+    offset: usize,
+    count: usize,
+}
+
+impl<'a> PiecewiseLinearSlice<'a> {
+    pub fn new(data: &'a [u32], offset: u32) -> Self {
+        let offset = offset as usize;
+
+        Self {
+            data,
+            offset,
+            count: count_at(data, offset),
+        }
+    }
+
+    pub fn integral(&self) -> f32 {
+        float_at(self.data, self.offset + 1)
+    }
+
+    #[inline]
+    fn value(&self, index: usize) -> f32 {
+        float_at(self.data, self.offset + 2 + index)
+    }
+
+    #[inline]
+    fn cdf_at(&self, index: usize) -> f32 {
+        if index == 0 {
+            0.0
+        } else {
+            float_at(self.data, self.offset + 2 + self.count + index)
+        }
+    }
+
+    pub fn sample(&self, u: f32) -> (f32, f32) {
+        let integral = self.integral();
+
+        if integral == 0.0 {
+            return (u, 0.0);
+        }
+
+        let offset = util::find_interval(self.count + 1, |index| self.cdf_at(index) <= u);
+
+        let mut du = u - self.cdf_at(offset);
+        let width = self.cdf_at(offset + 1) - self.cdf_at(offset);
+
+        if width > 0.0 {
+            du /= width;
+        }
+
+        let (a, b) = (self.value(offset), self.value(offset + 1));
+        let t = sample_linear(du, a, b);
+
+        ((offset as f32 + t) / self.count as f32, math::lerp(t, a, b) / integral)
+    }
+
+    pub fn pdf(&self, x: f32) -> f32 {
+        let integral = self.integral();
+
+        if integral == 0.0 {
+            return 0.0;
+        }
+
+        let scaled = x * self.count as f32;
+        let offset = (scaled as usize).min(self.count - 1);
+
+        math::lerp(scaled - offset as f32, self.value(offset), self.value(offset + 1)) / integral
+    }
+
+    pub fn invert(&self, x: f32) -> Option<f32> {
+        if !(0.0..=1.0).contains(&x) {
+            return None;
+        }
+
+        if self.integral() == 0.0 {
+            return Some(x);
+        }
+
+        let scaled = x * self.count as f32;
+        let offset = (scaled as usize).min(self.count - 1);
+        let t = invert_linear_sample(scaled - offset as f32, self.value(offset), self.value(offset + 1));
+
+        Some(math::lerp(t, self.cdf_at(offset), self.cdf_at(offset + 1)))
+    }
+}
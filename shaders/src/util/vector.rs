@@ -1,5 +1,7 @@
+use crate::util::math;
+use core::f32::consts;
 use spirv_std::num_traits::Float;
-use spirv_std::glam::{Vec3, Vec3A};
+use spirv_std::glam::{Vec2, Vec3, Vec3A};
 
 pub trait BasicVecOperation: Copy {
     fn reflect(&self, normal: Self) -> Self;
@@ -158,3 +160,113 @@ impl BasicVecOperation for Vec3A {
         }
     }
 }
+
+#[inline]
+pub fn spherical_direction(sin_theta: f32, cos_theta: f32, phi: f32) -> Vec3 {
+    let sin_theta = sin_theta.clamp(-1.0, 1.0);
+    Vec3::new(sin_theta * phi.cos(), sin_theta * phi.sin(), cos_theta.clamp(-1.0, 1.0))
+}
+
+#[inline]
+pub fn spherical_theta(v: Vec3) -> f32 {
+    v.z.clamp(-1.0, 1.0).acos()
+}
+
+#[inline]
+pub fn spherical_phi(v: Vec3) -> f32 {
+    let phi = v.y.atan2(v.x);
+
+    if phi < 0.0 {
+        phi + consts::TAU
+    } else {
+        phi
+    }
+}
+
+#[inline]
+pub fn equirectangular_square_to_sphere(p: Vec2) -> Vec3 {
+    let theta = consts::PI * p.y;
+    let phi = consts::TAU * p.x;
+
+    spherical_direction(theta.sin(), theta.cos(), phi)
+}
+
+#[inline]
+pub fn equirectangular_sphere_to_square(d: Vec3) -> Vec2 {
+    Vec2::new(spherical_phi(d) / consts::TAU, spherical_theta(d) / consts::PI)
+}
+
+pub fn equal_area_square_to_sphere(p: Vec2) -> Vec3 {
+    let u = 2.0 * p.x - 1.0;
+    let v = 2.0 * p.y - 1.0;
+    let up = u.abs();
+    let vp = v.abs();
+
+    let signed_distance = 1.0 - (up + vp);
+    let d = signed_distance.abs();
+    let r = 1.0 - d;
+
+    let phi = if r == 0.0 { 1.0 } else { (vp - up) / r + 1.0 } * consts::FRAC_PI_4;
+    let z = math::copysign(1.0 - r * r, signed_distance);
+
+    let cos_phi = math::copysign(phi.cos(), u);
+    let sin_phi = math::copysign(phi.sin(), v);
+    let radius = r * (2.0 - r * r).max(0.0).sqrt();
+
+    Vec3::new(cos_phi * radius, sin_phi * radius, z)
+}
+
+pub fn equal_area_sphere_to_square(d: Vec3) -> Vec2 {
+    let x = d.x.abs();
+    let y = d.y.abs();
+    let z = d.z.abs();
+
+    let r = (1.0 - z).max(0.0).sqrt();
+
+    let a = x.max(y);
+    let b = x.min(y);
+    let b = if a == 0.0 { 0.0 } else { b / a };
+
+    let mut phi = b.atan() * consts::FRAC_2_PI;
+
+    if x < y {
+        phi = 1.0 - phi;
+    }
+
+    let mut v = phi * r;
+    let mut u = r - v;
+
+    if d.z < 0.0 {
+        core::mem::swap(&mut u, &mut v);
+        u = 1.0 - u;
+        v = 1.0 - v;
+    }
+
+    u = math::copysign(u, d.x);
+    v = math::copysign(v, d.y);
+
+    Vec2::new(0.5 * (u + 1.0), 0.5 * (v + 1.0))
+}
+
+#[inline]
+pub fn wrap_equal_area_square(p: Vec2) -> Vec2 {
+    let mut p = p;
+
+    if p.x < 0.0 {
+        p.x = -p.x;
+        p.y = 1.0 - p.y;
+    } else if p.x > 1.0 {
+        p.x = 2.0 - p.x;
+        p.y = 1.0 - p.y;
+    }
+
+    if p.y < 0.0 {
+        p.x = 1.0 - p.x;
+        p.y = -p.y;
+    } else if p.y > 1.0 {
+        p.x = 1.0 - p.x;
+        p.y = 2.0 - p.y;
+    }
+
+    p
+}
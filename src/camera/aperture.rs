@@ -0,0 +1,157 @@
+use crate::sampling::{DistributionBuffer, PiecewiseConstant2D};
+use anyhow::{bail, Result};
+use glam::Vec2;
+use std::f32::consts;
+use std::path::Path;
+
+pub const APERTURE_IMAGE_RESOLUTION: usize = 32;
+
+#[derive(Copy, Clone, Debug)]
+pub enum ApertureType {
+    Circular,
+    Polygon,
+    Image,
+}
+
+impl Into<u32> for ApertureType {
+    fn into(self) -> u32 {
+        match self {
+            ApertureType::Circular => 0,
+            ApertureType::Polygon => 1,
+            ApertureType::Image => 2,
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct RenderAperture {
+    pub aperture_type: u32,
+    pub blade_count: u32,
+    pub rotation: f32,
+    pub cats_eye: f32,
+    // Offset of the image distribution in the flattened distribution buffer, only read for `ApertureType::Image`
+    pub image_offset: u32,
+}
+
+#[derive(Clone, Debug)]
+pub enum ApertureShape {
+    Circular,
+    Polygon { blade_count: u32, rotation: f32 },
+    Image { width: u32, height: u32, values: Vec<f32> },
+}
+
+#[derive(Clone, Debug)]
+pub struct ApertureDesc {
+    pub shape: ApertureShape,
+    pub cats_eye: f32,
+}
+
+impl Default for ApertureDesc {
+    fn default() -> Self {
+        Self {
+            shape: ApertureShape::Circular,
+            cats_eye: 0.0,
+        }
+    }
+}
+
+impl ApertureDesc {
+    pub fn polygon(blade_count: u32, rotation: f32) -> Self {
+        Self {
+            shape: ApertureShape::Polygon { blade_count, rotation },
+            ..Default::default()
+        }
+    }
+
+    pub fn image(width: u32, height: u32, values: Vec<f32>) -> Result<Self> {
+        if width == 0 || height == 0 || values.len() != (width * height) as usize {
+            bail!("Aperture image of {}x{} expects {} values, found {}", width, height, width * height, values.len());
+        }
+
+        if values.iter().all(|&value| value <= 0.0) {
+            bail!("Aperture image is fully opaque");
+        }
+
+        Ok(Self {
+            shape: ApertureShape::Image { width, height, values },
+            ..Default::default()
+        })
+    }
+
+    pub fn load_image(path: impl AsRef<Path>) -> Result<Self> {
+        let luma_image = image::open(path)?.to_luma8();
+        let (width, height) = luma_image.dimensions();
+        let values = luma_image.pixels().map(|pixel| pixel[0] as f32 / 255.0).collect();
+
+        Self::image(width, height, values)
+    }
+
+    pub fn cats_eye(mut self, cats_eye: f32) -> Self {
+        self.cats_eye = cats_eye.max(0.0);
+        self
+    }
+
+    // Transmission of the aperture at a point of the unit square [-1, 1]^2, with +y pointing up
+    pub fn transmission(&self, point: Vec2) -> f32 {
+        match &self.shape {
+            ApertureShape::Circular => (point.length_squared() <= 1.0) as u32 as f32,
+            ApertureShape::Polygon { blade_count, rotation } => {
+                let sector = consts::TAU / (*blade_count).max(3) as f32;
+                let phi = (point.y.atan2(point.x) - rotation).rem_euclid(sector);
+
+                (point.length() <= (0.5 * sector).cos() / (phi - 0.5 * sector).cos()) as u32 as f32
+            }
+            ApertureShape::Image { width, height, values } => {
+                if point.abs().max_element() > 1.0 {
+                    return 0.0;
+                }
+
+                let x = ((0.5 * (point.x + 1.0) * *width as f32) as u32).min(width - 1);
+                let y = ((0.5 * (1.0 - point.y) * *height as f32) as u32).min(height - 1);
+
+                values[(y * width + x) as usize].max(0.0)
+            }
+        }
+    }
+
+    // Image apertures push their sampling distribution into `distributions`, which is bound next to the camera
+    pub fn to_render_aperture(&self, distributions: &mut DistributionBuffer) -> RenderAperture {
+        let (aperture_type, blade_count, rotation, image_offset) = match &self.shape {
+            ApertureShape::Circular => (ApertureType::Circular, 0, 0.0, u32::MAX),
+            ApertureShape::Polygon { blade_count, rotation } => (ApertureType::Polygon, (*blade_count).max(3), *rotation, u32::MAX),
+            ApertureShape::Image { width, height, values } => {
+                let image = PiecewiseConstant2D::new(&resample_aperture_image(*width, *height, values), APERTURE_IMAGE_RESOLUTION, APERTURE_IMAGE_RESOLUTION);
+                (ApertureType::Image, 0, 0.0, distributions.push(&image))
+            }
+        };
+
+        RenderAperture {
+            aperture_type: aperture_type.into(),
+            blade_count,
+            rotation,
+            cats_eye: self.cats_eye,
+            image_offset,
+        }
+    }
+}
+
+fn resample_aperture_image(width: u32, height: u32, values: &[f32]) -> Vec<f32> {
+    let (width, height) = (width as usize, height as usize);
+    let mut resampled = vec![0.0; APERTURE_IMAGE_RESOLUTION * APERTURE_IMAGE_RESOLUTION];
+
+    for y in 0..APERTURE_IMAGE_RESOLUTION {
+        let y0 = y * height / APERTURE_IMAGE_RESOLUTION;
+        let y1 = ((y + 1) * height / APERTURE_IMAGE_RESOLUTION).max(y0 + 1);
+
+        for x in 0..APERTURE_IMAGE_RESOLUTION {
+            let x0 = x * width / APERTURE_IMAGE_RESOLUTION;
+            let x1 = ((x + 1) * width / APERTURE_IMAGE_RESOLUTION).max(x0 + 1);
+
+            let sum = (y0..y1).flat_map(|sy| (x0..x1).map(move |sx| values[sy * width + sx])).map(|value| value.max(0.0)).sum::<f32>();
+            resampled[(APERTURE_IMAGE_RESOLUTION - 1 - y) * APERTURE_IMAGE_RESOLUTION + x] = sum / ((y1 - y0) * (x1 - x0)) as f32;
+        }
+    }
+
+    resampled
+}
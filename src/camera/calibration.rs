@@ -0,0 +1,285 @@
+use crate::camera::sensor::{self, SensorProfile, SensorResponse};
+use anyhow::{anyhow, bail, Result};
+use glam::{DMat3, DVec3, Mat3, Vec3};
+use optics_common::calibration::{SWATCH_COUNT, SWATCH_LAMBDA_MIN, SWATCH_LAMBDA_STEP, SWATCH_REFLECTANCES, SWATCH_SAMPLE_COUNT};
+use std::path::Path;
+use std::{array, fs};
+
+#[derive(Clone, Debug)]
+pub struct IlluminantSpectrum {
+    pub lambdas: Vec<f32>,
+    pub values: Vec<f32>,
+}
+
+impl IlluminantSpectrum {
+    pub fn d65() -> Self {
+        Self::from_fn(|lambda| spectrum_table::cie_d65(lambda as f64) as f32)
+    }
+
+    pub fn d50() -> Self {
+        Self::from_fn(|lambda| spectrum_table::cie_d50(lambda as f64) as f32)
+    }
+
+    pub fn blackbody(temperature: f32) -> Self {
+        const C: f64 = 299792458.0;
+        const H: f64 = 6.62606957e-34;
+        const KB: f64 = 1.3806488e-23;
+
+        let planck = |lambda: f32| {
+            let l = lambda as f64 * 1e-9;
+            2.0 * H * C * C / (l.powi(5) * ((H * C / (l * KB * temperature as f64)).exp() - 1.0))
+        };
+
+        let normalization = planck(560.0);
+
+        Self::from_fn(|lambda| (planck(lambda) / normalization) as f32)
+    }
+
+    fn from_fn(evaluate: impl Fn(f32) -> f32) -> Self {
+        let lambdas = (sensor::LAMBDA_MIN as usize..=sensor::LAMBDA_MAX as usize).step_by(5).map(|lambda| lambda as f32).collect::<Vec<_>>();
+        let values = lambdas.iter().map(|&lambda| evaluate(lambda)).collect();
+
+        Self { lambdas, values }
+    }
+
+    // One `lambda,value` row per line, with the same comment and header rules as sensor curves
+    pub fn parse_csv(source: &str) -> Result<Self> {
+        let mut lambdas = Vec::new();
+        let mut values = Vec::new();
+
+        for (line_index, line) in source.lines().enumerate() {
+            let line = line.split('#').next().unwrap().trim();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            let row = match line.split([',', ';', '\t']).map(|token| token.trim().parse::<f32>()).collect::<Result<Vec<_>, _>>() {
+                Ok(row) => row,
+                Err(_) if lambdas.is_empty() => continue,
+                Err(err) => bail!("Line {} of illuminant: {}", line_index + 1, err),
+            };
+
+            if row.len() != 2 || lambdas.last().is_some_and(|&last| row[0] <= last) {
+                bail!("Line {} of illuminant: expected lambda,value with increasing wavelengths", line_index + 1);
+            }
+
+            lambdas.push(row[0]);
+            values.push(row[1]);
+        }
+
+        if lambdas.len() < 2 {
+            bail!("Illuminant needs at least two samples");
+        }
+
+        Ok(Self { lambdas, values })
+    }
+
+    pub fn load_csv(path: impl AsRef<Path>) -> Result<Self> {
+        Self::parse_csv(&fs::read_to_string(path)?)
+    }
+
+    pub fn evaluate(&self, lambda: f32) -> f32 {
+        sensor::interpolate_samples(&self.lambdas, &self.values, lambda)
+    }
+}
+
+// Linear camera RGB of each patch, averaged over the patch area of a photograph without clipped channels
+#[derive(Clone, Debug)]
+pub struct ColorCheckerMeasurement {
+    pub patches: [Vec3; SWATCH_COUNT],
+}
+
+impl ColorCheckerMeasurement {
+    // 24 `r,g,b` rows in chart reading order, an optional header row is skipped
+    pub fn parse_csv(source: &str) -> Result<Self> {
+        let mut patches = Vec::with_capacity(SWATCH_COUNT);
+
+        for (line_index, line) in source.lines().enumerate() {
+            let line = line.split('#').next().unwrap().trim();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            let row = match line.split([',', ';', '\t']).map(|token| token.trim().parse::<f32>()).collect::<Result<Vec<_>, _>>() {
+                Ok(row) => row,
+                Err(_) if patches.is_empty() => continue,
+                Err(err) => bail!("Line {} of color checker: {}", line_index + 1, err),
+            };
+
+            if row.len() != 3 {
+                bail!("Line {} of color checker: expected r,g,b, found {} values", line_index + 1, row.len());
+            }
+
+            patches.push(Vec3::from_slice(&row));
+        }
+
+        let patches: [Vec3; SWATCH_COUNT] = patches.try_into().map_err(|patches: Vec<Vec3>| anyhow!("Expected {} patches, found {}", SWATCH_COUNT, patches.len()))?;
+
+        Ok(Self { patches })
+    }
+
+    pub fn load_csv(path: impl AsRef<Path>) -> Result<Self> {
+        Self::parse_csv(&fs::read_to_string(path)?)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SensorCalibration {
+    pub profile: SensorProfile,
+    // Residuals of the fitted matrix in XYZ, relative to a perfect white of Y = 1
+    pub rms_error: f32,
+    pub max_error: f32,
+}
+
+fn swatch_lambda(k: usize) -> f32 {
+    SWATCH_LAMBDA_MIN + k as f32 * SWATCH_LAMBDA_STEP
+}
+
+// Tristimulus values of every patch under `illuminant`, scaled so a perfect reflector has Y = 1
+pub fn swatch_xyz(illuminant: &IlluminantSpectrum) -> [DVec3; SWATCH_COUNT] {
+    let illuminant_y = (0..SWATCH_SAMPLE_COUNT)
+        .map(|k| illuminant.evaluate(swatch_lambda(k)) as f64 * spectrum_table::cie_xyz(swatch_lambda(k) as f64)[1])
+        .sum::<f64>();
+
+    SWATCH_REFLECTANCES.map(|reflectance| {
+        (0..SWATCH_SAMPLE_COUNT)
+            .map(|k| DVec3::from_array(spectrum_table::cie_xyz(swatch_lambda(k) as f64)) * (reflectance[k] * illuminant.evaluate(swatch_lambda(k))) as f64)
+            .sum::<DVec3>()
+            / illuminant_y
+    })
+}
+
+// Least squares solution of `b = m * a` over all patches, as `PixelSensor::linear_least_square` does in the shaders
+fn linear_least_square(a: &[DVec3; SWATCH_COUNT], b: &[DVec3; SWATCH_COUNT]) -> Result<DMat3> {
+    let mut at_a = DMat3::ZERO;
+    let mut at_b = DMat3::ZERO;
+
+    for (a, b) in a.iter().zip(b) {
+        at_a += DMat3::from_cols(*a * a.x, *a * a.y, *a * a.z);
+        at_b += DMat3::from_cols(*b * a.x, *b * a.y, *b * a.z);
+    }
+
+    if at_a.determinant().abs() < 1e-12 {
+        bail!("Color checker measurements are degenerate, the patches do not span three channels");
+    }
+
+    Ok(at_b * at_a.inverse())
+}
+
+impl SensorCalibration {
+    pub fn fit(name: impl Into<String>, measurement: &ColorCheckerMeasurement, illuminant: &IlluminantSpectrum) -> Result<Self> {
+        let rgb = measurement.patches.map(|patch| patch.as_dvec3());
+        let xyz = swatch_xyz(illuminant);
+
+        let xyz_from_sensor_rgb = linear_least_square(&rgb, &xyz)?;
+
+        let errors = rgb.iter().zip(&xyz).map(|(rgb, xyz)| (xyz_from_sensor_rgb * *rgb - *xyz).length()).collect::<Vec<_>>();
+        let rms_error = (errors.iter().map(|error| error * error).sum::<f64>() / SWATCH_COUNT as f64).sqrt();
+        let max_error = errors.iter().copied().fold(0.0, f64::max);
+
+        Ok(Self {
+            profile: SensorProfile {
+                name: name.into(),
+                xyz_from_sensor_rgb: xyz_from_sensor_rgb.as_mat3(),
+                response: None,
+            },
+            rms_error: rms_error as f32,
+            max_error: max_error as f32,
+        })
+    }
+
+    // Estimates curves on the swatch grid that reproduce the measurements, with a second-difference penalty weighted by
+    // `smoothness` since 24 patches alone leave the curves underdetermined
+    pub fn fit_spectral_curves(mut self, measurement: &ColorCheckerMeasurement, illuminant: &IlluminantSpectrum, smoothness: f64) -> Result<Self> {
+        const N: usize = SWATCH_SAMPLE_COUNT;
+
+        let design = SWATCH_REFLECTANCES.map(|reflectance| {
+            let row: [f64; N] = array::from_fn(|k| (reflectance[k] * illuminant.evaluate(swatch_lambda(k)) * SWATCH_LAMBDA_STEP) as f64);
+            row
+        });
+
+        let mut normal = [[0.0; N]; N];
+        for row in &design {
+            for i in 0..N {
+                for j in 0..N {
+                    normal[i][j] += row[i] * row[j];
+                }
+            }
+        }
+
+        let penalty = smoothness.max(0.0) * (0..N).map(|i| normal[i][i]).sum::<f64>() / N as f64;
+        for k in 1..N - 1 {
+            let stencil = [(k - 1, 1.0), (k, -2.0), (k + 1, 1.0)];
+
+            for &(i, wi) in &stencil {
+                for &(j, wj) in &stencil {
+                    normal[i][j] += penalty * wi * wj;
+                }
+            }
+        }
+
+        let mut curves = [[0.0; N]; 3];
+
+        for (channel, curve) in curves.iter_mut().enumerate() {
+            let mut rhs = [0.0; N];
+
+            for (row, patch) in design.iter().zip(&measurement.patches) {
+                for i in 0..N {
+                    rhs[i] += row[i] * patch[channel] as f64;
+                }
+            }
+
+            *curve = solve_linear_system(normal, rhs)?.map(|value| value.max(0.0));
+        }
+
+        self.profile.response = Some(SensorResponse {
+            name: self.profile.name.clone(),
+            lambdas: (0..N).map(swatch_lambda).collect(),
+            r: curves[0].iter().map(|&value| value as f32).collect(),
+            g: curves[1].iter().map(|&value| value as f32).collect(),
+            b: curves[2].iter().map(|&value| value as f32).collect(),
+        });
+
+        Ok(self)
+    }
+
+    pub fn xyz_from_sensor_rgb(&self) -> Mat3 {
+        self.profile.xyz_from_sensor_rgb
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        self.profile.save(path)
+    }
+}
+
+// Gaussian elimination with partial pivoting
+fn solve_linear_system<const N: usize>(mut a: [[f64; N]; N], mut b: [f64; N]) -> Result<[f64; N]> {
+    for i in 0..N {
+        let pivot = (i..N).max_by(|&x, &y| a[x][i].abs().total_cmp(&a[y][i].abs())).unwrap();
+
+        if a[pivot][i].abs() < 1e-18 {
+            bail!("Spectral curve fit is singular, increase the smoothness");
+        }
+
+        a.swap(i, pivot);
+        b.swap(i, pivot);
+
+        for j in i + 1..N {
+            let factor = a[j][i] / a[i][i];
+
+            for k in i..N {
+                a[j][k] -= factor * a[i][k];
+            }
+            b[j] -= factor * b[i];
+        }
+    }
+
+    let mut x = [0.0; N];
+    for i in (0..N).rev() {
+        x[i] = (b[i] - (i + 1..N).map(|k| a[i][k] * x[k]).sum::<f64>()) / a[i][i];
+    }
+
+    Ok(x)
+}
@@ -0,0 +1,137 @@
+use crate::sampling::{DistributionBuffer, PiecewiseConstant2D};
+use glam::Vec2;
+use optics_common::film::{self, FILTER_SAMPLER_RESOLUTION};
+
+#[derive(Copy, Clone, Debug)]
+pub enum FilterType {
+    Box,
+    Gaussian,
+    Mitchell,
+    LanczosSinc,
+    Triangle,
+}
+
+impl Into<u32> for FilterType {
+    fn into(self) -> u32 {
+        match self {
+            FilterType::Box => 0,
+            FilterType::Gaussian => 1,
+            FilterType::Mitchell => 2,
+            FilterType::LanczosSinc => 3,
+            FilterType::Triangle => 4,
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct RenderFilter {
+    pub radius: Vec2,
+    // Sigma for the Gaussian, B and C for Mitchell, tau for Lanczos
+    pub parameters: Vec2,
+    pub filter_type: u32,
+    // Offset of the tabulated filter in the flattened distribution buffer, only read for filters without a closed form sample
+    pub sampler_offset: u32,
+    pub integral: f32,
+}
+
+// Reconstruction filter of the film the volumetric kernels record into
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum FilmFilterDesc {
+    Box { radius: Vec2 },
+    Gaussian { radius: Vec2, sigma: f32 },
+    Mitchell { radius: Vec2, b: f32, c: f32 },
+    LanczosSinc { radius: Vec2, tau: f32 },
+    Triangle { radius: Vec2 },
+}
+
+impl Default for FilmFilterDesc {
+    fn default() -> Self {
+        FilmFilterDesc::Box {
+            radius: Vec2::splat(film::FILTER_RADIUS),
+        }
+    }
+}
+
+impl FilmFilterDesc {
+    pub fn gaussian() -> Self {
+        FilmFilterDesc::Gaussian { radius: Vec2::splat(1.5), sigma: 0.5 }
+    }
+
+    pub fn mitchell() -> Self {
+        FilmFilterDesc::Mitchell {
+            radius: Vec2::splat(2.0),
+            b: 1.0 / 3.0,
+            c: 1.0 / 3.0,
+        }
+    }
+
+    pub fn lanczos_sinc() -> Self {
+        FilmFilterDesc::LanczosSinc { radius: Vec2::splat(0.5), tau: 3.0 }
+    }
+
+    pub fn triangle() -> Self {
+        FilmFilterDesc::Triangle { radius: Vec2::splat(2.0) }
+    }
+
+    pub fn radius(&self) -> Vec2 {
+        match *self {
+            FilmFilterDesc::Box { radius }
+            | FilmFilterDesc::Gaussian { radius, .. }
+            | FilmFilterDesc::Mitchell { radius, .. }
+            | FilmFilterDesc::LanczosSinc { radius, .. }
+            | FilmFilterDesc::Triangle { radius } => radius,
+        }
+    }
+
+    // Filters with a closed form sample return None and are never tabulated
+    fn evaluate(&self, point: Vec2) -> Option<f32> {
+        match *self {
+            FilmFilterDesc::Gaussian { radius, sigma } => Some(film::gaussian_filter(point.to_array(), radius.to_array(), sigma)),
+            FilmFilterDesc::Mitchell { radius, b, c } => Some(film::mitchell_filter(point.to_array(), radius.to_array(), b, c)),
+            FilmFilterDesc::LanczosSinc { radius, tau } => Some(film::lanczos_sinc_filter(point.to_array(), radius.to_array(), tau)),
+            FilmFilterDesc::Box { .. } | FilmFilterDesc::Triangle { .. } => None,
+        }
+    }
+
+    // Tabulated filters push their sampling distribution into `distributions`, which is bound next to the film
+    pub fn to_render_filter(&self, distributions: &mut DistributionBuffer) -> RenderFilter {
+        let radius = self.radius();
+
+        let (filter_type, parameters) = match *self {
+            FilmFilterDesc::Box { .. } => (FilterType::Box, Vec2::ZERO),
+            FilmFilterDesc::Gaussian { sigma, .. } => (FilterType::Gaussian, Vec2::new(sigma, 0.0)),
+            FilmFilterDesc::Mitchell { b, c, .. } => (FilterType::Mitchell, Vec2::new(b, c)),
+            FilmFilterDesc::LanczosSinc { tau, .. } => (FilterType::LanczosSinc, Vec2::new(tau, 0.0)),
+            FilmFilterDesc::Triangle { .. } => (FilterType::Triangle, Vec2::ZERO),
+        };
+
+        let cell_size = 2.0 * radius / FILTER_SAMPLER_RESOLUTION as f32;
+        let values = (0..FILTER_SAMPLER_RESOLUTION)
+            .flat_map(|v| (0..FILTER_SAMPLER_RESOLUTION).map(move |u| -radius + Vec2::new(u as f32 + 0.5, v as f32 + 0.5) * cell_size))
+            .map(|point| self.evaluate(point))
+            .collect::<Option<Vec<_>>>();
+
+        let sampler_offset = match &values {
+            Some(values) => distributions.push(&PiecewiseConstant2D::new(values, FILTER_SAMPLER_RESOLUTION, FILTER_SAMPLER_RESOLUTION)),
+            None => u32::MAX,
+        };
+
+        // The windowed sinc has no closed form integral, it is summed over the table instead
+        let integral = match *self {
+            FilmFilterDesc::Box { radius } => 4.0 * radius.x * radius.y,
+            FilmFilterDesc::Gaussian { radius, sigma } => film::gaussian_filter_integral(radius.to_array(), sigma),
+            FilmFilterDesc::Mitchell { radius, .. } => radius.x * radius.y / 4.0,
+            FilmFilterDesc::LanczosSinc { .. } => values.unwrap_or_default().iter().sum::<f32>() * cell_size.x * cell_size.y,
+            FilmFilterDesc::Triangle { radius } => radius.x * radius.x * radius.y * radius.y,
+        };
+
+        RenderFilter {
+            radius,
+            parameters,
+            filter_type: filter_type.into(),
+            sampler_offset,
+            integral,
+        }
+    }
+}
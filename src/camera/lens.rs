@@ -0,0 +1,232 @@
+use crate::sampling::FlattenedDistribution;
+use anyhow::{bail, Result};
+use glam::{UVec2, Vec2, Vec3, Vec4};
+use log::warn;
+use optics_common::lens;
+pub use optics_common::lens::{LensElementInterface, EXIT_PUPIL_BOUND_COUNT, MAX_LENS_ELEMENTS};
+use std::fs;
+use std::path::Path;
+
+pub const EXIT_PUPIL_SAMPLE_COUNT: u32 = 1 << 16;
+
+// Pushed into the `DistributionBuffer` by `CameraDesc::to_render_camera`, the shaders read it back through `camera::realistic::LensSlice`
+#[derive(Copy, Clone, Debug)]
+pub struct RenderLens {
+    pub exit_pupil_bounds: [Vec4; EXIT_PUPIL_BOUND_COUNT],
+    pub physical_extent: Vec4,
+    pub elements: [LensElementInterface; MAX_LENS_ELEMENTS],
+    pub element_count: u32,
+    pub film_diagonal: f32,
+}
+
+impl FlattenedDistribution for RenderLens {
+    fn flatten_into(&self, data: &mut Vec<u32>) {
+        let elements = &self.elements[..self.element_count as usize];
+
+        data.push(self.element_count);
+        data.push(elements.iter().any(|element| element.is_dispersive()) as u32);
+        data.push(self.film_diagonal.to_bits());
+        data.extend(self.physical_extent.to_array().map(f32::to_bits));
+        data.extend(self.exit_pupil_bounds.iter().flat_map(|bounds| bounds.to_array()).map(f32::to_bits));
+        data.extend(elements.iter().flat_map(|element| element.to_array()).map(f32::to_bits));
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct LensSystem {
+    pub elements: Vec<LensElementInterface>,
+}
+
+impl LensSystem {
+    pub fn load(path: impl AsRef<Path>, aperture_diameter: Option<f32>) -> Result<Self> {
+        Self::parse(&fs::read_to_string(path)?, aperture_diameter)
+    }
+
+    pub fn parse(source: &str, aperture_diameter: Option<f32>) -> Result<Self> {
+        let mut elements = Vec::new();
+
+        for (line_index, line) in source.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            let values = line.split_whitespace().map(|value| value.parse::<f32>()).collect::<Result<Vec<_>, _>>()?;
+
+            if values.len() != 4 && values.len() != 5 {
+                bail!("Lens element on line {} expects 4 or 5 values, found {}", line_index + 1, values.len());
+            }
+
+            let curvature_radius = values[0] / 1000.0;
+            let thickness = values[1] / 1000.0;
+            let eta = values[2];
+            let mut diameter = values[3] / 1000.0;
+            let abbe_number = values.get(4).copied().unwrap_or_default();
+
+            if curvature_radius == 0.0 {
+                if let Some(aperture_diameter) = aperture_diameter {
+                    let aperture_diameter = aperture_diameter / 1000.0;
+
+                    if aperture_diameter > diameter {
+                        warn!("Aperture diameter {} is larger than the lens stop {}, clamping", aperture_diameter, diameter);
+                    } else {
+                        diameter = aperture_diameter;
+                    }
+                }
+            }
+
+            elements.push(LensElementInterface {
+                curvature_radius,
+                thickness,
+                eta,
+                abbe_number,
+                aperture_radius: diameter / 2.0,
+            });
+        }
+
+        if elements.is_empty() {
+            bail!("Lens prescription contains no elements");
+        }
+
+        if elements.len() > MAX_LENS_ELEMENTS {
+            bail!("Lens prescription has {} elements, at most {} are supported", elements.len(), MAX_LENS_ELEMENTS);
+        }
+
+        Ok(Self { elements })
+    }
+
+    pub fn lens_rear_z(&self) -> f32 {
+        self.elements.last().unwrap().thickness
+    }
+
+    pub fn lens_front_z(&self) -> f32 {
+        self.elements.iter().map(|element| element.thickness).sum()
+    }
+
+    pub fn rear_element_radius(&self) -> f32 {
+        self.elements.last().unwrap().aperture_radius
+    }
+
+    // Nominal refractive indices only, dispersion is left to the shaders
+    pub fn trace_lenses_from_film(&self, origin: Vec3, direction: Vec3) -> Option<(Vec3, Vec3)> {
+        let (origin, direction) = lens::trace_lenses_from_film(self.elements.len(), |i| self.elements[i], origin.to_array(), direction.to_array(), lens::LAMBDA_NOMINAL)?;
+        Some((Vec3::from_array(origin), Vec3::from_array(direction)))
+    }
+
+    pub fn trace_lenses_from_scene(&self, origin: Vec3, direction: Vec3) -> Option<(Vec3, Vec3)> {
+        let (origin, direction) = lens::trace_lenses_from_scene(self.elements.len(), |i| self.elements[i], origin.to_array(), direction.to_array(), lens::LAMBDA_NOMINAL)?;
+        Some((Vec3::from_array(origin), Vec3::from_array(direction)))
+    }
+
+    fn compute_cardinal_points(ray_in: (Vec3, Vec3), ray_out: (Vec3, Vec3)) -> (f32, f32) {
+        let (origin_in, _) = ray_in;
+        let (origin_out, direction_out) = ray_out;
+
+        let tf = -origin_out.x / direction_out.x;
+        let focal_z = -(origin_out + direction_out * tf).z;
+
+        let tp = (origin_in.x - origin_out.x) / direction_out.x;
+        let principal_z = -(origin_out + direction_out * tp).z;
+
+        (principal_z, focal_z)
+    }
+
+    pub fn compute_thick_lens_approximation(&self, film_diagonal: f32) -> Result<([f32; 2], [f32; 2])> {
+        let x = 0.001 * film_diagonal;
+
+        let scene_ray = (Vec3::new(x, 0.0, self.lens_front_z() + 1.0), Vec3::NEG_Z);
+        let Some(film_ray) = self.trace_lenses_from_scene(scene_ray.0, scene_ray.1) else {
+            bail!("Unable to trace ray from scene to film for thick lens approximation");
+        };
+        let (principal_z0, focal_z0) = Self::compute_cardinal_points(scene_ray, film_ray);
+
+        let film_ray = (Vec3::new(x, 0.0, self.lens_rear_z() - 1.0), Vec3::Z);
+        let Some(scene_ray) = self.trace_lenses_from_film(film_ray.0, film_ray.1) else {
+            bail!("Unable to trace ray from film to scene for thick lens approximation");
+        };
+        let (principal_z1, focal_z1) = Self::compute_cardinal_points(film_ray, scene_ray);
+
+        Ok(([principal_z0, principal_z1], [focal_z0, focal_z1]))
+    }
+
+    pub fn focus_thick_lens(&self, focus_distance: f32, film_diagonal: f32) -> Result<f32> {
+        let (principal_z, focal_z) = self.compute_thick_lens_approximation(film_diagonal)?;
+
+        let f = focal_z[0] - principal_z[0];
+        let z = -focus_distance;
+        let c = (principal_z[1] - z - principal_z[0]) * (principal_z[1] - z - 4.0 * f - principal_z[0]);
+
+        if c <= 0.0 {
+            bail!("Focus distance {} is too short for the given lens configuration", focus_distance);
+        }
+
+        let delta = 0.5 * (principal_z[1] - z + principal_z[0] - c.sqrt());
+
+        Ok(self.lens_rear_z() + delta)
+    }
+
+    pub fn bound_exit_pupil(&self, film_x0: f32, film_x1: f32) -> (Vec2, Vec2) {
+        let rear_radius = self.rear_element_radius();
+        let projected_min = Vec2::splat(-1.5 * rear_radius);
+        let projected_max = Vec2::splat(1.5 * rear_radius);
+
+        let mut pupil_min = Vec2::INFINITY;
+        let mut pupil_max = Vec2::NEG_INFINITY;
+
+        for i in 0..EXIT_PUPIL_SAMPLE_COUNT {
+            let point_film = Vec3::new(lens::lerp((i as f32 + 0.5) / EXIT_PUPIL_SAMPLE_COUNT as f32, film_x0, film_x1), 0.0, 0.0);
+            let u = Vec2::new(lens::radical_inverse(2, i as u64), lens::radical_inverse(3, i as u64));
+            let point_rear = Vec3::new(lens::lerp(u.x, projected_min.x, projected_max.x), lens::lerp(u.y, projected_min.y, projected_max.y), self.lens_rear_z());
+
+            let inside = point_rear.x >= pupil_min.x && point_rear.x <= pupil_max.x && point_rear.y >= pupil_min.y && point_rear.y <= pupil_max.y;
+
+            if inside || self.trace_lenses_from_film(point_film, point_rear - point_film).is_some() {
+                pupil_min = pupil_min.min(point_rear.truncate());
+                pupil_max = pupil_max.max(point_rear.truncate());
+            }
+        }
+
+        if pupil_min.x > pupil_max.x || pupil_min.y > pupil_max.y {
+            return (Vec2::ZERO, Vec2::ZERO);
+        }
+
+        let expand = 2.0 * (projected_max - projected_min).length() / (EXIT_PUPIL_SAMPLE_COUNT as f32).sqrt();
+
+        (pupil_min - Vec2::splat(expand), pupil_max + Vec2::splat(expand))
+    }
+
+    pub fn to_render_lens(&self, resolution: UVec2, film_diagonal_mm: f32, focus_distance: f32) -> Result<RenderLens> {
+        let film_diagonal = film_diagonal_mm / 1000.0;
+
+        let mut system = self.clone();
+        let rear_z = system.focus_thick_lens(focus_distance, film_diagonal)?;
+        system.elements.last_mut().unwrap().thickness = rear_z;
+
+        let aspect = resolution.y as f32 / resolution.x as f32;
+        let x = (film_diagonal * film_diagonal / (1.0 + aspect * aspect)).sqrt();
+        let y = aspect * x;
+        let physical_extent = Vec4::new(-x / 2.0, -y / 2.0, x / 2.0, y / 2.0);
+
+        let mut exit_pupil_bounds = [Vec4::ZERO; EXIT_PUPIL_BOUND_COUNT];
+
+        for (i, bounds) in exit_pupil_bounds.iter_mut().enumerate() {
+            let r0 = i as f32 / EXIT_PUPIL_BOUND_COUNT as f32 * film_diagonal / 2.0;
+            let r1 = (i + 1) as f32 / EXIT_PUPIL_BOUND_COUNT as f32 * film_diagonal / 2.0;
+            let (min, max) = system.bound_exit_pupil(r0, r1);
+
+            *bounds = Vec4::new(min.x, min.y, max.x, max.y);
+        }
+
+        let mut elements = [LensElementInterface::default(); MAX_LENS_ELEMENTS];
+        elements[..system.elements.len()].copy_from_slice(&system.elements);
+
+        Ok(RenderLens {
+            exit_pupil_bounds,
+            physical_extent,
+            elements,
+            element_count: system.elements.len() as u32,
+            film_diagonal,
+        })
+    }
+}
@@ -0,0 +1,5 @@
+pub mod aperture;
+pub mod calibration;
+pub mod filter;
+pub mod lens;
+pub mod sensor;
@@ -0,0 +1,327 @@
+use crate::memory::render_buffer::{RenderBuffer, RenderBufferAllocator};
+use anyhow::{anyhow, bail, Result};
+use ash::vk::BufferUsageFlags;
+use glam::{Mat3, Mat4};
+use gpu_allocator::MemoryLocation;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::{array, fs, mem, slice};
+
+pub const LAMBDA_MIN: f32 = 360.0;
+pub const LAMBDA_MAX: f32 = 830.0;
+pub const LAMBDA_COUNT: usize = LAMBDA_MAX as usize - LAMBDA_MIN as usize + 1;
+
+// ISO 12232 saturation based speed with the usual lens and vignetting factor q = 0.65
+const SATURATION_CONSTANT: f32 = 78.0 / 0.65;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct ExposureSettings {
+    pub iso: f32,
+    pub shutter_time: f32,
+    pub f_number: f32,
+    pub compensation: f32,
+}
+
+impl Default for ExposureSettings {
+    fn default() -> Self {
+        Self {
+            iso: 100.0,
+            shutter_time: 1.0,
+            f_number: 1.0,
+            compensation: 0.0,
+        }
+    }
+}
+
+impl ExposureSettings {
+    pub fn new(iso: f32, shutter_time: f32, f_number: f32) -> Result<Self> {
+        if iso <= 0.0 || shutter_time <= 0.0 || f_number <= 0.0 {
+            bail!("Exposure settings must be positive: ISO {}, shutter time {}s, f/{}", iso, shutter_time, f_number);
+        }
+
+        Ok(Self {
+            iso,
+            shutter_time,
+            f_number,
+            compensation: 0.0,
+        })
+    }
+
+    pub fn compensation(mut self, compensation: f32) -> Self {
+        self.compensation = compensation;
+        self
+    }
+
+    pub fn ev100(&self) -> f32 {
+        (self.f_number * self.f_number / self.shutter_time * 100.0 / self.iso).log2()
+    }
+
+    // Matches `ExposureSettings::image_ratio` in the shaders, the value given to `PixelSensor::new`
+    pub fn image_ratio(&self) -> f32 {
+        (self.compensation - self.ev100()).exp2()
+    }
+
+    // Scene luminance in cd/m^2 that saturates the sensor
+    pub fn saturation_luminance(&self) -> f32 {
+        SATURATION_CONSTANT * self.f_number * self.f_number / (self.shutter_time * self.iso) * (-self.compensation).exp2()
+    }
+}
+
+// Spectral sensitivities of the red, green and blue channels of a camera body, sampled at `lambdas` in nanometers
+#[derive(Clone, Debug)]
+pub struct SensorResponse {
+    pub name: String,
+    pub lambdas: Vec<f32>,
+    pub r: Vec<f32>,
+    pub g: Vec<f32>,
+    pub b: Vec<f32>,
+}
+
+impl SensorResponse {
+    // One `lambda,r,g,b` row per line, '#' starts a comment and a non-numeric first row is taken as a header
+    pub fn parse_csv(name: impl Into<String>, source: &str) -> Result<Self> {
+        let name = name.into();
+        let mut response = Self {
+            name,
+            lambdas: Vec::new(),
+            r: Vec::new(),
+            g: Vec::new(),
+            b: Vec::new(),
+        };
+
+        for (line_index, line) in source.lines().enumerate() {
+            let line = line.split('#').next().unwrap().trim();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            let values = line.split([',', ';', '\t']).map(|token| token.trim().parse::<f32>()).collect::<Result<Vec<_>, _>>();
+
+            let values = match values {
+                Ok(values) => values,
+                Err(_) if response.lambdas.is_empty() => continue,
+                Err(err) => bail!("Line {} of sensor {}: {}", line_index + 1, response.name, err),
+            };
+
+            if values.len() != 4 {
+                bail!("Line {} of sensor {}: expected lambda,r,g,b, found {} values", line_index + 1, response.name, values.len());
+            }
+
+            if response.lambdas.last().is_some_and(|&last| values[0] <= last) {
+                bail!("Line {} of sensor {}: wavelengths must be strictly increasing", line_index + 1, response.name);
+            }
+
+            response.lambdas.push(values[0]);
+            response.r.push(values[1]);
+            response.g.push(values[2]);
+            response.b.push(values[3]);
+        }
+
+        if response.lambdas.len() < 2 {
+            bail!("Sensor {} needs at least two samples", response.name);
+        }
+
+        Ok(response)
+    }
+
+    pub fn load_csv(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let name = path.file_stem().and_then(|stem| stem.to_str()).ok_or_else(|| anyhow!("Invalid sensor file name {}", path.display()))?;
+
+        Self::parse_csv(name, &fs::read_to_string(path)?)
+    }
+
+    // The CIE 1931 2 degree observer as an ideal colorimetric sensor whose r, g and b channels record X, Y and Z,
+    // sampled from the matching functions of `spectrum_table`
+    pub fn cie_1931() -> Self {
+        let lambdas = (0..LAMBDA_COUNT).map(|i| LAMBDA_MIN + i as f32).collect::<Vec<_>>();
+        let xyz = lambdas.iter().map(|&lambda| spectrum_table::cie_xyz(lambda as f64)).collect::<Vec<_>>();
+
+        Self {
+            name: String::from("cie_1931_2deg"),
+            r: xyz.iter().map(|xyz| xyz[0] as f32).collect(),
+            g: xyz.iter().map(|xyz| xyz[1] as f32).collect(),
+            b: xyz.iter().map(|xyz| xyz[2] as f32).collect(),
+            lambdas,
+        }
+    }
+
+    // Resamples the curves to the 1nm grid of the shaders' `DenselySampledSpectrum`, zero outside the measured range
+    pub fn densely_sampled(&self) -> [[f32; LAMBDA_COUNT]; 3] {
+        [&self.r, &self.g, &self.b].map(|values| array::from_fn(|i| interpolate_samples(&self.lambdas, values, LAMBDA_MIN + i as f32)))
+    }
+}
+
+// Linear interpolation of a curve sampled at increasing `lambdas`, zero outside of them
+pub(crate) fn interpolate_samples(lambdas: &[f32], values: &[f32], lambda: f32) -> f32 {
+    if lambdas.is_empty() || lambda < lambdas[0] || lambda > *lambdas.last().unwrap() {
+        return 0.0;
+    }
+
+    if lambdas.len() == 1 {
+        return values[0];
+    }
+
+    let i = lambdas.partition_point(|&l| l <= lambda).clamp(1, lambdas.len() - 1);
+    let t = (lambda - lambdas[i - 1]) / (lambdas[i] - lambdas[i - 1]);
+
+    values[i - 1] * (1.0 - t) + values[i] * t
+}
+
+// A directory of CSV files named after the camera body, `sensors/` bundles the curves whose licence allows it
+#[derive(Clone, Debug, Default)]
+pub struct SensorLibrary {
+    sensors: BTreeMap<String, SensorResponse>,
+}
+
+impl SensorLibrary {
+    pub fn load_dir(directory: impl AsRef<Path>) -> Result<Self> {
+        let mut library = Self::default();
+
+        for entry in fs::read_dir(directory)? {
+            let path = entry?.path();
+
+            if path.extension().is_some_and(|extension| extension.eq_ignore_ascii_case("csv")) {
+                library.insert(SensorResponse::load_csv(&path)?);
+            }
+        }
+
+        Ok(library)
+    }
+
+    pub fn insert(&mut self, sensor: SensorResponse) {
+        self.sensors.insert(sensor.name.to_lowercase(), sensor);
+    }
+
+    pub fn get(&self, name: &str) -> Option<&SensorResponse> {
+        self.sensors.get(&name.to_lowercase())
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.sensors.values().map(|sensor| sensor.name.as_str())
+    }
+}
+
+// Mirrors `RenderSensor` in the shaders' film module, the curves are on the 1nm grid of `SensorResponse::densely_sampled`
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct RenderSensor {
+    pub xyz_from_sensor_rgb: Mat4,
+    pub image_ratio: f32,
+    pub curves: [[f32; LAMBDA_COUNT]; 3],
+}
+
+impl RenderSensor {
+    pub fn write_to_buffer(&self, allocator: &RenderBufferAllocator) -> Result<RenderBuffer> {
+        let buffer = allocator.allocate(
+            mem::size_of::<RenderSensor>() as _,
+            BufferUsageFlags::STORAGE_BUFFER | BufferUsageFlags::TRANSFER_DST,
+            MemoryLocation::GpuOnly,
+        )?;
+
+        allocator.upload_data(&buffer, slice::from_ref(self))?;
+
+        Ok(buffer)
+    }
+}
+
+// The CIE 1931 observer records XYZ, so its profile needs no fitted matrix
+pub fn cie_1931_sensor(exposure: &ExposureSettings) -> Result<RenderSensor> {
+    SensorProfile::colorimetric(SensorResponse::cie_1931(), Mat3::IDENTITY).to_render_sensor(exposure)
+}
+
+// A calibrated sensor: the matrix to XYZ and, when they were fitted or measured, the spectral curves it applies to
+#[derive(Clone, Debug)]
+pub struct SensorProfile {
+    pub name: String,
+    pub xyz_from_sensor_rgb: Mat3,
+    pub response: Option<SensorResponse>,
+}
+
+impl SensorProfile {
+    // For curves that already are a linear combination of the CIE matching functions, such as `SensorResponse::cie_1931`
+    pub fn colorimetric(response: SensorResponse, xyz_from_sensor_rgb: Mat3) -> Self {
+        Self {
+            name: response.name.clone(),
+            xyz_from_sensor_rgb,
+            response: Some(response),
+        }
+    }
+
+    // The image ratio is divided by the integral of the green curve, so a flat unit spectrum reads `image_ratio` in green
+    // like the CIE Y normalization of pbrt's XYZ sensor
+    pub fn to_render_sensor(&self, exposure: &ExposureSettings) -> Result<RenderSensor> {
+        let response = self.response.as_ref().ok_or_else(|| anyhow!("Sensor profile {} has no spectral curves", self.name))?;
+        let [r, g, b] = response.densely_sampled();
+
+        let g_integral = g.iter().sum::<f32>();
+
+        if g_integral <= 0.0 {
+            bail!("Green curve of sensor {} integrates to {}", self.name, g_integral);
+        }
+
+        Ok(RenderSensor {
+            xyz_from_sensor_rgb: Mat4::from_mat3(self.xyz_from_sensor_rgb),
+            image_ratio: exposure.image_ratio() / g_integral,
+            curves: [r, g, b],
+        })
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let mut text = String::from("# Sensor profile, the matrix is stored row by row\n");
+        text += &format!("name {}\n", self.name);
+
+        let rows = self.xyz_from_sensor_rgb.transpose().to_cols_array();
+        text += &format!("xyz_from_sensor_rgb {}\n", rows.map(|value| value.to_string()).join(" "));
+
+        if let Some(response) = &self.response {
+            for i in 0..response.lambdas.len() {
+                text += &format!("curve {} {} {} {}\n", response.lambdas[i], response.r[i], response.g[i], response.b[i]);
+            }
+        }
+
+        fs::write(path, text)?;
+
+        Ok(())
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let source = fs::read_to_string(path)?;
+
+        let mut name = None;
+        let mut matrix = None;
+        let mut curves = String::new();
+
+        for (line_index, line) in source.lines().enumerate() {
+            let line = line.split('#').next().unwrap().trim();
+
+            let Some((keyword, rest)) = line.split_once(char::is_whitespace) else {
+                continue;
+            };
+
+            match keyword {
+                "name" => name = Some(rest.trim().to_string()),
+                "xyz_from_sensor_rgb" => {
+                    let values = rest.split_whitespace().map(|token| token.parse::<f32>()).collect::<Result<Vec<_>, _>>()?;
+
+                    if values.len() != 9 {
+                        bail!("Line {} of {}: expected 9 matrix entries, found {}", line_index + 1, path.display(), values.len());
+                    }
+
+                    matrix = Some(Mat3::from_cols_slice(&values).transpose());
+                }
+                "curve" => curves += &format!("{}\n", rest.split_whitespace().collect::<Vec<_>>().join(",")),
+                _ => bail!("Line {} of {}: unknown keyword {}", line_index + 1, path.display(), keyword),
+            }
+        }
+
+        let name = name.ok_or_else(|| anyhow!("Sensor profile {} has no name", path.display()))?;
+        let xyz_from_sensor_rgb = matrix.ok_or_else(|| anyhow!("Sensor profile {} has no matrix", path.display()))?;
+        let response = if curves.is_empty() { None } else { Some(SensorResponse::parse_csv(name.clone(), &curves)?) };
+
+        Ok(Self { name, xyz_from_sensor_rgb, response })
+    }
+}
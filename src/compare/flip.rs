@@ -0,0 +1,262 @@
+use crate::compare::{ComparisonImage, PixelEncoding};
+use crate::output::color_space::RgbColorSpace;
+use crate::output::display::ToneMapOperator;
+use glam::Vec3;
+use std::f32::consts::PI;
+
+// A 0.7m wide 4K monitor seen from 0.7m, the default observer of the FLIP paper
+pub const PIXELS_PER_DEGREE: f32 = 67.0;
+
+const COLOR_EXPONENT: f32 = 0.7;
+const FEATURE_EXPONENT: f32 = 0.5;
+const COLOR_REMAP_POINT: f32 = 0.4;
+const COLOR_REMAP_THRESHOLD: f32 = 0.95;
+const FEATURE_WIDTH: f32 = 0.082;
+const EXPOSURE_TARGET: f32 = 0.85;
+
+// Contrast sensitivity of the achromatic, red-green and blue-yellow channels as sums of Gaussians, (a, b) pairs in degrees
+const CSF_A: [(f32, f32); 1] = [(1.0, 0.0047)];
+const CSF_RG: [(f32, f32); 1] = [(1.0, 0.0053)];
+const CSF_BY: [(f32, f32); 2] = [(34.1, 0.04), (13.5, 0.025)];
+
+// Per-pixel FLIP error in [0, 1]; sRGB encoded images use LDR-FLIP, linear ones the exposure bracketed HDR-FLIP
+pub fn flip(reference: &ComparisonImage, test: &ComparisonImage) -> Vec<f32> {
+    let rgb = |image: &ComparisonImage| image.linear_rgb().into_iter().map(Vec3::from).collect::<Vec<_>>();
+    let (reference_rgb, test_rgb) = (rgb(reference), rgb(test));
+
+    match reference.encoding {
+        PixelEncoding::Srgb => ldr_flip(reference.width, reference.height, &reference_rgb, &test_rgb, PIXELS_PER_DEGREE),
+        PixelEncoding::Linear => hdr_flip(reference.width, reference.height, &reference_rgb, &test_rgb, PIXELS_PER_DEGREE),
+    }
+}
+
+// Inputs are linear Rec.709 values in [0, 1]
+pub fn ldr_flip(width: u32, height: u32, reference: &[Vec3], test: &[Vec3], pixels_per_degree: f32) -> Vec<f32> {
+    let color_error = color_pipeline(width, height, reference, test, pixels_per_degree);
+    let feature_error = feature_pipeline(width, height, reference, test, pixels_per_degree);
+
+    color_error.iter().zip(&feature_error).map(|(&color, &feature)| color.powf(1.0 - feature)).collect()
+}
+
+// Tone maps both images with ACES over a range of exposures, from the reference maximum to its median reaching 0.85, and keeps the worst error
+pub fn hdr_flip(width: u32, height: u32, reference: &[Vec3], test: &[Vec3], pixels_per_degree: f32) -> Vec<f32> {
+    let luminance = RgbColorSpace::SRGB.xyz_from_rgb().row(1);
+
+    let mut luminances = reference.iter().map(|rgb| luminance.dot(*rgb).max(0.0)).filter(|value| value.is_finite()).collect::<Vec<_>>();
+    luminances.sort_by(f32::total_cmp);
+
+    let median = luminances.get(luminances.len() / 2).copied().unwrap_or(0.0).max(1e-6);
+    let max = luminances.last().copied().unwrap_or(0.0).max(median);
+
+    let target = inverse_tone_map(EXPOSURE_TARGET, luminance);
+    let start = (target / max).log2();
+    let stop = (target / median).log2();
+    let exposure_count = ((stop - start).ceil() as usize).max(2);
+
+    let mut error = vec![0.0_f32; reference.len()];
+
+    for i in 0..exposure_count {
+        let exposure = (start + (stop - start) * i as f32 / (exposure_count - 1) as f32).exp2();
+        let tone_map = |pixels: &[Vec3]| pixels.iter().map(|&rgb| ToneMapOperator::AcesFitted.apply(rgb * exposure, luminance)).collect::<Vec<_>>();
+
+        for (error, exposure_error) in error.iter_mut().zip(ldr_flip(width, height, &tone_map(reference), &tone_map(test), pixels_per_degree)) {
+            *error = error.max(exposure_error);
+        }
+    }
+
+    error
+}
+
+fn inverse_tone_map(value: f32, luminance: Vec3) -> f32 {
+    let (mut low, mut high) = (0.0_f32, 1e4_f32);
+
+    for _ in 0..64 {
+        let middle = 0.5 * (low + high);
+
+        if luminance.dot(ToneMapOperator::AcesFitted.apply(Vec3::splat(middle), luminance)) < value {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+
+    0.5 * (low + high)
+}
+
+fn color_pipeline(width: u32, height: u32, reference: &[Vec3], test: &[Vec3], pixels_per_degree: f32) -> Vec<f32> {
+    let filtered_lab = |pixels: &[Vec3]| {
+        let ycxcz = pixels.iter().map(|&rgb| linear_rgb_to_ycxcz(rgb)).collect::<Vec<_>>();
+
+        let channel = |c: usize, csf: &[(f32, f32)]| spatial_filter(width, height, &ycxcz.iter().map(|value| value[c]).collect::<Vec<_>>(), csf, pixels_per_degree);
+        let (y, cx, cz) = (channel(0, &CSF_A), channel(1, &CSF_RG), channel(2, &CSF_BY));
+
+        (0..pixels.len())
+            .map(|i| hunt_adjust(linear_rgb_to_lab(ycxcz_to_linear_rgb(Vec3::new(y[i], cx[i], cz[i])).clamp(Vec3::ZERO, Vec3::ONE))))
+            .collect::<Vec<_>>()
+    };
+
+    let (reference, test) = (filtered_lab(reference), filtered_lab(test));
+
+    // The largest difference between two colors of the gamut, pure green against pure blue
+    let max_error = hy_ab(hunt_adjust(linear_rgb_to_lab(Vec3::Y)), hunt_adjust(linear_rgb_to_lab(Vec3::Z))).powf(COLOR_EXPONENT);
+    let remap_point = COLOR_REMAP_POINT * max_error;
+
+    reference
+        .iter()
+        .zip(&test)
+        .map(|(&reference, &test)| {
+            let error = hy_ab(reference, test).powf(COLOR_EXPONENT);
+
+            if error < remap_point {
+                error * COLOR_REMAP_THRESHOLD / remap_point
+            } else {
+                COLOR_REMAP_THRESHOLD + (error - remap_point) / (max_error - remap_point) * (1.0 - COLOR_REMAP_THRESHOLD)
+            }
+        })
+        .collect()
+}
+
+fn feature_pipeline(width: u32, height: u32, reference: &[Vec3], test: &[Vec3], pixels_per_degree: f32) -> Vec<f32> {
+    let sigma = 0.5 * FEATURE_WIDTH * pixels_per_degree;
+    let radius = (3.0 * sigma).ceil() as i32;
+
+    let gaussian = normalized((-radius..=radius).map(|x| (-(x * x) as f32 / (2.0 * sigma * sigma)).exp()).collect());
+    let edge = signed_normalized((-radius..=radius).map(|x| -x as f32 * gaussian[(x + radius) as usize]).collect());
+    let point = signed_normalized((-radius..=radius).map(|x| ((x * x) as f32 / (sigma * sigma) - 1.0) * gaussian[(x + radius) as usize]).collect());
+
+    let features = |pixels: &[Vec3]| {
+        let achromatic = pixels.iter().map(|&rgb| (linear_rgb_to_ycxcz(rgb).x + 16.0) / 116.0).collect::<Vec<_>>();
+        let magnitude = |kernel: &[f32]| {
+            let dx = convolve_separable(width, height, &achromatic, kernel, &gaussian);
+            let dy = convolve_separable(width, height, &achromatic, &gaussian, kernel);
+
+            dx.iter().zip(&dy).map(|(x, y)| (x * x + y * y).sqrt()).collect::<Vec<_>>()
+        };
+
+        (magnitude(&edge), magnitude(&point))
+    };
+
+    let ((reference_edges, reference_points), (test_edges, test_points)) = (features(reference), features(test));
+
+    (0..reference.len())
+        .map(|i| {
+            let difference = (reference_edges[i] - test_edges[i]).abs().max((reference_points[i] - test_points[i]).abs());
+            (difference / 2.0_f32.sqrt()).powf(FEATURE_EXPONENT)
+        })
+        .collect()
+}
+
+// Each Gaussian term of the contrast sensitivity function is separable, the whole kernel is normalized to one
+fn spatial_filter(width: u32, height: u32, channel: &[f32], csf: &[(f32, f32)], pixels_per_degree: f32) -> Vec<f32> {
+    let max_b = csf.iter().map(|&(_, b)| b).fold(0.0, f32::max);
+    let radius = (3.0 * (max_b / (2.0 * PI * PI)).sqrt() * pixels_per_degree).ceil() as i32;
+
+    let terms = csf
+        .iter()
+        .map(|&(a, b)| {
+            let kernel = (-radius..=radius)
+                .map(|x| {
+                    let degrees = x as f32 / pixels_per_degree;
+                    (-PI * PI * degrees * degrees / b).exp()
+                })
+                .collect::<Vec<_>>();
+
+            (a * (PI / b).sqrt(), kernel)
+        })
+        .collect::<Vec<_>>();
+
+    let total = terms.iter().map(|(weight, kernel)| weight * kernel.iter().sum::<f32>().powi(2)).sum::<f32>();
+    let mut filtered = vec![0.0; channel.len()];
+
+    for (weight, kernel) in &terms {
+        for (filtered, value) in filtered.iter_mut().zip(convolve_separable(width, height, channel, kernel, kernel)) {
+            *filtered += weight / total * value;
+        }
+    }
+
+    filtered
+}
+
+// Edges are clamped, both kernels have an odd length centered on the pixel
+fn convolve_separable(width: u32, height: u32, channel: &[f32], kernel_x: &[f32], kernel_y: &[f32]) -> Vec<f32> {
+    let (width, height) = (width as i32, height as i32);
+    let (radius_x, radius_y) = (kernel_x.len() as i32 / 2, kernel_y.len() as i32 / 2);
+
+    let mut horizontal = vec![0.0; channel.len()];
+
+    for y in 0..height {
+        for x in 0..width {
+            horizontal[(y * width + x) as usize] = (-radius_x..=radius_x)
+                .map(|offset| kernel_x[(offset + radius_x) as usize] * channel[(y * width + (x + offset).clamp(0, width - 1)) as usize])
+                .sum();
+        }
+    }
+
+    let mut output = vec![0.0; channel.len()];
+
+    for y in 0..height {
+        for x in 0..width {
+            output[(y * width + x) as usize] = (-radius_y..=radius_y)
+                .map(|offset| kernel_y[(offset + radius_y) as usize] * horizontal[((y + offset).clamp(0, height - 1) * width + x) as usize])
+                .sum();
+        }
+    }
+
+    output
+}
+
+fn normalized(kernel: Vec<f32>) -> Vec<f32> {
+    let sum = kernel.iter().sum::<f32>();
+    kernel.into_iter().map(|value| value / sum).collect()
+}
+
+// Positive weights sum to one and negative weights to minus one
+fn signed_normalized(kernel: Vec<f32>) -> Vec<f32> {
+    let positive = kernel.iter().filter(|&&value| value > 0.0).sum::<f32>();
+    let negative = -kernel.iter().filter(|&&value| value < 0.0).sum::<f32>();
+
+    kernel.into_iter().map(|value| if value > 0.0 { value / positive } else { value / negative }).collect()
+}
+
+fn white_xyz() -> Vec3 {
+    RgbColorSpace::SRGB.xyz_from_rgb() * Vec3::ONE
+}
+
+fn linear_rgb_to_ycxcz(rgb: Vec3) -> Vec3 {
+    let xyz = RgbColorSpace::SRGB.xyz_from_rgb() * rgb / white_xyz();
+
+    Vec3::new(116.0 * xyz.y - 16.0, 500.0 * (xyz.x - xyz.y), 200.0 * (xyz.y - xyz.z))
+}
+
+fn ycxcz_to_linear_rgb(ycxcz: Vec3) -> Vec3 {
+    let y = (ycxcz.x + 16.0) / 116.0;
+    let xyz = Vec3::new(ycxcz.y / 500.0 + y, y, y - ycxcz.z / 200.0) * white_xyz();
+
+    RgbColorSpace::SRGB.rgb_from_xyz() * xyz
+}
+
+fn linear_rgb_to_lab(rgb: Vec3) -> Vec3 {
+    const DELTA: f32 = 6.0 / 29.0;
+
+    let f = |value: f32| {
+        if value > DELTA * DELTA * DELTA {
+            value.cbrt()
+        } else {
+            value / (3.0 * DELTA * DELTA) + 4.0 / 29.0
+        }
+    };
+    let xyz = RgbColorSpace::SRGB.xyz_from_rgb() * rgb / white_xyz();
+    let (fx, fy, fz) = (f(xyz.x), f(xyz.y), f(xyz.z));
+
+    Vec3::new(116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz))
+}
+
+// Chroma is scaled with lightness, the Hunt effect
+fn hunt_adjust(lab: Vec3) -> Vec3 {
+    Vec3::new(lab.x, 0.01 * lab.x * lab.y, 0.01 * lab.x * lab.z)
+}
+
+fn hy_ab(a: Vec3, b: Vec3) -> f32 {
+    let difference = a - b;
+    difference.x.abs() + (difference.y * difference.y + difference.z * difference.z).sqrt()
+}
@@ -0,0 +1,228 @@
+pub mod flip;
+pub mod regression;
+
+use crate::output::color_management::{ColorPipeline, TransferFunction};
+use crate::output::color_space::RgbColorSpace;
+use crate::output::exr::ExrImage;
+use crate::util::OutputFormat;
+use anyhow::{anyhow, bail, Result};
+use image::codecs::hdr::HdrDecoder;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+const REL_MSE_EPSILON: f32 = 1e-2;
+const SSIM_WINDOW_RADIUS: i32 = 5;
+const SSIM_SIGMA: f32 = 1.5;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PixelEncoding {
+    Linear,
+    Srgb,
+}
+
+#[derive(Clone, Debug)]
+pub struct ComparisonImage {
+    pub width: u32,
+    pub height: u32,
+    pub pixels: Vec<[f32; 4]>,
+    pub encoding: PixelEncoding,
+}
+
+impl ComparisonImage {
+    pub fn new(width: u32, height: u32, pixels: Vec<[f32; 4]>, encoding: PixelEncoding) -> Result<Self> {
+        if (width * height) as usize != pixels.len() {
+            bail!("Image dimensions does not match pixels length: {}", pixels.len());
+        }
+
+        Ok(Self { width, height, pixels, encoding })
+    }
+
+    // HDR and EXR files hold linear values, PNG and other 8-bit formats are kept sRGB encoded in [0, 1]
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let extension = path.extension().and_then(|extension| extension.to_str()).map(|extension| extension.to_ascii_lowercase());
+
+        match extension.as_deref() {
+            Some("hdr") => {
+                let decoder = HdrDecoder::new(BufReader::new(File::open(path)?))?;
+                let metadata = decoder.metadata();
+                let pixels = decoder.read_image_hdr()?.into_iter().map(|pixel| [pixel[0], pixel[1], pixel[2], 1.0]).collect();
+
+                Self::new(metadata.width, metadata.height, pixels, PixelEncoding::Linear)
+            }
+            Some("exr") => {
+                let image = ExrImage::read(path)?;
+                let pixels = image.layer_pixels("", "RGBA");
+
+                Self::new(image.data_window.width() as u32, image.data_window.height() as u32, pixels, PixelEncoding::Linear)
+            }
+            Some(_) => {
+                let image = image::open(path)?.to_rgba8();
+                let (width, height) = image.dimensions();
+                let pixels = image.pixels().map(|pixel| pixel.0.map(|value| value as f32 / 255.0)).collect();
+
+                Self::new(width, height, pixels, PixelEncoding::Srgb)
+            }
+            None => Err(anyhow!("Cannot tell the format of {} without an extension", path.display())),
+        }
+    }
+
+    pub fn linear_rgb(&self) -> Vec<[f32; 3]> {
+        let decode = |value: f32| match self.encoding {
+            PixelEncoding::Linear => value,
+            PixelEncoding::Srgb if value <= 0.04045 => value / 12.92,
+            PixelEncoding::Srgb => ((value + 0.055) / 1.055).powf(2.4),
+        };
+
+        self.pixels.iter().map(|pixel| [decode(pixel[0]), decode(pixel[1]), decode(pixel[2])]).collect()
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct ComparisonMetrics {
+    pub mse: f32,
+    pub rmse: f32,
+    pub rel_mse: f32,
+    pub ssim: f32,
+    pub mean_flip: f32,
+}
+
+pub struct Comparison {
+    pub metrics: ComparisonMetrics,
+    pub flip_map: Vec<f32>,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Comparison {
+    // The false color ramp is already display referred, so it is only encoded rather than tone mapped
+    pub fn write_flip_map(&self, path: impl AsRef<Path>) -> Result<()> {
+        let pixels = false_color(&self.flip_map);
+        ColorPipeline::new(RgbColorSpace::SRGB)
+            .encode(TransferFunction::Srgb)
+            .write(path, self.width, self.height, &pixels, OutputFormat::Png)
+    }
+}
+
+pub fn compare(reference: &ComparisonImage, test: &ComparisonImage) -> Result<Comparison> {
+    if reference.width != test.width || reference.height != test.height {
+        bail!("Cannot compare a {}x{} image against a {}x{} reference", test.width, test.height, reference.width, reference.height);
+    }
+
+    if reference.encoding != test.encoding {
+        bail!("Reference is {:?} encoded but the test image is {:?}", reference.encoding, test.encoding);
+    }
+
+    let (mse, rel_mse) = mean_squared_errors(&reference.pixels, &test.pixels);
+    let ssim = ssim(reference, test);
+    let flip_map = flip::flip(reference, test);
+    let mean_flip = flip_map.iter().sum::<f32>() / flip_map.len().max(1) as f32;
+
+    Ok(Comparison {
+        metrics: ComparisonMetrics {
+            mse,
+            rmse: mse.sqrt(),
+            rel_mse,
+            ssim,
+            mean_flip,
+        },
+        flip_map,
+        width: reference.width,
+        height: reference.height,
+    })
+}
+
+// Averaged over the RGB channels; relative MSE divides by the squared reference plus an epsilon, as pbrt's imgtool does
+pub fn mean_squared_errors(reference: &[[f32; 4]], test: &[[f32; 4]]) -> (f32, f32) {
+    let mut squared_error = 0.0_f64;
+    let mut relative_squared_error = 0.0_f64;
+
+    for (reference, test) in reference.iter().zip(test) {
+        for c in 0..3 {
+            let difference = (test[c] - reference[c]) as f64;
+            squared_error += difference * difference;
+            relative_squared_error += difference * difference / (reference[c] as f64 * reference[c] as f64 + REL_MSE_EPSILON as f64);
+        }
+    }
+
+    let count = (3 * reference.len().max(1)) as f64;
+
+    ((squared_error / count) as f32, (relative_squared_error / count) as f32)
+}
+
+// Mean structural similarity of the RGB channels with an 11x11 Gaussian window; linear images are clamped to [0, 1] first
+pub fn ssim(reference: &ComparisonImage, test: &ComparisonImage) -> f32 {
+    const C1: f32 = 0.01 * 0.01;
+    const C2: f32 = 0.03 * 0.03;
+
+    let (width, height) = (reference.width as i32, reference.height as i32);
+    let weights = (-SSIM_WINDOW_RADIUS..=SSIM_WINDOW_RADIUS)
+        .map(|offset| (-(offset * offset) as f32 / (2.0 * SSIM_SIGMA * SSIM_SIGMA)).exp())
+        .collect::<Vec<_>>();
+
+    let mut total = 0.0_f64;
+
+    for c in 0..3 {
+        let channel = |image: &ComparisonImage| image.pixels.iter().map(|pixel| pixel[c].clamp(0.0, 1.0)).collect::<Vec<_>>();
+        let (x, y) = (channel(reference), channel(test));
+
+        for py in 0..height {
+            for px in 0..width {
+                let (mut weight_sum, mut mean_x, mut mean_y, mut xx, mut yy, mut xy) = (0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+
+                for wy in -SSIM_WINDOW_RADIUS..=SSIM_WINDOW_RADIUS {
+                    for wx in -SSIM_WINDOW_RADIUS..=SSIM_WINDOW_RADIUS {
+                        let (sx, sy) = (px + wx, py + wy);
+
+                        if sx < 0 || sy < 0 || sx >= width || sy >= height {
+                            continue;
+                        }
+
+                        let weight = weights[(wx + SSIM_WINDOW_RADIUS) as usize] * weights[(wy + SSIM_WINDOW_RADIUS) as usize];
+                        let (vx, vy) = (x[(sy * width + sx) as usize], y[(sy * width + sx) as usize]);
+
+                        weight_sum += weight;
+                        mean_x += weight * vx;
+                        mean_y += weight * vy;
+                        xx += weight * vx * vx;
+                        yy += weight * vy * vy;
+                        xy += weight * vx * vy;
+                    }
+                }
+
+                let (mean_x, mean_y) = (mean_x / weight_sum, mean_y / weight_sum);
+                let variance_x = xx / weight_sum - mean_x * mean_x;
+                let variance_y = yy / weight_sum - mean_y * mean_y;
+                let covariance = xy / weight_sum - mean_x * mean_y;
+
+                total += ((2.0 * mean_x * mean_y + C1) * (2.0 * covariance + C2) / ((mean_x * mean_x + mean_y * mean_y + C1) * (variance_x + variance_y + C2))) as f64;
+            }
+        }
+    }
+
+    (total / (3 * reference.pixels.len().max(1)) as f64) as f32
+}
+
+// Magma-like ramp through five control points, the usual way of showing FLIP errors
+pub fn false_color(values: &[f32]) -> Vec<[f32; 4]> {
+    const RAMP: [[f32; 3]; 5] = [
+        [0.001462, 0.000466, 0.013866],
+        [0.316654, 0.07169, 0.48538],
+        [0.716387, 0.214982, 0.47529],
+        [0.9867, 0.535582, 0.38221],
+        [0.987053, 0.991438, 0.749504],
+    ];
+
+    values
+        .iter()
+        .map(|&value| {
+            let position = if value.is_finite() { value.clamp(0.0, 1.0) } else { 1.0 } * (RAMP.len() - 1) as f32;
+            let i = (position as usize).min(RAMP.len() - 2);
+            let t = position - i as f32;
+
+            let [r, g, b] = [0, 1, 2].map(|c| RAMP[i][c] * (1.0 - t) + RAMP[i + 1][c] * t);
+            [r, g, b, 1.0]
+        })
+        .collect()
+}
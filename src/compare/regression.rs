@@ -0,0 +1,190 @@
+use crate::compare;
+use crate::compare::{ComparisonImage, ComparisonMetrics};
+use anyhow::{bail, Result};
+use log::{info, warn};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Set to copy missing or failing outputs over their references instead of reporting them
+pub const UPDATE_REFERENCES_VAR: &str = "ATODIUM_UPDATE_REFERENCES";
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Tolerances {
+    pub max_mse: f32,
+    pub max_rel_mse: f32,
+    pub min_ssim: f32,
+    pub max_mean_flip: f32,
+}
+
+impl Default for Tolerances {
+    fn default() -> Self {
+        Self {
+            max_mse: 1e-3,
+            max_rel_mse: 1e-2,
+            min_ssim: 0.95,
+            max_mean_flip: 0.05,
+        }
+    }
+}
+
+impl Tolerances {
+    pub fn max_mse(mut self, max_mse: f32) -> Self {
+        self.max_mse = max_mse;
+        self
+    }
+
+    pub fn max_rel_mse(mut self, max_rel_mse: f32) -> Self {
+        self.max_rel_mse = max_rel_mse;
+        self
+    }
+
+    pub fn min_ssim(mut self, min_ssim: f32) -> Self {
+        self.min_ssim = min_ssim;
+        self
+    }
+
+    pub fn max_mean_flip(mut self, max_mean_flip: f32) -> Self {
+        self.max_mean_flip = max_mean_flip;
+        self
+    }
+
+    pub fn failures(&self, metrics: &ComparisonMetrics) -> Vec<String> {
+        let mut failures = Vec::new();
+
+        if metrics.mse.is_nan() || metrics.mse > self.max_mse {
+            failures.push(format!("MSE {} exceeds {}", metrics.mse, self.max_mse));
+        }
+
+        if metrics.rel_mse.is_nan() || metrics.rel_mse > self.max_rel_mse {
+            failures.push(format!("relMSE {} exceeds {}", metrics.rel_mse, self.max_rel_mse));
+        }
+
+        if metrics.ssim.is_nan() || metrics.ssim < self.min_ssim {
+            failures.push(format!("SSIM {} is below {}", metrics.ssim, self.min_ssim));
+        }
+
+        if metrics.mean_flip.is_nan() || metrics.mean_flip > self.max_mean_flip {
+            failures.push(format!("mean FLIP {} exceeds {}", metrics.mean_flip, self.max_mean_flip));
+        }
+
+        failures
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RegressionCase {
+    pub name: String,
+    pub output: PathBuf,
+    pub reference: PathBuf,
+    pub tolerances: Tolerances,
+}
+
+#[derive(Clone, Debug)]
+pub struct RegressionReport {
+    pub name: String,
+    pub metrics: Option<ComparisonMetrics>,
+    pub diff_image: Option<PathBuf>,
+    pub failures: Vec<String>,
+}
+
+impl RegressionReport {
+    pub fn passed(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+impl RegressionCase {
+    pub fn new(name: impl Into<String>, output: impl AsRef<Path>, reference: impl AsRef<Path>) -> Self {
+        Self {
+            name: name.into(),
+            output: output.as_ref().to_path_buf(),
+            reference: reference.as_ref().to_path_buf(),
+            tolerances: Tolerances::default(),
+        }
+    }
+
+    pub fn tolerances(mut self, tolerances: Tolerances) -> Self {
+        self.tolerances = tolerances;
+        self
+    }
+
+    // The FLIP map is written next to the output as `<output>_flip.png`
+    pub fn diff_image_path(&self) -> PathBuf {
+        let stem = self.output.file_stem().and_then(|stem| stem.to_str()).unwrap_or("output");
+        self.output.with_file_name(format!("{}_flip.png", stem))
+    }
+
+    pub fn run(&self) -> Result<RegressionReport> {
+        let update_references = env::var_os(UPDATE_REFERENCES_VAR).is_some();
+
+        if !self.reference.exists() {
+            if !update_references {
+                bail!("Reference {} of {} is missing, set {} to create it", self.reference.display(), self.name, UPDATE_REFERENCES_VAR);
+            }
+
+            self.update_reference()?;
+
+            return Ok(RegressionReport {
+                name: self.name.clone(),
+                metrics: None,
+                diff_image: None,
+                failures: Vec::new(),
+            });
+        }
+
+        let comparison = compare::compare(&ComparisonImage::load(&self.reference)?, &ComparisonImage::load(&self.output)?)?;
+
+        let diff_image = self.diff_image_path();
+        comparison.write_flip_map(&diff_image)?;
+
+        let metrics = comparison.metrics;
+        let mut failures = self.tolerances.failures(&metrics);
+
+        info!(
+            "{}: MSE {:.6}, RMSE {:.6}, relMSE {:.6}, SSIM {:.4}, mean FLIP {:.4}",
+            self.name, metrics.mse, metrics.rmse, metrics.rel_mse, metrics.ssim, metrics.mean_flip
+        );
+
+        if !failures.is_empty() && update_references {
+            warn!("{} differs from its reference, updating it: {}", self.name, failures.join(", "));
+            self.update_reference()?;
+            failures.clear();
+        }
+
+        Ok(RegressionReport {
+            name: self.name.clone(),
+            metrics: Some(metrics),
+            diff_image: Some(diff_image),
+            failures,
+        })
+    }
+
+    fn update_reference(&self) -> Result<()> {
+        if let Some(parent) = self.reference.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::copy(&self.output, &self.reference)?;
+        info!("Updated reference {}", self.reference.display());
+
+        Ok(())
+    }
+}
+
+// Runs every case and fails with all of the differences at once
+pub fn run_all(cases: &[RegressionCase]) -> Result<Vec<RegressionReport>> {
+    let reports = cases.iter().map(RegressionCase::run).collect::<Result<Vec<_>>>()?;
+
+    let failures = reports
+        .iter()
+        .filter(|report| !report.passed())
+        .map(|report| format!("{}: {}", report.name, report.failures.join(", ")))
+        .collect::<Vec<_>>();
+
+    if !failures.is_empty() {
+        bail!("Regression failures:\n{}", failures.join("\n"));
+    }
+
+    Ok(reports)
+}
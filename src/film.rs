@@ -0,0 +1,289 @@
+use crate::memory::render_image::{ImageAllocator, ImageDesc, RenderImage};
+use crate::output::exr::{ExrImage, ExrPixelType, RenderMetadata};
+use crate::render::descriptor_set::{DescriptorId, WrappedDescriptorSet};
+use crate::util;
+use crate::util::OutputFormat;
+use anyhow::Result;
+use ash::vk::{Format, ImageLayout, ImageUsageFlags, MemoryPropertyFlags};
+use optics_common::film::from_splat_fixed_point;
+use std::path::Path;
+
+fn allocate_film_image(image_allocator: &ImageAllocator, width: u32, height: u32, format: Format) -> Result<RenderImage> {
+    let mut image = image_allocator.allocate(
+        ImageDesc::default_2d(width, height, format, ImageUsageFlags::STORAGE | ImageUsageFlags::TRANSFER_SRC | ImageUsageFlags::TRANSFER_DST),
+        MemoryPropertyFlags::DEVICE_LOCAL,
+    )?;
+
+    image_allocator.transition_layout(&mut image, ImageLayout::GENERAL)?;
+    image_allocator.clear_image(&image)?;
+
+    Ok(image)
+}
+
+// Leaves the image in the general layout so the shaders can keep accumulating into it
+fn acquire_film_image(image_allocator: &ImageAllocator, image: &mut RenderImage) -> Result<Vec<[f32; 4]>> {
+    let pixels = image_allocator.acquire_pixels(image, None)?;
+    image_allocator.transition_layout(image, ImageLayout::GENERAL)?;
+
+    Ok(pixels)
+}
+
+// The splat image holds fixed-point sums, read as integers so values past 2^24 keep every bit
+fn acquire_splat_image(image_allocator: &ImageAllocator, image: &mut RenderImage) -> Result<Vec<i32>> {
+    let pixels = image_allocator.acquire_raw_pixels::<i32>(image, None)?;
+    image_allocator.transition_layout(image, ImageLayout::GENERAL)?;
+
+    Ok(pixels)
+}
+
+fn write_film_descriptors(descriptor: &WrappedDescriptorSet, first_binding: u32, images: &[&RenderImage]) -> Result<()> {
+    for (index, image) in images.iter().enumerate() {
+        descriptor.write_storage_image(DescriptorId::Index(first_binding + index as u32), image)?;
+    }
+
+    Ok(())
+}
+
+// The accumulated state of an `RgbFilmImages` for checkpoints, the splat image is left out since the volumetric kernels never splat
+#[derive(Clone, Debug)]
+pub struct RgbFilmPixels {
+    pub rgb_weight_sum: Vec<[f32; 4]>,
+    pub variance_mean: Vec<[f32; 4]>,
+    pub variance_m2: Vec<[f32; 4]>,
+}
+
+// Images of the shader `RgbFilm`, bound in the order of `RgbFilm::new`
+pub struct RgbFilmImages {
+    pub width: u32,
+    pub height: u32,
+    pub rgb_weight_sum: RenderImage,
+    pub rgb_splat: RenderImage,
+    pub variance_mean: RenderImage,
+    pub variance_m2: RenderImage,
+}
+
+impl RgbFilmImages {
+    pub const BINDING_COUNT: u32 = 4;
+
+    pub fn new(image_allocator: &ImageAllocator, width: u32, height: u32) -> Result<Self> {
+        Ok(Self {
+            width,
+            height,
+            rgb_weight_sum: allocate_film_image(image_allocator, width, height, Format::R32G32B32A32_SFLOAT)?,
+            rgb_splat: allocate_film_image(image_allocator, 3 * width, height, Format::R32_SINT)?,
+            variance_mean: allocate_film_image(image_allocator, width, height, Format::R32G32B32A32_SFLOAT)?,
+            variance_m2: allocate_film_image(image_allocator, width, height, Format::R32G32B32A32_SFLOAT)?,
+        })
+    }
+
+    pub fn write_descriptors(&self, descriptor: &WrappedDescriptorSet, first_binding: u32) -> Result<()> {
+        write_film_descriptors(descriptor, first_binding, &[&self.rgb_weight_sum, &self.rgb_splat, &self.variance_mean, &self.variance_m2])
+    }
+
+    // Weighted average of the samples plus the splats, the host side of `RgbFilm::get_pixel_rgb`
+    pub fn read_rgb(&mut self, image_allocator: &ImageAllocator, splat_scale: f32, filter_integral: f32) -> Result<Vec<[f32; 4]>> {
+        let rgb_weight_sum = acquire_film_image(image_allocator, &mut self.rgb_weight_sum)?;
+        let rgb_splat = acquire_splat_image(image_allocator, &mut self.rgb_splat)?;
+
+        let pixels = rgb_weight_sum
+            .iter()
+            .enumerate()
+            .map(|(i, &[r, g, b, weight_sum])| {
+                let mut rgb = if weight_sum != 0.0 { [r / weight_sum, g / weight_sum, b / weight_sum] } else { [0.0; 3] };
+
+                if filter_integral != 0.0 {
+                    for (c, value) in rgb.iter_mut().enumerate() {
+                        *value += splat_scale / filter_integral * from_splat_fixed_point(rgb_splat[3 * i + c]);
+                    }
+                }
+
+                [rgb[0], rgb[1], rgb[2], 1.0]
+            })
+            .collect();
+
+        Ok(pixels)
+    }
+
+    // Samples taken by every pixel, kept in the alpha channel of the variance mean
+    pub fn read_sample_counts(&mut self, image_allocator: &ImageAllocator) -> Result<Vec<f32>> {
+        let variance_mean = acquire_film_image(image_allocator, &mut self.variance_mean)?;
+
+        Ok(variance_mean.iter().map(|pixel| pixel[3]).collect())
+    }
+
+    pub fn download(&mut self, image_allocator: &ImageAllocator) -> Result<RgbFilmPixels> {
+        Ok(RgbFilmPixels {
+            rgb_weight_sum: acquire_film_image(image_allocator, &mut self.rgb_weight_sum)?,
+            variance_mean: acquire_film_image(image_allocator, &mut self.variance_mean)?,
+            variance_m2: acquire_film_image(image_allocator, &mut self.variance_m2)?,
+        })
+    }
+
+    pub fn upload(&mut self, image_allocator: &ImageAllocator, pixels: &RgbFilmPixels) -> Result<()> {
+        image_allocator.upload_pixels(&mut self.rgb_weight_sum, &pixels.rgb_weight_sum)?;
+        image_allocator.upload_pixels(&mut self.variance_mean, &pixels.variance_mean)?;
+        image_allocator.upload_pixels(&mut self.variance_m2, &pixels.variance_m2)
+    }
+
+    // Variance of the samples of every pixel, black until a pixel has two samples
+    pub fn read_variance(&mut self, image_allocator: &ImageAllocator) -> Result<Vec<[f32; 4]>> {
+        let variance_mean = acquire_film_image(image_allocator, &mut self.variance_mean)?;
+        let variance_m2 = acquire_film_image(image_allocator, &mut self.variance_m2)?;
+
+        let pixels = variance_mean
+            .iter()
+            .zip(&variance_m2)
+            .map(|(mean, &[m2_r, m2_g, m2_b, _])| {
+                let count = mean[3];
+
+                if count > 1.0 {
+                    [m2_r / (count - 1.0), m2_g / (count - 1.0), m2_b / (count - 1.0), 1.0]
+                } else {
+                    [0.0, 0.0, 0.0, 1.0]
+                }
+            })
+            .collect();
+
+        Ok(pixels)
+    }
+}
+
+// Images of the shader `GBufferFilm`, the `RgbFilm` images come first and the surface layers follow in the order of `GBufferFilm::new`
+pub struct GBufferImages {
+    pub film: RgbFilmImages,
+    pub albedo: RenderImage,
+    pub position_depth: RenderImage,
+    pub normal: RenderImage,
+    pub uv_depth_derivatives: RenderImage,
+}
+
+impl GBufferImages {
+    pub const BINDING_COUNT: u32 = RgbFilmImages::BINDING_COUNT + 4;
+
+    pub fn new(image_allocator: &ImageAllocator, width: u32, height: u32) -> Result<Self> {
+        Ok(Self {
+            film: RgbFilmImages::new(image_allocator, width, height)?,
+            albedo: allocate_film_image(image_allocator, width, height, Format::R32G32B32A32_SFLOAT)?,
+            position_depth: allocate_film_image(image_allocator, width, height, Format::R32G32B32A32_SFLOAT)?,
+            normal: allocate_film_image(image_allocator, width, height, Format::R32G32B32A32_SFLOAT)?,
+            uv_depth_derivatives: allocate_film_image(image_allocator, width, height, Format::R32G32B32A32_SFLOAT)?,
+        })
+    }
+
+    pub fn write_descriptors(&self, descriptor: &WrappedDescriptorSet, first_binding: u32) -> Result<()> {
+        self.film.write_descriptors(descriptor, first_binding)?;
+        write_film_descriptors(
+            descriptor,
+            first_binding + RgbFilmImages::BINDING_COUNT,
+            &[&self.albedo, &self.position_depth, &self.normal, &self.uv_depth_derivatives],
+        )
+    }
+
+    pub fn read_layers(&mut self, image_allocator: &ImageAllocator, splat_scale: f32, filter_integral: f32) -> Result<GBufferLayers> {
+        let (width, height) = (self.film.width, self.film.height);
+        let mut layers = GBufferLayers::new(width, height);
+
+        layers.beauty = self.film.read_rgb(image_allocator, splat_scale, filter_integral)?;
+        layers.variance = self.film.read_variance(image_allocator)?;
+
+        let albedo_sum = acquire_film_image(image_allocator, &mut self.albedo)?;
+        let position_depth_sum = acquire_film_image(image_allocator, &mut self.position_depth)?;
+        let normal_sum = acquire_film_image(image_allocator, &mut self.normal)?;
+        let uv_depth_derivatives_sum = acquire_film_image(image_allocator, &mut self.uv_depth_derivatives)?;
+
+        for i in 0..(width * height) as usize {
+            let surface_weight = normal_sum[i][3];
+
+            if surface_weight != 0.0 {
+                let normalize = |value: f32| value / surface_weight;
+
+                let [x, y, z, depth] = position_depth_sum[i].map(normalize);
+                let [u, v, dzdx, dzdy] = uv_depth_derivatives_sum[i].map(normalize);
+                let [nx, ny, nz, _] = normal_sum[i].map(normalize);
+                let [ar, ag, ab, _] = albedo_sum[i].map(normalize);
+
+                layers.albedo[i] = [ar, ag, ab, 1.0];
+                layers.normal[i] = [nx, ny, nz, 1.0];
+                layers.position[i] = [x, y, z, 1.0];
+                layers.depth[i] = [depth, depth, depth, 1.0];
+                layers.uv[i] = [u, v, 0.0, 1.0];
+                layers.depth_derivatives[i] = [dzdx, dzdy, 0.0, 1.0];
+            }
+        }
+
+        Ok(layers)
+    }
+}
+
+pub struct GBufferLayers {
+    pub width: u32,
+    pub height: u32,
+    pub beauty: Vec<[f32; 4]>,
+    pub albedo: Vec<[f32; 4]>,
+    pub normal: Vec<[f32; 4]>,
+    pub position: Vec<[f32; 4]>,
+    pub depth: Vec<[f32; 4]>,
+    pub uv: Vec<[f32; 4]>,
+    pub depth_derivatives: Vec<[f32; 4]>,
+    pub variance: Vec<[f32; 4]>,
+}
+
+impl GBufferLayers {
+    pub fn new(width: u32, height: u32) -> Self {
+        let empty = vec![[0.0, 0.0, 0.0, 1.0]; (width * height) as usize];
+
+        Self {
+            width,
+            height,
+            beauty: empty.clone(),
+            albedo: empty.clone(),
+            normal: empty.clone(),
+            position: empty.clone(),
+            depth: empty.clone(),
+            uv: empty.clone(),
+            depth_derivatives: empty.clone(),
+            variance: empty,
+        }
+    }
+
+    pub fn layers(&self) -> [(&'static str, &[[f32; 4]]); 8] {
+        [
+            ("beauty", &self.beauty),
+            ("albedo", &self.albedo),
+            ("normal", &self.normal),
+            ("position", &self.position),
+            ("depth", &self.depth),
+            ("uv", &self.uv),
+            ("depth_derivatives", &self.depth_derivatives),
+            ("variance", &self.variance),
+        ]
+    }
+
+    pub fn write_hdr(&self, directory: impl AsRef<Path>, prefix: &str) -> Result<()> {
+        for (name, pixels) in self.layers() {
+            let path = directory.as_ref().join(format!("{}_{}.hdr", prefix, name));
+            util::output_image(&path, self.width, self.height, pixels, OutputFormat::Hdr)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn write_exr(&self, path: impl AsRef<Path>, metadata: Option<&RenderMetadata>) -> Result<()> {
+        let mut image = ExrImage::new(self.width, self.height);
+
+        image.add_layer("", "RGBA", ExrPixelType::Float, &self.beauty)?;
+        image.add_layer("albedo", "RGB", ExrPixelType::Half, &self.albedo)?;
+        image.add_layer("normal", "XYZ", ExrPixelType::Half, &self.normal)?;
+        image.add_layer("position", "XYZ", ExrPixelType::Float, &self.position)?;
+        image.add_layer("depth", "Z", ExrPixelType::Float, &self.depth)?;
+        image.add_layer("uv", "UV", ExrPixelType::Float, &self.uv)?;
+        image.add_layer("depth_derivatives", "XY", ExrPixelType::Float, &self.depth_derivatives)?;
+        image.add_layer("variance", "RGB", ExrPixelType::Float, &self.variance)?;
+
+        if let Some(metadata) = metadata {
+            image.set_render_metadata(metadata);
+        }
+
+        image.write(path)
+    }
+}
@@ -0,0 +1,191 @@
+use crate::camera::filter::RenderFilter;
+use crate::compare;
+use glam::UVec2;
+use std::time::Duration;
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct VolumetricParameters {
+    pub resolution: UVec2,
+    pub sample_count: u32,
+    pub max_depth: u32,
+    pub camera_medium: u32,
+    pub time_slice: u32,
+    pub time_slice_count: u32,
+    pub time_min: f32,
+    pub time_max: f32,
+    // Half-open bounds of the film, the crop window of the render
+    pub pixel_bounds_min: UVec2,
+    pub pixel_bounds_max: UVec2,
+    // The dispatch covers this tile, which may reach past the film bounds by the filter footprint
+    pub tile_offset: UVec2,
+    pub tile_size: UVec2,
+    pub filter: RenderFilter,
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct AdaptiveParameters {
+    pub pass_index: u32,
+    pub first_sample_index: u32,
+    pub min_sample_count: u32,
+    pub max_sample_count: u32,
+    pub relative_error_threshold: f32,
+    pub volumetric: VolumetricParameters,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SampleBudget {
+    SamplesPerPixel(u32),
+    Time(Duration),
+}
+
+// Pixels keep taking `samples_per_pass` samples each pass until the relative standard error of their mean drops below the threshold
+#[derive(Copy, Clone, Debug)]
+pub struct AdaptiveSampling {
+    pub samples_per_pass: u32,
+    pub min_sample_count: u32,
+    pub relative_error_threshold: f32,
+    pub budget: SampleBudget,
+}
+
+impl Default for AdaptiveSampling {
+    fn default() -> Self {
+        Self {
+            samples_per_pass: 16,
+            min_sample_count: 64,
+            relative_error_threshold: 0.02,
+            budget: SampleBudget::SamplesPerPixel(1024),
+        }
+    }
+}
+
+impl AdaptiveSampling {
+    pub fn samples_per_pass(mut self, samples_per_pass: u32) -> Self {
+        self.samples_per_pass = samples_per_pass.max(1);
+        self
+    }
+
+    pub fn min_sample_count(mut self, min_sample_count: u32) -> Self {
+        self.min_sample_count = min_sample_count.max(2);
+        self
+    }
+
+    pub fn relative_error_threshold(mut self, relative_error_threshold: f32) -> Self {
+        self.relative_error_threshold = relative_error_threshold.max(0.0);
+        self
+    }
+
+    pub fn budget(mut self, budget: SampleBudget) -> Self {
+        self.budget = budget;
+        self
+    }
+
+    // A time budget leaves the sample count of a pixel unbounded
+    pub fn max_sample_count(&self) -> u32 {
+        match self.budget {
+            SampleBudget::SamplesPerPixel(sample_count) => sample_count.max(self.min_sample_count),
+            SampleBudget::Time(_) => u32::MAX,
+        }
+    }
+
+    pub fn has_budget(&self, pass_index: u32, elapsed: Duration) -> bool {
+        match self.budget {
+            SampleBudget::SamplesPerPixel(_) => pass_index.saturating_mul(self.samples_per_pass) < self.max_sample_count(),
+            SampleBudget::Time(time) => pass_index == 0 || elapsed < time,
+        }
+    }
+
+    pub fn parameters(&self, pass_index: u32, volumetric: VolumetricParameters) -> AdaptiveParameters {
+        AdaptiveParameters {
+            pass_index,
+            first_sample_index: pass_index.saturating_mul(self.samples_per_pass),
+            min_sample_count: self.min_sample_count,
+            max_sample_count: self.max_sample_count(),
+            relative_error_threshold: self.relative_error_threshold,
+            volumetric: VolumetricParameters {
+                sample_count: self.samples_per_pass,
+                ..volumetric
+            },
+        }
+    }
+}
+
+// Which kernel of `integrator::volumetric` renders the image and with how many samples per pixel
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum VolumetricQuality {
+    // Few samples from the blue noise PMJ02BN tables, which leave less visible noise than Sobol at low sample counts
+    Preview,
+    Final,
+}
+
+impl VolumetricQuality {
+    pub fn sample_count(&self) -> u32 {
+        match self {
+            VolumetricQuality::Preview => 16,
+            VolumetricQuality::Final => 256,
+        }
+    }
+
+    pub fn entry_point(&self) -> &'static str {
+        match self {
+            VolumetricQuality::Preview => "integrator::volumetric::preview_cs",
+            VolumetricQuality::Final => "integrator::volumetric::main_cs",
+        }
+    }
+
+    pub fn output_name(&self) -> &'static str {
+        match self {
+            VolumetricQuality::Preview => "cornell_volumetric_preview",
+            VolumetricQuality::Final => "cornell_volumetric",
+        }
+    }
+}
+
+// Motion blur renders the shutter interval as a sequence of slices, each with the scene frozen at the slice center and its own
+// acceleration structure. Samples still pick a time inside their slice for the camera, but objects move in discrete steps,
+// so fast motion shows up to `time_slice_count` ghost copies instead of a continuous streak. More slices approach the exact
+// result at the cost of one TLAS rebuild and one dispatch per slice
+#[derive(Copy, Clone, Debug)]
+pub struct MotionBlur {
+    pub time_slice_count: u32,
+}
+
+impl Default for MotionBlur {
+    fn default() -> Self {
+        Self { time_slice_count: 8 }
+    }
+}
+
+impl MotionBlur {
+    pub fn time_slice_count(mut self, time_slice_count: u32) -> Self {
+        self.time_slice_count = time_slice_count.max(1);
+        self
+    }
+
+    // Static scenes and instant shutters need a single slice
+    pub fn slice_count(&self, is_animated: bool, shutter_open: f32, shutter_close: f32) -> u32 {
+        if is_animated && shutter_close > shutter_open {
+            self.time_slice_count
+        } else {
+            1
+        }
+    }
+
+    pub fn slice_range(slice: u32, slice_count: u32, shutter_open: f32, shutter_close: f32) -> (f32, f32) {
+        let shutter_length = shutter_close - shutter_open;
+
+        (
+            shutter_open + shutter_length * slice as f32 / slice_count as f32,
+            shutter_open + shutter_length * (slice + 1) as f32 / slice_count as f32,
+        )
+    }
+}
+
+// Takes the counts of `RgbFilmImages::read_sample_counts`, the map is scaled to the largest one
+pub fn sample_count_heatmap(sample_counts: &[f32]) -> Vec<[f32; 4]> {
+    let max_count = sample_counts.iter().copied().fold(1.0, f32::max);
+    let normalized = sample_counts.iter().map(|count| count / max_count).collect::<Vec<_>>();
+
+    compare::false_color(&normalized)
+}
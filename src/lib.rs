@@ -1,23 +1,60 @@
+use crate::camera::calibration::{self, ColorCheckerMeasurement, IlluminantSpectrum, SensorCalibration};
+use crate::camera::filter::FilmFilterDesc;
+use crate::camera::lens::LensSystem;
+use crate::camera::sensor::{self, ExposureSettings};
+use crate::compare::regression;
+use crate::compare::regression::RegressionCase;
+use crate::film::{GBufferImages, RgbFilmImages};
+use crate::integrator::{AdaptiveParameters, AdaptiveSampling, MotionBlur, VolumetricParameters, VolumetricQuality};
 use crate::memory::render_image::ImageDesc;
+use crate::model::camera::{CameraDesc, CameraProjection, SphericalMapping};
+use crate::model::light::Light;
+use crate::model::medium::RenderMedium;
+use crate::model::mesh::{MeshBuffer, RenderMaterial, RenderMesh};
+use crate::model::RenderModel;
+use crate::output::color_management::{ColorPipeline, TransferFunction};
+use crate::output::color_space::RgbColorSpace;
+use crate::output::display::{DisplayTransform, Exposure, ToneMapOperator};
+use crate::output::hdr::{self, HdrBitDepth, HdrDisplayOutput};
+use crate::output::post::{PostParameters, PostProcess, Vignetting};
+use crate::progressive::{Checkpoint, ProgressiveRender, ProgressiveSettings};
+use crate::reference::subsurface::{SlabParameters, SlabWalkParameters};
 use crate::render::descriptor_set::{DescriptorId, WrappedDescriptorSet};
 use crate::render::pipeline::{PipelineDesc, WrappedPipeline};
 use crate::render::shader_builder;
 use crate::rt::{blas, tlas};
+use crate::sampling::{self, DistributionBuffer};
+use crate::tile::{CropWindow, PixelBounds, TileSchedule};
 use crate::util::OutputFormat;
-use anyhow::Result;
+use anyhow::{anyhow, bail, Result};
 use ash::vk;
 use ash::vk::{AccessFlags, BufferUsageFlags, DependencyFlags, DeviceSize, Format, ImageLayout, ImageTiling, ImageUsageFlags, MemoryBarrier, MemoryPropertyFlags, PipelineStageFlags};
-use glam::Vec4;
+use glam::{Mat3, Mat4, UVec2, Vec3, Vec4};
 use gpu_allocator::MemoryLocation;
 use image::codecs::hdr::HdrEncoder;
-use log::{error, info};
+use log::{error, info, warn};
+use optics_common::calibration::{SWATCH_LAMBDA_STEP, SWATCH_REFLECTANCES, SWATCH_SAMPLE_COUNT};
+use spectrum_table::Gamut;
 use std::fs::File;
-use std::{mem, slice};
+use std::path::Path;
+use std::time::{Duration, Instant};
+use std::{env, fs, mem, slice};
 
+pub mod camera;
+pub mod compare;
+pub mod film;
+pub mod integrator;
 pub mod memory;
 pub mod model;
+pub mod output;
+pub mod pmj02bn_table;
+pub mod progressive;
+pub mod reference;
 pub mod render;
 pub mod rt;
+pub mod sampling;
+pub mod spectrum;
+pub mod tile;
 pub mod util;
 
 pub fn test_hello_world() -> Result<()> {
@@ -106,16 +143,23 @@ pub fn test_cornell() -> Result<()> {
     let pipeline_desc = PipelineDesc::default().compute_name("test::cornell::main_cs".into());
     let pipeline = WrappedPipeline::new(device.clone(), &allocator, pipeline_desc, &shaders, None)?;
 
-    let buffer = allocator.allocate(800 * 600 * 4 * 4, BufferUsageFlags::STORAGE_BUFFER, MemoryLocation::GpuToCpu)?;
+    let render_width = 800;
+    let render_height = 600;
+
+    let buffer = allocator.allocate((render_width * render_height * 4 * 4) as DeviceSize, BufferUsageFlags::STORAGE_BUFFER, MemoryLocation::GpuToCpu)?;
+
+    let mut distributions = DistributionBuffer::default();
+    let camera_buffer = model.write_camera_to_buffer(&allocator, 0, UVec2::new(render_width, render_height), &mut distributions)?;
+    let distributions_buffer = distributions.write_to_buffer(&allocator)?;
 
     let descriptor = WrappedDescriptorSet::new(device.clone(), &pipeline, 0)?;
     descriptor.write_storage_buffer(DescriptorId::Index(0), &buffer)?;
     descriptor.write_tlas(DescriptorId::Index(1), &tlas)?;
     descriptor.write_storage_buffer(DescriptorId::Index(2), &vertices_buffer)?;
     descriptor.write_storage_buffer(DescriptorId::Index(3), &(model.meshes[0].0.mesh_buffer.index_buffer))?;
+    descriptor.write_storage_buffer(DescriptorId::Index(4), &camera_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(5), &distributions_buffer)?;
 
-    let render_width = 800;
-    let render_height = 600;
     let workgroup_width = 16;
     let workgroup_height = 8;
 
@@ -152,6 +196,28 @@ pub fn test_cornell() -> Result<()> {
     Ok(())
 }
 
+// Renders the Cornell box and compares it against `references/cornell.png`, see `references/README.md` for seeding it.
+// Skipped while no reference has been seeded, unless the run is the one seeding it
+pub fn test_cornell_regression() -> Result<()> {
+    let case = RegressionCase::new("cornell", util::lib_root().join("output").join("cornell.png"), util::lib_root().join("references").join("cornell.png"));
+
+    if !case.reference.exists() && env::var_os(regression::UPDATE_REFERENCES_VAR).is_none() {
+        warn!(
+            "Skipping {}, reference {} is missing, set {} to create it",
+            case.name,
+            case.reference.display(),
+            regression::UPDATE_REFERENCES_VAR
+        );
+        return Ok(());
+    }
+
+    test_cornell()?;
+
+    regression::run_all(slice::from_ref(&case))?;
+
+    Ok(())
+}
+
 pub fn test_rt_pipeline() -> Result<()> {
     let (device, allocator, image_allocator, _) = render::init_vulkan_context(true, "test_hello_world", vk::make_api_version(0, 1, 1, 1))?;
     let shaders = shader_builder::compile_spirv_shaders();
@@ -182,6 +248,10 @@ pub fn test_rt_pipeline() -> Result<()> {
     let render_width = 800;
     let render_height = 600;
 
+    let mut distributions = DistributionBuffer::default();
+    let camera_buffer = model.write_camera_to_buffer(&allocator, 0, UVec2::new(render_width, render_height), &mut distributions)?;
+    let distributions_buffer = distributions.write_to_buffer(&allocator)?;
+
     let mut shader_image = image_allocator.allocate(
         ImageDesc::default_2d(render_width, render_height, Format::R32G32B32A32_SFLOAT, ImageUsageFlags::STORAGE | ImageUsageFlags::TRANSFER_SRC),
         MemoryPropertyFlags::DEVICE_LOCAL,
@@ -208,6 +278,8 @@ pub fn test_rt_pipeline() -> Result<()> {
     descriptor.write_storage_buffer(DescriptorId::Index(3), &indices_buffer)?;
     descriptor.write_storage_buffer(DescriptorId::Index(4), &instance_metadata_buffer)?;
     descriptor.write_storage_buffer(DescriptorId::Index(5), &materials_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(6), &camera_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(7), &distributions_buffer)?;
 
     device.single_time_command(|cmd_buf| {
         pipeline.bind(cmd_buf);
@@ -225,3 +297,939 @@ pub fn test_rt_pipeline() -> Result<()> {
 
     Ok(())
 }
+
+pub fn test_volumetric() -> Result<()> {
+    test_volumetric_region(CropWindow::default(), TileSchedule::default(), MotionBlur::default(), VolumetricQuality::Final)
+}
+
+pub fn test_volumetric_preview() -> Result<()> {
+    test_volumetric_region(CropWindow::default(), TileSchedule::default(), MotionBlur::default(), VolumetricQuality::Preview)
+}
+
+// Renders the crop window tile by tile; a partial crop is written as its own image the size of the region
+pub fn test_volumetric_region(crop_window: CropWindow, tile_schedule: TileSchedule, motion_blur: MotionBlur, quality: VolumetricQuality) -> Result<()> {
+    render_volumetric_region(None, UVec2::new(800, 600), quality.output_name(), crop_window, tile_schedule, motion_blur, quality)
+}
+
+// Bakes the Cornell box as seen from the middle of the room into a panorama of the whole sphere
+pub fn test_environment_probe(mapping: SphericalMapping) -> Result<()> {
+    let (resolution, output_name) = match mapping {
+        SphericalMapping::EquiRectangular => (UVec2::new(1024, 512), "environment_probe_equirectangular"),
+        SphericalMapping::EqualArea => (UVec2::new(512, 512), "environment_probe_equal_area"),
+    };
+
+    let camera = CameraDesc::new(Mat4::from_translation(Vec3::new(0.0, 1.0, 0.0)), CameraProjection::Spherical { mapping });
+
+    render_volumetric_region(
+        Some(camera),
+        resolution,
+        output_name,
+        CropWindow::default(),
+        TileSchedule::default(),
+        MotionBlur::default(),
+        VolumetricQuality::Final,
+    )
+}
+
+// Renders the scene through the double Gauss prescription focused on the back wall, on a full frame sensor
+pub fn test_realistic_camera() -> Result<()> {
+    const FILM_DIAGONAL_MM: f32 = 43.27;
+    const FOCUS_DISTANCE: f32 = 7.0;
+
+    let resolution = UVec2::new(800, 600);
+    let system = LensSystem::load(util::lib_root().join("lenses").join("dgauss.50mm.dat"), None)?;
+    let lens = system.to_render_lens(resolution, FILM_DIAGONAL_MM, FOCUS_DISTANCE)?;
+
+    let camera = CameraDesc::new(Mat4::from_translation(Vec3::new(0.0, 1.0, 6.0)), CameraProjection::Realistic { lens });
+
+    render_volumetric_region(
+        Some(camera),
+        resolution,
+        "realistic_camera",
+        CropWindow::default(),
+        TileSchedule::default(),
+        MotionBlur::default(),
+        VolumetricQuality::Final,
+    )
+}
+
+// `camera` replaces the first camera of the scene when given
+fn render_volumetric_region(
+    camera: Option<CameraDesc>,
+    resolution: UVec2,
+    output_name: &str,
+    crop_window: CropWindow,
+    tile_schedule: TileSchedule,
+    motion_blur: MotionBlur,
+    quality: VolumetricQuality,
+) -> Result<()> {
+    let (device, allocator, image_allocator, _) = render::init_vulkan_context(true, "test_volumetric", vk::make_api_version(0, 1, 1, 1))?;
+    let shaders = shader_builder::compile_spirv_shaders();
+
+    let mut model = model::load_gltf(device.clone(), &allocator, &image_allocator, util::lib_root().join("models/cornell_color.gltf").to_str().unwrap())?;
+
+    if let Some(camera) = camera {
+        model.cameras.insert(0, camera);
+    }
+
+    let fog = model.add_medium(RenderMedium::homogeneous(Vec3::splat(0.02), Vec3::splat(0.1), 1.0, 0.6));
+    model.lights.push(Light::new(Vec3::new(0.0, 1.8, 0.0), 4.0));
+
+    info!("Render model loaded");
+
+    let vertices_buffer = model.write_vertices_to_buffer(&allocator)?;
+    let indices_buffer = model.write_indices_to_buffer(&allocator)?;
+    let materials_buffer = model.write_material_to_buffer(&allocator)?;
+    let media_buffer = model.write_media_to_buffer(&allocator)?;
+    let grid_data_buffer = model.write_grid_data_to_buffer(&allocator)?;
+    let lights_buffer = model.write_lights_to_buffer(&allocator)?;
+    let rgb_to_spectrum_table_buffer = spectrum::write_rgb_to_spectrum_table_to_buffer(&allocator, Gamut::Srgb)?;
+
+    let blas = model.build_blas(device.clone(), &allocator);
+
+    info!("Bottom-level acceleration structures created");
+
+    let pipeline_desc = PipelineDesc::default().compute_name(quality.entry_point().into());
+    let pipeline = WrappedPipeline::new(device.clone(), &allocator, pipeline_desc, &shaders, None)?;
+
+    let render_width = resolution.x;
+    let render_height = resolution.y;
+    let workgroup_width = 16;
+    let workgroup_height = 8;
+
+    let camera = model.camera(0);
+    let mut distributions = DistributionBuffer::default();
+    let camera_buffer = model.write_camera_to_buffer(&allocator, 0, UVec2::new(render_width, render_height), &mut distributions)?;
+    let filter = FilmFilterDesc::default().to_render_filter(&mut distributions);
+    let distributions_buffer = distributions.write_to_buffer(&allocator)?;
+    let pmj02bn_table_buffer = match quality {
+        VolumetricQuality::Preview => Some(sampling::write_pmj02bn_table_to_buffer(&allocator)?),
+        VolumetricQuality::Final => None,
+    };
+
+    let time_slice_count = motion_blur.slice_count(!model.animation.channels.is_empty(), camera.shutter_open, camera.shutter_close);
+
+    let pixel_bounds = crop_window.pixel_bounds(UVec2::new(render_width, render_height));
+    let tiles = tile_schedule.tiles(pixel_bounds);
+
+    let mut shader_image = image_allocator.allocate(
+        ImageDesc::default_2d(
+            render_width,
+            render_height,
+            Format::R32G32B32A32_SFLOAT,
+            ImageUsageFlags::STORAGE | ImageUsageFlags::TRANSFER_SRC | ImageUsageFlags::TRANSFER_DST,
+        ),
+        MemoryPropertyFlags::DEVICE_LOCAL,
+    )?;
+
+    let mut host_image = image_allocator.allocate(
+        ImageDesc::default_2d(
+            render_width,
+            render_height,
+            Format::R32G32B32A32_SFLOAT,
+            ImageUsageFlags::SAMPLED | ImageUsageFlags::TRANSFER_SRC | ImageUsageFlags::TRANSFER_DST,
+        )
+        .tiling(ImageTiling::LINEAR),
+        MemoryPropertyFlags::HOST_VISIBLE | MemoryPropertyFlags::HOST_COHERENT | MemoryPropertyFlags::HOST_CACHED,
+    )?;
+
+    image_allocator.transition_layout(&mut shader_image, ImageLayout::GENERAL)?;
+    image_allocator.transition_layout(&mut host_image, ImageLayout::TRANSFER_DST_OPTIMAL)?;
+    image_allocator.clear_image(&shader_image)?;
+
+    let descriptor = WrappedDescriptorSet::new(device.clone(), &pipeline, 0)?;
+    let params_buffer = allocator.allocate(mem::size_of::<VolumetricParameters>() as _, BufferUsageFlags::UNIFORM_BUFFER, MemoryLocation::CpuToGpu)?;
+
+    for time_slice in 0..time_slice_count {
+        let (time_min, time_max) = MotionBlur::slice_range(time_slice, time_slice_count, camera.shutter_open, camera.shutter_close);
+        let time = Some(0.5 * (time_min + time_max));
+
+        let instance_metadata_buffer = model.write_instance_metadata_to_buffer_at(&allocator, time)?;
+        let tlas = tlas::create_tlas_at(device.clone(), &allocator, &blas, slice::from_ref(&model), time)?;
+
+        info!("Top-level acceleration structure created for time slice {} / {}", time_slice + 1, time_slice_count);
+
+        descriptor.write_uniform_buffer(DescriptorId::Index(0), &params_buffer)?;
+        descriptor.write_storage_image(DescriptorId::Index(1), &shader_image)?;
+        descriptor.write_tlas(DescriptorId::Index(2), &tlas)?;
+        descriptor.write_storage_buffer(DescriptorId::Index(3), &vertices_buffer)?;
+        descriptor.write_storage_buffer(DescriptorId::Index(4), &indices_buffer)?;
+        descriptor.write_storage_buffer(DescriptorId::Index(5), &instance_metadata_buffer)?;
+        descriptor.write_storage_buffer(DescriptorId::Index(6), &materials_buffer)?;
+        descriptor.write_storage_buffer(DescriptorId::Index(7), &media_buffer)?;
+        descriptor.write_storage_buffer(DescriptorId::Index(8), &grid_data_buffer)?;
+        descriptor.write_storage_buffer(DescriptorId::Index(9), &lights_buffer)?;
+        descriptor.write_storage_buffer(DescriptorId::Index(10), &rgb_to_spectrum_table_buffer)?;
+        descriptor.write_storage_buffer(DescriptorId::Index(11), &camera_buffer)?;
+        descriptor.write_storage_buffer(DescriptorId::Index(12), &distributions_buffer)?;
+
+        if let Some(pmj02bn_table_buffer) = &pmj02bn_table_buffer {
+            descriptor.write_storage_buffer(DescriptorId::Index(13), pmj02bn_table_buffer)?;
+        }
+
+        for tile in &tiles {
+            let params = VolumetricParameters {
+                resolution: UVec2::new(render_width, render_height),
+                sample_count: (quality.sample_count() / time_slice_count).max(1),
+                max_depth: 16,
+                camera_medium: fog,
+                time_slice,
+                time_slice_count,
+                time_min,
+                time_max,
+                pixel_bounds_min: pixel_bounds.min,
+                pixel_bounds_max: pixel_bounds.max,
+                tile_offset: tile.bounds.min,
+                tile_size: tile.bounds.size(),
+                filter,
+            };
+
+            allocator.upload_data(&params_buffer, slice::from_ref(&params))?;
+
+            let tile_size = tile.bounds.size();
+
+            device.single_time_command(|cmd_buf| unsafe {
+                pipeline.bind(cmd_buf);
+                descriptor.bind(cmd_buf, &pipeline);
+
+                device.cmd_dispatch(
+                    cmd_buf,
+                    (tile_size.x + workgroup_width - 1) / workgroup_width,
+                    (tile_size.y + workgroup_height - 1) / workgroup_height,
+                    1,
+                );
+            })?;
+        }
+
+        info!("Rendered {} tiles of time slice {} / {}", tiles.len(), time_slice + 1, time_slice_count);
+    }
+
+    info!("Volumetric integrator finished");
+
+    image_allocator.copy_image(&shader_image, &host_image, None)?;
+
+    // Lens effects act on the accumulated radiance, so they run between the integrator and the display transform of the PNG.
+    // Bloom needs the host FFT and is left out of the compute pass. A panorama has no lens, so it is left untouched, and a realistic lens
+    // already vignettes and disperses in the integrator
+    let post_process = match camera.projection {
+        CameraProjection::Spherical { .. } | CameraProjection::Realistic { .. } => PostProcess::default(),
+        _ => PostProcess::default().vignetting(Vignetting::default()).chromatic_aberration(0.002)?,
+    };
+
+    let post_pipeline = WrappedPipeline::new(device.clone(), &allocator, PipelineDesc::default().compute_name("post::main_cs".into()), &shaders, None)?;
+    let post_descriptor = WrappedDescriptorSet::new(device.clone(), &post_pipeline, 0)?;
+    let post_params_buffer = allocator.allocate(mem::size_of::<PostParameters>() as _, BufferUsageFlags::UNIFORM_BUFFER, MemoryLocation::CpuToGpu)?;
+
+    let mut post_image = image_allocator.allocate(
+        ImageDesc::default_2d(render_width, render_height, Format::R32G32B32A32_SFLOAT, ImageUsageFlags::STORAGE | ImageUsageFlags::TRANSFER_SRC),
+        MemoryPropertyFlags::DEVICE_LOCAL,
+    )?;
+
+    image_allocator.transition_layout(&mut post_image, ImageLayout::GENERAL)?;
+
+    allocator.upload_data(&post_params_buffer, slice::from_ref(&post_process.to_parameters(UVec2::new(render_width, render_height))))?;
+
+    post_descriptor.write_uniform_buffer(DescriptorId::Index(0), &post_params_buffer)?;
+    post_descriptor.write_storage_image(DescriptorId::Index(1), &shader_image)?;
+    post_descriptor.write_storage_image(DescriptorId::Index(2), &post_image)?;
+
+    device.single_time_command(|cmd_buf| unsafe {
+        post_pipeline.bind(cmd_buf);
+        post_descriptor.bind(cmd_buf, &post_pipeline);
+
+        device.cmd_dispatch(
+            cmd_buf,
+            (render_width + workgroup_width - 1) / workgroup_width,
+            (render_height + workgroup_height - 1) / workgroup_height,
+            1,
+        );
+    })?;
+
+    info!("Post processing finished");
+
+    let pixels = image_allocator.acquire_pixels(&mut host_image, None)?;
+    let post_pixels = image_allocator.acquire_pixels(&mut post_image, None)?;
+    let output = util::lib_root().join("output");
+    let display_transform = DisplayTransform::default().exposure(Exposure::auto()).operator(ToneMapOperator::AgX);
+
+    if pixel_bounds == PixelBounds::full(UVec2::new(render_width, render_height)) {
+        util::output_image(&output.join(format!("{}.hdr", output_name)), render_width, render_height, &pixels, OutputFormat::Hdr)?;
+        util::output_display_image(&output.join(format!("{}.png", output_name)), render_width, render_height, &post_pixels, &display_transform)?;
+    } else {
+        let size = pixel_bounds.size();
+        util::output_image(
+            &output.join(format!("{}_crop.hdr", output_name)),
+            size.x,
+            size.y,
+            &pixel_bounds.crop(&pixels, render_width),
+            OutputFormat::Hdr,
+        )?;
+        util::output_display_image(
+            &output.join(format!("{}_crop.png", output_name)),
+            size.x,
+            size.y,
+            &pixel_bounds.crop(&post_pixels, render_width),
+            &display_transform,
+        )?;
+    }
+
+    Ok(())
+}
+
+pub fn test_gbuffer() -> Result<()> {
+    test_gbuffer_region(CropWindow::default(), TileSchedule::default(), FilmFilterDesc::default())
+}
+
+// Renders the volumetric integrator into a G-buffer film and writes every layer for compositing and denoising.
+// The film bounds are the crop window, pixels outside of it stay empty in the full size layers. Samples are weighted by `film_filter`
+pub fn test_gbuffer_region(crop_window: CropWindow, tile_schedule: TileSchedule, film_filter: FilmFilterDesc) -> Result<()> {
+    let (device, allocator, image_allocator, _) = render::init_vulkan_context(true, "test_gbuffer", vk::make_api_version(0, 1, 1, 1))?;
+    let shaders = shader_builder::compile_spirv_shaders();
+
+    let mut model = model::load_gltf(device.clone(), &allocator, &image_allocator, util::lib_root().join("models/cornell_color.gltf").to_str().unwrap())?;
+
+    let fog = model.add_medium(RenderMedium::homogeneous(Vec3::splat(0.02), Vec3::splat(0.1), 1.0, 0.6));
+    model.lights.push(Light::new(Vec3::new(0.0, 1.8, 0.0), 4.0));
+
+    info!("Render model loaded");
+
+    let vertices_buffer = model.write_vertices_to_buffer(&allocator)?;
+    let indices_buffer = model.write_indices_to_buffer(&allocator)?;
+    let materials_buffer = model.write_material_to_buffer(&allocator)?;
+    let media_buffer = model.write_media_to_buffer(&allocator)?;
+    let grid_data_buffer = model.write_grid_data_to_buffer(&allocator)?;
+    let lights_buffer = model.write_lights_to_buffer(&allocator)?;
+    let rgb_to_spectrum_table_buffer = spectrum::write_rgb_to_spectrum_table_to_buffer(&allocator, Gamut::Srgb)?;
+
+    let blas = model.build_blas(device.clone(), &allocator);
+
+    info!("Bottom-level acceleration structures created");
+
+    let pipeline_desc = PipelineDesc::default().compute_name("integrator::gbuffer::main_cs".into());
+    let pipeline = WrappedPipeline::new(device.clone(), &allocator, pipeline_desc, &shaders, None)?;
+
+    let render_width = 800;
+    let render_height = 600;
+    let workgroup_width = 16;
+    let workgroup_height = 8;
+
+    let camera = model.camera(0);
+    let mut distributions = DistributionBuffer::default();
+    let camera_buffer = model.write_camera_to_buffer(&allocator, 0, UVec2::new(render_width, render_height), &mut distributions)?;
+    let filter = film_filter.to_render_filter(&mut distributions);
+    let distributions_buffer = distributions.write_to_buffer(&allocator)?;
+
+    let sensor_buffer = sensor::cie_1931_sensor(&ExposureSettings::default())?.write_to_buffer(&allocator)?;
+
+    let time = Some(0.5 * (camera.shutter_open + camera.shutter_close));
+    let instance_metadata_buffer = model.write_instance_metadata_to_buffer_at(&allocator, time)?;
+    let tlas = tlas::create_tlas_at(device.clone(), &allocator, &blas, slice::from_ref(&model), time)?;
+
+    let mut gbuffer = GBufferImages::new(&image_allocator, render_width, render_height)?;
+
+    let descriptor = WrappedDescriptorSet::new(device.clone(), &pipeline, 0)?;
+    let params_buffer = allocator.allocate(mem::size_of::<VolumetricParameters>() as _, BufferUsageFlags::UNIFORM_BUFFER, MemoryLocation::CpuToGpu)?;
+
+    let first_scene_binding = 1 + GBufferImages::BINDING_COUNT;
+
+    descriptor.write_uniform_buffer(DescriptorId::Index(0), &params_buffer)?;
+    gbuffer.write_descriptors(&descriptor, 1)?;
+    descriptor.write_tlas(DescriptorId::Index(first_scene_binding), &tlas)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(first_scene_binding + 1), &vertices_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(first_scene_binding + 2), &indices_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(first_scene_binding + 3), &instance_metadata_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(first_scene_binding + 4), &materials_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(first_scene_binding + 5), &media_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(first_scene_binding + 6), &grid_data_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(first_scene_binding + 7), &lights_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(first_scene_binding + 8), &rgb_to_spectrum_table_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(first_scene_binding + 9), &camera_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(first_scene_binding + 10), &distributions_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(first_scene_binding + 11), &sensor_buffer)?;
+
+    let resolution = UVec2::new(render_width, render_height);
+    // The G-buffer film records every sample at its own pixel, so nothing outside the crop window reaches it
+    let pixel_bounds = crop_window.pixel_bounds(resolution);
+    let tiles = tile_schedule.tiles(pixel_bounds);
+
+    for tile in &tiles {
+        let params = VolumetricParameters {
+            resolution,
+            sample_count: 256,
+            max_depth: 16,
+            camera_medium: fog,
+            time_slice: 0,
+            time_slice_count: 1,
+            time_min: camera.shutter_open,
+            time_max: camera.shutter_close.max(camera.shutter_open),
+            pixel_bounds_min: pixel_bounds.min,
+            pixel_bounds_max: pixel_bounds.max,
+            tile_offset: tile.bounds.min,
+            tile_size: tile.bounds.size(),
+            filter,
+        };
+
+        allocator.upload_data(&params_buffer, slice::from_ref(&params))?;
+
+        let tile_size = tile.bounds.size();
+
+        device.single_time_command(|cmd_buf| unsafe {
+            pipeline.bind(cmd_buf);
+            descriptor.bind(cmd_buf, &pipeline);
+
+            device.cmd_dispatch(
+                cmd_buf,
+                (tile_size.x + workgroup_width - 1) / workgroup_width,
+                (tile_size.y + workgroup_height - 1) / workgroup_height,
+                1,
+            );
+        })?;
+    }
+
+    info!("G-buffer integrator finished {} tiles", tiles.len());
+
+    let layers = gbuffer.read_layers(&image_allocator, 1.0, filter.integral)?;
+    let output = util::lib_root().join("output");
+
+    layers.write_exr(output.join("cornell_gbuffer.exr"), None)?;
+    layers.write_hdr(&output, "cornell_gbuffer")?;
+
+    Ok(())
+}
+
+pub fn test_adaptive_volumetric() -> Result<()> {
+    let (device, allocator, image_allocator, _) = render::init_vulkan_context(true, "test_adaptive_volumetric", vk::make_api_version(0, 1, 1, 1))?;
+    let shaders = shader_builder::compile_spirv_shaders();
+
+    let mut model = model::load_gltf(device.clone(), &allocator, &image_allocator, util::lib_root().join("models/cornell_color.gltf").to_str().unwrap())?;
+
+    let fog = model.add_medium(RenderMedium::homogeneous(Vec3::splat(0.02), Vec3::splat(0.1), 1.0, 0.6));
+    model.lights.push(Light::new(Vec3::new(0.0, 1.8, 0.0), 4.0));
+
+    info!("Render model loaded");
+
+    let vertices_buffer = model.write_vertices_to_buffer(&allocator)?;
+    let indices_buffer = model.write_indices_to_buffer(&allocator)?;
+    let materials_buffer = model.write_material_to_buffer(&allocator)?;
+    let media_buffer = model.write_media_to_buffer(&allocator)?;
+    let grid_data_buffer = model.write_grid_data_to_buffer(&allocator)?;
+    let lights_buffer = model.write_lights_to_buffer(&allocator)?;
+    let rgb_to_spectrum_table_buffer = spectrum::write_rgb_to_spectrum_table_to_buffer(&allocator, Gamut::Srgb)?;
+
+    let blas = model.build_blas(device.clone(), &allocator);
+
+    info!("Bottom-level acceleration structures created");
+
+    let pipeline_desc = PipelineDesc::default().compute_name("integrator::adaptive::main_cs".into());
+    let pipeline = WrappedPipeline::new(device.clone(), &allocator, pipeline_desc, &shaders, None)?;
+
+    let render_width = 800;
+    let render_height = 600;
+    let workgroup_width = 16;
+    let workgroup_height = 8;
+
+    let camera = model.camera(0);
+    let mut distributions = DistributionBuffer::default();
+    let camera_buffer = model.write_camera_to_buffer(&allocator, 0, UVec2::new(render_width, render_height), &mut distributions)?;
+    let filter = FilmFilterDesc::default().to_render_filter(&mut distributions);
+    let distributions_buffer = distributions.write_to_buffer(&allocator)?;
+
+    let time = Some(0.5 * (camera.shutter_open + camera.shutter_close));
+    let instance_metadata_buffer = model.write_instance_metadata_to_buffer_at(&allocator, time)?;
+    let tlas = tlas::create_tlas_at(device.clone(), &allocator, &blas, slice::from_ref(&model), time)?;
+
+    let sensor_buffer = sensor::cie_1931_sensor(&ExposureSettings::default())?.write_to_buffer(&allocator)?;
+    let mut film = RgbFilmImages::new(&image_allocator, render_width, render_height)?;
+
+    let descriptor = WrappedDescriptorSet::new(device.clone(), &pipeline, 0)?;
+    let params_buffer = allocator.allocate(mem::size_of::<AdaptiveParameters>() as _, BufferUsageFlags::UNIFORM_BUFFER, MemoryLocation::CpuToGpu)?;
+
+    descriptor.write_uniform_buffer(DescriptorId::Index(0), &params_buffer)?;
+    film.write_descriptors(&descriptor, 1)?;
+
+    let first_scene_binding = 1 + RgbFilmImages::BINDING_COUNT;
+    descriptor.write_tlas(DescriptorId::Index(first_scene_binding), &tlas)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(first_scene_binding + 1), &vertices_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(first_scene_binding + 2), &indices_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(first_scene_binding + 3), &instance_metadata_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(first_scene_binding + 4), &materials_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(first_scene_binding + 5), &media_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(first_scene_binding + 6), &grid_data_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(first_scene_binding + 7), &lights_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(first_scene_binding + 8), &rgb_to_spectrum_table_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(first_scene_binding + 9), &camera_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(first_scene_binding + 10), &distributions_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(first_scene_binding + 11), &sensor_buffer)?;
+
+    let adaptive = AdaptiveSampling::default();
+    let volumetric = VolumetricParameters {
+        resolution: UVec2::new(render_width, render_height),
+        sample_count: adaptive.samples_per_pass,
+        max_depth: 16,
+        camera_medium: fog,
+        time_slice: 0,
+        time_slice_count: 1,
+        time_min: camera.shutter_open,
+        time_max: camera.shutter_close.max(camera.shutter_open),
+        pixel_bounds_min: UVec2::ZERO,
+        pixel_bounds_max: UVec2::new(render_width, render_height),
+        tile_offset: UVec2::ZERO,
+        tile_size: UVec2::new(render_width, render_height),
+        filter,
+    };
+
+    let start = Instant::now();
+    let mut pass_index = 0;
+    let mut sample_counts = vec![0.0; (render_width * render_height) as usize];
+
+    while adaptive.has_budget(pass_index, start.elapsed()) {
+        allocator.upload_data(&params_buffer, slice::from_ref(&adaptive.parameters(pass_index, volumetric)))?;
+
+        device.single_time_command(|cmd_buf| unsafe {
+            pipeline.bind(cmd_buf);
+            descriptor.bind(cmd_buf, &pipeline);
+
+            device.cmd_dispatch(
+                cmd_buf,
+                (render_width + workgroup_width - 1) / workgroup_width,
+                (render_height + workgroup_height - 1) / workgroup_height,
+                1,
+            );
+        })?;
+
+        // A pixel took samples this pass when its count moved, the render has converged once none did
+        let previous = mem::replace(&mut sample_counts, film.read_sample_counts(&image_allocator)?);
+        let active_pixels = sample_counts.iter().zip(&previous).filter(|(count, previous)| count != previous).count();
+
+        info!("Adaptive pass {} sampled {} pixels", pass_index, active_pixels);
+
+        pass_index += 1;
+
+        if active_pixels == 0 {
+            break;
+        }
+    }
+
+    info!("Adaptive volumetric integrator finished after {} passes in {:.2?}", pass_index, start.elapsed());
+
+    let output = util::lib_root().join("output");
+    let rgb = film.read_rgb(&image_allocator, 1.0, filter.integral)?;
+
+    util::output_image(&output.join("cornell_adaptive.hdr"), render_width, render_height, &rgb, OutputFormat::Hdr)?;
+    ColorPipeline::new(RgbColorSpace::SRGB).encode(TransferFunction::Srgb).write(
+        output.join("cornell_adaptive_samples.png"),
+        render_width,
+        render_height,
+        &integrator::sample_count_heatmap(&sample_counts),
+        OutputFormat::Png,
+    )?;
+
+    Ok(())
+}
+
+const PROGRESSIVE_RESOLUTION: UVec2 = UVec2::new(800, 600);
+
+// Accumulates `target_sample_count` samples per pixel, resuming from and periodically writing `checkpoint_path`.
+// A `time_limit` cancels the run after the pass in flight, the checkpoint then holds everything accumulated so far
+pub fn test_progressive_volumetric(target_sample_count: u32, time_limit: Option<Duration>, checkpoint_path: &Path) -> Result<()> {
+    let pixels = render_progressive_volumetric(target_sample_count, time_limit, checkpoint_path)?;
+
+    util::output_image(
+        &util::lib_root().join("output").join("cornell_progressive.hdr"),
+        PROGRESSIVE_RESOLUTION.x,
+        PROGRESSIVE_RESOLUTION.y,
+        &pixels,
+        OutputFormat::Hdr,
+    )?;
+
+    Ok(())
+}
+
+// Renders each target once in a single run and once split into two runs through a checkpoint at the split sample count.
+// Each pass starts at the first sample index the host hands it, so the resumed run continues the sample sequences instead of repeating them
+// and both films match up to the rounding of the accumulation. The second case stops the first run on a partial pass of 8 samples
+pub fn test_progressive_resume() -> Result<()> {
+    const CASES: [(u32, u32); 2] = [(64, 32), (72, 40)];
+    const MAX_DIFFERENCE: f32 = 1.0e-4;
+
+    let output = util::lib_root().join("output");
+
+    for (target_sample_count, split_sample_count) in CASES {
+        let single_path = output.join(format!("cornell_progressive_single_{}.exr", target_sample_count));
+        let resumed_path = output.join(format!("cornell_progressive_resumed_{}.exr", target_sample_count));
+
+        for path in [&single_path, &resumed_path] {
+            if path.exists() {
+                fs::remove_file(path)?;
+            }
+        }
+
+        let single = render_progressive_volumetric(target_sample_count, None, &single_path)?;
+
+        render_progressive_volumetric(split_sample_count, None, &resumed_path)?;
+        let resumed = render_progressive_volumetric(target_sample_count, None, &resumed_path)?;
+
+        let max_difference = single
+            .iter()
+            .zip(&resumed)
+            .flat_map(|(a, b)| (0..3).map(move |c| (a[c] - b[c]).abs() / a[c].abs().max(1.0)))
+            .fold(0.0, f32::max);
+
+        info!(
+            "Resumed progressive render of {} samples split at {} differs by at most {:e} from the single run",
+            target_sample_count, split_sample_count, max_difference
+        );
+
+        if max_difference > MAX_DIFFERENCE {
+            bail!("Resumed progressive render of {} samples differs by {:e} from the single run", target_sample_count, max_difference);
+        }
+    }
+
+    Ok(())
+}
+
+fn render_progressive_volumetric(target_sample_count: u32, time_limit: Option<Duration>, checkpoint_path: &Path) -> Result<Vec<[f32; 4]>> {
+    const CHECKPOINT_INTERVAL: u32 = 8;
+
+    let (device, allocator, image_allocator, _) = render::init_vulkan_context(true, "test_progressive_volumetric", vk::make_api_version(0, 1, 1, 1))?;
+    let shaders = shader_builder::compile_spirv_shaders();
+
+    let mut model = model::load_gltf(device.clone(), &allocator, &image_allocator, util::lib_root().join("models/cornell_color.gltf").to_str().unwrap())?;
+
+    let fog = model.add_medium(RenderMedium::homogeneous(Vec3::splat(0.02), Vec3::splat(0.1), 1.0, 0.6));
+    model.lights.push(Light::new(Vec3::new(0.0, 1.8, 0.0), 4.0));
+
+    info!("Render model loaded");
+
+    let vertices_buffer = model.write_vertices_to_buffer(&allocator)?;
+    let indices_buffer = model.write_indices_to_buffer(&allocator)?;
+    let materials_buffer = model.write_material_to_buffer(&allocator)?;
+    let media_buffer = model.write_media_to_buffer(&allocator)?;
+    let grid_data_buffer = model.write_grid_data_to_buffer(&allocator)?;
+    let lights_buffer = model.write_lights_to_buffer(&allocator)?;
+    let rgb_to_spectrum_table_buffer = spectrum::write_rgb_to_spectrum_table_to_buffer(&allocator, Gamut::Srgb)?;
+
+    let blas = model.build_blas(device.clone(), &allocator);
+
+    info!("Bottom-level acceleration structures created");
+
+    let pipeline_desc = PipelineDesc::default().compute_name("integrator::adaptive::main_cs".into());
+    let pipeline = WrappedPipeline::new(device.clone(), &allocator, pipeline_desc, &shaders, None)?;
+
+    let render_width = PROGRESSIVE_RESOLUTION.x;
+    let render_height = PROGRESSIVE_RESOLUTION.y;
+    let workgroup_width = 16;
+    let workgroup_height = 8;
+
+    let camera = model.camera(0);
+    let mut distributions = DistributionBuffer::default();
+    let camera_buffer = model.write_camera_to_buffer(&allocator, 0, UVec2::new(render_width, render_height), &mut distributions)?;
+    let filter = FilmFilterDesc::default().to_render_filter(&mut distributions);
+    let distributions_buffer = distributions.write_to_buffer(&allocator)?;
+
+    let time = Some(0.5 * (camera.shutter_open + camera.shutter_close));
+    let instance_metadata_buffer = model.write_instance_metadata_to_buffer_at(&allocator, time)?;
+    let tlas = tlas::create_tlas_at(device.clone(), &allocator, &blas, slice::from_ref(&model), time)?;
+
+    let sensor_buffer = sensor::cie_1931_sensor(&ExposureSettings::default())?.write_to_buffer(&allocator)?;
+    let mut film = RgbFilmImages::new(&image_allocator, render_width, render_height)?;
+
+    let settings = ProgressiveSettings::new("cornell_color", UVec2::new(render_width, render_height)).target_sample_count(target_sample_count);
+
+    let mut progressive = if checkpoint_path.exists() {
+        let checkpoint = Checkpoint::load(checkpoint_path)?;
+        let progressive = ProgressiveRender::resume(settings, &checkpoint)?;
+
+        film.upload(&image_allocator, &checkpoint.film)?;
+
+        progressive
+    } else {
+        ProgressiveRender::new(settings)
+    };
+
+    if let Some(time_limit) = time_limit {
+        progressive.cancel_after(time_limit);
+    }
+
+    let descriptor = WrappedDescriptorSet::new(device.clone(), &pipeline, 0)?;
+    let params_buffer = allocator.allocate(mem::size_of::<AdaptiveParameters>() as _, BufferUsageFlags::UNIFORM_BUFFER, MemoryLocation::CpuToGpu)?;
+
+    descriptor.write_uniform_buffer(DescriptorId::Index(0), &params_buffer)?;
+    film.write_descriptors(&descriptor, 1)?;
+
+    let first_scene_binding = 1 + RgbFilmImages::BINDING_COUNT;
+    descriptor.write_tlas(DescriptorId::Index(first_scene_binding), &tlas)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(first_scene_binding + 1), &vertices_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(first_scene_binding + 2), &indices_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(first_scene_binding + 3), &instance_metadata_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(first_scene_binding + 4), &materials_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(first_scene_binding + 5), &media_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(first_scene_binding + 6), &grid_data_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(first_scene_binding + 7), &lights_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(first_scene_binding + 8), &rgb_to_spectrum_table_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(first_scene_binding + 9), &camera_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(first_scene_binding + 10), &distributions_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(first_scene_binding + 11), &sensor_buffer)?;
+
+    while let Some(pass) = progressive.next_pass() {
+        // Never converging early, every pixel takes the pass's samples until it reaches the target
+        let params = AdaptiveParameters {
+            pass_index: pass.pass_index,
+            first_sample_index: pass.first_sample,
+            min_sample_count: u32::MAX,
+            max_sample_count: progressive.settings.target_sample_count,
+            relative_error_threshold: 0.0,
+            volumetric: VolumetricParameters {
+                resolution: UVec2::new(render_width, render_height),
+                sample_count: pass.sample_count,
+                max_depth: progressive.settings.max_depth,
+                camera_medium: fog,
+                time_slice: 0,
+                time_slice_count: 1,
+                time_min: camera.shutter_open,
+                time_max: camera.shutter_close.max(camera.shutter_open),
+                pixel_bounds_min: UVec2::ZERO,
+                pixel_bounds_max: UVec2::new(render_width, render_height),
+                tile_offset: UVec2::ZERO,
+                tile_size: UVec2::new(render_width, render_height),
+                filter,
+            },
+        };
+
+        allocator.upload_data(&params_buffer, slice::from_ref(&params))?;
+
+        device.single_time_command(|cmd_buf| unsafe {
+            pipeline.bind(cmd_buf);
+            descriptor.bind(cmd_buf, &pipeline);
+
+            device.cmd_dispatch(
+                cmd_buf,
+                (render_width + workgroup_width - 1) / workgroup_width,
+                (render_height + workgroup_height - 1) / workgroup_height,
+                1,
+            );
+        })?;
+
+        let progress = progressive.finish_pass(pass);
+
+        if progress.pass_index % CHECKPOINT_INTERVAL == 0 {
+            progressive.checkpoint(film.download(&image_allocator)?).save(checkpoint_path)?;
+        }
+    }
+
+    progressive.checkpoint(film.download(&image_allocator)?).save(checkpoint_path)?;
+    let pixels = film.read_rgb(&image_allocator, 1.0, filter.integral)?;
+
+    if progressive.is_complete() {
+        info!("Progressive render finished in {:.2?}", progressive.progress().elapsed);
+    } else {
+        info!("Progressive render cancelled at {} samples per pixel", progressive.progress().sample_index);
+    }
+
+    Ok(pixels)
+}
+
+pub fn test_subsurface_reference() -> Result<()> {
+    const MAX_STANDARD_SCORE: f32 = 4.0;
+    // Wide enough that walks leaving through the sides of the slab are negligible
+    const SLAB_HALF_WIDTH: f32 = 100.0;
+
+    // The integrator's walk is spectral, a gray material keeps every wavelength equal to the per channel reference
+    let params = SlabParameters {
+        albedo: Vec3::splat(0.6),
+        mean_free_path: Vec3::splat(0.25),
+        eta: 1.33,
+        thickness: 1.0,
+    };
+
+    let cpu_sample_count = 1 << 20;
+    let estimate = reference::subsurface::random_walk_slab(&params, cpu_sample_count, 0x5eed);
+
+    info!("Subsurface slab reflectance: {:?}", estimate.reflectance);
+    info!("Subsurface slab transmittance: {:?}", estimate.transmittance);
+
+    let (device, allocator, _, _) = render::init_vulkan_context(true, "test_subsurface_reference", vk::make_api_version(0, 1, 1, 1))?;
+    let shaders = shader_builder::compile_spirv_shaders();
+
+    let slab = MeshBuffer::cuboid(
+        &allocator,
+        Vec3::new(-SLAB_HALF_WIDTH, 0.0, -SLAB_HALF_WIDTH),
+        Vec3::new(SLAB_HALF_WIDTH, params.thickness, SLAB_HALF_WIDTH),
+    )?;
+    let material = RenderMaterial::subsurface(params.albedo, params.mean_free_path, params.eta, 0.0);
+    let model = RenderModel::new(vec![(RenderMesh::new(slab, material), Mat4::IDENTITY)], Vec::new());
+
+    let vertices_buffer = model.write_vertices_to_buffer(&allocator)?;
+    let indices_buffer = model.write_indices_to_buffer(&allocator)?;
+    let instance_metadata_buffer = model.write_instance_metadata_to_buffer(&allocator)?;
+    let materials_buffer = model.write_material_to_buffer(&allocator)?;
+    let media_buffer = model.write_media_to_buffer(&allocator)?;
+    let grid_data_buffer = model.write_grid_data_to_buffer(&allocator)?;
+    let lights_buffer = model.write_lights_to_buffer(&allocator)?;
+    let rgb_to_spectrum_table_buffer = spectrum::write_rgb_to_spectrum_table_to_buffer(&allocator, Gamut::Srgb)?;
+
+    let blas = model.build_blas(device.clone(), &allocator);
+    let tlas = tlas::create_tlas(device.clone(), &allocator, &blas, slice::from_ref(&model))?;
+
+    let pipeline_desc = PipelineDesc::default().compute_name("test::subsurface_slab::main_cs".into());
+    let pipeline = WrappedPipeline::new(device.clone(), &allocator, pipeline_desc, &shaders, None)?;
+
+    let workgroup_width = 16;
+    let workgroup_height = 8;
+
+    let walk_params = SlabWalkParameters::new(params.thickness, 64, UVec2::new(256, 128), 0x5eed);
+    let invocation_count = (walk_params.grid_size.x * walk_params.grid_size.y) as u64;
+
+    let params_buffer = allocator.allocate(mem::size_of::<SlabWalkParameters>() as _, BufferUsageFlags::UNIFORM_BUFFER, MemoryLocation::CpuToGpu)?;
+    let tally_buffer = allocator.allocate(invocation_count * mem::size_of::<[f32; 4]>() as u64, BufferUsageFlags::STORAGE_BUFFER, MemoryLocation::GpuToCpu)?;
+    allocator.upload_data(&params_buffer, slice::from_ref(&walk_params))?;
+
+    let descriptor = WrappedDescriptorSet::new(device.clone(), &pipeline, 0)?;
+    descriptor.write_uniform_buffer(DescriptorId::Index(0), &params_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(1), &tally_buffer)?;
+    descriptor.write_tlas(DescriptorId::Index(2), &tlas)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(3), &vertices_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(4), &indices_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(5), &instance_metadata_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(6), &materials_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(7), &media_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(8), &grid_data_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(9), &lights_buffer)?;
+    descriptor.write_storage_buffer(DescriptorId::Index(10), &rgb_to_spectrum_table_buffer)?;
+
+    device.single_time_command(|cmd_buf| unsafe {
+        pipeline.bind(cmd_buf);
+        descriptor.bind(cmd_buf, &pipeline);
+
+        device.cmd_dispatch(
+            cmd_buf,
+            (walk_params.grid_size.x + workgroup_width - 1) / workgroup_width,
+            (walk_params.grid_size.y + workgroup_height - 1) / workgroup_height,
+            1,
+        );
+    })?;
+
+    let tallies: Vec<[f32; 4]> = allocator.download_data(&tally_buffer)?;
+    let gpu_estimate = walk_params.estimate(&tallies);
+
+    info!("Integrator subsurface slab reflectance: {:?}", gpu_estimate.estimate.reflectance);
+    info!("Integrator subsurface slab transmittance: {:?}", gpu_estimate.estimate.transmittance);
+
+    // Both sides are unbiased estimates of the same slab, so they have to agree to within a few standard errors
+    let score = estimate.standard_score(cpu_sample_count as u64, &gpu_estimate);
+
+    if score > MAX_STANDARD_SCORE {
+        bail!("Integrator subsurface slab differs from the CPU reference by {:.2} standard errors", score);
+    }
+
+    Ok(())
+}
+
+// The double Gauss prescription from Modern Lens Design is a 50 mm lens, so the traced thick lens has to land on that focal length
+pub fn test_lens_prescription() -> Result<()> {
+    const EXPECTED_FOCAL_LENGTH: f32 = 0.050;
+    const FOCAL_LENGTH_TOLERANCE: f32 = 0.01;
+
+    let system = LensSystem::load(util::lib_root().join("lenses").join("dgauss.50mm.dat"), None)?;
+
+    if system.elements.len() != 11 || system.elements.iter().filter(|element| element.is_stop()).count() != 1 {
+        bail!("Double Gauss prescription parsed into {} elements", system.elements.len());
+    }
+
+    let film_diagonal = 0.035;
+    let (principal_z, focal_z) = system.compute_thick_lens_approximation(film_diagonal)?;
+    let focal_lengths = [focal_z[0] - principal_z[0], principal_z[1] - focal_z[1]];
+
+    info!("Double Gauss focal lengths: {:?}", focal_lengths);
+
+    for focal_length in focal_lengths {
+        if (focal_length / EXPECTED_FOCAL_LENGTH - 1.0).abs() > FOCAL_LENGTH_TOLERANCE {
+            bail!("Double Gauss focal length {} m, expected {} m", focal_length, EXPECTED_FOCAL_LENGTH);
+        }
+    }
+
+    let render_lens = system.to_render_lens(UVec2::new(800, 600), film_diagonal * 1000.0, 10.0)?;
+    let rear_element = render_lens.elements[render_lens.element_count as usize - 1];
+    let center_pupil = render_lens.exit_pupil_bounds[0];
+
+    if render_lens.element_count != 11 || rear_element.thickness <= 0.0 {
+        bail!("Double Gauss render lens has {} elements and rear thickness {}", render_lens.element_count, rear_element.thickness);
+    }
+
+    if center_pupil.z <= center_pupil.x || center_pupil.w <= center_pupil.y {
+        bail!("Double Gauss exit pupil is empty at the film center");
+    }
+
+    Ok(())
+}
+
+// Writes HDR PNGs of a gradient past the peak luminance and decodes them back, the samples have to survive the 16 bit byte swap unchanged
+pub fn test_hdr_png_roundtrip() -> Result<()> {
+    let (width, height) = (64, 16);
+    let pixels = (0..width * height)
+        .map(|i| {
+            let value = 8.0 * (i % width) as f32 / (width - 1) as f32;
+            [value, value * 0.5, value * (i / width) as f32 / height as f32, 1.0]
+        })
+        .collect::<Vec<_>>();
+
+    let output = util::lib_root().join("output");
+
+    for (name, settings) in [
+        ("pq_16", HdrDisplayOutput::pq()),
+        ("pq_10", HdrDisplayOutput::pq().bit_depth(HdrBitDepth::Ten)),
+        ("hlg_16", HdrDisplayOutput::hlg()),
+    ] {
+        let path = output.join(format!("hdr_roundtrip_{}.png", name));
+        settings.write(&path, width, height, &pixels)?;
+
+        if hdr::decode_png_samples(&fs::read(&path)?, width, height)? != settings.quantized_samples(&pixels) {
+            bail!("HDR PNG {} does not decode back to the quantized samples", name);
+        }
+    }
+
+    Ok(())
+}
+
+// Fits a synthetic sensor, whose curves are a known mix of the CIE matching functions, from the ColorChecker values it would record under D65
+pub fn test_sensor_calibration() -> Result<()> {
+    const MATRIX_TOLERANCE: f32 = 1e-4;
+    const PATCH_TOLERANCE: f32 = 0.01;
+
+    // Every channel is a positive mix of X, Y and Z, so the curves are nonnegative like a real sensor's
+    let sensor_rgb_from_xyz = Mat3::from_cols(Vec3::new(0.9, 0.2, 0.0), Vec3::new(0.3, 1.0, 0.1), Vec3::new(0.0, 0.1, 0.8));
+    let xyz_from_sensor_rgb = sensor_rgb_from_xyz.inverse();
+    let illuminant = IlluminantSpectrum::d65();
+
+    let patches = calibration::swatch_xyz(&illuminant).map(|xyz| sensor_rgb_from_xyz * xyz.as_vec3());
+    let rows = patches.iter().map(|patch| format!("{},{},{}\n", patch.x, patch.y, patch.z)).collect::<String>();
+
+    let path = util::lib_root().join("output").join("sensor_calibration_patches.csv");
+    fs::write(&path, format!("r,g,b\n{}", rows))?;
+    let measurement = ColorCheckerMeasurement::load_csv(&path)?;
+
+    let calibration = SensorCalibration::fit("synthetic", &measurement, &illuminant)?;
+
+    info!("Recovered sensor matrix {:?}, rms error {}", calibration.xyz_from_sensor_rgb(), calibration.rms_error);
+
+    if !calibration.xyz_from_sensor_rgb().abs_diff_eq(xyz_from_sensor_rgb, MATRIX_TOLERANCE) {
+        bail!("Fitted sensor matrix {:?} does not match {:?}", calibration.xyz_from_sensor_rgb(), xyz_from_sensor_rgb);
+    }
+
+    // The smoothed curves only have to reproduce the measurements, relative to the brightest patch
+    let calibration = calibration.fit_spectral_curves(&measurement, &illuminant, 1e-3)?;
+    let response = calibration.profile.response.as_ref().ok_or_else(|| anyhow!("Spectral fit produced no curves"))?;
+    let white = patches.iter().map(|patch| patch.max_element()).fold(0.0, f32::max);
+
+    for (reflectance, patch) in SWATCH_REFLECTANCES.iter().zip(&patches) {
+        let recorded = (0..SWATCH_SAMPLE_COUNT)
+            .map(|k| Vec3::new(response.r[k], response.g[k], response.b[k]) * reflectance[k] * illuminant.evaluate(response.lambdas[k]) * SWATCH_LAMBDA_STEP)
+            .sum::<Vec3>();
+
+        if (recorded - *patch).abs().max_element() > PATCH_TOLERANCE * white {
+            bail!("Fitted sensor curves record {} instead of {}", recorded, patch);
+        }
+    }
+
+    Ok(())
+}
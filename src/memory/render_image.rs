@@ -2,9 +2,9 @@ use crate::memory::render_buffer::{RenderBuffer, RenderBufferAllocatorRef};
 use crate::render::device::WrappedDeviceRef;
 use anyhow::{anyhow, bail, Result};
 use ash::vk::{
-    AccessFlags, BufferImageCopy, BufferUsageFlags, DependencyFlags, DeviceMemory, DeviceSize, Extent3D, Format, Image, ImageAspectFlags, ImageCopy, ImageCreateInfo, ImageLayout, ImageMemoryBarrier,
-    ImageSubresourceLayers, ImageSubresourceRange, ImageTiling, ImageType, ImageUsageFlags, ImageView, ImageViewCreateInfo, ImageViewType, MemoryAllocateInfo, MemoryPropertyFlags, Offset3D,
-    PipelineStageFlags, SampleCountFlags, SharingMode,
+    AccessFlags, BufferImageCopy, BufferUsageFlags, ClearColorValue, DependencyFlags, DeviceMemory, DeviceSize, Extent3D, Format, Image, ImageAspectFlags, ImageCopy, ImageCreateInfo, ImageLayout,
+    ImageMemoryBarrier, ImageSubresourceLayers, ImageSubresourceRange, ImageTiling, ImageType, ImageUsageFlags, ImageView, ImageViewCreateInfo, ImageViewType, MemoryAllocateInfo, MemoryPropertyFlags,
+    Offset3D, PipelineStageFlags, SampleCountFlags, SharingMode,
 };
 use core::slice;
 use gpu_allocator::MemoryLocation;
@@ -179,6 +179,29 @@ impl ImageAllocator {
         Ok(image)
     }
 
+    // Leaves the image in the general layout, ready to be bound as a storage image
+    pub fn upload_pixels(&self, image: &mut RenderImage, pixels: &[[f32; 4]]) -> Result<()> {
+        if image.desc.format != Format::R32G32B32A32_SFLOAT {
+            bail!("Unsupported image format: {:?}", image.desc.format);
+        }
+
+        if pixels.len() != (image.desc.width * image.desc.height) as usize {
+            bail!("Pixel array size {} mismatch with width {} and height {}", pixels.len(), image.desc.width, image.desc.height);
+        }
+
+        let staging_buffer = self
+            .buffer_allocator
+            .allocate(mem::size_of_val(pixels) as DeviceSize, BufferUsageFlags::TRANSFER_SRC, MemoryLocation::CpuToGpu)?;
+
+        self.buffer_allocator.upload_data(&staging_buffer, pixels)?;
+
+        self.transition_layout(image, ImageLayout::TRANSFER_DST_OPTIMAL)?;
+        self.upload_from_buffer(&staging_buffer, image)?;
+        self.transition_layout(image, ImageLayout::GENERAL)?;
+
+        Ok(())
+    }
+
     pub fn transition_layout(&self, image: &mut RenderImage, new_layout: ImageLayout) -> Result<()> {
         if image.current_layout == new_layout {
             return Ok(());
@@ -231,6 +254,24 @@ impl ImageAllocator {
         Ok(())
     }
 
+    pub fn clear_image(&self, image: &RenderImage) -> Result<()> {
+        let subresource_range = ImageSubresourceRange::default()
+            .aspect_mask(image.desc.aspect_flags)
+            .base_mip_level(0)
+            .level_count(image.desc.mip_level)
+            .base_array_layer(0)
+            .layer_count(1);
+
+        let clear_value = ClearColorValue { float32: [0.0; 4] };
+
+        self.device.single_time_command(|cmd_buf| unsafe {
+            self.device
+                .cmd_clear_color_image(cmd_buf, image.image, image.current_layout, &clear_value, slice::from_ref(&subresource_range));
+        })?;
+
+        Ok(())
+    }
+
     pub fn copy_image(&self, src_image: &RenderImage, dst_image: &RenderImage, mip_level: Option<u32>) -> Result<()> {
         if src_image.desc.aspect_flags != dst_image.desc.aspect_flags {
             bail!("Image aspect flags mismatch");
@@ -259,17 +300,44 @@ impl ImageAllocator {
     }
 
     pub fn acquire_pixels(&self, image: &mut RenderImage, mip_level: Option<u32>) -> Result<Vec<[f32; 4]>> {
+        let pixels = match image.desc.format {
+            Format::R32G32B32A32_SFLOAT => self.acquire_raw_pixels::<[f32; 4]>(image, mip_level)?,
+            Format::R8G8B8A8_UNORM | Format::R8G8B8A8_SRGB => self
+                .acquire_raw_pixels::<[u8; 4]>(image, mip_level)?
+                .iter()
+                .map(|pixel| {
+                    let r = pixel[0] as f32 / 255.0;
+                    let g = pixel[1] as f32 / 255.0;
+                    let b = pixel[2] as f32 / 255.0;
+                    let a = pixel[3] as f32 / 255.0;
+
+                    [r, g, b, a]
+                })
+                .collect(),
+            _ => bail!("Unsupported image format: {:?}", image.desc.format),
+        };
+
+        Ok(pixels)
+    }
+
+    // Texels as they are stored, e.g. the `i32` fixed-point values of an R32_SINT image, which would lose precision as floats
+    pub fn acquire_raw_pixels<T: Copy>(&self, image: &mut RenderImage, mip_level: Option<u32>) -> Result<Vec<T>> {
         if image.desc.aspect_flags != ImageAspectFlags::COLOR {
             bail!("Only images with color aspect flag supported");
         }
 
         let pixel_size = match image.desc.format {
             Format::R32G32B32A32_SFLOAT => 4 * mem::size_of::<f32>(),
-            Format::R8G8B8A8_UNORM => 4 * mem::size_of::<u32>(),
-            Format::R8G8B8A8_SRGB => 4 * mem::size_of::<u32>(),
+            Format::R8G8B8A8_UNORM => 4 * mem::size_of::<u8>(),
+            Format::R8G8B8A8_SRGB => 4 * mem::size_of::<u8>(),
+            Format::R32_SINT => mem::size_of::<i32>(),
             _ => bail!("Unsupported image format: {:?}", image.desc.format),
         };
 
+        if pixel_size != mem::size_of::<T>() {
+            bail!("Pixels of {:?} are {} bytes, read as {} bytes", image.desc.format, pixel_size, mem::size_of::<T>());
+        }
+
         let staging_size = (image.desc.width * image.desc.height * pixel_size as u32) as DeviceSize;
         if staging_size == 0 {
             return Ok(Vec::new());
@@ -301,38 +369,7 @@ impl ImageAllocator {
                 .cmd_copy_image_to_buffer(cmd_buf, image.image, ImageLayout::TRANSFER_SRC_OPTIMAL, staging_buffer.buffer, slice::from_ref(&buffer_image_copy));
         })?;
 
-        let pixels = match image.desc.format {
-            Format::R32G32B32A32_SFLOAT => self.buffer_allocator.download_data::<[f32; 4]>(&staging_buffer)?,
-            Format::R8G8B8A8_UNORM => self
-                .buffer_allocator
-                .download_data::<[u8; 4]>(&staging_buffer)?
-                .iter()
-                .map(|pixel| {
-                    let r = pixel[0] as f32 / 255.0;
-                    let g = pixel[1] as f32 / 255.0;
-                    let b = pixel[2] as f32 / 255.0;
-                    let a = pixel[3] as f32 / 255.0;
-
-                    [r, g, b, a]
-                })
-                .collect(),
-            Format::R8G8B8A8_SRGB => self
-                .buffer_allocator
-                .download_data::<[u8; 4]>(&staging_buffer)?
-                .iter()
-                .map(|pixel| {
-                    let r = pixel[0] as f32 / 255.0;
-                    let g = pixel[1] as f32 / 255.0;
-                    let b = pixel[2] as f32 / 255.0;
-                    let a = pixel[3] as f32 / 255.0;
-
-                    [r, g, b, a]
-                })
-                .collect(),
-            _ => bail!("Unsupported image format: {:?}", image.desc.format),
-        };
-
-        Ok(pixels)
+        self.buffer_allocator.download_data::<T>(&staging_buffer)
     }
 
     fn allocate_image(&self, desc: ImageDesc, properties: MemoryPropertyFlags) -> Result<(Image, DeviceMemory)> {
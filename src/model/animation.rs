@@ -0,0 +1,192 @@
+use glam::{Mat4, Quat, Vec3, Vec4};
+use gltf::animation::util::ReadOutputs;
+use gltf::animation::Interpolation;
+use gltf::buffer::Data as GltfBufferData;
+use gltf::Document;
+
+#[derive(Copy, Clone, Debug)]
+pub enum AnimationProperty {
+    Translation,
+    Rotation,
+    Scale,
+}
+
+#[derive(Copy, Clone, Debug)]
+pub enum AnimationInterpolation {
+    Step,
+    Linear,
+}
+
+#[derive(Clone, Debug)]
+pub struct AnimationChannel {
+    pub node: usize,
+    pub property: AnimationProperty,
+    pub interpolation: AnimationInterpolation,
+    pub times: Vec<f32>,
+    pub values: Vec<Vec4>,
+}
+
+impl AnimationChannel {
+    pub fn sample(&self, time: f32) -> Vec4 {
+        let last = self.times.len() - 1;
+
+        if time <= self.times[0] {
+            return self.values[0];
+        }
+
+        if time >= self.times[last] {
+            return self.values[last];
+        }
+
+        let next = self.times.partition_point(|&t| t <= time);
+        let previous = next - 1;
+
+        match self.interpolation {
+            AnimationInterpolation::Step => self.values[previous],
+            AnimationInterpolation::Linear => {
+                let dt = (time - self.times[previous]) / (self.times[next] - self.times[previous]);
+
+                match self.property {
+                    AnimationProperty::Rotation => {
+                        let start = Quat::from_vec4(self.values[previous]);
+                        let end = Quat::from_vec4(self.values[next]);
+                        Vec4::from(start.slerp(end, dt))
+                    }
+                    _ => self.values[previous].lerp(self.values[next], dt),
+                }
+            }
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct NodeTransform {
+    pub parent: Option<usize>,
+    pub translation: Vec3,
+    pub rotation: Quat,
+    pub scale: Vec3,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ModelAnimation {
+    pub nodes: Vec<NodeTransform>,
+    pub channels: Vec<AnimationChannel>,
+}
+
+impl ModelAnimation {
+    pub fn from_gltf(document: &Document, buffers: &[GltfBufferData]) -> Self {
+        let mut nodes = document
+            .nodes()
+            .map(|node| {
+                let (translation, rotation, scale) = node.transform().decomposed();
+
+                NodeTransform {
+                    parent: None,
+                    translation: Vec3::from(translation),
+                    rotation: Quat::from_array(rotation),
+                    scale: Vec3::from(scale),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        for node in document.nodes() {
+            for child in node.children() {
+                nodes[child.index()].parent = Some(node.index());
+            }
+        }
+
+        let mut channels = Vec::new();
+
+        for animation in document.animations() {
+            for channel in animation.channels() {
+                let reader = channel.reader(|buffer| Some(&buffers[buffer.index()]));
+
+                let Some(times) = reader.read_inputs().map(|inputs| inputs.collect::<Vec<f32>>()) else {
+                    continue;
+                };
+
+                let (property, values) = match reader.read_outputs() {
+                    Some(ReadOutputs::Translations(translations)) => (AnimationProperty::Translation, translations.map(|t| Vec3::from(t).extend(0.0)).collect::<Vec<_>>()),
+                    Some(ReadOutputs::Rotations(rotations)) => (AnimationProperty::Rotation, rotations.into_f32().map(Vec4::from).collect()),
+                    Some(ReadOutputs::Scales(scales)) => (AnimationProperty::Scale, scales.map(|s| Vec3::from(s).extend(0.0)).collect()),
+                    _ => continue,
+                };
+
+                let (interpolation, values) = match channel.sampler().interpolation() {
+                    Interpolation::Step => (AnimationInterpolation::Step, values),
+                    Interpolation::Linear => (AnimationInterpolation::Linear, values),
+                    Interpolation::CubicSpline => (AnimationInterpolation::Linear, values.chunks(3).map(|keyframe| keyframe[1]).collect()),
+                };
+
+                if times.is_empty() || times.len() != values.len() {
+                    continue;
+                }
+
+                channels.push(AnimationChannel {
+                    node: channel.target().node().index(),
+                    property,
+                    interpolation,
+                    times,
+                    values,
+                });
+            }
+        }
+
+        Self { nodes, channels }
+    }
+
+    pub fn merge(&mut self, other: ModelAnimation) -> usize {
+        let offset = self.nodes.len();
+
+        self.nodes.extend(other.nodes.into_iter().map(|node| NodeTransform {
+            parent: node.parent.map(|parent| parent + offset),
+            ..node
+        }));
+
+        self.channels.extend(other.channels.into_iter().map(|channel| AnimationChannel {
+            node: channel.node + offset,
+            ..channel
+        }));
+
+        offset
+    }
+
+    pub fn is_animated(&self, node: usize) -> bool {
+        let mut current = Some(node);
+
+        while let Some(index) = current {
+            if self.channels.iter().any(|channel| channel.node == index) {
+                return true;
+            }
+
+            current = self.nodes[index].parent;
+        }
+
+        false
+    }
+
+    pub fn local_transform(&self, node: usize, time: f32) -> Mat4 {
+        let mut transform = self.nodes[node];
+
+        for channel in self.channels.iter().filter(|channel| channel.node == node) {
+            let value = channel.sample(time);
+
+            match channel.property {
+                AnimationProperty::Translation => transform.translation = value.truncate(),
+                AnimationProperty::Rotation => transform.rotation = Quat::from_vec4(value).normalize(),
+                AnimationProperty::Scale => transform.scale = value.truncate(),
+            }
+        }
+
+        Mat4::from_scale_rotation_translation(transform.scale, transform.rotation, transform.translation)
+    }
+
+    pub fn world_transform(&self, node: usize, time: f32) -> Mat4 {
+        let local = self.local_transform(node, time);
+
+        match self.nodes[node].parent {
+            Some(parent) => self.world_transform(parent, time) * local,
+            None => local,
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use crate::camera::aperture::{ApertureDesc, RenderAperture};
+use crate::camera::lens::RenderLens;
+use crate::sampling::DistributionBuffer;
+use glam::{Mat4, UVec2, Vec2, Vec3, Vec4};
+use optics_common::camera;
+
+#[derive(Copy, Clone, Debug)]
+pub enum CameraType {
+    Perspective,
+    Orthographic,
+    Spherical,
+    Realistic,
+}
+
+impl Into<u32> for CameraType {
+    fn into(self) -> u32 {
+        match self {
+            CameraType::Perspective => 0,
+            CameraType::Orthographic => 1,
+            CameraType::Spherical => 2,
+            CameraType::Realistic => 3,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SphericalMapping {
+    EquiRectangular,
+    EqualArea,
+}
+
+impl Into<u32> for SphericalMapping {
+    fn into(self) -> u32 {
+        match self {
+            SphericalMapping::EquiRectangular => 0,
+            SphericalMapping::EqualArea => 1,
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct RenderCamera {
+    pub camera_transform: Mat4,
+    pub camera_transform_end: Mat4,
+    pub camera_from_raster: Mat4,
+    pub window: Vec4,
+    pub resolution: UVec2,
+    pub fov: f32,
+    pub lens_radius: f32,
+    pub focal_distance: f32,
+    pub shutter_open: f32,
+    pub shutter_close: f32,
+    pub camera_type: u32,
+    pub spherical_mapping: u32,
+    // Offset of the lens in the flattened distribution buffer, only read for `CameraType::Realistic`
+    pub lens_offset: u32,
+    pub aperture: RenderAperture,
+}
+
+// Cameras take only the rotation and translation of their node, a scale would skew the generated rays
+pub fn rigid_camera_transform(node_transform: Mat4) -> Mat4 {
+    let (_, rotation, translation) = node_transform.to_scale_rotation_translation();
+    Mat4::from_rotation_translation(rotation, translation)
+}
+
+#[derive(Copy, Clone, Debug)]
+pub enum CameraProjection {
+    Perspective { yfov: f32, znear: f32, zfar: f32 },
+    Orthographic { xmag: f32, ymag: f32, znear: f32, zfar: f32 },
+    // Covers the whole sphere around the camera, e.g. for baking environment probes
+    Spherical { mapping: SphericalMapping },
+    // Traces the film rays through a lens prescription, built by `LensSystem::to_render_lens` for the render resolution
+    Realistic { lens: RenderLens },
+}
+
+#[derive(Clone, Debug)]
+pub struct CameraDesc {
+    pub name: Option<String>,
+    pub node: Option<usize>,
+    pub world_from_camera: Mat4,
+    pub world_from_camera_end: Mat4,
+    pub projection: CameraProjection,
+    pub lens_radius: f32,
+    pub focal_distance: f32,
+    pub aperture: ApertureDesc,
+    pub shutter_open: f32,
+    pub shutter_close: f32,
+}
+
+impl Default for CameraDesc {
+    fn default() -> Self {
+        Self {
+            name: None,
+            node: None,
+            world_from_camera: Mat4::from_translation(Vec3::new(-0.001, 1.0, 6.0)),
+            world_from_camera_end: Mat4::from_translation(Vec3::new(-0.001, 1.0, 6.0)),
+            projection: CameraProjection::Perspective {
+                yfov: 2.0 * (1.0_f32 / 5.0).atan(),
+                znear: 0.01,
+                zfar: 1000.0,
+            },
+            lens_radius: 0.0,
+            focal_distance: 1.0e6,
+            aperture: ApertureDesc::default(),
+            shutter_open: 0.0,
+            shutter_close: 0.0,
+        }
+    }
+}
+
+impl CameraDesc {
+    pub fn new(world_from_camera: Mat4, projection: CameraProjection) -> Self {
+        Self {
+            world_from_camera,
+            world_from_camera_end: world_from_camera,
+            projection,
+            ..Default::default()
+        }
+    }
+
+    pub fn thin_lens(mut self, lens_radius: f32, focal_distance: f32) -> Self {
+        self.lens_radius = lens_radius;
+        self.focal_distance = focal_distance;
+        self
+    }
+
+    pub fn aperture(mut self, aperture: ApertureDesc) -> Self {
+        self.aperture = aperture;
+        self
+    }
+
+    pub fn shutter(mut self, shutter_open: f32, shutter_close: f32) -> Self {
+        self.shutter_open = shutter_open;
+        self.shutter_close = shutter_close;
+        self
+    }
+
+    pub fn motion(mut self, world_from_camera_end: Mat4) -> Self {
+        self.world_from_camera_end = world_from_camera_end;
+        self
+    }
+
+    pub fn to_render_camera(&self, resolution: UVec2, distributions: &mut DistributionBuffer) -> RenderCamera {
+        let aspect = resolution.x as f32 / resolution.y as f32;
+
+        let (screen_from_camera, window_min, window_max, fov, camera_type) = match self.projection {
+            CameraProjection::Perspective { yfov, znear, zfar } => {
+                let (window, fov) = if aspect > 1.0 {
+                    (Vec2::new(aspect, 1.0), yfov)
+                } else {
+                    (Vec2::new(1.0, 1.0 / aspect), 2.0 * ((0.5 * yfov).tan() * aspect).atan())
+                };
+
+                (camera::perspective(fov, znear, zfar), -window, window, fov, CameraType::Perspective)
+            }
+            CameraProjection::Orthographic { xmag, ymag, znear, zfar } => (camera::orthographic(znear, zfar), Vec2::new(-xmag, -ymag), Vec2::new(xmag, ymag), 0.0, CameraType::Orthographic),
+            // Raster positions map straight to directions or through the lens, the projective matrices are left unused
+            CameraProjection::Spherical { .. } => (camera::orthographic(0.0, 1.0), Vec2::NEG_ONE, Vec2::ONE, 0.0, CameraType::Spherical),
+            CameraProjection::Realistic { .. } => (camera::orthographic(0.0, 1.0), Vec2::NEG_ONE, Vec2::ONE, 0.0, CameraType::Realistic),
+        };
+
+        let spherical_mapping = match self.projection {
+            CameraProjection::Spherical { mapping } => mapping,
+            _ => SphericalMapping::EquiRectangular,
+        };
+
+        let lens_offset = match &self.projection {
+            CameraProjection::Realistic { lens } => distributions.push(lens),
+            _ => u32::MAX,
+        };
+
+        let screen_from_camera = Mat4::from_cols_array_2d(&screen_from_camera);
+        let raster_from_screen = Mat4::from_cols_array_2d(&camera::raster_from_screen(window_min.to_array(), window_max.to_array(), resolution.to_array()));
+
+        let camera_from_gltf = Mat4::from_scale(Vec3::new(1.0, 1.0, -1.0));
+
+        RenderCamera {
+            camera_transform: self.world_from_camera * camera_from_gltf,
+            camera_transform_end: self.world_from_camera_end * camera_from_gltf,
+            camera_from_raster: screen_from_camera.inverse() * raster_from_screen.inverse(),
+            window: Vec4::new(window_min.x, window_min.y, window_max.x, window_max.y),
+            resolution,
+            fov,
+            lens_radius: self.lens_radius,
+            focal_distance: self.focal_distance,
+            shutter_open: self.shutter_open,
+            shutter_close: self.shutter_close,
+            camera_type: camera_type.into(),
+            spherical_mapping: spherical_mapping.into(),
+            lens_offset,
+            aperture: self.aperture.to_render_aperture(distributions),
+        }
+    }
+}
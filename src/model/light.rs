@@ -0,0 +1,14 @@
+use glam::Vec3;
+
+#[derive(Copy, Clone, Debug, Default)]
+#[repr(C)]
+pub struct Light {
+    pub pos: Vec3,
+    pub scale: f32,
+}
+
+impl Light {
+    pub fn new(pos: Vec3, scale: f32) -> Self {
+        Self { pos, scale }
+    }
+}
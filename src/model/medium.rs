@@ -0,0 +1,173 @@
+use anyhow::{bail, Result};
+use glam::{Mat4, UVec3, UVec4, Vec3, Vec4};
+
+pub const NO_MEDIUM: u32 = u32::MAX;
+
+#[derive(Copy, Clone, Debug)]
+pub enum MediumType {
+    Homogeneous,
+    Grid,
+}
+
+impl Into<u32> for MediumType {
+    fn into(self) -> u32 {
+        match self {
+            MediumType::Homogeneous => 0,
+            MediumType::Grid => 1,
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct RenderMedium {
+    pub medium_from_render: Mat4,
+    pub bounds_min: Vec4,
+    pub bounds_max: Vec4,
+    pub sigma_a: Vec4,
+    pub sigma_s: Vec4,
+    pub emitted: Vec4,
+    pub density_grid: UVec4,
+    pub temperature_grid: UVec4,
+
+    pub scale: f32,
+    pub g: f32,
+    pub emission_scale: f32,
+    pub temperature_scale: f32,
+    pub temperature_offset: f32,
+    pub density_max: f32,
+
+    pub medium_type: u32,
+}
+
+impl Default for RenderMedium {
+    fn default() -> Self {
+        Self {
+            medium_from_render: Mat4::IDENTITY,
+            bounds_min: Vec4::ZERO,
+            bounds_max: Vec4::ONE,
+            sigma_a: Vec4::ONE,
+            sigma_s: Vec4::ONE,
+            emitted: Vec4::ZERO,
+            density_grid: UVec4::ZERO,
+            temperature_grid: UVec4::ZERO,
+            scale: 1.0,
+            g: 0.0,
+            emission_scale: 0.0,
+            temperature_scale: 1.0,
+            temperature_offset: 0.0,
+            density_max: 0.0,
+            medium_type: MediumType::Homogeneous.into(),
+        }
+    }
+}
+
+impl RenderMedium {
+    pub fn homogeneous(sigma_a: Vec3, sigma_s: Vec3, scale: f32, g: f32) -> Self {
+        Self {
+            sigma_a: sigma_a.extend(0.0),
+            sigma_s: sigma_s.extend(0.0),
+            scale,
+            g,
+            medium_type: MediumType::Homogeneous.into(),
+            ..Default::default()
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn grid(render_from_medium: Mat4, bounds_min: Vec3, bounds_max: Vec3, sigma_a: Vec3, sigma_s: Vec3, scale: f32, g: f32, density_grid: GridDesc) -> Self {
+        Self {
+            medium_from_render: render_from_medium.inverse(),
+            bounds_min: bounds_min.extend(0.0),
+            bounds_max: bounds_max.extend(0.0),
+            sigma_a: sigma_a.extend(0.0),
+            sigma_s: sigma_s.extend(0.0),
+            density_grid: density_grid.into(),
+            scale,
+            g,
+            medium_type: MediumType::Grid.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn emission(mut self, emitted: Vec3, emission_scale: f32) -> Self {
+        self.emitted = emitted.extend(0.0);
+        self.emission_scale = emission_scale;
+        self
+    }
+
+    // Only homogeneous and density grid media exist in the shaders, anything else is rejected before it is uploaded
+    pub fn validate(&self, grid_data_len: usize) -> Result<()> {
+        let grid_fits = |grid: UVec4| grid.w as u64 + grid_len(grid) <= grid_data_len as u64;
+        let homogeneous: u32 = MediumType::Homogeneous.into();
+        let grid: u32 = MediumType::Grid.into();
+
+        if self.medium_type == grid {
+            if !grid_fits(self.density_grid) {
+                bail!("Density grid {} does not fit in {} grid values", self.density_grid, grid_data_len);
+            }
+
+            if self.emission_scale > 0.0 && !grid_fits(self.temperature_grid) {
+                bail!("Temperature grid {} does not fit in {} grid values", self.temperature_grid, grid_data_len);
+            }
+        } else if self.medium_type != homogeneous {
+            bail!("Unsupported medium type {}", self.medium_type);
+        }
+
+        Ok(())
+    }
+
+    // The majorant bound of the whole grid, found once here instead of every time a shader builds the medium
+    pub fn with_density_max(mut self, grid_data: &[f32]) -> Self {
+        let grid: u32 = MediumType::Grid.into();
+
+        if self.medium_type == grid {
+            let start = self.density_grid.w as usize;
+            let end = start + grid_len(self.density_grid) as usize;
+
+            self.density_max = grid_data[start..end].iter().fold(0.0, |max, &value| value.max(max));
+        }
+
+        self
+    }
+
+    pub fn blackbody_emission(mut self, temperature_grid: GridDesc, temperature_scale: f32, temperature_offset: f32, emission_scale: f32) -> Self {
+        self.temperature_grid = temperature_grid.into();
+        self.temperature_scale = temperature_scale;
+        self.temperature_offset = temperature_offset;
+        self.emission_scale = emission_scale;
+        self
+    }
+}
+
+// Voxel count of a grid stored as resolution and offset, wide enough that valid resolutions cannot overflow
+fn grid_len(grid: UVec4) -> u64 {
+    grid.x as u64 * grid.y as u64 * grid.z as u64
+}
+
+#[derive(Copy, Clone, Debug, Default)]
+pub struct GridDesc {
+    pub resolution: UVec3,
+    pub offset: u32,
+}
+
+impl Into<UVec4> for GridDesc {
+    fn into(self) -> UVec4 {
+        self.resolution.extend(self.offset)
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct MediumInterfaceDesc {
+    pub inside: u32,
+    pub outside: u32,
+}
+
+impl Default for MediumInterfaceDesc {
+    fn default() -> Self {
+        Self {
+            inside: NO_MEDIUM,
+            outside: NO_MEDIUM,
+        }
+    }
+}
@@ -1,8 +1,9 @@
 use crate::memory::render_buffer::{RenderBuffer, RenderBufferAllocator};
+use crate::model::medium::MediumInterfaceDesc;
 use crate::model::vertex::Vertex;
 use anyhow::Result;
 use ash::vk::{BufferUsageFlags, DeviceSize};
-use glam::Vec4;
+use glam::{Vec2, Vec3, Vec4};
 use gpu_allocator::MemoryLocation;
 use std::mem;
 
@@ -46,6 +47,40 @@ impl MeshBuffer {
             vertex_buffer,
         })
     }
+
+    // Axis aligned box with outward facing triangles, e.g. the slab of the subsurface reference test
+    pub fn cuboid(allocator: &RenderBufferAllocator, min: Vec3, max: Vec3) -> Result<Self> {
+        let center = 0.5 * (min + max);
+        let half_size = 0.5 * (max - min);
+
+        // (normal, u, v) with u x v = normal, so the quads wind counter clockwise seen from outside
+        let faces = [
+            (Vec3::X, Vec3::Y, Vec3::Z),
+            (Vec3::NEG_X, Vec3::Z, Vec3::Y),
+            (Vec3::Y, Vec3::Z, Vec3::X),
+            (Vec3::NEG_Y, Vec3::X, Vec3::Z),
+            (Vec3::Z, Vec3::X, Vec3::Y),
+            (Vec3::NEG_Z, Vec3::Y, Vec3::X),
+        ];
+
+        let mut indices = Vec::with_capacity(6 * faces.len());
+        let mut vertices = Vec::with_capacity(4 * faces.len());
+
+        for (normal, u, v) in faces {
+            let first = vertices.len() as u32;
+
+            for (su, sv) in [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)] {
+                let pos = center + half_size * (normal + su * u + sv * v);
+                let uv = Vec2::new(0.5 * (su + 1.0), 0.5 * (sv + 1.0));
+
+                vertices.push(Vertex::new(pos.extend(1.0), normal, uv, Vec4::ONE, Vec4::ZERO));
+            }
+
+            indices.extend([first, first + 1, first + 2, first, first + 2, first + 3]);
+        }
+
+        Self::new(allocator, indices, vertices)
+    }
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -53,6 +88,8 @@ pub enum MaterialType {
     Lambertian,
     Conductor,
     Dielectric,
+    Interface,
+    Subsurface,
 }
 
 impl Default for MaterialType {
@@ -67,6 +104,8 @@ impl Into<u32> for MaterialType {
             MaterialType::Lambertian => 0,
             MaterialType::Conductor => 1,
             MaterialType::Dielectric => 2,
+            MaterialType::Interface => 3,
+            MaterialType::Subsurface => 4,
         }
     }
 }
@@ -85,15 +124,51 @@ pub struct RenderMaterial {
 
     pub material_type: u32,
     pub material_property: f32,
+
+    pub mean_free_path: Vec4,
+}
+
+impl RenderMaterial {
+    pub fn subsurface(albedo: Vec3, mean_free_path: Vec3, eta: f32, roughness: f32) -> Self {
+        Self {
+            base_color: albedo.extend(1.0),
+            roughness_factor: roughness,
+            diffuse_map: u32::MAX,
+            normal_map: u32::MAX,
+            metallic_roughness_map: u32::MAX,
+            occlusion_map: u32::MAX,
+            material_type: MaterialType::Subsurface.into(),
+            material_property: eta,
+            mean_free_path: mean_free_path.extend(0.0),
+            ..Default::default()
+        }
+    }
 }
 
 pub struct RenderMesh {
     pub mesh_buffer: MeshBuffer,
     pub material: RenderMaterial,
+    pub medium_interface: MediumInterfaceDesc,
+    pub node: Option<usize>,
 }
 
 impl RenderMesh {
     pub fn new(mesh_buffer: MeshBuffer, material: RenderMaterial) -> Self {
-        Self { mesh_buffer, material }
+        Self {
+            mesh_buffer,
+            material,
+            medium_interface: MediumInterfaceDesc::default(),
+            node: None,
+        }
+    }
+
+    pub fn medium_interface(mut self, medium_interface: MediumInterfaceDesc) -> Self {
+        self.medium_interface = medium_interface;
+        self
+    }
+
+    pub fn node(mut self, node: usize) -> Self {
+        self.node = Some(node);
+        self
     }
 }
@@ -1,23 +1,35 @@
 use crate::memory::render_buffer::{RenderBuffer, RenderBufferAllocator};
 use crate::memory::render_image::ImageAllocator;
 use crate::memory::texture::Texture;
+use crate::model::animation::ModelAnimation;
+use crate::model::camera::{rigid_camera_transform, CameraDesc, CameraProjection, RenderCamera};
+use crate::model::light::Light;
+use crate::model::medium::{RenderMedium, NO_MEDIUM};
 use crate::model::mesh::{MaterialType, MeshBuffer, RenderMaterial, RenderMesh};
 use crate::model::vertex::Vertex;
 use crate::render::device::WrappedDeviceRef;
 use crate::rt::blas;
 use crate::rt::blas::Blas;
 use crate::rt::tlas::InstanceMetadata;
+use crate::sampling::DistributionBuffer;
 use anyhow::{anyhow, bail, Result};
 use ash::vk::BufferUsageFlags;
-use glam::{Mat4, Vec2, Vec3, Vec4};
+use glam::{Mat4, UVec2, Vec2, Vec3, Vec4};
 use gltf::buffer::Data as GltfBufferData;
+use gltf::camera::Projection as GltfProjection;
 use gltf::image::Format as GltfFormat;
+use gltf::json::Value as GltfValue;
+use gltf::Material as GltfMaterial;
 use gltf::Node as GltfNode;
 use gpu_allocator::MemoryLocation;
 use image::{DynamicImage, RgbImage};
 use log::{error, info};
-use std::mem;
+use std::{mem, slice};
 
+pub mod animation;
+pub mod camera;
+pub mod light;
+pub mod medium;
 pub mod mesh;
 pub mod vertex;
 
@@ -25,16 +37,62 @@ pub mod vertex;
 pub struct RenderModel {
     pub meshes: Vec<(RenderMesh, Mat4)>,
     pub textures: Vec<Texture>,
+    pub media: Vec<RenderMedium>,
+    pub grid_data: Vec<f32>,
+    pub lights: Vec<Light>,
+    pub cameras: Vec<CameraDesc>,
+    pub animation: ModelAnimation,
 }
 
 impl RenderModel {
     pub fn new(meshes: Vec<(RenderMesh, Mat4)>, textures: Vec<Texture>) -> Self {
-        Self { meshes, textures }
+        Self {
+            meshes,
+            textures,
+            media: Vec::new(),
+            grid_data: Vec::new(),
+            lights: Vec::new(),
+            cameras: Vec::new(),
+            animation: ModelAnimation::default(),
+        }
     }
 
     pub fn merge(&mut self, other: RenderModel) {
-        self.meshes.extend(other.meshes);
+        let node_offset = self.animation.merge(other.animation);
+        let medium_offset = self.media.len() as u32;
+        let grid_offset = self.grid_data.len() as u32;
+
+        let rebase_medium = |medium: u32| if medium == NO_MEDIUM { NO_MEDIUM } else { medium + medium_offset };
+
+        self.meshes.extend(other.meshes.into_iter().map(|(mut mesh, transform)| {
+            mesh.node = mesh.node.map(|node| node + node_offset);
+            mesh.medium_interface.inside = rebase_medium(mesh.medium_interface.inside);
+            mesh.medium_interface.outside = rebase_medium(mesh.medium_interface.outside);
+            (mesh, transform)
+        }));
+        self.media.extend(other.media.into_iter().map(|mut medium| {
+            medium.density_grid.w += grid_offset;
+            medium.temperature_grid.w += grid_offset;
+            medium
+        }));
+        self.grid_data.extend(other.grid_data);
         self.textures.extend(other.textures);
+        self.lights.extend(other.lights);
+        self.cameras.extend(other.cameras.into_iter().map(|camera| CameraDesc {
+            node: camera.node.map(|node| node + node_offset),
+            ..camera
+        }));
+    }
+
+    pub fn add_medium(&mut self, medium: RenderMedium) -> u32 {
+        self.media.push(medium);
+        (self.media.len() - 1) as u32
+    }
+
+    pub fn add_grid(&mut self, values: &[f32]) -> u32 {
+        let offset = self.grid_data.len() as u32;
+        self.grid_data.extend_from_slice(values);
+        offset
     }
 
     pub fn write_vertices_to_buffer(&self, allocator: &RenderBufferAllocator) -> Result<RenderBuffer> {
@@ -71,14 +129,30 @@ impl RenderModel {
         Ok(indices_buffer)
     }
 
+    pub fn mesh_transforms(&self, time: Option<f32>) -> Vec<Mat4> {
+        self.meshes
+            .iter()
+            .map(|(mesh, transform)| match (time, mesh.node) {
+                (Some(time), Some(node)) if self.animation.is_animated(node) => self.animation.world_transform(node, time),
+                _ => *transform,
+            })
+            .collect()
+    }
+
     pub fn write_instance_metadata_to_buffer(&self, allocator: &RenderBufferAllocator) -> Result<RenderBuffer> {
+        self.write_instance_metadata_to_buffer_at(allocator, None)
+    }
+
+    pub fn write_instance_metadata_to_buffer_at(&self, allocator: &RenderBufferAllocator, time: Option<f32>) -> Result<RenderBuffer> {
         let mut current_index = 0_u32;
         let mut metadata = Vec::new();
 
-        for (mesh, transform) in &self.meshes {
+        for ((mesh, _), transform) in self.meshes.iter().zip(self.mesh_transforms(time)) {
             metadata.push(InstanceMetadata {
-                transform: *transform,
+                transform,
                 index_offset: current_index,
+                medium_inside: mesh.medium_interface.inside,
+                medium_outside: mesh.medium_interface.outside,
             });
 
             current_index += mesh.mesh_buffer.indices.len() as u32;
@@ -109,6 +183,87 @@ impl RenderModel {
         Ok(material_buffer)
     }
 
+    pub fn write_media_to_buffer(&self, allocator: &RenderBufferAllocator) -> Result<RenderBuffer> {
+        for medium in &self.media {
+            medium.validate(self.grid_data.len())?;
+        }
+
+        let media = if self.media.is_empty() {
+            vec![RenderMedium::default()]
+        } else {
+            self.media.iter().map(|medium| medium.with_density_max(&self.grid_data)).collect()
+        };
+
+        let media_buffer = allocator.allocate(
+            (media.len() * mem::size_of::<RenderMedium>()) as _,
+            BufferUsageFlags::STORAGE_BUFFER | BufferUsageFlags::TRANSFER_DST,
+            MemoryLocation::GpuOnly,
+        )?;
+
+        allocator.upload_data(&media_buffer, &media)?;
+
+        Ok(media_buffer)
+    }
+
+    pub fn write_grid_data_to_buffer(&self, allocator: &RenderBufferAllocator) -> Result<RenderBuffer> {
+        let grid_data = if self.grid_data.is_empty() { vec![0.0] } else { self.grid_data.clone() };
+
+        let grid_buffer = allocator.allocate(
+            (grid_data.len() * mem::size_of::<f32>()) as _,
+            BufferUsageFlags::STORAGE_BUFFER | BufferUsageFlags::TRANSFER_DST,
+            MemoryLocation::GpuOnly,
+        )?;
+
+        allocator.upload_data(&grid_buffer, &grid_data)?;
+
+        Ok(grid_buffer)
+    }
+
+    pub fn write_lights_to_buffer(&self, allocator: &RenderBufferAllocator) -> Result<RenderBuffer> {
+        let lights_buffer = allocator.allocate(
+            (self.lights.len().max(1) * mem::size_of::<Light>()) as _,
+            BufferUsageFlags::STORAGE_BUFFER | BufferUsageFlags::TRANSFER_DST,
+            MemoryLocation::GpuOnly,
+        )?;
+
+        allocator.upload_data(&lights_buffer, &self.lights)?;
+
+        Ok(lights_buffer)
+    }
+
+    pub fn camera(&self, index: usize) -> CameraDesc {
+        let camera = self.cameras.get(index).cloned().unwrap_or_default();
+
+        match camera.node {
+            Some(node) if self.animation.is_animated(node) => {
+                let world_from_camera = rigid_camera_transform(self.animation.world_transform(node, camera.shutter_open));
+                let world_from_camera_end = rigid_camera_transform(self.animation.world_transform(node, camera.shutter_close));
+
+                CameraDesc {
+                    world_from_camera,
+                    world_from_camera_end,
+                    ..camera
+                }
+            }
+            _ => camera,
+        }
+    }
+
+    // The camera's aperture image goes to `distributions`, which has to be bound alongside the camera buffer
+    pub fn write_camera_to_buffer(&self, allocator: &RenderBufferAllocator, index: usize, resolution: UVec2, distributions: &mut DistributionBuffer) -> Result<RenderBuffer> {
+        let camera = self.camera(index).to_render_camera(resolution, distributions);
+
+        let camera_buffer = allocator.allocate(
+            mem::size_of::<RenderCamera>() as _,
+            BufferUsageFlags::STORAGE_BUFFER | BufferUsageFlags::TRANSFER_DST,
+            MemoryLocation::GpuOnly,
+        )?;
+
+        allocator.upload_data(&camera_buffer, slice::from_ref(&camera))?;
+
+        Ok(camera_buffer)
+    }
+
     pub fn build_blas(&self, device: WrappedDeviceRef, allocator: &RenderBufferAllocator) -> Vec<Blas> {
         self.meshes
             .iter()
@@ -124,9 +279,43 @@ impl RenderModel {
     }
 }
 
-pub fn load_gltf_node(buffer_allocator: &RenderBufferAllocator, node: &GltfNode, buffers: &[GltfBufferData], parent_transform: Mat4) -> Vec<(RenderMesh, Mat4)> {
+pub fn load_gltf_camera(node: &GltfNode, node_transform: Mat4) -> Option<CameraDesc> {
+    let camera = node.camera()?;
+
+    let projection = match camera.projection() {
+        GltfProjection::Perspective(perspective) => CameraProjection::Perspective {
+            yfov: perspective.yfov(),
+            znear: perspective.znear(),
+            zfar: perspective.zfar().unwrap_or(1000.0),
+        },
+        GltfProjection::Orthographic(orthographic) => CameraProjection::Orthographic {
+            xmag: orthographic.xmag(),
+            ymag: orthographic.ymag(),
+            znear: orthographic.znear(),
+            zfar: orthographic.zfar(),
+        },
+    };
+
+    let (scale, _, _) = node_transform.to_scale_rotation_translation();
+
+    if !scale.abs_diff_eq(Vec3::ONE, 1.0e-4) {
+        info!("Ignoring scale {:?} on camera node [ {} ]", scale, node.name().unwrap_or_default());
+    }
+
+    Some(CameraDesc {
+        name: camera.name().or(node.name()).map(String::from),
+        node: Some(node.index()),
+        ..CameraDesc::new(rigid_camera_transform(node_transform), projection)
+    })
+}
+
+pub fn load_gltf_node(buffer_allocator: &RenderBufferAllocator, node: &GltfNode, buffers: &[GltfBufferData], parent_transform: Mat4, cameras: &mut Vec<CameraDesc>) -> Vec<(RenderMesh, Mat4)> {
     let node_transform = parent_transform * Mat4::from_cols_array_2d(&node.transform().matrix());
 
+    if let Some(camera) = load_gltf_camera(node, node_transform) {
+        cameras.push(camera);
+    }
+
     let mut meshes = if let Some(mesh) = node.mesh() {
         let primitives = mesh.primitives();
 
@@ -172,6 +361,7 @@ pub fn load_gltf_node(buffer_allocator: &RenderBufferAllocator, node: &GltfNode,
             match MeshBuffer::new(buffer_allocator, indices, vertices) {
                 Ok(mesh_buffer) => {
                     let material = primitive.material();
+                    let (material_type, material_property, mean_free_path) = gltf_material_type(&material);
 
                     let pbr = material.pbr_metallic_roughness();
 
@@ -192,11 +382,12 @@ pub fn load_gltf_node(buffer_allocator: &RenderBufferAllocator, node: &GltfNode,
                         occlusion_map: occlusion_index,
                         metallic_factor,
                         roughness_factor,
-                        material_type: MaterialType::default().into(),
-                        material_property: 0.0,
+                        material_type: material_type.into(),
+                        material_property,
+                        mean_free_path: mean_free_path.extend(0.0),
                     };
 
-                    meshes.push((RenderMesh::new(mesh_buffer, render_material), node_transform));
+                    meshes.push((RenderMesh::new(mesh_buffer, render_material).node(node.index()), node_transform));
                 }
                 Err(error) => {
                     error!("{}", error);
@@ -210,7 +401,7 @@ pub fn load_gltf_node(buffer_allocator: &RenderBufferAllocator, node: &GltfNode,
     };
 
     for child in node.children() {
-        meshes.extend(load_gltf_node(buffer_allocator, &child, buffers, node_transform));
+        meshes.extend(load_gltf_node(buffer_allocator, &child, buffers, node_transform, cameras));
     }
 
     meshes
@@ -243,11 +434,58 @@ pub fn load_gltf(device: WrappedDeviceRef, buffer_allocator: &RenderBufferAlloca
     }
 
     let mut meshes = vec![];
+    let mut cameras = vec![];
     for scene in gltf.scenes() {
         for node in scene.nodes() {
-            meshes.extend(load_gltf_node(buffer_allocator, &node, &buffers, Mat4::IDENTITY));
+            meshes.extend(load_gltf_node(buffer_allocator, &node, &buffers, Mat4::IDENTITY, &mut cameras));
         }
     }
 
-    Ok(RenderModel::new(meshes, textures))
+    info!("Loaded {} cameras", cameras.len());
+
+    let mut model = RenderModel::new(meshes, textures);
+    model.cameras = cameras;
+    model.animation = ModelAnimation::from_gltf(&gltf, &buffers);
+
+    Ok(model)
+}
+
+// Primitives are Lambertian unless their material extras pick another type, e.g. `"extras": { "material": "dielectric", "ior": 1.5 }`.
+// `"interface"` marks an invisible boundary between media, so it has to be asked for explicitly. `"subsurface"` takes the base color as its
+// multiple scattering albedo and needs a per channel mean free path in scene units, e.g. `"mean_free_path": [0.5, 0.25, 0.1]`
+fn gltf_material_type(material: &GltfMaterial) -> (MaterialType, f32, Vec3) {
+    let extras = material.extras().as_ref().and_then(|extras| gltf::json::deserialize::from_str::<GltfValue>(extras.get()).ok());
+    let Some(extras) = extras else {
+        return (MaterialType::default(), 0.0, Vec3::ZERO);
+    };
+
+    let material_type = match extras.get("material").and_then(GltfValue::as_str) {
+        Some("conductor") | Some("metal") => MaterialType::Conductor,
+        Some("dielectric") => MaterialType::Dielectric,
+        Some("interface") => MaterialType::Interface,
+        Some("subsurface") => MaterialType::Subsurface,
+        Some("lambertian") | None => MaterialType::Lambertian,
+        Some(other) => {
+            error!("Unknown material type {} in glTF extras, using Lambertian", other);
+            MaterialType::Lambertian
+        }
+    };
+
+    let ior = extras.get("ior").and_then(GltfValue::as_f64).unwrap_or(0.0) as f32;
+
+    let mean_free_path = extras
+        .get("mean_free_path")
+        .and_then(GltfValue::as_array)
+        .map(|values| values.iter().filter_map(GltfValue::as_f64).collect::<Vec<_>>());
+
+    let mean_free_path = match mean_free_path.as_deref() {
+        Some(&[r, g, b]) => Vec3::new(r as f32, g as f32, b as f32),
+        _ if matches!(material_type, MaterialType::Subsurface) => {
+            error!("Subsurface material without a mean_free_path of three values in glTF extras, using Lambertian");
+            return (MaterialType::Lambertian, ior, Vec3::ZERO);
+        }
+        _ => Vec3::ZERO,
+    };
+
+    (material_type, ior, mean_free_path)
 }
@@ -0,0 +1,172 @@
+use crate::output::color_space::RgbColorSpace;
+use crate::output::display;
+use crate::output::exr::{ExrAttribute, ExrImage, ExrPixelType};
+use crate::output::lut::CubeLut;
+use crate::util;
+use crate::util::OutputFormat;
+use anyhow::{bail, Result};
+use glam::{UVec2, Vec3};
+use image::{ImageBuffer, ImageFormat};
+use std::path::Path;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TransferFunction {
+    Linear,
+    Srgb,
+    Gamma(f32),
+}
+
+impl TransferFunction {
+    pub fn encode(&self, value: f32) -> f32 {
+        match *self {
+            TransferFunction::Linear => value,
+            TransferFunction::Srgb => display::srgb_oetf(value.max(0.0)),
+            TransferFunction::Gamma(gamma) => value.max(0.0).powf(1.0 / gamma),
+        }
+    }
+
+    pub fn decode(&self, value: f32) -> f32 {
+        match *self {
+            TransferFunction::Linear => value,
+            TransferFunction::Srgb => {
+                if value <= 0.04045 {
+                    value / 12.92
+                } else {
+                    ((value + 0.055) / 1.055).powf(2.4)
+                }
+            }
+            TransferFunction::Gamma(gamma) => value.max(0.0).powf(gamma),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum ColorStage {
+    // Converts from the primaries of the previous stage, the pipeline input for the first one
+    ColorSpace(RgbColorSpace),
+    Exposure(f32),
+    Lut(CubeLut),
+    Encode(TransferFunction),
+    Decode(TransferFunction),
+}
+
+// Stages run in order on the linear pixels of an `RgbFilm`, which are in the film's output color space
+#[derive(Clone, Debug)]
+pub struct ColorPipeline {
+    pub input_space: RgbColorSpace,
+    pub stages: Vec<ColorStage>,
+}
+
+impl ColorPipeline {
+    pub fn new(input_space: RgbColorSpace) -> Self {
+        Self { input_space, stages: Vec::new() }
+    }
+
+    pub fn srgb_display(input_space: RgbColorSpace) -> Self {
+        Self::new(input_space).color_space(RgbColorSpace::SRGB).encode(TransferFunction::Srgb)
+    }
+
+    pub fn color_space(mut self, color_space: RgbColorSpace) -> Self {
+        self.stages.push(ColorStage::ColorSpace(color_space));
+        self
+    }
+
+    pub fn exposure(mut self, ev: f32) -> Self {
+        self.stages.push(ColorStage::Exposure(ev));
+        self
+    }
+
+    pub fn lut(mut self, lut: CubeLut) -> Self {
+        self.stages.push(ColorStage::Lut(lut));
+        self
+    }
+
+    pub fn load_lut(self, path: impl AsRef<Path>) -> Result<Self> {
+        Ok(self.lut(CubeLut::load(path)?))
+    }
+
+    pub fn encode(mut self, transfer_function: TransferFunction) -> Self {
+        self.stages.push(ColorStage::Encode(transfer_function));
+        self
+    }
+
+    pub fn decode(mut self, transfer_function: TransferFunction) -> Self {
+        self.stages.push(ColorStage::Decode(transfer_function));
+        self
+    }
+
+    pub fn output_space(&self) -> RgbColorSpace {
+        self.stages
+            .iter()
+            .rev()
+            .find_map(|stage| match stage {
+                ColorStage::ColorSpace(color_space) => Some(*color_space),
+                _ => None,
+            })
+            .unwrap_or(self.input_space)
+    }
+
+    pub fn apply_rgb(&self, rgb: Vec3) -> Vec3 {
+        let mut rgb = if rgb.is_finite() { rgb } else { Vec3::ZERO };
+        let mut current_space = self.input_space;
+
+        for stage in &self.stages {
+            rgb = match stage {
+                ColorStage::ColorSpace(color_space) => {
+                    let converted = current_space.convert_to(color_space) * rgb;
+                    current_space = *color_space;
+                    converted
+                }
+                ColorStage::Exposure(ev) => rgb * 2.0_f32.powf(*ev),
+                ColorStage::Lut(lut) => lut.apply(rgb),
+                ColorStage::Encode(transfer_function) => Vec3::new(transfer_function.encode(rgb.x), transfer_function.encode(rgb.y), transfer_function.encode(rgb.z)),
+                ColorStage::Decode(transfer_function) => Vec3::new(transfer_function.decode(rgb.x), transfer_function.decode(rgb.y), transfer_function.decode(rgb.z)),
+            };
+        }
+
+        rgb
+    }
+
+    pub fn apply(&self, pixels: &[[f32; 4]]) -> Vec<[f32; 4]> {
+        pixels
+            .iter()
+            .map(|pixel| {
+                let rgb = self.apply_rgb(Vec3::new(pixel[0], pixel[1], pixel[2]));
+                [rgb.x, rgb.y, rgb.z, pixel[3]]
+            })
+            .collect()
+    }
+
+    // PNG output expects the chain to end in a display encoding, values are quantized as they come out
+    pub fn write(&self, path: impl AsRef<Path>, width: u32, height: u32, pixels: &[[f32; 4]], output_format: OutputFormat) -> Result<()> {
+        if (width * height) as usize != pixels.len() {
+            bail!("Image dimensions does not match pixels length: {}", pixels.len());
+        }
+
+        let processed = self.apply(pixels);
+
+        match output_format {
+            OutputFormat::Png => {
+                let quantize = |value: f32, pixel: UVec2, channel: u32| (value * 255.0 + 0.5 + display::dither_noise(pixel, channel)).clamp(0.0, 255.0) as u8;
+
+                let image = ImageBuffer::from_fn(width, height, |x, y| {
+                    let [r, g, b, a] = processed[(y * width + x) as usize];
+                    let pixel = UVec2::new(x, y);
+
+                    image::Rgba([quantize(r, pixel, 0), quantize(g, pixel, 1), quantize(b, pixel, 2), (a.clamp(0.0, 1.0) * 255.0 + 0.5) as u8])
+                });
+
+                image.save_with_format(path, ImageFormat::Png)?;
+            }
+            OutputFormat::Exr => {
+                let mut image = ExrImage::new(width, height);
+                image.add_layer("", "RGBA", ExrPixelType::Float, &processed)?;
+                image.set_attribute("chromaticities", ExrAttribute::Chromaticities(self.output_space().chromaticities()));
+                image.write(path)?;
+            }
+            output_format => util::output_image(&path, width, height, &processed, output_format)?,
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+use glam::{Mat3, Vec2, Vec3};
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RgbColorSpace {
+    pub red: Vec2,
+    pub green: Vec2,
+    pub blue: Vec2,
+    pub white: Vec2,
+}
+
+impl RgbColorSpace {
+    pub const SRGB: Self = Self::new(Vec2::new(0.64, 0.33), Vec2::new(0.3, 0.6), Vec2::new(0.15, 0.06), Vec2::new(0.3127, 0.329));
+    pub const DISPLAY_P3: Self = Self::new(Vec2::new(0.68, 0.32), Vec2::new(0.265, 0.69), Vec2::new(0.15, 0.06), Vec2::new(0.3127, 0.329));
+    pub const DCI_P3: Self = Self::new(Vec2::new(0.68, 0.32), Vec2::new(0.265, 0.69), Vec2::new(0.15, 0.06), Vec2::new(0.314, 0.351));
+    pub const REC_2020: Self = Self::new(Vec2::new(0.708, 0.292), Vec2::new(0.17, 0.797), Vec2::new(0.131, 0.046), Vec2::new(0.3127, 0.329));
+    pub const ACES2065_1: Self = Self::new(Vec2::new(0.7347, 0.2653), Vec2::new(0.0, 1.0), Vec2::new(0.0001, -0.077), Vec2::new(0.32168, 0.33767));
+    pub const ACES_CG: Self = Self::new(Vec2::new(0.713, 0.293), Vec2::new(0.165, 0.83), Vec2::new(0.128, 0.044), Vec2::new(0.32168, 0.33767));
+    pub const PROPHOTO_RGB: Self = Self::new(
+        Vec2::new(0.734699, 0.265301),
+        Vec2::new(0.159597, 0.840403),
+        Vec2::new(0.036598, 0.000105),
+        Vec2::new(0.345704, 0.358540),
+    );
+
+    pub const fn new(red: Vec2, green: Vec2, blue: Vec2, white: Vec2) -> Self {
+        Self { red, green, blue, white }
+    }
+
+    pub fn chromaticities(&self) -> [f32; 8] {
+        [self.red.x, self.red.y, self.green.x, self.green.y, self.blue.x, self.blue.y, self.white.x, self.white.y]
+    }
+
+    pub fn xyz_from_rgb(&self) -> Mat3 {
+        let xyz_from_xy = |xy: Vec2| Vec3::new(xy.x / xy.y, 1.0, (1.0 - xy.x - xy.y) / xy.y);
+
+        let rgb = Mat3::from_cols(xyz_from_xy(self.red), xyz_from_xy(self.green), xyz_from_xy(self.blue));
+        let scale = rgb.inverse() * xyz_from_xy(self.white);
+
+        rgb * Mat3::from_diagonal(scale)
+    }
+
+    pub fn rgb_from_xyz(&self) -> Mat3 {
+        self.xyz_from_rgb().inverse()
+    }
+
+    pub fn convert_to(&self, target: &RgbColorSpace) -> Mat3 {
+        target.rgb_from_xyz() * self.xyz_from_rgb()
+    }
+}
@@ -0,0 +1,268 @@
+use crate::output::color_space::RgbColorSpace;
+use glam::{Mat4, UVec2, Vec3};
+use optics_common::display;
+
+const AUTO_EXPOSURE_DELTA: f32 = 1e-4;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Exposure {
+    Ev(f32),
+    // Scales the log-average luminance of the image to `key`, 0.18 for a mid-grey rendition
+    Auto { key: f32, compensation: f32 },
+}
+
+impl Exposure {
+    pub fn auto() -> Self {
+        Exposure::Auto { key: 0.18, compensation: 0.0 }
+    }
+
+    pub fn scale(&self, pixels: &[[f32; 4]], luminance: Vec3) -> f32 {
+        match *self {
+            Exposure::Ev(ev) => 2.0_f32.powf(ev),
+            Exposure::Auto { key, compensation } => {
+                let log_average = log_average_luminance(pixels, luminance);
+
+                if log_average > 0.0 {
+                    key / log_average * 2.0_f32.powf(compensation)
+                } else {
+                    2.0_f32.powf(compensation)
+                }
+            }
+        }
+    }
+}
+
+pub fn log_average_luminance(pixels: &[[f32; 4]], luminance: Vec3) -> f32 {
+    let (log_sum, count) = pixels
+        .iter()
+        .map(|pixel| luminance.dot(Vec3::new(pixel[0], pixel[1], pixel[2])).max(0.0))
+        .filter(|value| value.is_finite())
+        .fold((0.0_f64, 0_usize), |(log_sum, count), value| (log_sum + (AUTO_EXPOSURE_DELTA + value).ln() as f64, count + 1));
+
+    // Non-finite pixels are left out of the sum, so they must not count towards the average either
+    if count == 0 {
+        return 0.0;
+    }
+
+    (log_sum / count as f64).exp() as f32
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ToneMapOperator {
+    Clamp,
+    Reinhard { white_point: f32 },
+    AcesFitted,
+    AgX,
+    Hable { white_point: f32 },
+}
+
+impl ToneMapOperator {
+    pub fn reinhard() -> Self {
+        ToneMapOperator::Reinhard { white_point: f32::INFINITY }
+    }
+
+    pub fn hable() -> Self {
+        ToneMapOperator::Hable { white_point: 11.2 }
+    }
+
+    // The fitted curves are only defined on Rec.709 primaries
+    pub fn color_space(&self, working_space: RgbColorSpace) -> RgbColorSpace {
+        match self {
+            ToneMapOperator::AcesFitted | ToneMapOperator::AgX => RgbColorSpace::SRGB,
+            _ => working_space,
+        }
+    }
+
+    fn white_point(&self) -> f32 {
+        match *self {
+            ToneMapOperator::Reinhard { white_point } | ToneMapOperator::Hable { white_point } => white_point,
+            _ => 0.0,
+        }
+    }
+
+    // Maps scene-referred linear rgb to display-referred linear rgb, in the space returned by `color_space`
+    pub fn apply(&self, rgb: Vec3, luminance: Vec3) -> Vec3 {
+        match *self {
+            ToneMapOperator::Clamp => rgb.clamp(Vec3::ZERO, Vec3::ONE),
+            ToneMapOperator::Reinhard { white_point } => Vec3::from_array(display::reinhard(rgb.to_array(), luminance.to_array(), white_point)),
+            ToneMapOperator::AcesFitted => Vec3::from_array(display::aces_fitted(rgb.to_array())),
+            ToneMapOperator::AgX => Vec3::from_array(display::agx(rgb.to_array())),
+            ToneMapOperator::Hable { white_point } => Vec3::from_array(display::hable(rgb.to_array(), white_point)),
+        }
+    }
+}
+
+impl Into<u32> for ToneMapOperator {
+    fn into(self) -> u32 {
+        match self {
+            ToneMapOperator::Clamp => 0,
+            ToneMapOperator::Reinhard { .. } => 1,
+            ToneMapOperator::AcesFitted => 2,
+            ToneMapOperator::AgX => 3,
+            ToneMapOperator::Hable { .. } => 4,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum DisplayEncoding {
+    Srgb,
+    DisplayP3,
+}
+
+impl DisplayEncoding {
+    pub fn color_space(&self) -> RgbColorSpace {
+        match self {
+            DisplayEncoding::Srgb => RgbColorSpace::SRGB,
+            DisplayEncoding::DisplayP3 => RgbColorSpace::DISPLAY_P3,
+        }
+    }
+}
+
+impl Into<u32> for DisplayEncoding {
+    fn into(self) -> u32 {
+        match self {
+            DisplayEncoding::Srgb => 0,
+            DisplayEncoding::DisplayP3 => 1,
+        }
+    }
+}
+
+// The curves live in `optics_common::display` so the host and the display shader stay in step
+pub fn srgb_oetf(value: f32) -> f32 {
+    display::srgb_oetf(value)
+}
+
+pub fn gamut_map(rgb: Vec3, luminance: Vec3) -> Vec3 {
+    Vec3::from_array(display::gamut_map(rgb.to_array(), luminance.to_array()))
+}
+
+pub fn dither_noise(pixel: UVec2, channel: u32) -> f32 {
+    display::dither_noise(pixel.to_array(), channel)
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct DisplayTransform {
+    pub exposure: Exposure,
+    pub operator: ToneMapOperator,
+    pub working_space: RgbColorSpace,
+    pub encoding: DisplayEncoding,
+    pub dither: bool,
+}
+
+impl Default for DisplayTransform {
+    fn default() -> Self {
+        Self {
+            exposure: Exposure::Ev(0.0),
+            operator: ToneMapOperator::Clamp,
+            working_space: RgbColorSpace::SRGB,
+            encoding: DisplayEncoding::Srgb,
+            dither: true,
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct DisplayParameters {
+    pub tone_map_from_working: Mat4,
+    pub display_from_tone_map: Mat4,
+    pub tone_map_luminance: Vec3,
+    pub exposure_scale: f32,
+    pub display_luminance: Vec3,
+    pub white_point: f32,
+    pub resolution: UVec2,
+    pub operator: u32,
+    pub dither: u32,
+}
+
+impl DisplayTransform {
+    pub fn exposure(mut self, exposure: Exposure) -> Self {
+        self.exposure = exposure;
+        self
+    }
+
+    pub fn operator(mut self, operator: ToneMapOperator) -> Self {
+        self.operator = operator;
+        self
+    }
+
+    pub fn working_space(mut self, working_space: RgbColorSpace) -> Self {
+        self.working_space = working_space;
+        self
+    }
+
+    pub fn encoding(mut self, encoding: DisplayEncoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    pub fn dither(mut self, dither: bool) -> Self {
+        self.dither = dither;
+        self
+    }
+
+    fn working_luminance(&self) -> Vec3 {
+        self.working_space.xyz_from_rgb().row(1)
+    }
+
+    fn tone_map_space(&self) -> RgbColorSpace {
+        self.operator.color_space(self.working_space)
+    }
+
+    fn display_luminance(&self) -> Vec3 {
+        self.encoding.color_space().xyz_from_rgb().row(1)
+    }
+
+    pub fn exposure_scale(&self, pixels: &[[f32; 4]]) -> f32 {
+        self.exposure.scale(pixels, self.working_luminance())
+    }
+
+    // Returns display-encoded values in [0, 1] before quantization
+    pub fn encode(&self, rgb: Vec3, exposure_scale: f32) -> Vec3 {
+        let rgb = if rgb.is_finite() { rgb * exposure_scale } else { Vec3::ZERO };
+
+        let tone_map_space = self.tone_map_space();
+        let rgb = self.working_space.convert_to(&tone_map_space) * rgb;
+
+        let mapped = self.operator.apply(rgb, tone_map_space.xyz_from_rgb().row(1));
+        let display = gamut_map(tone_map_space.convert_to(&self.encoding.color_space()) * mapped, self.display_luminance());
+
+        Vec3::new(srgb_oetf(display.x), srgb_oetf(display.y), srgb_oetf(display.z))
+    }
+
+    pub fn apply(&self, pixels: &[[f32; 4]], width: u32, height: u32) -> Vec<[u8; 4]> {
+        let exposure_scale = self.exposure_scale(pixels);
+
+        (0..height)
+            .flat_map(|y| (0..width).map(move |x| UVec2::new(x, y)))
+            .zip(pixels)
+            .map(|(pixel, value)| {
+                let encoded = self.encode(Vec3::new(value[0], value[1], value[2]), exposure_scale);
+                let alpha = if value[3].is_finite() { value[3].clamp(0.0, 1.0) } else { 1.0 };
+
+                let quantize = |value: f32, channel: u32| {
+                    let noise = if self.dither { dither_noise(pixel, channel) } else { 0.0 };
+                    (value * 255.0 + 0.5 + noise).clamp(0.0, 255.0) as u8
+                };
+
+                [quantize(encoded.x, 0), quantize(encoded.y, 1), quantize(encoded.z, 2), (alpha * 255.0 + 0.5) as u8]
+            })
+            .collect()
+    }
+
+    // The compute pass can't reduce the image for auto exposure, so the host resolves the scale up front
+    pub fn to_parameters(&self, resolution: UVec2, exposure_scale: f32) -> DisplayParameters {
+        DisplayParameters {
+            tone_map_from_working: Mat4::from_mat3(self.working_space.convert_to(&self.tone_map_space())),
+            display_from_tone_map: Mat4::from_mat3(self.tone_map_space().convert_to(&self.encoding.color_space())),
+            tone_map_luminance: self.tone_map_space().xyz_from_rgb().row(1),
+            exposure_scale,
+            display_luminance: self.display_luminance(),
+            white_point: self.operator.white_point(),
+            resolution,
+            operator: self.operator.into(),
+            dither: self.dither as u32,
+        }
+    }
+}
@@ -0,0 +1,539 @@
+use crate::output::color_space::RgbColorSpace;
+use anyhow::{anyhow, bail, Result};
+use glam::{IVec2, Mat4, Vec2};
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+const MAGIC_NUMBER: u32 = 20000630;
+const VERSION: u32 = 2;
+const LONG_NAMES_FLAG: u32 = 0x400;
+const SHORT_NAME_LENGTH: usize = 31;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ExrPixelType {
+    Half,
+    Float,
+}
+
+impl Into<i32> for ExrPixelType {
+    fn into(self) -> i32 {
+        match self {
+            ExrPixelType::Half => 1,
+            ExrPixelType::Float => 2,
+        }
+    }
+}
+
+impl ExrPixelType {
+    fn size(&self) -> usize {
+        match self {
+            ExrPixelType::Half => 2,
+            ExrPixelType::Float => 4,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum ExrAttribute {
+    Int(i32),
+    Float(f32),
+    Double(f64),
+    String(String),
+    V2f(Vec2),
+    M44f(Mat4),
+    Chromaticities([f32; 8]),
+}
+
+impl ExrAttribute {
+    fn type_name(&self) -> &'static str {
+        match self {
+            ExrAttribute::Int(_) => "int",
+            ExrAttribute::Float(_) => "float",
+            ExrAttribute::Double(_) => "double",
+            ExrAttribute::String(_) => "string",
+            ExrAttribute::V2f(_) => "v2f",
+            ExrAttribute::M44f(_) => "m44f",
+            ExrAttribute::Chromaticities(_) => "chromaticities",
+        }
+    }
+
+    fn value_bytes(&self) -> Vec<u8> {
+        match self {
+            ExrAttribute::Int(value) => value.to_le_bytes().to_vec(),
+            ExrAttribute::Float(value) => value.to_le_bytes().to_vec(),
+            ExrAttribute::Double(value) => value.to_le_bytes().to_vec(),
+            ExrAttribute::String(value) => value.as_bytes().to_vec(),
+            ExrAttribute::V2f(value) => value.to_array().iter().flat_map(|v| v.to_le_bytes()).collect(),
+            // Imath stores matrices row-major with the translation in the last row, which is glam's column-major layout
+            ExrAttribute::M44f(value) => value.to_cols_array().iter().flat_map(|v| v.to_le_bytes()).collect(),
+            ExrAttribute::Chromaticities(value) => value.iter().flat_map(|v| v.to_le_bytes()).collect(),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ExrWindow {
+    pub min: IVec2,
+    pub max: IVec2,
+}
+
+impl ExrWindow {
+    pub fn from_size(width: u32, height: u32) -> Self {
+        Self {
+            min: IVec2::ZERO,
+            max: IVec2::new(width as i32 - 1, height as i32 - 1),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        (self.max.x - self.min.x + 1) as usize
+    }
+
+    pub fn height(&self) -> usize {
+        (self.max.y - self.min.y + 1) as usize
+    }
+
+    fn to_bytes(self) -> Vec<u8> {
+        [self.min.x, self.min.y, self.max.x, self.max.y].iter().flat_map(|v| v.to_le_bytes()).collect()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ExrChannel {
+    pub name: String,
+    pub pixel_type: ExrPixelType,
+    pub values: Vec<f32>,
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct RenderMetadata {
+    pub world_to_camera: Mat4,
+    pub world_to_ndc: Mat4,
+    pub samples_per_pixel: u32,
+    pub render_time: Duration,
+    pub color_space: RgbColorSpace,
+}
+
+#[derive(Clone, Debug)]
+pub struct ExrImage {
+    pub data_window: ExrWindow,
+    pub display_window: ExrWindow,
+    pub channels: Vec<ExrChannel>,
+    pub attributes: Vec<(String, ExrAttribute)>,
+}
+
+impl ExrImage {
+    pub fn new(width: u32, height: u32) -> Self {
+        Self::with_windows(ExrWindow::from_size(width, height), ExrWindow::from_size(width, height))
+    }
+
+    pub fn with_windows(data_window: ExrWindow, display_window: ExrWindow) -> Self {
+        Self {
+            data_window,
+            display_window,
+            channels: Vec::new(),
+            attributes: Vec::new(),
+        }
+    }
+
+    pub fn add_channel(&mut self, name: impl Into<String>, pixel_type: ExrPixelType, values: Vec<f32>) -> Result<()> {
+        let name = name.into();
+
+        if values.len() != self.data_window.width() * self.data_window.height() {
+            bail!(
+                "Channel {} has {} values but the data window holds {}",
+                name,
+                values.len(),
+                self.data_window.width() * self.data_window.height()
+            );
+        }
+
+        if name.is_empty() || self.channels.iter().any(|channel| channel.name == name) {
+            bail!("Channel name {:?} is empty or already used", name);
+        }
+
+        self.channels.push(ExrChannel { name, pixel_type, values });
+
+        Ok(())
+    }
+
+    // Channel names are taken from `components`, e.g. "RGBA" or "XYZ", and prefixed with the layer name unless it is empty
+    pub fn add_layer(&mut self, layer: &str, components: &str, pixel_type: ExrPixelType, pixels: &[[f32; 4]]) -> Result<()> {
+        if components.is_empty() || components.len() > 4 {
+            bail!("Layer {} expects between one and four components, found {:?}", layer, components);
+        }
+
+        for (index, component) in components.chars().enumerate() {
+            let name = if layer.is_empty() { component.to_string() } else { format!("{}.{}", layer, component) };
+            self.add_channel(name, pixel_type, pixels.iter().map(|pixel| pixel[index]).collect())?;
+        }
+
+        Ok(())
+    }
+
+    // Follows the spectral OpenEXR naming convention of emissive bands, e.g. "S0.550,000000nm"
+    pub fn add_spectral_bands(&mut self, wavelengths: &[f32], bands: Vec<Vec<f32>>, pixel_type: ExrPixelType) -> Result<()> {
+        if wavelengths.len() != bands.len() {
+            bail!("Expected {} spectral bands, found {}", wavelengths.len(), bands.len());
+        }
+
+        for (lambda, values) in wavelengths.iter().zip(bands) {
+            let name = format!("S0.{:.6}nm", lambda).replace('.', ",").replacen(',', ".", 1);
+            self.add_channel(name, pixel_type, values)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn set_attribute(&mut self, name: impl Into<String>, value: ExrAttribute) {
+        let name = name.into();
+
+        match self.attributes.iter_mut().find(|(attribute_name, _)| *attribute_name == name) {
+            Some((_, attribute)) => *attribute = value,
+            None => self.attributes.push((name, value)),
+        }
+    }
+
+    pub fn set_render_metadata(&mut self, metadata: &RenderMetadata) {
+        self.set_attribute("worldToCamera", ExrAttribute::M44f(metadata.world_to_camera));
+        self.set_attribute("worldToNDC", ExrAttribute::M44f(metadata.world_to_ndc));
+        self.set_attribute("samplesPerPixel", ExrAttribute::Int(metadata.samples_per_pixel as i32));
+        self.set_attribute("renderTimeSeconds", ExrAttribute::Float(metadata.render_time.as_secs_f32()));
+        self.set_attribute("chromaticities", ExrAttribute::Chromaticities(metadata.color_space.chromaticities()));
+    }
+
+    pub fn attribute(&self, name: &str) -> Option<&ExrAttribute> {
+        self.attributes.iter().find(|(attribute_name, _)| attribute_name == name).map(|(_, attribute)| attribute)
+    }
+
+    pub fn channel(&self, name: &str) -> Option<&ExrChannel> {
+        self.channels.iter().find(|channel| channel.name == name)
+    }
+
+    // Gathers up to four channels by name, missing ones read as zero except alpha which reads as one
+    pub fn layer_pixels(&self, layer: &str, components: &str) -> Vec<[f32; 4]> {
+        let mut pixels = vec![[0.0, 0.0, 0.0, 1.0]; self.data_window.width() * self.data_window.height()];
+
+        for (index, component) in components.chars().take(4).enumerate() {
+            let name = if layer.is_empty() { component.to_string() } else { format!("{}.{}", layer, component) };
+
+            if let Some(channel) = self.channel(&name) {
+                for (pixel, &value) in pixels.iter_mut().zip(&channel.values) {
+                    pixel[index] = value;
+                }
+            }
+        }
+
+        pixels
+    }
+
+    pub fn read(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_bytes(&fs::read(path)?)
+    }
+
+    // Reads the scanline, uncompressed subset of OpenEXR that `to_bytes` produces
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let mut reader = ExrReader { bytes, offset: 0 };
+
+        if reader.u32()? != MAGIC_NUMBER {
+            bail!("Not an OpenEXR file");
+        }
+
+        let version = reader.u32()?;
+        if version & 0xff != VERSION || version & !(0xff | LONG_NAMES_FLAG) != 0 {
+            bail!("Unsupported OpenEXR version or flags {:#x}, only single part scanline images are read", version);
+        }
+
+        let mut channel_types = Vec::new();
+        let mut data_window = None;
+        let mut display_window = None;
+        let mut attributes = Vec::new();
+
+        loop {
+            let name = reader.null_terminated()?;
+
+            if name.is_empty() {
+                break;
+            }
+
+            let type_name = reader.null_terminated()?;
+            let size = reader.i32()? as usize;
+            let value = reader.take(size)?;
+
+            match name.as_str() {
+                "channels" => channel_types = parse_channel_list(value)?,
+                "compression" if value != [0] => bail!("Compressed OpenEXR files are not supported"),
+                "dataWindow" => data_window = Some(parse_window(value)?),
+                "displayWindow" => display_window = Some(parse_window(value)?),
+                "compression" | "lineOrder" | "pixelAspectRatio" | "screenWindowCenter" | "screenWindowWidth" => {}
+                _ => {
+                    if let Some(attribute) = parse_attribute(&type_name, value) {
+                        attributes.push((name, attribute));
+                    }
+                }
+            }
+        }
+
+        let data_window = data_window.ok_or_else(|| anyhow!("OpenEXR header has no data window"))?;
+        let mut image = Self::with_windows(data_window, display_window.unwrap_or(data_window));
+        image.attributes = attributes;
+
+        let (width, height) = (data_window.width(), data_window.height());
+        let mut values = channel_types.iter().map(|_| Vec::with_capacity(width * height)).collect::<Vec<_>>();
+
+        let offsets = (0..height).map(|_| reader.u64()).collect::<Result<Vec<_>>>()?;
+
+        for offset in offsets {
+            reader.offset = offset as usize;
+            reader.take(8)?;
+
+            for ((_, pixel_type), values) in channel_types.iter().zip(&mut values) {
+                for _ in 0..width {
+                    values.push(match pixel_type {
+                        ExrPixelType::Half => f16_to_f32(u16::from_le_bytes(reader.take(2)?.try_into()?)),
+                        ExrPixelType::Float => f32::from_le_bytes(reader.take(4)?.try_into()?),
+                    });
+                }
+            }
+        }
+
+        for ((name, pixel_type), values) in channel_types.into_iter().zip(values) {
+            image.add_channel(name, pixel_type, values)?;
+        }
+
+        Ok(image)
+    }
+
+    pub fn write(&self, path: impl AsRef<Path>) -> Result<()> {
+        fs::write(path, self.to_bytes()?)?;
+        Ok(())
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        if self.channels.is_empty() {
+            bail!("OpenEXR image has no channels");
+        }
+
+        let mut channels = self.channels.iter().collect::<Vec<_>>();
+        channels.sort_by(|a, b| a.name.as_bytes().cmp(b.name.as_bytes()));
+
+        let long_names = channels
+            .iter()
+            .map(|channel| channel.name.len())
+            .chain(self.attributes.iter().map(|(name, _)| name.len()))
+            .any(|length| length > SHORT_NAME_LENGTH);
+
+        let mut bytes = Vec::new();
+        bytes.extend(MAGIC_NUMBER.to_le_bytes());
+        bytes.extend((VERSION | if long_names { LONG_NAMES_FLAG } else { 0 }).to_le_bytes());
+
+        let mut channel_list = Vec::new();
+        for channel in &channels {
+            channel_list.extend(channel.name.as_bytes());
+            channel_list.push(0);
+            channel_list.extend(<ExrPixelType as Into<i32>>::into(channel.pixel_type).to_le_bytes());
+            channel_list.extend([0, 0, 0, 0]);
+            channel_list.extend(1_i32.to_le_bytes());
+            channel_list.extend(1_i32.to_le_bytes());
+        }
+        channel_list.push(0);
+
+        write_header_attribute(&mut bytes, "channels", "chlist", &channel_list);
+        write_header_attribute(&mut bytes, "compression", "compression", &[0]);
+        write_header_attribute(&mut bytes, "dataWindow", "box2i", &self.data_window.to_bytes());
+        write_header_attribute(&mut bytes, "displayWindow", "box2i", &self.display_window.to_bytes());
+        write_header_attribute(&mut bytes, "lineOrder", "lineOrder", &[0]);
+        write_header_attribute(&mut bytes, "pixelAspectRatio", "float", &1.0_f32.to_le_bytes());
+        write_header_attribute(&mut bytes, "screenWindowCenter", "v2f", &[0.0_f32.to_le_bytes(), 0.0_f32.to_le_bytes()].concat());
+        write_header_attribute(&mut bytes, "screenWindowWidth", "float", &1.0_f32.to_le_bytes());
+
+        for (name, attribute) in &self.attributes {
+            write_header_attribute(&mut bytes, name, attribute.type_name(), &attribute.value_bytes());
+        }
+
+        bytes.push(0);
+
+        let width = self.data_window.width();
+        let height = self.data_window.height();
+        let line_size = channels.iter().map(|channel| channel.pixel_type.size() * width).sum::<usize>();
+
+        let offset_table_start = bytes.len();
+        let first_chunk = offset_table_start + height * 8;
+
+        for line in 0..height {
+            bytes.extend(((first_chunk + line * (line_size + 8)) as u64).to_le_bytes());
+        }
+
+        for line in 0..height {
+            bytes.extend((self.data_window.min.y + line as i32).to_le_bytes());
+            bytes.extend((line_size as i32).to_le_bytes());
+
+            for channel in &channels {
+                for &value in &channel.values[line * width..(line + 1) * width] {
+                    match channel.pixel_type {
+                        ExrPixelType::Half => bytes.extend(f32_to_f16(value).to_le_bytes()),
+                        ExrPixelType::Float => bytes.extend(value.to_le_bytes()),
+                    }
+                }
+            }
+        }
+
+        Ok(bytes)
+    }
+}
+
+struct ExrReader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> ExrReader<'a> {
+    fn take(&mut self, count: usize) -> Result<&'a [u8]> {
+        let end = self.offset + count;
+
+        if end > self.bytes.len() {
+            bail!("Unexpected end of OpenEXR data at byte {}", self.offset);
+        }
+
+        let slice = &self.bytes[self.offset..end];
+        self.offset = end;
+
+        Ok(slice)
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into()?))
+    }
+
+    fn i32(&mut self) -> Result<i32> {
+        Ok(i32::from_le_bytes(self.take(4)?.try_into()?))
+    }
+
+    fn u64(&mut self) -> Result<u64> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into()?))
+    }
+
+    fn null_terminated(&mut self) -> Result<String> {
+        let length = self.bytes[self.offset..].iter().position(|&byte| byte == 0).ok_or_else(|| anyhow!("Unterminated OpenEXR string"))?;
+        let string = String::from_utf8(self.take(length)?.to_vec())?;
+        self.take(1)?;
+
+        Ok(string)
+    }
+}
+
+fn parse_channel_list(value: &[u8]) -> Result<Vec<(String, ExrPixelType)>> {
+    let mut reader = ExrReader { bytes: value, offset: 0 };
+    let mut channels = Vec::new();
+
+    loop {
+        let name = reader.null_terminated()?;
+
+        if name.is_empty() {
+            return Ok(channels);
+        }
+
+        let pixel_type = match reader.i32()? {
+            1 => ExrPixelType::Half,
+            2 => ExrPixelType::Float,
+            other => bail!("Unsupported OpenEXR pixel type {} of channel {}", other, name),
+        };
+
+        reader.take(4)?;
+
+        if reader.i32()? != 1 || reader.i32()? != 1 {
+            bail!("Subsampled OpenEXR channel {} is not supported", name);
+        }
+
+        channels.push((name, pixel_type));
+    }
+}
+
+fn parse_window(value: &[u8]) -> Result<ExrWindow> {
+    let mut reader = ExrReader { bytes: value, offset: 0 };
+
+    let min = IVec2::new(reader.i32()?, reader.i32()?);
+    let max = IVec2::new(reader.i32()?, reader.i32()?);
+
+    Ok(ExrWindow { min, max })
+}
+
+fn parse_attribute(type_name: &str, value: &[u8]) -> Option<ExrAttribute> {
+    let floats = value.chunks_exact(4).map(|chunk| f32::from_le_bytes(chunk.try_into().unwrap())).collect::<Vec<_>>();
+
+    match (type_name, value.len()) {
+        ("int", 4) => Some(ExrAttribute::Int(i32::from_le_bytes(value.try_into().ok()?))),
+        ("float", 4) => Some(ExrAttribute::Float(floats[0])),
+        ("double", 8) => Some(ExrAttribute::Double(f64::from_le_bytes(value.try_into().ok()?))),
+        ("string", _) => Some(ExrAttribute::String(String::from_utf8(value.to_vec()).ok()?)),
+        ("v2f", 8) => Some(ExrAttribute::V2f(Vec2::from_slice(&floats))),
+        ("m44f", 64) => Some(ExrAttribute::M44f(Mat4::from_cols_slice(&floats))),
+        ("chromaticities", 32) => Some(ExrAttribute::Chromaticities(floats.try_into().ok()?)),
+        _ => None,
+    }
+}
+
+fn write_header_attribute(bytes: &mut Vec<u8>, name: &str, type_name: &str, value: &[u8]) {
+    bytes.extend(name.as_bytes());
+    bytes.push(0);
+    bytes.extend(type_name.as_bytes());
+    bytes.push(0);
+    bytes.extend((value.len() as i32).to_le_bytes());
+    bytes.extend(value);
+}
+
+pub fn f32_to_f16(value: f32) -> u16 {
+    let bits = value.to_bits();
+    let sign = ((bits >> 16) & 0x8000) as u16;
+    let exponent = ((bits >> 23) & 0xff) as i32;
+    let mantissa = bits & 0x7f_ffff;
+
+    if exponent == 0xff {
+        return sign | 0x7c00 | if mantissa != 0 { 0x200 } else { 0 };
+    }
+
+    let half_exponent = exponent - 127 + 15;
+
+    if half_exponent >= 0x1f {
+        return sign | 0x7c00;
+    }
+
+    // Round to nearest even: round up when the dropped half is set and either the sticky bits or the kept lowest bit are set
+    let round = |mantissa: u32, shift: u32| -> u16 {
+        let round_bit = 1 << (shift - 1);
+        ((mantissa & round_bit) != 0 && (mantissa & (3 * round_bit - 1)) != 0) as u16
+    };
+
+    if half_exponent <= 0 {
+        if half_exponent < -10 {
+            return sign;
+        }
+
+        let mantissa = mantissa | 0x80_0000;
+        let shift = (14 - half_exponent) as u32;
+
+        return sign | ((mantissa >> shift) as u16 + round(mantissa, shift));
+    }
+
+    (sign | ((half_exponent as u16) << 10) | (mantissa >> 13) as u16) + round(mantissa, 13)
+}
+
+pub fn f16_to_f32(value: u16) -> f32 {
+    let sign = ((value & 0x8000) as u32) << 16;
+    let exponent = ((value >> 10) & 0x1f) as u32;
+    let mantissa = (value & 0x3ff) as u32;
+
+    let bits = match (exponent, mantissa) {
+        (0, 0) => sign,
+        (0, _) => {
+            // Subnormal halves are normal floats, shift the mantissa up until its leading bit becomes implicit
+            let shift = mantissa.leading_zeros() - 21;
+            sign | ((127 - 15 + 1 - shift) << 23) | ((mantissa << shift) & 0x3ff) << 13
+        }
+        (0x1f, _) => sign | 0x7f80_0000 | (mantissa << 13),
+        _ => sign | ((exponent + 127 - 15) << 23) | (mantissa << 13),
+    };
+
+    f32::from_bits(bits)
+}
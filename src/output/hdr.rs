@@ -0,0 +1,259 @@
+use crate::output::color_space::RgbColorSpace;
+use crate::output::display;
+use anyhow::{bail, Result};
+use glam::Vec3;
+use image::codecs::png::{PngDecoder, PngEncoder};
+use image::{ColorType, ImageDecoder, ImageEncoder, ImageFormat};
+use std::fs;
+use std::io::Cursor;
+use std::path::Path;
+
+const PQ_M1: f32 = 2610.0 / 16384.0;
+const PQ_M2: f32 = 2523.0 / 4096.0 * 128.0;
+const PQ_C1: f32 = 3424.0 / 4096.0;
+const PQ_C2: f32 = 2413.0 / 4096.0 * 32.0;
+const PQ_C3: f32 = 2392.0 / 4096.0 * 32.0;
+const PQ_MAX_LUMINANCE: f32 = 10000.0;
+
+const HLG_A: f32 = 0.17883277;
+const HLG_B: f32 = 0.28466892;
+const HLG_C: f32 = 0.55991073;
+
+// SMPTE ST 2084 inverse EOTF, absolute luminance in cd/m^2 to a signal in [0, 1]
+pub fn pq_inverse_eotf(luminance: f32) -> f32 {
+    let y = (luminance / PQ_MAX_LUMINANCE).clamp(0.0, 1.0).powf(PQ_M1);
+
+    ((PQ_C1 + PQ_C2 * y) / (1.0 + PQ_C3 * y)).powf(PQ_M2)
+}
+
+pub fn pq_eotf(signal: f32) -> f32 {
+    let e = signal.clamp(0.0, 1.0).powf(1.0 / PQ_M2);
+
+    PQ_MAX_LUMINANCE * ((e - PQ_C1).max(0.0) / (PQ_C2 - PQ_C3 * e)).powf(1.0 / PQ_M1)
+}
+
+// ITU-R BT.2100 HLG OETF, normalized scene light in [0, 1]
+pub fn hlg_oetf(value: f32) -> f32 {
+    let value = value.clamp(0.0, 1.0);
+
+    if value <= 1.0 / 12.0 {
+        (3.0 * value).sqrt()
+    } else {
+        HLG_A * (12.0 * value - HLG_B).ln() + HLG_C
+    }
+}
+
+pub fn hlg_system_gamma(peak_luminance: f32) -> f32 {
+    1.2 + 0.42 * (peak_luminance / 1000.0).log10()
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum HdrEncoding {
+    Pq,
+    Hlg,
+}
+
+impl HdrEncoding {
+    // Transfer characteristics code point of ITU-T H.273
+    fn transfer_characteristics(&self) -> u8 {
+        match self {
+            HdrEncoding::Pq => 16,
+            HdrEncoding::Hlg => 18,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum HdrBitDepth {
+    Ten,
+    Sixteen,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum HdrContainer {
+    Png,
+    Tiff,
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct HdrDisplayOutput {
+    pub encoding: HdrEncoding,
+    pub peak_luminance: f32,
+    // Luminance of a linear value of 1.0, 203 cd/m^2 is the BT.2408 reference white
+    pub reference_white: f32,
+    pub working_space: RgbColorSpace,
+    pub bit_depth: HdrBitDepth,
+    pub container: HdrContainer,
+}
+
+impl Default for HdrDisplayOutput {
+    fn default() -> Self {
+        Self {
+            encoding: HdrEncoding::Pq,
+            peak_luminance: 1000.0,
+            reference_white: 203.0,
+            working_space: RgbColorSpace::SRGB,
+            bit_depth: HdrBitDepth::Sixteen,
+            container: HdrContainer::Png,
+        }
+    }
+}
+
+impl HdrDisplayOutput {
+    pub fn pq() -> Self {
+        Self::default()
+    }
+
+    pub fn hlg() -> Self {
+        Self {
+            encoding: HdrEncoding::Hlg,
+            ..Default::default()
+        }
+    }
+
+    pub fn peak_luminance(mut self, peak_luminance: f32) -> Self {
+        self.peak_luminance = peak_luminance.max(1.0);
+        self
+    }
+
+    pub fn reference_white(mut self, reference_white: f32) -> Self {
+        self.reference_white = reference_white.max(0.0);
+        self
+    }
+
+    pub fn working_space(mut self, working_space: RgbColorSpace) -> Self {
+        self.working_space = working_space;
+        self
+    }
+
+    pub fn bit_depth(mut self, bit_depth: HdrBitDepth) -> Self {
+        self.bit_depth = bit_depth;
+        self
+    }
+
+    pub fn container(mut self, container: HdrContainer) -> Self {
+        self.container = container;
+        self
+    }
+
+    // Returns the non-linear Rec.2020 signal in [0, 1]
+    pub fn encode(&self, rgb: Vec3) -> Vec3 {
+        let rgb = if rgb.is_finite() { rgb } else { Vec3::ZERO };
+
+        let rec_2020 = RgbColorSpace::REC_2020;
+        let luminance = rec_2020.xyz_from_rgb().row(1);
+
+        // Display light relative to the peak, clipped into the Rec.2020 gamut without shifting hue
+        let relative = self.working_space.convert_to(&rec_2020) * rgb * (self.reference_white / self.peak_luminance);
+        let relative = display::gamut_map(relative, luminance);
+
+        match self.encoding {
+            HdrEncoding::Pq => (relative * self.peak_luminance).to_array().map(pq_inverse_eotf).into(),
+            HdrEncoding::Hlg => {
+                let gamma = hlg_system_gamma(self.peak_luminance);
+                let display_luminance = luminance.dot(relative);
+
+                // Inverse of the HLG OOTF, so the reference display reproduces `relative` at its nominal peak
+                let scene = if display_luminance > 0.0 {
+                    relative * display_luminance.powf((1.0 - gamma) / gamma)
+                } else {
+                    Vec3::ZERO
+                };
+
+                scene.to_array().map(hlg_oetf).into()
+            }
+        }
+    }
+
+    fn quantize(&self, value: f32) -> u16 {
+        match self.bit_depth {
+            HdrBitDepth::Ten => {
+                let code = (value * 1023.0 + 0.5).clamp(0.0, 1023.0) as u16;
+                code << 6 | code >> 4
+            }
+            HdrBitDepth::Sixteen => (value * 65535.0 + 0.5).clamp(0.0, 65535.0) as u16,
+        }
+    }
+
+    // Interleaved RGB samples in native endian order, as they are handed to the encoder
+    pub fn quantized_samples(&self, pixels: &[[f32; 4]]) -> Vec<u16> {
+        pixels
+            .iter()
+            .flat_map(|pixel| self.encode(Vec3::new(pixel[0], pixel[1], pixel[2])).to_array())
+            .map(|value| self.quantize(value))
+            .collect()
+    }
+
+    pub fn write(&self, path: impl AsRef<Path>, width: u32, height: u32, pixels: &[[f32; 4]]) -> Result<()> {
+        if (width * height) as usize != pixels.len() {
+            bail!("Image dimensions does not match pixels length: {}", pixels.len());
+        }
+
+        let samples = self.quantized_samples(pixels);
+        let bytes = bytemuck::cast_slice::<u16, u8>(&samples);
+
+        match self.container {
+            HdrContainer::Png => {
+                // `write_image` takes native endian samples and swaps them to the big endian order PNG stores
+                let mut png = Vec::new();
+                PngEncoder::new(&mut png).write_image(bytes, width, height, ColorType::Rgb16)?;
+                let png = insert_png_cicp_chunk(png, self.encoding)?;
+                fs::write(path, png)?;
+            }
+            HdrContainer::Tiff => image::save_buffer_with_format(path, bytes, width, height, ColorType::Rgb16, ImageFormat::Tiff)?,
+        }
+
+        Ok(())
+    }
+}
+
+// Decodes the file the way a viewer would, which catches byte order mistakes in the 16 bit samples
+pub fn decode_png_samples(png: &[u8], width: u32, height: u32) -> Result<Vec<u16>> {
+    let decoder = PngDecoder::new(Cursor::new(png))?;
+
+    if decoder.dimensions() != (width, height) || decoder.color_type() != ColorType::Rgb16 {
+        bail!("Encoded PNG is {:?} {:?} instead of {}x{} Rgb16", decoder.dimensions(), decoder.color_type(), width, height);
+    }
+
+    let mut samples = vec![0_u16; (width * height * 3) as usize];
+    decoder.read_image(bytemuck::cast_slice_mut(&mut samples))?;
+
+    Ok(samples)
+}
+
+// The cICP chunk tags the file as BT.2020 primaries with a PQ or HLG transfer, full range RGB, so HDR aware viewers decode it
+fn insert_png_cicp_chunk(png: Vec<u8>, encoding: HdrEncoding) -> Result<Vec<u8>> {
+    const SIGNATURE_LENGTH: usize = 8;
+    const IHDR_CHUNK_LENGTH: usize = 4 + 4 + 13 + 4;
+
+    if png.len() < SIGNATURE_LENGTH + IHDR_CHUNK_LENGTH || &png[SIGNATURE_LENGTH + 4..SIGNATURE_LENGTH + 8] != b"IHDR" {
+        bail!("PNG encoder output does not start with an IHDR chunk");
+    }
+
+    let mut chunk_data = b"cICP".to_vec();
+    chunk_data.extend([9, encoding.transfer_characteristics(), 0, 1]);
+
+    let insert_at = SIGNATURE_LENGTH + IHDR_CHUNK_LENGTH;
+    let mut output = Vec::with_capacity(png.len() + 16);
+    output.extend(&png[..insert_at]);
+    output.extend(4_u32.to_be_bytes());
+    output.extend(&chunk_data);
+    output.extend(crc32(&chunk_data).to_be_bytes());
+    output.extend(&png[insert_at..]);
+
+    Ok(output)
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = 0xffff_ffff_u32;
+
+    for &byte in bytes {
+        crc ^= byte as u32;
+
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 { 0xedb8_8320 ^ (crc >> 1) } else { crc >> 1 };
+        }
+    }
+
+    !crc
+}
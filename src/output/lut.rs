@@ -0,0 +1,208 @@
+use anyhow::{anyhow, bail, Result};
+use glam::{UVec3, Vec3};
+use log::warn;
+use std::fs;
+use std::path::Path;
+
+const MAX_LUT_1D_SIZE: usize = 65536;
+const MAX_LUT_3D_SIZE: usize = 256;
+
+#[derive(Clone, Debug)]
+pub struct Lut1D {
+    pub domain_min: Vec3,
+    pub domain_max: Vec3,
+    pub table: Vec<Vec3>,
+}
+
+impl Lut1D {
+    pub fn apply(&self, rgb: Vec3) -> Vec3 {
+        let last = (self.table.len() - 1) as f32;
+        let position = ((rgb - self.domain_min) / (self.domain_max - self.domain_min)).clamp(Vec3::ZERO, Vec3::ONE) * last;
+
+        let channel = |position: f32, c: usize| {
+            let i = (position as usize).min(self.table.len() - 2);
+            let t = position - i as f32;
+
+            self.table[i][c] * (1.0 - t) + self.table[i + 1][c] * t
+        };
+
+        Vec3::new(channel(position.x, 0), channel(position.y, 1), channel(position.z, 2))
+    }
+}
+
+// Entries are stored with red changing fastest, as in the file
+#[derive(Clone, Debug)]
+pub struct Lut3D {
+    pub domain_min: Vec3,
+    pub domain_max: Vec3,
+    pub size: usize,
+    pub table: Vec<Vec3>,
+}
+
+impl Lut3D {
+    fn at(&self, r: usize, g: usize, b: usize) -> Vec3 {
+        self.table[(b * self.size + g) * self.size + r]
+    }
+
+    pub fn apply(&self, rgb: Vec3) -> Vec3 {
+        let last = (self.size - 1) as f32;
+        let position = ((rgb - self.domain_min) / (self.domain_max - self.domain_min)).clamp(Vec3::ZERO, Vec3::ONE) * last;
+
+        let index = position.as_uvec3().min(UVec3::splat(self.size as u32 - 2));
+        let (r, g, b) = (index.x as usize, index.y as usize, index.z as usize);
+        let f = position - index.as_vec3();
+
+        let c000 = self.at(r, g, b);
+        let c111 = self.at(r + 1, g + 1, b + 1);
+
+        // Tetrahedral interpolation splits the cell along its main diagonal into six tetrahedra
+        if f.x > f.y {
+            if f.y > f.z {
+                let c100 = self.at(r + 1, g, b);
+                let c110 = self.at(r + 1, g + 1, b);
+                c000 + f.x * (c100 - c000) + f.y * (c110 - c100) + f.z * (c111 - c110)
+            } else if f.x > f.z {
+                let c100 = self.at(r + 1, g, b);
+                let c101 = self.at(r + 1, g, b + 1);
+                c000 + f.x * (c100 - c000) + f.z * (c101 - c100) + f.y * (c111 - c101)
+            } else {
+                let c001 = self.at(r, g, b + 1);
+                let c101 = self.at(r + 1, g, b + 1);
+                c000 + f.z * (c001 - c000) + f.x * (c101 - c001) + f.y * (c111 - c101)
+            }
+        } else if f.z > f.y {
+            let c001 = self.at(r, g, b + 1);
+            let c011 = self.at(r, g + 1, b + 1);
+            c000 + f.z * (c001 - c000) + f.y * (c011 - c001) + f.x * (c111 - c011)
+        } else if f.z > f.x {
+            let c010 = self.at(r, g + 1, b);
+            let c011 = self.at(r, g + 1, b + 1);
+            c000 + f.y * (c010 - c000) + f.z * (c011 - c010) + f.x * (c111 - c011)
+        } else {
+            let c010 = self.at(r, g + 1, b);
+            let c110 = self.at(r + 1, g + 1, b);
+            c000 + f.y * (c010 - c000) + f.x * (c110 - c010) + f.z * (c111 - c110)
+        }
+    }
+}
+
+// Adobe .cube files hold either a 1D or a 3D table; Resolve files may carry a 1D shaper followed by a 3D table
+#[derive(Clone, Debug)]
+pub struct CubeLut {
+    pub title: Option<String>,
+    pub lut_1d: Option<Lut1D>,
+    pub lut_3d: Option<Lut3D>,
+}
+
+impl CubeLut {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let source = fs::read_to_string(path)?;
+
+        Self::parse(&source).map_err(|err| anyhow!("Failed to parse {}: {}", path.display(), err))
+    }
+
+    pub fn parse(source: &str) -> Result<Self> {
+        let mut title = None;
+        let mut size_1d = None;
+        let mut size_3d = None;
+        let mut domain_min = Vec3::ZERO;
+        let mut domain_max = Vec3::ONE;
+        let mut range_1d = None;
+        let mut range_3d = None;
+        let mut entries = Vec::new();
+
+        for (line_index, line) in source.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut tokens = line.split_whitespace();
+            let keyword = tokens.next().unwrap();
+
+            let parse_floats = |tokens: std::str::SplitWhitespace, count: usize| -> Result<Vec<f32>> {
+                let values = tokens.map(|token| token.parse::<f32>()).collect::<Result<Vec<_>, _>>()?;
+
+                if values.len() != count {
+                    bail!("Line {}: expected {} values, found {}", line_index + 1, count, values.len());
+                }
+
+                Ok(values)
+            };
+
+            let parse_size = |tokens: std::str::SplitWhitespace, max: usize| -> Result<usize> {
+                let size = parse_floats(tokens, 1)?[0] as usize;
+
+                if size < 2 || size > max {
+                    bail!("Line {}: table size {} is outside [2, {}]", line_index + 1, size, max);
+                }
+
+                Ok(size)
+            };
+
+            match keyword {
+                "TITLE" => title = Some(line["TITLE".len()..].trim().trim_matches('"').to_string()),
+                "LUT_1D_SIZE" => size_1d = Some(parse_size(tokens, MAX_LUT_1D_SIZE)?),
+                "LUT_3D_SIZE" => size_3d = Some(parse_size(tokens, MAX_LUT_3D_SIZE)?),
+                "DOMAIN_MIN" => domain_min = Vec3::from_slice(&parse_floats(tokens, 3)?),
+                "DOMAIN_MAX" => domain_max = Vec3::from_slice(&parse_floats(tokens, 3)?),
+                "LUT_1D_INPUT_RANGE" => range_1d = Some(parse_floats(tokens, 2)?),
+                "LUT_3D_INPUT_RANGE" => range_3d = Some(parse_floats(tokens, 2)?),
+                _ if keyword.starts_with(|c: char| c.is_ascii_digit() || c == '-' || c == '+' || c == '.') => {
+                    let values = parse_floats(line.split_whitespace(), 3)?;
+                    entries.push(Vec3::from_slice(&values));
+                }
+                _ => warn!("Ignoring unknown .cube keyword {} on line {}", keyword, line_index + 1),
+            }
+        }
+
+        if size_1d.is_none() && size_3d.is_none() {
+            bail!("Missing LUT_1D_SIZE or LUT_3D_SIZE");
+        }
+
+        if domain_min.cmpge(domain_max).any() {
+            bail!("DOMAIN_MIN {} must be below DOMAIN_MAX {}", domain_min, domain_max);
+        }
+
+        let expected = size_1d.unwrap_or(0) + size_3d.map_or(0, |size| size * size * size);
+
+        if entries.len() != expected {
+            bail!("Expected {} table entries, found {}", expected, entries.len());
+        }
+
+        let domain = |range: Option<Vec<f32>>| match range {
+            Some(range) => (Vec3::splat(range[0]), Vec3::splat(range[1])),
+            None => (domain_min, domain_max),
+        };
+
+        let lut_3d_entries = entries.split_off(size_1d.unwrap_or(0));
+
+        let lut_1d = size_1d.map(|_| {
+            let (domain_min, domain_max) = domain(range_1d);
+            Lut1D {
+                domain_min,
+                domain_max,
+                table: entries,
+            }
+        });
+
+        let lut_3d = size_3d.map(|size| {
+            let (domain_min, domain_max) = domain(range_3d);
+            Lut3D {
+                domain_min,
+                domain_max,
+                size,
+                table: lut_3d_entries,
+            }
+        });
+
+        Ok(Self { title, lut_1d, lut_3d })
+    }
+
+    pub fn apply(&self, rgb: Vec3) -> Vec3 {
+        let rgb = self.lut_1d.as_ref().map_or(rgb, |lut| lut.apply(rgb));
+        self.lut_3d.as_ref().map_or(rgb, |lut| lut.apply(rgb))
+    }
+}
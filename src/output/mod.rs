@@ -0,0 +1,7 @@
+pub mod color_management;
+pub mod color_space;
+pub mod display;
+pub mod exr;
+pub mod hdr;
+pub mod lut;
+pub mod post;
@@ -0,0 +1,406 @@
+use crate::camera::aperture::ApertureDesc;
+use anyhow::{bail, Result};
+use glam::{UVec2, Vec2};
+use std::f32::consts;
+use std::ops::{Add, Mul, Sub};
+
+const PUPIL_SUPERSAMPLING: u32 = 4;
+
+// Natural vignetting following the cos^4 law, with tan(theta) = r / falloff_radius and r relative to the half diagonal
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Vignetting {
+    pub strength: f32,
+    pub falloff_radius: f32,
+}
+
+impl Default for Vignetting {
+    fn default() -> Self {
+        Self { strength: 1.0, falloff_radius: 1.5 }
+    }
+}
+
+impl Vignetting {
+    pub fn factor(&self, radius: f32) -> f32 {
+        let cos2 = 1.0 / (1.0 + (radius / self.falloff_radius).powi(2));
+
+        1.0 + self.strength * (cos2 * cos2 - 1.0)
+    }
+}
+
+// Lateral chromatic aberration magnifies red and shrinks blue around the image center by `amount`, relative to green
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ChromaticAberration {
+    pub amount: f32,
+}
+
+impl ChromaticAberration {
+    // Blue samples at `(point - center) / (1 - amount)`, which blows up at 1 and mirrors the channel past it
+    pub fn new(amount: f32) -> Result<Self> {
+        if !(0.0..1.0).contains(&amount) {
+            bail!("Chromatic aberration amount {} must be in [0, 1)", amount);
+        }
+
+        Ok(Self { amount })
+    }
+}
+
+// Glare from diffraction at the aperture: the point spread function is the Fraunhofer pattern |F(pupil)|^2, scaled per
+// channel with the wavelength
+#[derive(Clone, Debug)]
+pub struct Bloom {
+    pub aperture: ApertureDesc,
+    pub kernel_size: u32,
+    // Radius in pixels of the first dark ring of a circular aperture at the green wavelength
+    pub spread: f32,
+    pub intensity: f32,
+    pub wavelengths: [f32; 3],
+}
+
+impl Default for Bloom {
+    fn default() -> Self {
+        Self {
+            aperture: ApertureDesc::default(),
+            kernel_size: 256,
+            spread: 2.0,
+            intensity: 0.05,
+            wavelengths: [630.0, 532.0, 465.0],
+        }
+    }
+}
+
+impl Bloom {
+    pub fn kernels(&self) -> Result<[Vec<f32>; 3]> {
+        let size = self.kernel_size;
+
+        if !size.is_power_of_two() || size < 16 {
+            bail!("Bloom kernel size {} must be a power of two of at least 16", size);
+        }
+
+        let reference = self.wavelengths[1];
+
+        let kernel = |lambda: f32| {
+            let radius = (0.61 * size as f32 / self.spread.max(1e-3) * reference / lambda).clamp(2.0, 0.5 * size as f32);
+            let pupil = rasterize_pupil(&self.aperture, size, radius);
+
+            let mut spectrum = pupil.iter().map(|&value| Complex::new(value, 0.0)).collect::<Vec<_>>();
+            fft_2d(&mut spectrum, size as usize, size as usize, false);
+
+            let mut psf = vec![0.0; (size * size) as usize];
+            let half = size / 2;
+
+            // Shift the zero frequency to the kernel center
+            for y in 0..size {
+                for x in 0..size {
+                    psf[(((y + half) % size) * size + (x + half) % size) as usize] = spectrum[(y * size + x) as usize].norm_sqr();
+                }
+            }
+
+            let sum = psf.iter().sum::<f32>();
+            if sum > 0.0 {
+                psf.iter_mut().for_each(|value| *value /= sum);
+            }
+
+            psf
+        };
+
+        Ok(self.wavelengths.map(kernel))
+    }
+}
+
+fn rasterize_pupil(aperture: &ApertureDesc, size: u32, radius: f32) -> Vec<f32> {
+    let center = 0.5 * size as f32;
+    let mut pupil = vec![0.0; (size * size) as usize];
+
+    for y in 0..size {
+        for x in 0..size {
+            let mut transmission = 0.0;
+
+            for sy in 0..PUPIL_SUPERSAMPLING {
+                for sx in 0..PUPIL_SUPERSAMPLING {
+                    let offset = (Vec2::new(sx as f32, sy as f32) + 0.5) / PUPIL_SUPERSAMPLING as f32;
+                    let point = (Vec2::new(x as f32, y as f32) + offset - center) / radius;
+
+                    transmission += aperture.transmission(Vec2::new(point.x, -point.y));
+                }
+            }
+
+            pupil[(y * size + x) as usize] = transmission / (PUPIL_SUPERSAMPLING * PUPIL_SUPERSAMPLING) as f32;
+        }
+    }
+
+    pupil
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct PostProcess {
+    pub vignetting: Option<Vignetting>,
+    pub chromatic_aberration: Option<ChromaticAberration>,
+    pub bloom: Option<Bloom>,
+}
+
+// Mirrors `PostParameters` in shaders/src/post.rs field for field, the uniform buffer is uploaded as these bytes
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct PostParameters {
+    pub resolution: UVec2,
+    pub vignetting_strength: f32,
+    pub vignetting_falloff_radius: f32,
+    pub chromatic_aberration: f32,
+}
+
+impl PostProcess {
+    pub fn vignetting(mut self, vignetting: Vignetting) -> Self {
+        self.vignetting = Some(vignetting);
+        self
+    }
+
+    pub fn chromatic_aberration(mut self, amount: f32) -> Result<Self> {
+        self.chromatic_aberration = Some(ChromaticAberration::new(amount)?);
+        Ok(self)
+    }
+
+    pub fn bloom(mut self, bloom: Bloom) -> Self {
+        self.bloom = Some(bloom);
+        self
+    }
+
+    // Runs on linear scene-referred pixels, before any display transform
+    pub fn apply(&self, pixels: &[[f32; 4]], width: u32, height: u32) -> Result<Vec<[f32; 4]>> {
+        if (width * height) as usize != pixels.len() {
+            bail!("Image dimensions does not match pixels length: {}", pixels.len());
+        }
+
+        let mut pixels = pixels.to_vec();
+
+        if let Some(bloom) = &self.bloom {
+            apply_bloom(&mut pixels, width, height, bloom)?;
+        }
+
+        if let Some(chromatic_aberration) = &self.chromatic_aberration {
+            pixels = apply_chromatic_aberration(&pixels, width, height, chromatic_aberration.amount);
+        }
+
+        if let Some(vignetting) = &self.vignetting {
+            apply_vignetting(&mut pixels, width, height, vignetting);
+        }
+
+        Ok(pixels)
+    }
+
+    // The compute pass covers vignetting and chromatic aberration, bloom needs the host FFT
+    pub fn to_parameters(&self, resolution: UVec2) -> PostParameters {
+        let vignetting = self.vignetting.unwrap_or(Vignetting { strength: 0.0, ..Default::default() });
+
+        PostParameters {
+            resolution,
+            vignetting_strength: vignetting.strength,
+            vignetting_falloff_radius: vignetting.falloff_radius,
+            chromatic_aberration: self.chromatic_aberration.map_or(0.0, |chromatic_aberration| chromatic_aberration.amount),
+        }
+    }
+}
+
+fn normalized_radius(x: f32, y: f32, width: u32, height: u32) -> Vec2 {
+    let half = 0.5 * Vec2::new(width as f32, height as f32);
+
+    (Vec2::new(x, y) + 0.5 - half) / half.length()
+}
+
+fn apply_vignetting(pixels: &mut [[f32; 4]], width: u32, height: u32, vignetting: &Vignetting) {
+    for y in 0..height {
+        for x in 0..width {
+            let factor = vignetting.factor(normalized_radius(x as f32, y as f32, width, height).length());
+            let pixel = &mut pixels[(y * width + x) as usize];
+
+            for value in &mut pixel[..3] {
+                *value *= factor;
+            }
+        }
+    }
+}
+
+fn sample_bilinear(pixels: &[[f32; 4]], width: u32, height: u32, point: Vec2, channel: usize) -> f32 {
+    let point = (point - 0.5).clamp(Vec2::ZERO, Vec2::new(width as f32 - 1.0, height as f32 - 1.0));
+
+    let x0 = point.x as u32;
+    let y0 = point.y as u32;
+    let x1 = (x0 + 1).min(width - 1);
+    let y1 = (y0 + 1).min(height - 1);
+    let t = point - Vec2::new(x0 as f32, y0 as f32);
+
+    let at = |x: u32, y: u32| pixels[(y * width + x) as usize][channel];
+
+    (at(x0, y0) * (1.0 - t.x) + at(x1, y0) * t.x) * (1.0 - t.y) + (at(x0, y1) * (1.0 - t.x) + at(x1, y1) * t.x) * t.y
+}
+
+fn apply_chromatic_aberration(pixels: &[[f32; 4]], width: u32, height: u32, amount: f32) -> Vec<[f32; 4]> {
+    let center = 0.5 * Vec2::new(width as f32, height as f32);
+
+    (0..height)
+        .flat_map(|y| (0..width).map(move |x| (x, y)))
+        .map(|(x, y)| {
+            let point = Vec2::new(x as f32, y as f32) + 0.5;
+            let pixel = pixels[(y * width + x) as usize];
+
+            // Sampling closer to the center magnifies the channel
+            let red = sample_bilinear(pixels, width, height, center + (point - center) / (1.0 + amount), 0);
+            let blue = sample_bilinear(pixels, width, height, center + (point - center) / (1.0 - amount), 2);
+
+            [red, pixel[1], blue, pixel[3]]
+        })
+        .collect()
+}
+
+fn apply_bloom(pixels: &mut [[f32; 4]], width: u32, height: u32, bloom: &Bloom) -> Result<()> {
+    let kernels = bloom.kernels()?;
+    let kernel_size = bloom.kernel_size;
+
+    // Pad so the circular convolution doesn't wrap the glare of one border onto the other
+    let padded_width = (width + kernel_size).next_power_of_two() as usize;
+    let padded_height = (height + kernel_size).next_power_of_two() as usize;
+
+    for (channel, kernel) in kernels.iter().enumerate() {
+        let mut image = vec![Complex::default(); padded_width * padded_height];
+        let mut filter = vec![Complex::default(); padded_width * padded_height];
+
+        for y in 0..height as usize {
+            for x in 0..width as usize {
+                let value = pixels[y * width as usize + x][channel];
+                image[y * padded_width + x] = Complex::new(if value.is_finite() { value } else { 0.0 }, 0.0);
+            }
+        }
+
+        // Kernel center goes to the origin, negative offsets wrap around
+        let half = (kernel_size / 2) as usize;
+        for y in 0..kernel_size as usize {
+            for x in 0..kernel_size as usize {
+                let fx = (x + padded_width - half) % padded_width;
+                let fy = (y + padded_height - half) % padded_height;
+                filter[fy * padded_width + fx] = Complex::new(kernel[y * kernel_size as usize + x], 0.0);
+            }
+        }
+
+        fft_2d(&mut image, padded_width, padded_height, false);
+        fft_2d(&mut filter, padded_width, padded_height, false);
+
+        for (value, filter) in image.iter_mut().zip(&filter) {
+            *value = *value * *filter;
+        }
+
+        fft_2d(&mut image, padded_width, padded_height, true);
+
+        for y in 0..height as usize {
+            for x in 0..width as usize {
+                let value = &mut pixels[y * width as usize + x][channel];
+                *value = (1.0 - bloom.intensity) * *value + bloom.intensity * image[y * padded_width + x].re;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+struct Complex {
+    re: f32,
+    im: f32,
+}
+
+impl Complex {
+    fn new(re: f32, im: f32) -> Self {
+        Self { re, im }
+    }
+
+    fn norm_sqr(&self) -> f32 {
+        self.re * self.re + self.im * self.im
+    }
+}
+
+impl Add for Complex {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl Sub for Complex {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl Mul for Complex {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(self.re * rhs.re - self.im * rhs.im, self.re * rhs.im + self.im * rhs.re)
+    }
+}
+
+// In-place iterative radix-2 FFT, the inverse transform is scaled by 1 / n
+fn fft(data: &mut [Complex], inverse: bool) {
+    let n = data.len();
+
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+
+    let sign = if inverse { 1.0 } else { -1.0 };
+    let mut length = 2;
+
+    while length <= n {
+        let angle = sign * consts::TAU / length as f32;
+
+        for start in (0..n).step_by(length) {
+            for k in 0..length / 2 {
+                let twiddle = Complex::new((angle * k as f32).cos(), (angle * k as f32).sin());
+                let even = data[start + k];
+                let odd = data[start + k + length / 2] * twiddle;
+
+                data[start + k] = even + odd;
+                data[start + k + length / 2] = even - odd;
+            }
+        }
+
+        length <<= 1;
+    }
+
+    if inverse {
+        for value in data.iter_mut() {
+            *value = Complex::new(value.re / n as f32, value.im / n as f32);
+        }
+    }
+}
+
+fn fft_2d(data: &mut [Complex], width: usize, height: usize, inverse: bool) {
+    for row in data.chunks_mut(width) {
+        fft(row, inverse);
+    }
+
+    let mut column = vec![Complex::default(); height];
+
+    for x in 0..width {
+        for y in 0..height {
+            column[y] = data[y * width + x];
+        }
+
+        fft(&mut column, inverse);
+
+        for y in 0..height {
+            data[y * width + x] = column[y];
+        }
+    }
+}
@@ -0,0 +1,647 @@
+// Generated by shaders/pmj02bn_table/code, do not edit
+
+use optics_common::sampler::{PMJ02BN_SAMPLE_COUNT, PMJ02BN_SET_COUNT};
+
+#[rustfmt::skip]
+pub const PMJ02BN_SAMPLES: [[u32; 2]; (PMJ02BN_SET_COUNT * PMJ02BN_SAMPLE_COUNT) as usize] = [
+    [0xe220a839, 0x6e789e6a], [0x7c45dc54, 0x8d9cc4b5], [0x316104ce, 0x0fb95e24], [0x995fbb37, 0xf0b4c9b8], [0xb1c3eeaa, 0x36a9c1e2], [0x0fa5304b, 0xd879e2e2], [0x5b0351a5, 0x5b602c05], [0xddbebe3b, 0xaa892d5a],
+    [0xc69b82eb, 0x140d29eb], [0x2f16952e, 0xb377d35d], [0x6d254741, 0x269630f7], [0xa6e19905, 0x94fa9fa7], [0x8134f709, 0x407dc930], [0x4cc06c31, 0xeecee656], [0x1690ed47, 0x7774615d], [0xfe3ddd86, 0xcdf268ad],
+    [0xd01eb422, 0x564a962b], [0x5181301a, 0xa67a2452], [0x05fef576, 0x3f6bca01], [0xb9f0051a, 0xd712ad91], [0x92336af3, 0x0211bae5], [0x3997e55a, 0xfcf301cc], [0x76d810ac, 0x62e66f3c], [0xef782a7e, 0x86fc1009],
+    [0xf626e981, 0x2ec2f90c], [0x1cb52b9f, 0x9a354fed], [0x4228f290, 0x1ab721cc], [0x8e33cf70, 0xbc946b79], [0xac04368c, 0x790e3713], [0x648e6b21, 0xc3bfe303], [0x2097b21b, 0x4b24a921], [0xcee26309, 0xe1a82dbb],
+    [0xc869d928, 0x72f93456], [0x6bcea6f3, 0xb8d96c54], [0x19a9debe, 0x105e590b], [0x8716109f, 0xc7eb63f1], [0x8a64b761, 0x21fef576], [0x267fa9f7, 0xc902c4ac], [0x61bc4e12, 0x444b4171], [0xc13bab60, 0x9fa026b2],
+    [0xda1eeb95, 0x39fcd9ee], [0x3717f224, 0x80decbe8], [0x5428ff1d, 0x3392c8de], [0xb4e2dba8, 0xa024ff71], [0xbc8b5634, 0x5e1f5294], [0x5eafb3dd, 0xd2e1a1b2], [0x09badfc3, 0x533d0a18], [0xe75251d3, 0xfac7521c],
+    [0xfb871218, 0x4ea185b7], [0x46bf42d2, 0x9399257d], [0x2a2f2f37, 0x28904abd], [0xa88ce43a, 0xe8946b79], [0xa32d05f0, 0x1e00a3e3], [0x10b621b0, 0xe65ca5d3], [0x486563b5, 0x7f297ca5], [0xf1060042, 0xb660a38a],
+    [0xe8cf756d, 0x088f237f], [0x009a213a, 0xaf2aa0be], [0x796a8090, 0x06cc7645], [0x9770f0c5, 0x88f74e66], [0x9f220062, 0x66e730d5], [0x72fd8dba, 0xf75ef50a], [0x3e42e870, 0x691264f4], [0xd514160f, 0xdfb0ac2c],
+    [0xf3c6f50d, 0x7cfa34c2], [0x63dfb058, 0x9d5f1380], [0x24d88e03, 0x1d246e67], [0x8959826a, 0xe4a412e1], [0xab536166, 0x2b89a0d9], [0x1abe3c89, 0xc4d107a8], [0x45cd016d, 0x4cbb93b4], [0xcb9ccbe8, 0xba696041],
+    [0xd6d5bb9d, 0x05c49dda], [0x3dfb707d, 0xa2f43a00], [0x71a67e9a, 0x3b044de4], [0xbeef0de7, 0x8365044b], [0x940b7a02, 0x51a60446], [0x573cf347, 0xf913bf09], [0x02b4b065, 0x6597b8da], [0xeaad88aa, 0xd0d24de6],
+    [0xc2f11fbc, 0x473088ad], [0x4a7a2a01, 0xb54293a2], [0x12608029, 0x22fd3afc], [0xa0e40ed9, 0xcb267a81], [0x8492e460, 0x12c703f8], [0x29f30330, 0xea859563], [0x68dd68d7, 0x709b9453], [0xf8f81bbb, 0x914c411e],
+    [0xe41ee42c, 0x31486904], [0x0aee66a2, 0x8afbc263], [0x5c100fcf, 0x0a18b0af], [0x9c3b28a0, 0xad4ea005], [0xb79d9321, 0x6bf80036], [0x7bb54bf0, 0xdc8b5634], [0x34116cb8, 0x5cc332af], [0xd9db61a1, 0xf44740c7],
+    [0xded75b3e, 0x60e399cf], [0x756e7507, 0xa85009b1], [0x0de8fd80, 0x014d7299], [0x9067740b, 0xda0659a6], [0x9a944c96, 0x3c3f4339], [0x32ba69bc, 0xd456302b], [0x7ec7521c, 0x54988f51], [0xd397a0cb, 0x8ec7f4e0],
+    [0xcca185b7, 0x252780a2], [0x225a5fca, 0x96bf42d2], [0x4ec2c610, 0x2d0c2e63], [0xaeb1b931, 0xb158cfcb], [0xa4a0d024, 0x48cb2abe], [0x40a61731, 0xce82c948], [0x1fdb51f4, 0x434d674c], [0xf46b9985, 0xed22eaff],
+    [0xecf0ef34, 0x59989d8d], [0x5913bcb3, 0x848b1237], [0x3b5c5cfd, 0x3538d2e7], [0xb2017725, 0xfebb3e59], [0xba591ec6, 0x0cc799bb], [0x07d57aa1, 0xf30a4656], [0x52b4c6a6, 0x6d5634e6], [0xe05482ed, 0xa50e3db0],
+    [0xfdcd0aac, 0x18f28c68], [0x1520e8dc, 0xbe607e44], [0x665057c4, 0x171b48cb], [0x83c21c08, 0x99c7df00], [0x8d60df49, 0x7531b576], [0x6fa92c09, 0xe23ae764], [0x2c545c6f, 0x7a8256c6], [0xc445a8e3, 0xc03020b2],
+    [0xff99fb91, 0x64485011], [0x6c001fce, 0x82e7035a], [0x2e6a6bba, 0x04bc83eb], [0x9589908f, 0xef30307a], [0xa79217fa, 0x3aa27ca1], [0x171318b3, 0xd1f1eedc], [0x56a492fa, 0x41c16c74], [0xd7c23fe6, 0xb2593573],
+    [0xdca7ce59, 0x1cac52a0], [0x3074f7df, 0xbbb93bd9], [0x62c6a321, 0x37811e01], [0xaa6a393c, 0x8c2419d2], [0x889ac235, 0x5adb17fb], [0x44a4b32c, 0xf13729e6], [0x1b8eccc0, 0x6f61d1b6], [0xf24dc253, 0xd9214619],
+    [0xd85ef5bb, 0x4af1cae3], [0x43a0fff8, 0xacd0ae24], [0x0b260182, 0x2f1a68be], [0xaded8916, 0xdd37695a], [0x9d24e864, 0x1bba69a8], [0x21ffa0d4, 0xf5f8ec28], [0x7a0bde70, 0x78f9d495], [0xf7f47213, 0x8b400830],
+    [0xf90f0a3c, 0x3e00fba4], [0x1362c7ba, 0x87ca9b27], [0x506b4b89, 0x134980af], [0x8504dfae, 0xa74c3433], [0xa1556e1a, 0x63a6a324], [0x699fc26e, 0xd64fc2cc], [0x28a1bdb5, 0x57ebfde1], [0xc3dc0d87, 0xfd4293a2],
+    [0xc5157786, 0x68b0752c], [0x784d7c14, 0xb0613a9c], [0x0121dc52, 0x190251b7], [0x8c48a695, 0xd322abaa], [0x96157f0e, 0x2cd889ba], [0x2d3cb6b8, 0xdeade415], [0x6ed2f48e, 0x5f1542a7], [0xcdb31ad5, 0x8114f8ec],
+    [0xc00134cf, 0x343934dc], [0x3ae65f95, 0x9e12a9fe], [0x47e17960, 0x3d54238c], [0xbbfd8563, 0xb94423c5], [0xb3251d48, 0x453f66ca], [0x53b000cb, 0xc6d2e998], [0x1178da7c, 0x58d5e363], [0xed8acfe2, 0xe7f4fe6b],
+    [0xe69ba452, 0x4260d53c], [0x55cbf6a4, 0x985c0880], [0x3640613d, 0x24873450], [0xa5c6d657, 0xf643df2a], [0xaf9b6f7d, 0x071591bc], [0x1e549cbb, 0xfbe45cd3], [0x41515e34, 0x67db1b56], [0xfc3a91df, 0xae30e27d],
+    [0xf0647b11, 0x007dcdca], [0x18d0867d, 0xa47faa29], [0x74a6c803, 0x1ffe3316], [0x9bb2747d, 0x92fa6ed4], [0x86d33fcd, 0x6c371ad2], [0x604cb261, 0xffeb0074], [0x277d26c9, 0x61fb023c], [0xc94051c8, 0xd5ad5fb3],
+    [0xeb24765f, 0x764ba5bd], [0x706e992a, 0x958e833a], [0x3cd033c4, 0x15109596], [0x80cac8e4, 0xf88d2dda], [0xbf890dd6, 0x27326a9a], [0x036e3070, 0xcc4e6545], [0x4dc2c610, 0x506a2cf7], [0xc7b82b56, 0xa373cbe4],
+    [0xcacf7e1d, 0x0e449c53], [0x255b0d87, 0xab491c73], [0x7d0cff6b, 0x2a1d6b45], [0xb0da68ad, 0x9c8de990], [0x985b3b3a, 0x4d66598c], [0x5aaf82a4, 0xe5c7ce4b], [0x0e994f1f, 0x7da04691], [0xe3aa94a8, 0xc506316d],
+    [0xcf057748, 0x5d1a3704], [0x5d4e6e17, 0xbd36c4e9], [0x1df0ef34, 0x301bc57b], [0xb617dda7, 0xc29bdae1], [0x8f426ac0, 0x0b1f6bf9], [0x3533cd73, 0xe0bf7d81], [0x65eadf33, 0x6a1dfcf9], [0xe5f317f5, 0x9bc81c28],
+    [0xee09654e, 0x237fac22], [0x046786f2, 0x90c5d9b4], [0x4bcd6572, 0x031fec2a], [0x93a23a58, 0xb4809704], [0xb8c768ec, 0x7164da73], [0x7789ceaa, 0xca11266d], [0x38fa6594, 0x464d12ed], [0xd175d44c, 0xebf690c8],
+    [0xd45ce6c3, 0x7bd71c34], [0x67afe259, 0xa15b92f7], [0x14bec629, 0x09840784], [0x9e2b686d, 0xcf862caf], [0x82ada2b6, 0x32e9bff1], [0x3fdd2e5d, 0xc174cdd1], [0x73893a48, 0x495d88d6], [0xdbfc7b43, 0x97cbd18e],
+    [0xd20f87ee, 0x29dad6d0], [0x2b7e2ea1, 0x8ff70304], [0x5817b0c9, 0x2065ef98], [0xa231a80f, 0xa9719544], [0xa9fecaaf, 0x554e4882], [0x49bfc582, 0xdb1f2db3], [0x06805880, 0x4ff1de08], [0xfaa1c603, 0xf2b5be0c],
+    [0xf56f71ee, 0x52d61f8c], [0x4f6e8e18, 0x89553f7f], [0x2398ec5e, 0x38a6ae40], [0xbd7abf2e, 0xe3bf4e2f], [0xb5a8f148, 0x16d6e2f0], [0x08219142, 0xec003f9c], [0x5f533b11, 0x740c5d65], [0xe98b7332, 0xbf999051],
+    [0xe169a7bf, 0x11d7a6ad], [0x0c84ed15, 0xb715be59], [0x6a5e2496, 0x0d8507c9], [0x8bfec636, 0x8508ada7], [0x9158eb04, 0x7ec97791], [0x7f9d47ce, 0xe952de01], [0x33ded704, 0x734329a1], [0xdf557476, 0xc8663ebf],
+    [0xefa299ab, 0x622c2f36], [0x76251959, 0x8610448c], [0x390c550e, 0x02f88c4c], [0x92d49432, 0xfc1b62b5], [0xb907bae7, 0x3fe78acb], [0x050c0fce, 0xd7f49499], [0x51239d97, 0x56a98d02], [0xd0f38bea, 0xa6f9a2e4],
+    [0xce3f255f, 0x1a7008aa], [0x20237e2f, 0xbc5f7098], [0x64657c7e, 0x2e1f38ed], [0xaca7e302, 0x9aec4cf8], [0x8e8649a0, 0x4b8663c1], [0x428517f7, 0xe15dfa27], [0x1c7041f4, 0x79dac80b], [0xf6ae3f4e, 0xc30907b0],
+    [0xdd5c6104, 0x5b8f7b61], [0x5ba28d0e, 0xaa106a88], [0x0f73f45b, 0x362939c4], [0xb154f158, 0xd89ea0de], [0x99bd38a6, 0x0f4b97c1], [0x31e9f87c, 0xf0769ecc], [0x7cd9e562, 0x6e850516], [0xe2891f1b, 0x8d442fb9],
+    [0xfefb4bcf, 0x263f3e6f], [0x1662930c, 0x943b5361], [0x4c2cfae3, 0x1497954e], [0x81bf61f2, 0xb3c9a749], [0xa622fcf5, 0x77ce1e30], [0x6db81506, 0xcd7d16c4], [0x2fc51ede, 0x40dd3947], [0xc63b3071, 0xee56f229],
+    [0xc1c34681, 0x7fd5fb53], [0x614c099c, 0xb6f2ae66], [0x104d3743, 0x1ec9d8b2], [0x8ac01728, 0xc9f64d23], [0x87985918, 0x281a95d2], [0x2a9183fb, 0xc7128652], [0x6b63b177, 0x4e0a64d9], [0xc8cf2424, 0x93678281],
+    [0xd5fa2b41, 0x3322592f], [0x3ef5e405, 0x883665a8], [0x5e6ff8ed, 0x3902a260], [0xbc5e3131, 0xafb0798e], [0xb44e3791, 0x5380d502], [0x54ca57bd, 0xdf56c229], [0x000930f2, 0x5eb676c3], [0xe808c7f8, 0xf79b8b99],
+    [0xf1c160f4, 0x4489e549], [0x48967d17, 0x9f6e56a8], [0x26d68ea8, 0x217c0279], [0xa398acba, 0xe6cb63c9], [0xa847fca4, 0x109ceccd], [0x1957939f, 0xe87a1ec1], [0x4630ce39, 0x727b6e85], [0xfb0d1c46, 0xb8149fd4],
+    [0xe7d9a81a, 0x067b4814], [0x0936b204, 0xa081760a], [0x7276f47b, 0x0850f6e5], [0x9f9fc2e3, 0x8037b1cf], [0x97d300de, 0x69c55618], [0x79900c97, 0xfa60804a], [0x37f39205, 0x6673342c], [0xdae503e8, 0xd231c9e0],
+    [0xf86a2a93, 0x70183486], [0x687d3d3f, 0x91fd79a0], [0x2945a577, 0x12510c12], [0x8403b5ac, 0xea366606], [0xa0511dc0, 0x221e91bc], [0x12fd429f, 0xcbd986cb], [0x4a94b507, 0x47bc6520], [0xc269908d, 0xb59e267c],
+    [0xd9774deb, 0x0ac0bc16], [0x348b1f95, 0xad9acb50], [0x7b602a7b, 0x31d0f69d], [0xb739d1c5, 0x8a25079a], [0x9cc86ae7, 0x5c06d2ac], [0x5c901d24, 0xf4859ab1], [0x0a6c7693, 0x6b08c24d], [0xe494bfa0, 0xdc038a6c],
+    [0xcb34c390, 0x4c57e412], [0x4529b3de, 0xbad83190], [0x1a762f7e, 0x2b5d3f42], [0xab9a486f, 0xc40a7370], [0x89f0db79, 0x1d821db6], [0x2430dbc3, 0xe47faf15], [0x63685937, 0x7c54deb7], [0xf371b7ab, 0x9ddd0e32],
+    [0xea717c11, 0x3b9f8a5a], [0x0245c1ec, 0x83ca59c5], [0x57df253f, 0x054af839], [0x949fed4a, 0xa211c632], [0xbe21816f, 0x65333be7], [0x717d7077, 0xd05b60b4], [0x3d4b87a5, 0x5177c3b3], [0xd63d9293, 0xf9f77979],
+    [0xd3074d94, 0x6dbf87dc], [0x7e45f398, 0xa5926a64], [0x0727847c, 0x0c7590a9], [0x9a7de9e8, 0xd490e962], [0x90ab457e, 0x35ef3d17], [0x3bc6e278, 0xdac566a6], [0x7594afb6, 0x5924282f], [0xde061320, 0x846fcecb],
+    [0xc4beb7c0, 0x2ddce111], [0x2c83fd2b, 0x9968418a], [0x4066cdd0, 0x25cd4840], [0xa43ca24f, 0xbed2e998], [0xae2f947e, 0x43ae5acb], [0x4e6ce79b, 0xc0c342a1], [0x15ff5ebd, 0x48050302], [0xfd0cc2ee, 0xe289066c],
+    [0xe0af8ca4, 0x5459cb1c], [0x52430812, 0x8e6272f2], [0x3266a7fd, 0x3cc7f928], [0xba88446d, 0xf3ff47a4], [0xb2d037be, 0x019a33f9], [0x0d27aa7b, 0xfe012a12], [0x598f199e, 0x60688468], [0xec2add94, 0xa8996a67],
+    [0xf4bdda81, 0x17b1b3ef], [0x1f57c1d6, 0xb1c87c21], [0x6f2337de, 0x18034ba3], [0x8dfe51c0, 0x9643df2a], [0x834de865, 0x7a07baba], [0x66ec66be, 0xedb2c683], [0x22b2366e, 0x75b8e223], [0xcc6f9a5b, 0xce7b26bf],
+    [0xf7649511, 0x6abe42a2], [0x65748da0, 0x8baf7dd8], [0x2106d228, 0x0bc0ac4e], [0x9dd03b81, 0xe02cd105], [0xad777b25, 0x30a8a946], [0x1d636984, 0xdde407b1], [0x5dfb4aa2, 0x4a178ddc], [0xd8947fb4, 0xbdd176fa],
+    [0xd1a78f2a, 0x13cf6b7f], [0x38003c78, 0xb4021178], [0x69596677, 0x3ee14c61], [0xa193b988, 0x87514818], [0x85d7079e, 0x5758dd1e], [0x4b4a5057, 0xfddc2fdf], [0x13a12e98, 0x631d7d8a], [0xf9e48d03, 0xd6cb1cc5],
+    [0xd745e7f2, 0x41442a8a], [0x4d6af5fc, 0xa3a2d9f3], [0x03de113a, 0x27d55227], [0xa729820d, 0xd15434e0], [0x952d8fc4, 0x15ac4a8e], [0x2eb6f290, 0xf80932f7], [0x70924178, 0x76b6746b], [0xff6b75b0, 0x82782706],
+    [0xf2af99b8, 0x373c0311], [0x1b17aca9, 0x8cacabcc], [0x5a437cf3, 0x1c399fe1], [0x88598460, 0xabff5bb3], [0xaa813224, 0x6f8cea46], [0x620f8850, 0xd9c64375], [0x25b235d3, 0x5a322250], [0xca4f5ae1, 0xf1b09418],
+    [0xcd1e7801, 0x674b9c9a], [0x7367d41f, 0xbf44e5ed], [0x0896faef, 0x1648771a], [0x8243b033, 0xde5401c1], [0x9eeac6f1, 0x243bfbf5], [0x2364a7f0, 0xd3baca6f], [0x6719ba61, 0x52006546], [0xc584edfe, 0x89ce6545],
+    [0xc9b0ce33, 0x3dc81f87], [0x33119e5e, 0x926339bc], [0x492f5c46, 0x349a8969], [0xb3e539f0, 0xb7a7dc9e], [0xbb576e2d, 0x4f651546], [0x5881f8bd, 0xc8828dd9], [0x18109385, 0x5584ff71], [0xe1e9efbc, 0xe9e07379],
+    [0xe97d68cf, 0x49e80164], [0x5fc6b49d, 0x976a5a26], [0x3f464540, 0x2c2c5fa8], [0xaf6183fe, 0xfb0b62d4], [0xa50b901c, 0x094a286a], [0x1475b930, 0xf6e6598c], [0x4fd05169, 0x685562ba], [0xf5cc09c7, 0xa1f8dc2f],
+    [0xfa787777, 0x0d31ac66], [0x11d799a8, 0xa9e64fae], [0x7f3433f0, 0x11315382], [0x9187fe11, 0x9ebf23a9], [0x8b31712a, 0x615f433d], [0x6acc463f, 0xf21a3704], [0x2bb5348c, 0x6c8f23a6], [0xc08082fa, 0xdb812d0e],
+    [0xe547a87f, 0x787c9790], [0x7ae3c526, 0x9b4ed69a], [0x35fd7688, 0x1b7ec107], [0x8f98b888, 0xf5221b8f], [0xb6b0d658, 0x2faeffb9], [0x0ba1ca3c, 0xc27e3cc7], [0x43667d78, 0x5df709c0], [0xcfb2e15d, 0xac659660],
+    [0xc34a5fd9, 0x03a5b482], [0x28226327, 0xa7efc646], [0x775c68ce, 0x23f1d387], [0xb8510780, 0x90338cd5], [0x9332423a, 0x4683e4f2], [0x50961d56, 0xeb6fbfae], [0x04e1443c, 0x71cd88ea], [0xeeb561c0, 0xcaf67fbd],
+    [0xc70d8375, 0x508d4fd9], [0x5662dc4b, 0xb299e8e3], [0x178ed20c, 0x3a6222b3], [0xbf3e5fca, 0xccbb7d9b], [0x804e4b9d, 0x045ef087], [0x3c4dcfbe, 0xef862a79], [0x6cf82342, 0x64ef4ec1], [0xebcf46de, 0x953cd51e],
+    [0xe328b8c8, 0x2aba5df3], [0x0e03915c, 0x9c5d7c29], [0x4464e86d, 0x0e8a2600], [0x989ff337, 0xbb7444f9], [0xb039f3cb, 0x7d6f6c58], [0x7dc2245d, 0xc5999d05], [0x30eba735, 0x4ded47cf], [0xdc1a879f, 0xe562a1a7],
+    [0xdb795f8a, 0x74b40a5c], [0x6e54ed48, 0xaed0f33a], [0x1ed92828, 0x07a1b886], [0x96dee651, 0xc1b913ed], [0x8ce6e09f, 0x385ff4b4], [0x36f75806, 0xcf22fdb7], [0x788e2d63, 0x42dc9737], [0xd4f85cd7, 0x988c24d7],
+    [0xdf85df0c, 0x20d33efd], [0x27ae93a4, 0x85a7f0fb], [0x5343e33c, 0x297063a1], [0xa92d3d0e, 0xa4cbd18e], [0xa29a3569, 0x581be05c], [0x477eb475, 0xd54425f9], [0x0c36cb0c, 0x45ee6470], [0xf0a36110, 0xff41b9c2],
+    [0xfce07e11, 0x5fd4d9ee], [0x41ac9974, 0x81c3f8b7], [0x2df70f6f, 0x321f23c0], [0xb55e03e2, 0xecbe8425], [0xbdf3e583, 0x19b63bf6], [0x01b14cc4, 0xe34e4882], [0x556e3b9e, 0x7b5e974e], [0xe671ddf8, 0xb0b684b9],
+    [0xed3cb54d, 0x1f02ba5c], [0x0667c46a, 0xb9bc462d], [0x6095e02e, 0x00a41685], [0x865c71c1, 0x8f702c68], [0x9b67d192, 0x73f3d354], [0x74466236, 0xe746754a], [0x3a1be319, 0x7e3765dd], [0xd2e4a0ee, 0xc642909a],
+    [0xe739a01a, 0x66395e04], [0x7918762f, 0x80408a55], [0x3eb388c7, 0x08c59283], [0x97a36d0b, 0xf7045396], [0xbc11799a, 0x33e7d3e8], [0x09d8e808, 0xd27e5bc1], [0x54aa0159, 0x5e43dcd7], [0xda6d17de, 0xa0c47e97],
+    [0xc16bf3c5, 0x1ea702bf], [0x2a5c6684, 0xb85b51f5], [0x611ef44f, 0x218036ce], [0xa3621bb6, 0x9f29b6af], [0x876c91a5, 0x4e429633], [0x4667f5cc, 0xe8c607b9], [0x10c91fa9, 0x7fae5745], [0xfbdd4c79, 0xc753a4f2],
+    [0xdaac9353, 0x535d130c], [0x5e0d0042, 0xa0622cf2], [0x00ee30ab, 0x337ce7a8], [0xb435872a, 0xd2a1d5e8], [0x9728be65, 0x082bbf6d], [0x37becc50, 0xfab86113], [0x79c3513a, 0x66a084a0], [0xe78f3f5f, 0x808f012e],
+    [0xfb72c2b2, 0x28c48188], [0x1023d8ab, 0x9fc7be4e], [0x46d341e3, 0x10c41c2b], [0x8a08d1a6, 0xb6a14376], [0xa3db5493, 0x7f486103], [0x61f68be6, 0xc98b6aa7], [0x263d7240, 0x44c9954e], [0xc1878572, 0xe6115c27],
+    [0xce9b7be2, 0x79b9a2e4], [0x6425283e, 0xbcda938d], [0x1cfd883a, 0x1a0aab21], [0x8ed0fc79, 0xc3c4a4aa], [0x8e6ad69c, 0x2e712801], [0x20f5d95e, 0xc35f5ebc], [0x6df58cf1, 0x4013d0a5], [0xc6fe561b, 0x944649a0],
+    [0xddf0139c, 0x364550f9], [0x397b0641, 0x86980c32], [0x51c3a206, 0x3f90c4a6], [0xb96233ab, 0xa618a155], [0xb12ce276, 0x5b2e3bc2], [0x5b6a4c14, 0xd8dc6104], [0x0fd0efab, 0x5bf2ccb0], [0xe2684040, 0xf0163518],
+    [0xf6e686b5, 0x4b5545dd], [0x4c6eb9c7, 0x94bb7ec7], [0x2f7ffbaf, 0x2645b3eb], [0xa6720ee2, 0xee9276bb], [0xa6a25f5f, 0x14c70dd6], [0x1c099df1, 0xe1ee9526], [0x42e2c660, 0x796c6b1e], [0xf64743e4, 0xbc34a6f4],
+    [0xe2f0bcc9, 0x0fd7ab7f], [0x0f315ca3, 0xaad5edf7], [0x767f5b39, 0x027b2834], [0x99ec2e9c, 0x8dfbe892], [0x92a58ea4, 0x628e3e08], [0x7c9d5add, 0xf0d55fc7], [0x39c02033, 0x62763bd9], [0xdd3d4214, 0xd83e875e],
+    [0xfd64d042, 0x7ac59ccd], [0x6f6f92cd, 0x998f9bd9], [0x22149062, 0x17e71982], [0x8d0571a0, 0xedfbf066], [0xa470a18c, 0x2d57269e], [0x159a8bdb, 0xc069bdf1], [0x4e175d73, 0x4890b321], [0xccc299d1, 0xb1afd84b],
+    [0xd3e6fb76, 0x0c0c67db], [0x3b1cbdf6, 0xa8d18a71], [0x750f70d0, 0x3581f8a6], [0xb2bda20f, 0x84ebeebd], [0x902bea05, 0x596567f4], [0x52ce5903, 0xf3b7ba64], [0x07989043, 0x6de3b177], [0xe0ed8f36, 0xd4365cd5],
+    [0xcc2e0517, 0x433e0955], [0x4e82764a, 0xbe91a0cc], [0x1f219e60, 0x25625938], [0xa4feed1a, 0xc09cdd18], [0x838ea1a1, 0x18667468], [0x22d33cdf, 0xed4b2054], [0x6fc959b7, 0x7a70798e], [0xfda09394, 0x9919e314],
+    [0xe0360ccf, 0x3c8ffb10], [0x0db60822, 0x84312d8b], [0x5234ae88, 0x0c8930f2], [0x90d731a7, 0xa8263ab8], [0xbae56472, 0x6d2d8ef0], [0x7eac4f09, 0xd4e19e71], [0x3b999e95, 0x59d86b31], [0xdeb6fbdf, 0xfe5f757d],
+    [0xd645acd9, 0x65dcb413], [0x7b2c9e67, 0xad14bc8e], [0x0214fb3e, 0x05b48220], [0x9caa5df7, 0xdcd73cbf], [0x9c4109da, 0x31a83724], [0x3442c354, 0xdc452aab], [0x71396041, 0x51e0f50e], [0xd90545ab, 0x8a86f191],
+    [0xcbdce676, 0x2b1d879e], [0x2913a9da, 0x9123415d], [0x457ee74d, 0x2bca2cef], [0xab00ec13, 0xba8617a0], [0xa0869d76, 0x47c11b1b], [0x4af745ef, 0xcb648a65], [0x128fbdbd, 0x47679d2f], [0xf3ae1c4f, 0xe400f039],
+    [0xe4e24619, 0x5c883124], [0x57a2eab7, 0x833b867a], [0x34db459e, 0x31021151], [0xb7c3bcc7, 0xf4ca3eec], [0xb742a672, 0x0a4b526f], [0x02eddc1e, 0xf9aa2a93], [0x5cf88f0a, 0x6bb9a4c2], [0xea332841, 0xa2926b10],
+    [0xf887d5f2, 0x12046219], [0x121552df, 0xb5eadb10], [0x63bb947f, 0x1df32d94], [0x89ac66c5, 0x9d13ac38], [0x893a4a0e, 0x7c13f42d], [0x6317f1bb, 0xe4f30f5a], [0x24510a9e, 0x7c9815b3], [0xc2a6d442, 0xcbac8c86],
+    [0xf0dbda47, 0x619e267c], [0x60c50a14, 0x85774deb], [0x27ec283e, 0x000e97be], [0x91125057, 0xe7088355], [0xa24aa55a, 0x34c6a1eb], [0x113ccb63, 0xdbca072d], [0x58c5bda2, 0x454a3bad], [0xdff576fa, 0xb76331e8],
+    [0xd484a689, 0x19692289], [0x361d495e, 0xb0c66959], [0x6e0c0ac3, 0x3282b658], [0xafed7793, 0x81878a95], [0x8c33d148, 0x524039bc], [0x4132bd0a, 0xfb55b36e], [0x1405e5b7, 0x68f38a5f], [0xfcbfa640, 0xdecb21f7],
+    [0xdf28c9d7, 0x45b1373b], [0x494ac7ce, 0xa9303726], [0x0600bddd, 0x2986de29], [0xa2f6b52d, 0xdb5984aa], [0x9bfbbafb, 0x1148e29b], [0x2731f28a, 0xff0625b6], [0x743c731e, 0x7e9deaf5], [0xfa06073c, 0x8fb0ce7b],
+    [0xfc5833c1, 0x3257dedf], [0x1e875ea7, 0x814ded65], [0x5fa03f68, 0x16bb42fb], [0x8c9b3cd5, 0xa1109835], [0xa584b21f, 0x680582c4], [0x6e9eb9ce, 0xde059805], [0x232c4055, 0x52b58ff5], [0xc54c5e66, 0xf687f8df],
+    [0xc3925397, 0x63685701], [0x7d5ed2e9, 0xbb164295], [0x0427535e, 0x13b1ff16], [0x85b98201, 0xd600461d], [0x98f17202, 0x2a491597], [0x28f472c1, 0xd6afb622], [0x69f50d63, 0x573991c2], [0xc32f3b5e, 0x87b8e1d5],
+    [0xc7fbadf8, 0x3a2385d9], [0x3c8cbbf9, 0x9567997a], [0x43ed923c, 0x30df857a], [0xb6d6c597, 0xbd6b457e], [0xbf575bfa, 0x41907ff6], [0x5daefb3e, 0xc2d8acb3], [0x175eab62, 0x50f5a725], [0xe53fee79, 0xe0db3a81],
+    [0xeec0c43f, 0x4609575a], [0x50228cc4, 0x907b2557], [0x302bcc13, 0x2adc550a], [0xa1265ef8, 0xfda6fe2b], [0xa1d0087e, 0x034c01e3], [0x13d9bd0e, 0xfd162005], [0x44f273b7, 0x6ff21627], [0xf94a703e, 0xa782c95b],
+    [0xff0cdae2, 0x04c02ec0], [0x17d857d9, 0xa324efa6], [0x702ecc2e, 0x15e351b5], [0x9d77e2fd, 0x9b26120f], [0x8ff6a9d1, 0x6a4800ef], [0x658e79be, 0xf54fc9e7], [0x2ee214ad, 0x642ce0b8], [0xc74851ab, 0xd18f1cb3],
+    [0xe19c4b15, 0x731883a5], [0x7f49b90f, 0x92a523f2], [0x337bbd75, 0x119e4681], [0x86a5eafc, 0xf2652fa5], [0xbb854d49, 0x293ff7c7], [0x06cb3dc8, 0xc61b2b8a], [0x471d6dcc, 0x5500f7f2], [0xc9d3c29b, 0xa41bd3b2],
+    [0xcdd13ffc, 0x07f15593], [0x23d32c41, 0xa1a5c72b], [0x732a5542, 0x2c83ed82], [0xb5c51920, 0x973c33d0], [0x9e743959, 0x42a337de], [0x5516945e, 0xe3d62c40], [0x0856210e, 0x74e9133c], [0xe92d9f81, 0xc112b264],
+    [0xc9293c21, 0x55da60f2], [0x53279d79, 0xb9318325], [0x11b8e703, 0x3470f310], [0xbb1572ea, 0xc6a014b7], [0x8bb4fae4, 0x00e630d6], [0x3a58853e, 0xe789c923], [0x6abb6d59, 0x6c714bb4], [0xed420059, 0x9e533db0],
+    [0xe634c29f, 0x24f5d6da], [0x017d5359, 0x98c46f53], [0x4f13b7a3, 0x09065994], [0x9e98cfd1, 0xb0360ca0], [0xb52cb524, 0x747d3225], [0x73d0e3db, 0xc1e407b1], [0x3f932fab, 0x49a07eb4], [0xd42ed0ba, 0xe321dfe2],
+    [0xd13c7608, 0x71a797fc], [0x69076398, 0xa722e909], [0x1b593d5b, 0x0e03f898], [0x98170ce7, 0xc5eb8d0c], [0x8814b057, 0x37f639b7], [0x30a80538, 0xc5730080], [0x77f0ad12, 0x46dbfcc5], [0xdcdea7a2, 0x9c23350e],
+    [0xd78cfab8, 0x27a131ca], [0x2e0620b0, 0x821244c7], [0x5612c499, 0x274b2be1], [0xad0796b2, 0xac9167c0], [0xadbb0a00, 0x5d9daafb], [0x4db92199, 0xd13ded8b], [0x0bf0bb88, 0x4a846c3d], [0xffc1abaf, 0xf86be545],
+    [0xf9bd9674, 0x578c7496], [0x442805ab, 0x8c5ee9d5], [0x251a836e, 0x377524ed], [0xb8b7ae15, 0xeb296b29], [0xb83961df, 0x130bb887], [0x04b0aeee, 0xeb9cdb37], [0x5ace5d0e, 0x7d13b114], [0xee655e78, 0xb44f777c],
+    [0xeb9dfb50, 0x15409482], [0x0b4848b1, 0xbd8a4285], [0x6c66dbe7, 0x04101fea], [0x80202914, 0x8286345b], [0x9d8be1d2, 0x78837cf3], [0x70ffd21b, 0xef51ecaf], [0x3541ea6d, 0x7831e62e], [0xd7123a4c, 0xcc266636],
+    [0xe87f3b40, 0x69582d38], [0x721fa36f, 0x8883d941], [0x376f2fd5, 0x06231356], [0x9f4d7ee4, 0xfa1744f6], [0xb4975663, 0x3969b362], [0x005d3a8b, 0xdfe0a3cb], [0x5ef09418, 0x53e6f6da], [0xd5a9184c, 0xaf780090],
+    [0xc8a52529, 0x1006ec18], [0x26aeed66, 0xb627d41f], [0x6bae006c, 0x285b58b7], [0xa81de954, 0x93fd7260], [0x8a940667, 0x4406c472], [0x48155e46, 0xe683b033], [0x1931500f, 0x72a3ae53], [0xf19d0254, 0xc964dae7],
+    [0xd5558484, 0x5ef3c255], [0x546a0924, 0xaff02626], [0x094675f2, 0x39b87aa3], [0xbcc49f72, 0xdf081fb4], [0x9fe6dcb6, 0x06a5db66], [0x3e034a86, 0xf7c3910b], [0x7289bfbd, 0x69ac8d2a], [0xe8b59e05, 0x884a166c],
+    [0xf148862c, 0x21102693], [0x19dba8d1, 0x930af1a8], [0x48f01ea4, 0x1e77e4c2], [0x87d4dfc7, 0xb8b18a7b], [0xa8caf6aa, 0x722f3ecf], [0x6b0c654d, 0xc7b5b761], [0x2afc95ff, 0x4ef142f9], [0xc83a4cf0, 0xe810f85c],
+    [0xc66a6c3f, 0x773de5c2], [0x6d7200f7, 0xb3b12fdb], [0x16092775, 0x1471b70b], [0x8146718b, 0xcd354433], [0x81f97630, 0x26faafb2], [0x2fa98996, 0xcdb83827], [0x64ea3326, 0x4be8696b], [0xce5cab8a, 0x9a59809f],
+    [0xd0a86e3b, 0x3f00ba3c], [0x313a1b0f, 0x8d35d88d], [0x5bcc3701, 0x36f67aa4], [0xb190a300, 0xaa52ca06], [0xb99ad064, 0x56fcceaf], [0x517cba95, 0xd77c3041], [0x056bec18, 0x561102df], [0xefe440ca, 0xfcaa4f6a],
+    [0xfe40d4a7, 0x40b07be7], [0x42665c54, 0x9a8ebdaf], [0x205e9106, 0x2e8e04e9], [0xac4db160, 0xe136c8da], [0xacf28835, 0x1af7c576], [0x16d274e9, 0xee328138], [0x4c9721ae, 0x77a274dd], [0xfe82d377, 0xb321ecf3],
+    [0xef030dc1, 0x02a8bfc1], [0x058dc1dc, 0xa68d5e0b], [0x7c0b83c1, 0x0f0c351a], [0x92456787, 0x8649652a], [0x99168795, 0x6ece1d2b], [0x76ba3bdb, 0xfc7e6dcc], [0x31a457bc, 0x6e3a43d1], [0xd045cd2d, 0xd797e89b],
+    [0xf40a3c4a, 0x75e50fd4], [0x66091493, 0x9632bdaf], [0x2ccf13ba, 0x189f343c], [0x8312bc56, 0xe25a689e], [0xaedb8c1b, 0x25b28c86], [0x1fa44bb5, 0xce3d456b], [0x40cad649, 0x43eed451], [0xc4e35507, 0xbe26f9c0],
+    [0xde484313, 0x0125e20c], [0x32137768, 0xa558db3f], [0x7e340aac, 0x3c603c9a], [0xba03fd21, 0x8e3d7688], [0x9a253878, 0x54eaf84c], [0x59c75873, 0xfed7752a], [0x0d771696, 0x60b5848b], [0xec96cb73, 0xda8a5ee8],
+    [0xc43198ae, 0x485bc809], [0x402fbbfa, 0xb120624b], [0x15406bee, 0x2d91f5b2], [0xae6d6921, 0xcef91d60], [0x8dbc8b48, 0x176b5f61], [0x2c1f6065, 0xe2d9ea48], [0x6681a9a6, 0x7570c696], [0xf4e391c1, 0x96f1971d],
+    [0xec54af49, 0x357dadd9], [0x07588855, 0x8eb97e6f], [0x597b01bd, 0x01e941b4], [0x9ac11330, 0xa5ce3bb9], [0xb25d6973, 0x60332f87], [0x75e9eaba, 0xda6c6c56], [0x32f11efc, 0x5419d75a], [0xd3657584, 0xf34a942b],
+    [0xd989addb, 0x6b4abb3d], [0x71ee2a37, 0xa25d7d6a], [0x0a8584a4, 0x0a82527a], [0x945bf0c2, 0xd0269a1d], [0x94df7e44, 0x3b5008d3], [0x3d36089d, 0xd0989f5a], [0x7bd25636, 0x5c4a1e37], [0xd6a8aab2, 0x83811d59],
+    [0xc23149dc, 0x22865112], [0x2482a123, 0x9da7843c], [0x4a0cad43, 0x2270899f], [0xa02150b4, 0xb5159e53], [0xabd9e13c, 0x4ccd362e], [0x45b652cd, 0xc445014f], [0x1ac5f117, 0x4c350f3c], [0xf817ad89, 0xeac6def4],
+    [0xeaddad29, 0x51146c19], [0x5c7b136e, 0x8a50c35f], [0x3d84e71b, 0x3bcb40cc], [0xbe7db441, 0xf97232e1], [0xbeaf4ec1, 0x0519eace], [0x0a290289, 0xf41af261], [0x577b91c0, 0x656b6bc4], [0xe44c05c2, 0xadcdecda],
+    [0xf3392204, 0x1d7ce50b], [0x1a0ac95e, 0xba28c56f], [0x6812d5e0, 0x12ac077e], [0x84c3c71e, 0x91a8af9b], [0x84593da7, 0x70fa66da], [0x68ba1caf, 0xea4d6dfc], [0x298dcd88, 0x705520b3], [0xcb7a8306, 0xc483616f],
+    [0xfad4a06b, 0x6cda0503], [0x6a307257, 0x8f3801d4], [0x2b1859a6, 0x0d403570], [0x9b29c2a6, 0xe93752b8], [0xa9527be2, 0x3d1e0132], [0x186078d5, 0xd5d94441], [0x53fae37e, 0x4f07af06], [0xd2519d29, 0xb9f95f8a],
+    [0xdb80fe71, 0x160bacc2], [0x3f25d6bf, 0xbff3973c], [0x67de3942, 0x382567cc], [0xa561ad47, 0x891697a3], [0x82207528, 0x5f4f93e5], [0x4fb74679, 0xf60385c3], [0x1e080247, 0x6716d768], [0xf507c3d7, 0xd3c0fd21],
+    [0xd2b7ac75, 0x4faf0a83], [0x478114f0, 0xa4a8d633], [0x0cf493b5, 0x208fe360], [0xa9bfbc00, 0xd52302d3], [0x91e6446d, 0x1fa7ff73], [0x2bcb869e, 0xf2eedf0d], [0x7fdb115c, 0x738393a2], [0xf002248d, 0x85d8c8b1],
+    [0xf590f516, 0x38c21c0f], [0x14cda941, 0x89beffc8], [0x5594a4eb, 0x19ea2837], [0x82ff5889, 0xae823074], [0xaf02fece, 0x679295c8], [0x676cc674, 0xd34f8100], [0x2d5a724f, 0x5f9aa588], [0xcd7cba5b, 0xfb80464e],
+    [0xca10b644, 0x6f2cf011], [0x771935ec, 0xb4d725d3], [0x0e5bd88b, 0x1cce2fd3], [0x88dd0320, 0xd9ad982c], [0x93c7a683, 0x23af3cbf], [0x25efc2bb, 0xd9409402], [0x6266023f, 0x5aad323b], [0xcaaca79e, 0x8cc5a570],
+    [0xcfd4477b, 0x306e7324], [0x3588e7b5, 0x9b9794d3], [0x4d172f8e, 0x3ad9240a], [0xbfdb066f, 0xb2dc4382], [0xb6459b31, 0x4a5fc475], [0x56ea7e28, 0xccf78c72], [0x1d2abd86, 0x5d4badee], [0xeb7c2857, 0xefda5cf4],
+    [0xe3ec3270, 0x4da0f676], [0x5a363836, 0x9cf14cc4], [0x384988c1, 0x231b79e1], [0xaad44dc5, 0xf1748c32], [0xaa1df08b, 0x0ee5520d], [0x1bc949a9, 0xf1e78585], [0x4b3684b9, 0x63e3a09e], [0xf2cdf681, 0xab336a86],
+    [0xf7a7ee6f, 0x0bbddfeb], [0x1dad5ea9, 0xac014cf9], [0x7a87f1b0, 0x1bf5f810], [0x95472ab7, 0x95ef68bd], [0x808cb263, 0x6490ba3c], [0x6c8a81cb, 0xf8db2e63], [0x219a06a9, 0x6ad930be], [0xcf592143, 0xdd8e4903],
+    [0xedc4c118, 0x7e438f2d], [0x74d21168, 0x9ede1c59], [0x3a9beb41, 0x1f4fc8bb], [0x8b770255, 0xffb94f11], [0xb39ddd4d, 0x2011a5ff], [0x0c69a7ec, 0xc83df447], [0x49e7c4ac, 0x58578d5d], [0xc0642155, 0xa9b9e6d8],
+    [0xc5d87447, 0x09d4112a], [0x2d95f14e, 0xae4f93c6], [0x780a6192, 0x247bb9f6], [0xbdbcbba9, 0x983dfdcc], [0x96b8c896, 0x49060a16], [0x5f2aedc3, 0xecd49e45], [0x01c69989, 0x7baa7d32], [0xe6da19d4, 0xcf4a5f5b],
+    [0xc0cf6230, 0x58936bbb], [0x5845b4e2, 0xb7ea606d], [0x18833456, 0x3daf5d06], [0xb3450cd6, 0xc8e1673f], [0x863aec2e, 0x0deebf5a], [0x33b7b0f4, 0xe9b5ec01], [0x60386499, 0x612b5357], [0xe11997d4, 0x922325bb],
+    [0xe9c8b041, 0x2c7e8906], [0x08e089a2, 0x97a544db], [0x41e02cfc, 0x076f7b9c], [0x9670ecb0, 0xbf1b3c0d], [0xbd3f27c4, 0x7b019921], [0x78d19f4d, 0xcfe1c7a4], [0x369f4805, 0x42045393], [0xdb052f78, 0xec5ceb91],
+    [0xdc69f590, 0x7dd3beb7], [0x62be5926, 0xabb2d797], [0x13291af1, 0x03f5ff11], [0x9348ea3b, 0xca74fa91], [0x8562c68a, 0x3ea1b865], [0x38a7a5f3, 0xcab48e7d], [0x7db281d4, 0x4d1896d6], [0xd1ce9081, 0x909873bf],
+    [0xd8dcd904, 0x2f713ba1], [0x214a2eac, 0x8b2deba4], [0x5d349f52, 0x2ff68427], [0xa7eb84e9, 0xa3ce3b32], [0xa74f7896, 0x500d60ec], [0x43060f45, 0xdd56e810], [0x03285a0e, 0x412b3176], [0xf727315f, 0xf5afd1fd],
+    [0xf2142182, 0x5a689005], [0x4b8a3e09, 0x8731e20b], [0x2868affb, 0x3e7c19b8], [0xb05b8385, 0xe585d19c], [0xb08e3378, 0x1c6db49f], [0x0ef9684a, 0xe5338914], [0x50da7b79, 0x7139aad2], [0xe3625106, 0xbbc22506],
+    [0xe5bc036a, 0x1b2e8f5a], [0x039640fd, 0xb217335d], [0x650c983a, 0x0b4aa583], [0x8f1467d5, 0x8beaaeea], [0x95fb2601, 0x76ff2dae], [0x7a6d0818, 0xe0686cff], [0x3c3cb2ee, 0x762499ed], [0xd805ee62, 0xc21f09f6],
+    [0x910a2dec, 0xbeeb8da1], [0x38e0c348, 0x38ddaa6c], [0x6179eec3, 0xd245c637], [0xd9dd771d, 0x66bf3513], [0xf0f6683a, 0xedf36db5], [0x54a694d4, 0x5a175a1b], [0x1c4c6306, 0x821c588d], [0xafde3fa1, 0x03f0820c],
+    [0xb83f91ca, 0xcb6b9aee], [0x0990cd70, 0x7962b196], [0x440d6824, 0xa879e2e2], [0xed16aa4b, 0x2ab27a17], [0xcb54b3c4, 0x93c821fb], [0x7de70d10, 0x1d6de6ac], [0x228bff9e, 0xfe4d9303], [0x84d4673e, 0x421af8cf],
+    [0xa78579e7, 0x8b263858], [0x14db3ce4, 0x093e6afc], [0x5f014294, 0xe4362e8d], [0xfe04d0d4, 0x5445c4a7], [0xd23d4ee3, 0xd84caa2d], [0x6c8c336a, 0x68f5cb3e], [0x369630f7, 0xb7c810aa], [0x9c8665b5, 0x33c3c41a],
+    [0x8ee19905, 0xf6722d1a], [0x2d32598e, 0x49c424a8], [0x7718eae7, 0x9d7b05d5], [0xc6012bf2, 0x11039593], [0xe5e79adb, 0xa63e4ab9], [0x48c64052, 0x279f8525], [0x00c0515e, 0xc6d90148], [0xb0003bdd, 0x72782f81],
+    [0x82a8f794, 0x99ef10ba], [0x268fa6fe, 0x141e055d], [0x4d8b6c5e, 0xcc98cd1a], [0xe0bd6599, 0x7c668f16], [0xe9e545e6, 0xc24016fb], [0x73e16961, 0x45e8b666], [0x28601449, 0x95949394], [0xbcf7e767, 0x2214b1fa],
+    [0x99d6f146, 0xd42fe67f], [0x305674b3, 0x618ea95e], [0x6a58bfa4, 0xba31f284], [0xf74b1e93, 0x0e2daeec], [0xf8fb4602, 0x860a4061], [0x58ec9a0b, 0x0527a4b3], [0x3ca3df7b, 0xdfbdb226], [0xa2e39514, 0x5f1957b8],
+    [0xb569a087, 0xaf6e9618], [0x058c59a5, 0x2c402e99], [0x79b17313, 0xf018c059], [0xcd4df20b, 0x4c262dd3], [0xc15b8a83, 0xfbb2c9fd], [0x413a4a78, 0x75c8ef44], [0x0db7dae4, 0xa2b92202], [0x8b51edff, 0x1babad20],
+    [0xa845600f, 0xe138eb6f], [0x1b7b8028, 0x519cdd61], [0x53ed1cee, 0x8c628e78], [0xd57f828f, 0x3db5436a], [0xdda43fcd, 0xb1b6216a], [0x64407638, 0x35eb2ec3], [0x1045a9d4, 0xe89e4168], [0x94d02772, 0x6ed2f720],
+    [0x882c030c, 0xa1785566], [0x2a100808, 0x21a789a6], [0x716b8350, 0xc0aad02a], [0xc333bfb4, 0x772a9d86], [0xe22a50ce, 0xf2cb55a0], [0x4eb0b5f8, 0x4fe2551a], [0x061aec36, 0x9b427ce4], [0xb6a19f10, 0x16f8e5bb],
+    [0xa0b2155c, 0xdcecfa1c], [0x12470e18, 0x6dd0ca59], [0x5b2240cf, 0xb2d5e930], [0xfb16d4ef, 0x3780ea5f], [0xd67c2fcd, 0x8fd93916], [0x686971e6, 0x0cf00997], [0x3241267f, 0xe2b93524], [0x9afa6a1f, 0x53a71c64],
+    [0xbe5fe0aa, 0x96dfec12], [0x0ea90419, 0x1802cc31], [0x4263bfc5, 0xf8867cf8], [0xeafd1f3f, 0x4671a78a], [0xce9e9bf8, 0xcf8ff6b6], [0x7b886138, 0x7f55360d], [0x256ec4e2, 0xaceed709], [0x8113c89e, 0x2e21deaa],
+    [0x96e8395a, 0xeadc7fe0], [0x3e48a81b, 0x5c57bb54], [0x668209a2, 0x84e9ad97], [0xdea35b95, 0x06d990af], [0xf5cefcba, 0xb8c1dbc1], [0x51703a48, 0x3ef893af], [0x19b2bce0, 0xd6f6d0a8], [0xaabdb9e5, 0x6228d5b4],
+    [0x9f938681, 0x815ffe42], [0x342921cd, 0x01733f90], [0x56b13c18, 0xdba78773], [0xf24ccceb, 0x6a21bac2], [0xfcd78fba, 0xd1b18fb9], [0x63996eea, 0x5763783b], [0x3a52dbdd, 0x89676377], [0xa57d41dd, 0x3af3fabc],
+    [0x86bcd15e, 0xc4dec471], [0x21bdc7e7, 0x71e04340], [0x7f1082b1, 0xa404af9c], [0xe621fbb2, 0x1e3b77c8], [0xee7a4e48, 0x9f19580f], [0x475dc93d, 0x13b3b687], [0x2ffb9d7a, 0xc9389f00], [0xbaf10b2d, 0x4beec437],
+    [0xadc867f7, 0xb4eaf684], [0x1ecbea7f, 0x312962d6], [0x6f906158, 0xeefbe5a3], [0xd1ded36f, 0x58872317], [0xdb86d8be, 0xe6684410], [0x5d18f578, 0x6443aa30], [0x17945f0f, 0xbd53ece0], [0x92ec64fc, 0x0b9bb9e8],
+    [0xb3c2df04, 0xfcf48120], [0x02b6938c, 0x40f77d53], [0x4ad4fe78, 0x915b633f], [0xc872138c, 0x2560659f], [0xc4332ba3, 0xaaefa056], [0x75eff6aa, 0x28d6cedc], [0x0b2c96c4, 0xf5b46df2], [0x8c436107, 0x7b85e1cd],
+    [0x856dcc23, 0xb36a91e1], [0x23f7b35c, 0x36de2891], [0x7c1a8a00, 0xdd6b0a76], [0xca041fe2, 0x6c453131], [0xec32af04, 0xe33e7853], [0x45af6c06, 0x52c6f50d], [0x082ca457, 0x8e374f00], [0xa1d04139, 0x1cfed8f3],
+    [0xaebc82fc, 0xc119ae45], [0x07db7cc1, 0x67e2f7a3], [0x554cf51b, 0xa03df0a4], [0xf13eab7d, 0x208f46ca], [0xc2368d20, 0x831b9df6], [0x70397253, 0x02a79254], [0x391ae02b, 0xf3c7fd0b], [0x90590dc0, 0x4e37478a],
+    [0xab60c1df, 0x9ca46c1d], [0x18fc6d93, 0x105ecb07], [0x50267de1, 0xf70df6c2], [0xf47914bd, 0x48292a76], [0xdf4d2f0a, 0xc742914c], [0x76c6c3fd, 0x6324bee8], [0x3ffa4e4a, 0xa7bd90e8], [0x8fb844e2, 0x3ff53c7b],
+    [0x9dd10266, 0xf9e02f86], [0x378e60a4, 0x471c4139], [0x6daa0e60, 0x97619b42], [0xcffac08f, 0x084aba1f], [0xebbca978, 0xb64ef660], [0x433d3573, 0x32760b24], [0x0fbc496d, 0xd9c60273], [0xbf5c8771, 0x692ac3b9],
+    [0x8dcd34bc, 0x8d1dbc47], [0x2ebf42e0, 0x0f16f44c], [0x59c0cf1e, 0xc5bb4acb], [0xf916cbc4, 0x702cc223], [0xf6cc6824, 0xc8218b7c], [0x7e6691de, 0x5ea42937], [0x20538e19, 0x87438b15], [0xa96669b3, 0x29859a31],
+    [0x93048a58, 0xcd0f2526], [0x3bfec545, 0x7dad612e], [0x62fa2c02, 0xaed9b257], [0xe898532e, 0x002a7070], [0xf35fed3c, 0x940b0256], [0x57bb7c52, 0x1a8ef5a3], [0x27bf1778, 0xd0bc80bd], [0xac3f7732, 0x449d9321],
+    [0xbbf82010, 0xbbea4d28], [0x11849b17, 0x24b5be7c], [0x742d896a, 0xe048545c], [0xc5b3961f, 0x50efb023], [0xd407cf6e, 0xf4dc1110], [0x4b8f1bec, 0x6f2a93b0], [0x03858a39, 0xb03b4b25], [0x871bf885, 0x044afc38],
+    [0xbd9692b7, 0xef4637f6], [0x164f3721, 0x4d9c1ac0], [0x5ca04534, 0x989c4168], [0xc087bdab, 0x305db633], [0xd05279fd, 0xa31d150b], [0x6e33379e, 0x231cbc93], [0x04f825ee, 0xe78f4956], [0x9e26c1fc, 0x7454da5d],
+    [0x9bed48bc, 0xa9bfff0c], [0x330d3f32, 0x2b5d57d7], [0x69870ded, 0xcacf322b], [0xd7b9e10b, 0x78634910], [0xfa8d203e, 0xff5c9968], [0x5aebfad8, 0x438d6d2e], [0x1345af27, 0x9204cc0b], [0xb9443ebb, 0x0dcbd683],
+    [0xb7fdef66, 0xd352e8ca], [0x1de9e6e3, 0x76092039], [0x4f8edf51, 0xbfb563a3], [0xe343bf1a, 0x396bbc0a], [0xd8b823c0, 0x9a9a8be7], [0x601004d5, 0x173b179e], [0x2b3eef8d, 0xec551e8a], [0x89f4453d, 0x5bdf74fd],
+    [0xb161b6f0, 0x85a81966], [0x01423174, 0x071832b2], [0x49ad02b4, 0xeb7ef474], [0xe461fb56, 0x5d4484ba], [0xc70477b0, 0xd741bba1], [0x67a2592f, 0x73008482], [0x2ca93799, 0xb9c06485], [0x97682b15, 0x26ff2cac],
+    [0x80643083, 0xe58de284], [0x243a5dbd, 0x5588dbc2], [0x7a42e217, 0x8acc33fb], [0xd3684973, 0x1906a125], [0xffc91a7a, 0xade2db18], [0x5e241260, 0x2f4c03ce], [0x15b4c6a6, 0xced29d5d], [0xa65f0dce, 0x7e5abfa6],
+    [0x955482ed, 0x90388389], [0x3d8b3f5a, 0x1f7e3f53], [0x46c7fd33, 0xd57ce823], [0xef097368, 0x6032a037], [0xe7b227d3, 0xde51fefb], [0x6b6a7817, 0x4a1e8797], [0x31116eed, 0x9eb706b2], [0xb20ec50a, 0x34da0423],
+    [0x8a13392a, 0xdaf5cacd], [0x29f954ee, 0x6bb97ec7], [0x72e8bf81, 0xb55f9e35], [0xfd39e73a, 0x15a34eb7], [0xe14309cb, 0x88b6121c], [0x4c990a41, 0x0a3c3639], [0x35e92cb9, 0xc3c01df3], [0xb4ca59f5, 0x56628460],
+    [0xa37456f0, 0xa55ab8e5], [0x0a7c38b9, 0x3ca6c795], [0x65a74649, 0xfd4a11f2], [0xdc455c55, 0x41efbede], [0xc98753aa, 0xe9d4e066], [0x52766bd9, 0x7a29cbb5], [0x1aa7dfd9, 0xab3b3580], [0x986193ad, 0x1268614c],
+    [0xa4ff2e87, 0xf11738c3], [0x0cedd345, 0x5988a19f], [0x40077ca3, 0x80b3b400], [0xda53df16, 0x2d7c37c3], [0xcc214824, 0xbc01f352], [0x78c7ea75, 0x3beb1dd7], [0x1f786788, 0xfa9a66f0], [0x836a9eaa, 0x6506d5c6],
+    [0x9c065067, 0xb70a5c72], [0x3647a4ee, 0x334e6c0d], [0x6c0cb264, 0xd8c56141], [0xd2bc44f9, 0x6804c52c], [0xfec7543e, 0xe498e375], [0x5fe45c90, 0x54e1ba24], [0x14217a10, 0x8bc5ae2d], [0xa718a9df, 0x09814c0c],
+    [0xb0873380, 0xc613c26f], [0x0036dc34, 0x72a2f759], [0x483c1d77, 0xa6d3b659], [0xe57fbc17, 0x27334088], [0xc6a629c4, 0x9de2578d], [0x77bd69e3, 0x118ba0c3], [0x2dc2e457, 0xf6893324], [0x8e427c89, 0x4945cfc5],
+    [0xaf67c64b, 0x82f80170], [0x1cc0852d, 0x034a8126], [0x547a65d0, 0xed3bda79], [0xf009dccf, 0x5a808701], [0xd92bcd7d, 0xd2a5dc02], [0x6191054f, 0x6632cee4], [0x380418c7, 0xbe5c0a55], [0x91f3aa05, 0x383ccb57],
+    [0x845934e5, 0xfe8362bc], [0x224c02d3, 0x42f8c423], [0x7d088295, 0x931b6785], [0xcba409e1, 0x1db89d1f], [0xedf238dc, 0xa897d1fd], [0x4495ddb7, 0x2a764cc1], [0x091c7d32, 0xcb827f33], [0xb8dd38bd, 0x79a5dd38],
+    [0x8bea7ed0, 0x9544523e], [0x28d03c4f, 0x1b333547], [0x41ed8046, 0xc28e5d2a], [0xe91471a6, 0x75564a6d], [0xe06e9309, 0xcc403fbd], [0x7970e9df, 0x4cb1b3a8], [0x2601d17f, 0x996896fc], [0xb5acc6a2, 0x2ccd463f],
+    [0x945a3dd4, 0xdf4c572d], [0x3c3fcd44, 0x6e761a95], [0x64b77b47, 0xb15939ee], [0xf8722fc9, 0x05f8ec28], [0xf7a971d4, 0x8c9a918f], [0x534d1ba8, 0x0ecb6969], [0x30e3a69a, 0xd49fd52b], [0xa8ece254, 0x51685583],
+    [0xbc25a1e8, 0xa22246c7], [0x0d3bb000, 0x22915c9b], [0x73108dae, 0xfb1c7564], [0xc184d81a, 0x456f1031], [0xcd9697d9, 0xf0a77234], [0x4d477327, 0x7c8c3015], [0x0542c3e2, 0xafc09696], [0x82615ef8, 0x14ca477b],
+    [0xa2525e57, 0xe87adeeb], [0x10bfcb79, 0x5f87cd8b], [0x58151ce5, 0x86e103fc], [0xdd440e7e, 0x3538c893], [0xd5accba9, 0xbad928f6], [0x6aaf3bf1, 0x3d7350ad], [0x1ba22a17, 0xe1a74a3a], [0x9956edca, 0x6114798c],
+    [0x81d259bf, 0xac6ef2b2], [0x25974199, 0x2ed8bbd5], [0x7b3d1d4f, 0xcf629e4c], [0xce69380c, 0x7f9669a2], [0xea4e8df2, 0xf8029be7], [0x42eba1ff, 0x46db9828], [0x0e780b3c, 0x960e7a46], [0xbea122e5, 0x18d6df4f],
+    [0xaa2a3872, 0xd630ca77], [0x192d94f3, 0x62b4203d], [0x5197e0cb, 0xb8246068], [0xf57eac69, 0x3e0f04d0], [0xde63e5c6, 0x8458fb47], [0x660356ad, 0x066d6463], [0x3e99a8bd, 0xea63c9dc], [0x9640daaa, 0x5c9c54b8],
+    [0xb65ffd9b, 0x9ba9d0ee], [0x06a878b2, 0x160f34cb], [0x4e0405a6, 0xf27b45b1], [0xe2945171, 0x4f5889ba], [0xc3d59f92, 0xc0254525], [0x71b153b2, 0x77d40dbe], [0x2a9aa19e, 0xa1e6eb96], [0x88adc4d1, 0x214addca],
+    [0x9a6a4b94, 0xe23dff45], [0x32f7212f, 0x53142fe9], [0x68afff09, 0x8f28ba71], [0xd6cc45b2, 0x0c219d04], [0xfbb0ca64, 0xb207990a], [0x5b916ecc, 0x37188bd5], [0x128623be, 0xdc64eb1a], [0xa078d422, 0x6d53e70c],
+    [0x922f0601, 0x89afaf75], [0x3ae11915, 0x0b51298c], [0x5ddb3a9a, 0xd11e306b], [0xfc2ab792, 0x64957524], [0xf2867936, 0xdb29fe8d], [0x6f556499, 0x5835566a], [0x348e8be9, 0x81ec04df], [0xad326a00, 0x31e57039],
+    [0x8cf20c29, 0xc9c6974b], [0x2f76b2c7, 0x7b01c14d], [0x750f1602, 0xaa445128], [0xeec2a1e3, 0x136d811b], [0xe6d8308b, 0x91d56dd4], [0x4a6dff91, 0x1eb37923], [0x2128d361, 0xc458db6f], [0xb35a5970, 0x4076bcf3],
+    [0xa5aaae45, 0xbd85a026], [0x17647617, 0x3a75a236], [0x637e32cb, 0xe6d5af95], [0xdb6a0069, 0x57b70e41], [0xd11c56e8, 0xee12c349], [0x561ba113, 0x6adfe9d2], [0x1e7da526, 0xb4687a57], [0x9f0427bb, 0x01f9afaa],
+    [0xba688169, 0xf5430fc1], [0x0bcc0f1b, 0x4b50c5aa], [0x47bcea82, 0x9fa14909], [0xc4fd2f71, 0x283ef26b], [0xc8ad99c9, 0xa49a5eea], [0x7ff13cf3, 0x25c09a93], [0x020e793d, 0xfc713acb], [0x864ddedb, 0x71498c5a],
+    [0x8f532953, 0xb970fbcc], [0x2c6f1e0b, 0x3f0b62a8], [0x7676b30d, 0xd78b5750], [0xc78d5093, 0x63cd4a37], [0xe4ba3dc8, 0xebf63b9e], [0x495a86f7, 0x5de15098], [0x01f65d14, 0x851574f1], [0xaba11f17, 0x10ecae54],
+    [0xa6ac73a1, 0xce498e47], [0x0f60b5e5, 0x69c9bc46], [0x5ec55446, 0xad2c6fe1], [0xff7dd2fb, 0x2ffd236d], [0xcf0474fb, 0x8a02a9ec], [0x7aee829c, 0x08e3c40f], [0x374fdce2, 0xf94eb30a], [0x9d4c980d, 0x47c2aba4],
+    [0xa17cdca2, 0x92e8ee8d], [0x138859af, 0x1c6e072c], [0x5a7f9c92, 0xff9d2302], [0xfa72a371, 0x430182f7], [0xd774f4c6, 0xca59b505], [0x7c83fec4, 0x6cffc380], [0x33ecb73e, 0xa9509e9d], [0x85b68097, 0x3649c3ea],
+    [0x90cb4367, 0xf35cb217], [0x39865016, 0x4ec4e2e4], [0x6088bc04, 0x9a252d65], [0xc2919cc6, 0x0248e287], [0xe38b54ea, 0xbf010b65], [0x4f315214, 0x39ffcc27], [0x07125d04, 0xd39bf48c], [0xb70e08b5, 0x674a7fad],
+    [0x87e60bd2, 0x87febdeb], [0x20ce9878, 0x04dafdba], [0x52c15e65, 0xc8e4465b], [0xf60b5f24, 0x7a8d2dda], [0xf9ecdbaa, 0xc510478a], [0x74f5d19e, 0x502fe3dd], [0x2e2dd549, 0x8dc861f8], [0xa3ae5ba7, 0x24054247],
+    [0x9e891a7f, 0xc3641401], [0x3544b26d, 0x74f74651], [0x6e87933e, 0xa39aed94], [0xe1c75bbb, 0x0acd03d7], [0xfdec076b, 0x986cbc97], [0x5c403e10, 0x1541820d], [0x291d4b4d, 0xda0efb65], [0xa428ec55, 0x4d320420],
+    [0xb2adbd06, 0xb0ce319d], [0x1a0eaf94, 0x29113c09], [0x7ea378fb, 0xe937e612], [0xc920beff, 0x5e776171], [0xdca54e87, 0xfd9d0fa0], [0x46133043, 0x60dc9aed], [0x0ad79d80, 0xbb5a6dc6], [0x8d54ccf2, 0x0f93eb0f],
+    [0xb4639678, 0xe75dbf39], [0x1fded8b3, 0x445b0c71], [0x575cd4dc, 0x94d302c4], [0xccf14e4f, 0x3b49ce92], [0xdaacb43d, 0xae336acf], [0x625687c1, 0x2df13ea8], [0x0c413694, 0xefcd2cd8], [0x93ee8c5b, 0x7d0a13e6],
+    [0x97b918d1, 0xa71440c8], [0x3f07adaa, 0x26783127], [0x673138c1, 0xc7e4400b], [0xdfd56e5d, 0x73c48ae7], [0xf4df8068, 0xf7f924a2], [0x5096704d, 0x488f2211], [0x1836b485, 0x9c0a8831], [0xb182fa85, 0x07d6c050],
+    [0xbfbb55ed, 0xd941a826], [0x15650d59, 0x7ec20627], [0x43932225, 0xb6dce645], [0xeb3b9f8e, 0x32c81c28], [0xd383f0b8, 0x97fbec4c], [0x6d41b143, 0x19ffac22], [0x249b9e73, 0xe559e133], [0x8091b194, 0x5512c895],
+    [0xb9cadd72, 0x8ef18f6b], [0x08af51e5, 0x0d407812], [0x457eed4b, 0xe3b9a795], [0xece9bdde, 0x521b69a3], [0xcaa7a017, 0xdda0bc8d], [0x69588516, 0x78d83a8e], [0x234653bf, 0xb3e055e9], [0x9b711f5d, 0x2bc93867],
+    [0x8925f283, 0xecdfbaf2], [0x2be65b98, 0x5b4d12ed], [0x70ac15c9, 0x83daa44b], [0xd802de36, 0x17f690c8], [0xf1c4a21a, 0xa0ef717d], [0x55ede1ca, 0x20549577], [0x1d760e33, 0xc182ca4a], [0xae5e3765, 0x76990370],
+    [0x989a18d3, 0x9e1aaded], [0x31b27cac, 0x1288e2e9], [0x4b03d2e5, 0xdeac7ec1], [0xe70858b9, 0x6ffdedef], [0xefcf1812, 0xd5af80fe], [0x652b837f, 0x412e356c], [0x3d6d31a0, 0x90987949], [0xbb6078b0, 0x3c1e2cdc],
+    [0x83e9277f, 0xd01c9721], [0x2724c253, 0x65e4a906], [0x78156ea6, 0xbc8e4cba], [0xf3968fcc, 0x1a29a863], [0xe81a0e32, 0x8024f02d], [0x4092e522, 0x00a9ec8f], [0x3b48e429, 0xcd83efaf], [0xbd711119, 0x59763a61],
+    [0xa9c4c5fb, 0xabfb4d7a], [0x036b6703, 0x34032c50], [0x6bc67c7b, 0xf4199db4], [0xd4ca5eed, 0x4ac3193f], [0xc50f8889, 0xe0b801e5], [0x596df41d, 0x70ed0c1f], [0x11259fd5, 0xa5dd4e0a], [0x959cec28, 0x1f9d5de4],
+    [0xace016be, 0xfa563d2c], [0x0454c345, 0x56ba9e8e], [0x4c489134, 0x8856e005], [0xd0839af7, 0x23f36112], [0xc02bf9aa, 0xb5e0a6ec], [0x725a2b15, 0x30a16209], [0x16c06d9e, 0xf199065a], [0x8ab5ca5e, 0x6b4ee668],
+    [0x99199ec4, 0xba635ff0], [0x3cc15c18, 0x357283a3], [0x6ad230a1, 0xd45e63b9], [0xd5ca40a6, 0x61e4fd15], [0xf725c439, 0xe1cfe937], [0x538c8c2b, 0x51001fce], [0x1b054a4d, 0x8ce99d88], [0xa8307cb1, 0x0e9ea264],
+    [0xb5f2b979, 0xccc90e42], [0x05297e23, 0x7c159e53], [0x416b87ee, 0xa261b7fe], [0xe02e2a1d, 0x2c099bce], [0xcd0255b8, 0x993e01b6], [0x79ddbcc5, 0x1496407a], [0x28a3e2e5, 0xfbc31375], [0x82e4f886, 0x4cd2ecf2],
+    [0xa892d98c, 0x8c2911fa], [0x1bd23080, 0x0e4f6af2], [0x533f40ef, 0xe1549f94], [0xf8bb9c43, 0x5ff2af26], [0xdd2c0866, 0xdfd7316f], [0x64c720cf, 0x6ebbce45], [0x3c78e271, 0xb1eb333e], [0x99a7da9a, 0x3d35cf0f],
+    [0x8b3a375a, 0xfb4463be], [0x264fd243, 0x4c449265], [0x73a8a343, 0x9537023d], [0xc1093e6b, 0x1b525ce3], [0xe0e6d887, 0xaf97ca93], [0x41a3a0e2, 0x225bbbdf], [0x05c37e03, 0xcc1e17ff], [0xbc60172f, 0x75a50646],
+    [0x8e16870b, 0x9d26b0be], [0x2dbc58db, 0x115ea475], [0x44e6d262, 0xcb012cd1], [0xe580aa80, 0x72e10b67], [0xed7ed14a, 0xcbe7ec2d], [0x7d52b9e2, 0x426b5616], [0x220f3f7d, 0x93aebf08], [0xb0cf40f8, 0x27e21dab],
+    [0x9ce25b26, 0xd8a74818], [0x3867fceb, 0x66c3cd14], [0x61c93ca1, 0xbebafc1a], [0xf0bef8b1, 0x032dba24], [0xfe72afc0, 0x8b92bdf4], [0x542a9f6b, 0x03ae134c], [0x38a4c68f, 0xd2d3159f], [0xa7e087ca, 0x54a2474f],
+    [0xb05ee5cf, 0xa6b3f45b], [0x00a4c9a8, 0x27454672], [0x7d96cabf, 0xfefd651b], [0xc6d2a292, 0x49b056fa], [0xc66688e0, 0xf6e5b6c6], [0x447e5e11, 0x79dd163c], [0x09d2b659, 0xa8f9e9ff], [0x8e8de55c, 0x11d7d1e1],
+    [0xaf8d9443, 0xed442fb9], [0x14510b6a, 0x543a0871], [0x5f9a3803, 0x8b74af81], [0xd260358f, 0x3334b307], [0xd2fb39f0, 0xb7937253], [0x611b6102, 0x38563999], [0x1c922d55, 0xedb01eb9], [0x9c5c9287, 0x688543fe],
+    [0x8620eaa2, 0xa44570b3], [0x2f9cd418, 0x287d16c4], [0x75847062, 0xc9851ede], [0xc8d33bd5, 0x71a0ed1e], [0xe6431d76, 0xfc282ce8], [0x47cb82ed, 0x4ba176d7], [0x0bb2836e, 0x9f6f6806], [0xba0ff345, 0x13e23dcf],
+    [0xa5122ed1, 0xd14da889], [0x1e2bb1e6, 0x6a403169], [0x5d77eccb, 0xbdf637be], [0xf2d5e111, 0x31704f2a], [0xd1a0d38a, 0x81a9a32f], [0x6fd45735, 0x01bd2c29], [0x34530a80, 0xee522f58], [0x927fbc31, 0x573ba32c],
+    [0xb322ff02, 0x9110d50e], [0x026302b7, 0x1e4328d7], [0x4733dd48, 0xf51d23f0], [0xee10cbb1, 0x4b1899de], [0xc835eaa7, 0xc435378c], [0x754d7695, 0x7bf712ff], [0x21e94701, 0xa4fce74f], [0x86fe2e9d, 0x25a8e96c],
+    [0x9f649706, 0xeeb8968e], [0x34ce539d, 0x58d188c5], [0x6f2baeaf, 0x8113fbc8], [0xdbcaeb34, 0x0b270e42], [0xfc43ddda, 0xbd185a3d], [0x56f7816f, 0x31b92c1c], [0x170b9357, 0xd1ca7055], [0xadb79235, 0x6a938abb],
+    [0x9633718d, 0x849e470e], [0x321e76f7, 0x0c76b797], [0x51391c06, 0xd67da742], [0xf5bc28bd, 0x62c7fca4], [0xf51663cd, 0xd691e220], [0x68c33c7b, 0x53c59751], [0x3e21a7e6, 0x84258b5b], [0xa0dc874c, 0x3764e589],
+    [0x8153d866, 0xcf39d3e6], [0x2536bf1a, 0x7ff2138b], [0x71fa4312, 0xa11c1a82], [0xea30de46, 0x1866aa1d], [0xe2cfd741, 0x9b029ffe], [0x423d9d54, 0x1895184c], [0x25c5e021, 0xcfc02ca4], [0xbe166c06, 0x46b834f1],
+    [0xaad5518e, 0xb843f185], [0x19f4a7fb, 0x3e536a80], [0x6817068b, 0xe2413074], [0xde3fbcdf, 0x5c20abe4], [0xded8c839, 0xea22bd23], [0x51d648b8, 0x6268751e], [0x12220610, 0xb261621f], [0x9a12b701, 0x0c861a02],
+    [0xb6c5c6bf, 0xf20fd653], [0x066d2d11, 0x4f055bff], [0x4efc22f5, 0x9be756b6], [0xced32f9a, 0x2e83367c], [0xce26ca4f, 0xacb12070], [0x711c518a, 0x212dc4b3], [0x06fb2dbb, 0xf295ceb4], [0x885ac3c0, 0x778e361f],
+    [0x8322d138, 0xbce72411], [0x27db2c73, 0x3b19c3b4], [0x720063a7, 0xda99e5a5], [0xcc68581b, 0x65a8cd9f], [0xe1bc9e51, 0xe7ff0a64], [0x4c216d0b, 0x562e80a6], [0x0425ebbb, 0x88d017fe], [0xaca7fa87, 0x1ae10e20],
+    [0xa3d7e21c, 0xc5c382ff], [0x0a845dc5, 0x60459ab1], [0x521e222b, 0xabb82216], [0xf680a5eb, 0x297db782], [0xc94c6790, 0x87065520], [0x746dd0e2, 0x0fd9f7a3], [0x3dd9e56c, 0xfdcfd411], [0x95226619, 0x41675f78],
+    [0xac6295aa, 0x94a6c1af], [0x16b47f72, 0x15eb51b9], [0x5c238ae0, 0xf16fa783], [0xf33a692a, 0x4401cdcb], [0xd0fabc7e, 0xc39c9e9f], [0x72a7392d, 0x6b3e6bb7], [0x35358b55, 0xa37404ba], [0x83b36c81, 0x3ba2c7f2],
+    [0x95fb27e1, 0xfd04fad2], [0x3d2daf87, 0x41b6c250], [0x6b274773, 0x9e55cecc], [0xc57b397e, 0x0f63e255], [0xef635e80, 0xbb2f4cc9], [0x469fb50a, 0x3c7eea6c], [0x03127311, 0xde36fa44], [0xbb8ecb50, 0x608b6ab1],
+    [0x80c7acd5, 0x8a9ba81a], [0x2b594e69, 0x022788c8], [0x55b78454, 0xc1530208], [0xff9456f3, 0x7ea68625], [0xf154f387, 0xc1fbe2ed], [0x70cfe2a5, 0x5b8413f6], [0x244f5fe5, 0x8a4a5454], [0xa6d9e8eb, 0x2f089b68],
+    [0x97166492, 0xc7a84977], [0x3f876b6f, 0x73a89b43], [0x690885cb, 0xa9f213ec], [0xec70dd91, 0x0d13674c], [0xfa3de099, 0x927c29de], [0x506c3280, 0x109dd47d], [0x2c324950, 0xd73362c4], [0xa13665f9, 0x43cf2ac9],
+    [0xbf236f9b, 0xb687873b], [0x1d190951, 0x20f63335], [0x7aba2f3e, 0xe5f91dbb], [0xcf4a297b, 0x55e41d81], [0xd84fa576, 0xf3815970], [0x4fd743ba, 0x67302524], [0x0f04e835, 0xb63707d9], [0x8039ba21, 0x08891d0d],
+    [0xb1feb410, 0xeb382992], [0x1869d02d, 0x4847b505], [0x50fdd093, 0x9cebce9b], [0xcaeaa66a, 0x36a4e1af], [0xdfa8bc97, 0xa7ee3a7f], [0x69fa922b, 0x2bbb3827], [0x012012db, 0xebbb3a9a], [0x9b93eaad, 0x78a7ce2b],
+    [0x9e7d8bf1, 0xa3f25381], [0x3b0a94e0, 0x2d00d865], [0x6234ad76, 0xcd4c8450], [0xdad2e6d9, 0x7dcd5965], [0xf3fb227e, 0xfac70f70], [0x5cea1a48, 0x4d412a12], [0x16376675, 0x980c2ce2], [0xbde8c205, 0x00e81439],
+    [0xb2747f88, 0xdefe9e7a], [0x1af4b36b, 0x7afdda81], [0x4643b61c, 0xbbb2583b], [0xe7fae5b1, 0x346a825e], [0xdc04011c, 0x90ff45f7], [0x6b9bd9cf, 0x121500e6], [0x2ed860c1, 0xe0dbcf89], [0x8da54192, 0x50776e95],
+    [0xb4bfbf38, 0x88249e7e], [0x04a6fcf1, 0x0ab7dcdf], [0x4ceb8b6f, 0xe704d1d1], [0xe848f66e, 0x59f08bf0], [0xc0f23426, 0xda731e4c], [0x6eefb4df, 0x74108ab4], [0x29a91a68, 0xb58f7d8e], [0x9eeac851, 0x234c2c33],
+    [0x87593215, 0xe95b3540], [0x201df497, 0x5e269045], [0x7497c1cc, 0x8d7e71dd], [0xdcd00079, 0x1f3019db], [0xf967b4dc, 0xa5839a6e], [0x59ada0d7, 0x247f2eab], [0x1a5db030, 0xc871fd58], [0xa9885816, 0x7a6a6fdc],
+    [0x900f2f45, 0x9a5bf5c6], [0x371228c8, 0x19418b51], [0x43484d29, 0xd934f819], [0xe3cfaefd, 0x67821178], [0xe33e9e3f, 0xd3c6f9d7], [0x6df78097, 0x47b10c19], [0x39ef43d2, 0x9af67c2a], [0xb7ab269b, 0x3997079e],
+    [0x8fffb5ab, 0xd7f33995], [0x2313db5d, 0x6c282dc7], [0x7685f9da, 0xb907a608], [0xfad355a0, 0x1c0f43d3], [0xecbd5a15, 0x8e53b2dd], [0x49d4e843, 0x078680bf], [0x3374a442, 0xca01f5fa], [0xb106e871, 0x5d895163],
+    [0xae2e6681, 0xa05e113a], [0x0776c37c, 0x390246aa], [0x6d1f84a1, 0xf9a08cb0], [0xd3e6f5d3, 0x4752c3d6], [0xc2fd8207, 0xec3f974e], [0x55312739, 0x76dd765d], [0x150e7e78, 0xad91bd46], [0x9da8a199, 0x19a13b7f],
+    [0xa19753f7, 0xffdd5b18], [0x01bcc9bb, 0x5d063feb], [0x45f0881c, 0x8e8ecf56], [0xdf11b4fa, 0x26208874], [0xca659c94, 0xb3a37f75], [0x7609dfa9, 0x3fb3d1e6], [0x13f12834, 0xff14f9a3], [0x851a2f98, 0x6cbd1464],
+    [0x9498975e, 0xb13cbd48], [0x3089fcc1, 0x3dfc0290], [0x64176b00, 0xdf1b35a1], [0xddd24105, 0x6e307828], [0xf82626e1, 0xe8c25432], [0x5859986a, 0x5f7479d9], [0x10f2b1f6, 0x864c412c], [0xa23fdb81, 0x05562606],
+    [0xbcab3fe7, 0xc2fef1cc], [0x0deab179, 0x75117551], [0x4d123354, 0xaf0c3aa5], [0xe9ac8dbd, 0x22fdce93], [0xc1d09b35, 0x95dc1d9f], [0x7340cd56, 0x1bf73e56], [0x26e11d1b, 0xf0d2139a], [0x8bb09623, 0x4587abc9],
+    [0xa28510fa, 0x869384de], [0x1020a20e, 0x05aae80e], [0x58b5caf7, 0xe8302b6c], [0xf7fe83b1, 0x51f03a4a], [0xd52e6877, 0xd4d57a1e], [0x6a1f6b69, 0x616a2211], [0x300e6545, 0xbaa3e6d9], [0x94170c8f, 0x35960245],
+    [0x820324c3, 0xf073880b], [0x282ce8ec, 0x4536d271], [0x79051fb4, 0x99977b22], [0xcdfdaeb7, 0x1462f916], [0xe95fe6d8, 0xa2cd494a], [0x4deafe9f, 0x2c87652a], [0x0d4d10b1, 0xc23034ad], [0xb52cb4a6, 0x7cf991aa],
+    [0x84be9dd5, 0x935a79ac], [0x22ed169d, 0x1dea2f86], [0x48a7c1ec, 0xc64d7e1a], [0xed999aa8, 0x79109385], [0xe52a3384, 0xc6ab999d], [0x774b19f1, 0x490f23ce], [0x2d6b958e, 0x9d8b8c94], [0xb844e6ed, 0x2a3d68cf],
+    [0x918dd601, 0xd215dc20], [0x36fb13fa, 0x686a7dc3], [0x6cdd8e00, 0xb77d2ab4], [0xfeb3e735, 0x096eeeda], [0xf05193bb, 0x827a0296], [0x5f71ed1a, 0x09d48fb2], [0x360b62d4, 0xd81f1c48], [0xaf2a8e6c, 0x5a755e00],
+    [0xb88a286a, 0xa8040296], [0x0946bfd8, 0x2acf41c0], [0x77e6598c, 0xf60d2450], [0xcb0fb3e7, 0x4288537d], [0xcbf114b1, 0xfe0c12a9], [0x4861716e, 0x722d1914], [0x00496320, 0xa66ac198], [0x843f8928, 0x1d0aae3f],
+    [0xa767a434, 0xe46ecff9], [0x1c0e46cc, 0x5add2f5a], [0x54c8c4ce, 0x82b98cfb], [0xd94cfc21, 0x38b52e60], [0xd9b6f68d, 0xbe208196], [0x6c7dae4c, 0x33a7511e], [0x14a0d08a, 0xe4c65e36], [0x914f2a03, 0x6649640d],
+    [0x8c02b495, 0xaa1e1157], [0x217ab627, 0x253d3f58], [0x7faf6366, 0xc497567e], [0xc4643edf, 0x7b7e88fd], [0xeeafe9e9, 0xf5edd57a], [0x4a835985, 0x401d2f94], [0x02ebfb78, 0x91ad37c5], [0xb38a6ed5, 0x1ec03a25],
+    [0xad48cef8, 0xdbdab41f], [0x17e732fd, 0x64e5bfa2], [0x565050c1, 0xb4ac5cd2], [0xfc861e67, 0x3a09743b], [0xdb23262d, 0x890eb29c], [0x6323b44b, 0x0bfbb496], [0x3a833608, 0xe62cdb7b], [0x9ffc08b7, 0x58515f38],
+    [0xba9abff4, 0x9fff1521], [0x0b6c427d, 0x1306fe4f], [0x4a01e67a, 0xfc8d6d72], [0xe68e747a, 0x408d5967], [0xc4b2cdb8, 0xc9542dac], [0x7f5624dc, 0x7118d903], [0x2f3141e3, 0xaa9efd27], [0x8c8d6b38, 0x28baffe8],
+    [0x92a5b482, 0xe6b48dc3], [0x3a1885ae, 0x57e26327], [0x63d63db7, 0x89e36eb8], [0xd16d1d5d, 0x011b256f], [0xf23cd8c5, 0xb42cf22b], [0x5d994f46, 0x3ab63b92], [0x1e874a46, 0xdb4c26a4], [0xa5e4395c, 0x6417764c],
+    [0x9a95ff56, 0x8f403381], [0x3ed3727b, 0x06a95f60], [0x5bc269b2, 0xdcab7fa6], [0xfbe1821c, 0x6d840999], [0xfb74005b, 0xdc3cad28], [0x66ce3778, 0x5cea04ba], [0x3290084c, 0x8f9165fb], [0xaa782d21, 0x3eb2b787],
+    [0x88cd4fd9, 0xc0c7e2c7], [0x2ad820d4, 0x777c64db], [0x7bd4f158, 0xac30c707], [0xe26bd18b, 0x16685a9b], [0xea853543, 0x96745732], [0x4e6da879, 0x16be5fca], [0x2a75fee9, 0xc057e790], [0xb613bd79, 0x4fa9fb3a],
+    [0xa0172f09, 0xb28cf16f], [0x12e89d8f, 0x37cf1eeb], [0x667519e1, 0xeabc7db8], [0xd635b874, 0x535a553e], [0xd6b43a10, 0xe2e54c7d], [0x5b6d1190, 0x6d071190], [0x19517c02, 0xb8b7b617], [0x968d7c24, 0x060fdeca],
+    [0xbee08f4e, 0xf8f798c4], [0x0e1240a4, 0x46376eb9], [0x429c4b48, 0x968b1e11], [0xc3bc48fc, 0x21d2d987], [0xc379d7ec, 0xa1b35945], [0x7b6a292f, 0x2e645098], [0x0ecdb806, 0xf856e758], [0x8185b658, 0x7f2d7d8e],
+    [0x8af433ed, 0xb51c7ec4], [0x297d36e0, 0x30218b3e], [0x789e4ebd, 0xd05d1d1f], [0xc0629ca8, 0x6bc4fb18], [0xe8cb1e2d, 0xefb8fcf7], [0x407c7119, 0x592a2834], [0x0c8ab99e, 0x805adb19], [0xa497add8, 0x151d6766],
+    [0xa90d5644, 0xc8a86e20], [0x03ea8acc, 0x6f825956], [0x59119079, 0xa535e225], [0xf98affb7, 0x24c58062], [0xc5d342e6, 0x8d9c9c2f], [0x7ef7f176, 0x040f3608], [0x31f913ed, 0xf48ebe4c], [0x98f82353, 0x4a7820ce],
+    [0xa45eb156, 0x98d469f9], [0x1f22970f, 0x1a5c8c18], [0x57fc8646, 0xfa0fe112], [0xfd8eaee1, 0x4df36176], [0xda0bb35d, 0xcdcc65a2], [0x785c680e, 0x6544085a], [0x3bbcc0af, 0xae55c3c2], [0x8a541ba8, 0x30fcb2bf],
+    [0x982b87fa, 0xf45eff6c], [0x314e70f6, 0x4a8771bb], [0x6567f0fb, 0x9046e183], [0xc9e67ba5, 0x04a470e2], [0xe76f2043, 0xb060080f], [0x4b79269e, 0x34b1f4d9], [0x0a255a1e, 0xd5ed7970], [0xb2da659c, 0x6f73bdf8],
+    [0x899a3731, 0x83858a8a], [0x24e70871, 0x0823e887], [0x5e5bb449, 0xce0acbc3], [0xf19814c2, 0x7676cb0c], [0xff2ada5f, 0xceacdf86], [0x7a227476, 0x55756f16], [0x2bbab3dc, 0x837f8c50], [0xaeddf9c0, 0x2025250a],
+    [0x9b2e627e, 0xca9cce29], [0x33a425b3, 0x7808ce56], [0x67c2b559, 0xa76285a5], [0xe4dfc33c, 0x075aa7ef], [0xf430f8e7, 0x9c46e62d], [0x5a017c8d, 0x1ca8b103], [0x23b3776f, 0xddd18255], [0xab228834, 0x48faa1b4],
+    [0xb77679a1, 0xbff30be9], [0x15ceb14b, 0x2f83b37f], [0x704c1c9b, 0xec92d43b], [0xc24b5d8d, 0x5b0e7012], [0xd3149e33, 0xf92c9033], [0x43ff8cd4, 0x696a4c6a], [0x07b7e561, 0xbf55516a], [0x894708e6, 0x02e1e7a4],
+    [0xb901c1bb, 0xe3d96085], [0x1338bcc5, 0x436fd3f1], [0x5ab816d0, 0x92b3f06a], [0xc7763f58, 0x3f7d0d23], [0xd7e5d483, 0xa919cb88], [0x677de656, 0x268a2345], [0x08f145b9, 0xe3708eda], [0x97e1694b, 0x734445b2],
+    [0x93abf865, 0xae85374e], [0x359c3117, 0x238ff665], [0x6e4aa5ad, 0xc322c399], [0xd021268a, 0x74826677], [0xfd7d62a9, 0xf1fd94fa], [0x573f760c, 0x44e742d9], [0x1f88a231, 0x9450023c], [0xb41e26a8, 0x0a77241e],
+    [0xbb30fb74, 0xd5239b76], [0x114a04e0, 0x70498c79], [0x4be8b6e0, 0xb0a10052], [0xefa3b910, 0x3cda9d39], [0xd4a3597e, 0x9ec3b568], [0x65c88b1f, 0x1fc09a1e], [0x20b70079, 0xe99c2fce], [0x878f657c, 0x5edb159f],
+    [0xbd2c0e29, 0x80d9ee85], [0x0c35cc25, 0x0057ced9], [0x40e9ab9c, 0xef230a6b], [0xe124bbe7, 0x56e735f2], [0xcc97b557, 0xd0fb2b4b], [0x629be7ff, 0x7d4b589f], [0x27632ef3, 0xbc4472e8], [0x93566074, 0x2db7217d],
+    [0x8d1a3042, 0xe016e7bd], [0x2e402be9, 0x508ce393], [0x7e1eeb2e, 0x879b1d0d], [0xd44b44df, 0x12fcfb24], [0xf64de8a6, 0xab7b9ae0], [0x5283e69f, 0x29ed350a], [0x11ccc220, 0xc575361b], [0xa31d7bdc, 0x708d44fc],
+    [0x9d38cd09, 0x970d8589], [0x39415e04, 0x17bdafd8], [0x4f42f0d2, 0xd310182d], [0xebe1b730, 0x69806d9d], [0xeb6cab87, 0xd9b4a8db], [0x60dbc661, 0x4e44376c], [0x37d2f018, 0x97a4ebc4], [0xbfdd4b91, 0x320c25ad],
+    [0x85c3726e, 0xdd2ccfbe], [0x2cf54ac9, 0x638951e2], [0x7ccb8bbc, 0xb309d3a1], [0xf4952d2f, 0x101e94b7], [0xe41912fa, 0x856a9b62], [0x451fa2b3, 0x0d9c2260], [0x3f70173e, 0xc72587e7], [0xb9be18b4, 0x529d178c],
+    [0xa60ff1ac, 0xad67bfe7], [0x0ff41404, 0x32bef409], [0x6066d5f0, 0xf3113fbc], [0xd8f8ed4d, 0x4ea128e3], [0xcf97b820, 0xe53efec6], [0x5e9e3df3, 0x7e16b2ad], [0x1da44a44, 0xa0a3af7c], [0x90bbd653, 0x174c2852],
+    [0xabe02038, 0xf748335e], [0x085fdb02, 0x5278903e], [0x490b315d, 0x85c62105], [0xd735ea3a, 0x2b3bd688], [0xc7f88cd0, 0xb9b3c719], [0x7c6a6452, 0x362e301d], [0x1895b529, 0xf781a668], [0x8f0b9864, 0x637bf20f],
+    [0x975835de, 0xbfc84610], [0x61f9413d, 0x27e2235a], [0xef4d34eb, 0x50051e2f], [0x15ba1126, 0xdc05582b], [0xcbf63281, 0xfdd2f3e4], [0x2667809c, 0x6ba7c8fd], [0xa6b9ddb5, 0x018ad9b1], [0x4c31357c, 0x8a95005a],
+    [0xf8675d8d, 0x99ea9566], [0x5ddfcd92, 0x49a7aa72], [0xde2311b5, 0x3594dac7], [0x7e6efa7f, 0xe391cf0d], [0xb2b37574, 0xc021aa27], [0x0d6bc3af, 0x16faa36c], [0x8d9d9fe7, 0x72cddd68], [0x3789e2d5, 0xac069b99],
+    [0xacc1cf3a, 0x84cc32fe], [0x44c49410, 0x0ad93d0c], [0xc15a18cd, 0x672d7f56], [0x2f461c3f, 0xf2985423], [0xe4ec2610, 0xd4c74cf6], [0x1ca6e86e, 0x5e872e1b], [0x98f85008, 0x2c33e324], [0x6d8dd7d8, 0xb1ba54ff],
+    [0xd3005149, 0xa464286e], [0x7680a07a, 0x7af598a1], [0xf0989967, 0x1a708723], [0x57b7971a, 0xcf70a52d], [0x877842d9, 0xea912692], [0x3b13aaf7, 0x39d76333], [0xbaab509a, 0x44897c11], [0x01033ff1, 0x945e289f],
+    [0xbd76b8fa, 0xab60bd8e], [0x7aaf6130, 0x1f8579e7], [0xd6dd08b7, 0x4c7bb726], [0x0a5e957c, 0xefc3e11b], [0xdbfc67bd, 0xc95483bb], [0x05a74283, 0x76248e87], [0x811255dd, 0x11eb3839], [0x71a8426f, 0x9d3c5af4],
+    [0xc56649dc, 0x8ce7cf3d], [0x4a2f8c4b, 0x63490c65], [0xe3d385c8, 0x229630f7], [0x657f5da9, 0xd0d78ed7], [0x9eb80609, 0xd8f69252], [0x10391a53, 0x2ae19905], [0x93e1e45b, 0x5b46cbd4], [0x214e4504, 0x83f57c14],
+    [0x881c7acd, 0x90489c11], [0x51d51355, 0x319c44e1], [0xff86d6c1, 0x7eebd031], [0x3da12da2, 0xc756d6ba], [0xf625277d, 0xe653203d], [0x339c7fd2, 0x4125a16a], [0xb485411b, 0x3cf8e00c], [0x5b8d1b01, 0xa0a9995e],
+    [0xeadfce29, 0xb43cc597], [0x68411040, 0x570221f2], [0xcc89d4b4, 0x0f0ed494], [0x431443bf, 0xfb83f544], [0xa0afb68a, 0xf5a8a0ab], [0x2aa36b11, 0x04e57ea5], [0xaa9ceec8, 0x6c6c412b], [0x1a50f21d, 0xba808f9e],
+    [0x829df239, 0xa3260b86], [0x72166e94, 0x3e8916ea], [0xf544619f, 0x42f52d75], [0x07783526, 0xcaa35b94], [0xd4c603a7, 0xed1f5eb8], [0x3f458308, 0x7c160f1e], [0xbe1fd990, 0x1df70f17], [0x528762a8, 0x932d6a4c],
+    [0xe03666c8, 0x8086f55b], [0x40a74b24, 0x59867334], [0xc7dbd650, 0x29854e07], [0x6a29c418, 0xf68b2c61], [0xa8f62184, 0xd3c412d5], [0x199b8a9e, 0x0c2dfa62], [0x9dc7700d, 0x6039892e], [0x28369a42, 0xb68713dd],
+    [0xb7a2a7dd, 0x9e23f8e9], [0x58f8c470, 0x131ec9ab], [0xd9d6330f, 0x74922888], [0x30df0690, 0xe48d4298], [0xfcea405c, 0xc57646b2], [0x08407fc0, 0x4fe5ca9c], [0x8a9a5124, 0x33f2c065], [0x782745c3, 0xa8c340ff],
+    [0xcf8c7b29, 0xb8f8eb85], [0x67111a70, 0x6f6bb69d], [0xe8fff6dc, 0x07489142], [0x480b4f77, 0xda2393fa], [0x907cec7e, 0xf81e2662], [0x22f59f89, 0x2024e696], [0xa2206fb1, 0x55e8353e], [0x13a2751c, 0x8f99126b],
+    [0xa47d4a4f, 0xb3216c1c], [0x6f062af4, 0x02553e7a], [0xc981dab2, 0x5df2d825], [0x1e864ee0, 0xfee3a388], [0xc2495df8, 0xdf9bb73a], [0x171619cc, 0x64d71dd0], [0x9576b3d1, 0x0836d502], [0x62da3d0d, 0x86882b91],
+    [0xdc9836ab, 0x96c54a86], [0x552f0089, 0x70b5fa39], [0xfb73ea1e, 0x3be7b68f], [0x756d36fe, 0xc3b17313], [0x8f10d334, 0xcd9e024e], [0x03737d94, 0x3681ad90], [0x843a73a2, 0x4ba914c9], [0x3803dc10, 0x9bb8e69c],
+    [0x9a23d56a, 0x895bae16], [0x4e8f6911, 0x2ea3785f], [0xe66edb99, 0x693db661], [0x256c2eb5, 0xd71f3d95], [0xedfa5b80, 0xf0fc6225], [0x2c68b905, 0x52e3b3ce], [0xaf82e1fc, 0x25b76bb4], [0x472503c8, 0xbd3bbeb2],
+    [0xf3db065e, 0xae929722], [0x7d7f8811, 0x470fa409], [0xd016a588, 0x15a2ff00], [0x5fac8225, 0xe9389247], [0xb935929e, 0xe06a5d23], [0x3421d409, 0x19e62d2c], [0xb04ec045, 0x78c1a653], [0x0f2fe1a8, 0xa7740162],
+    [0x8c5848ed, 0xb74faf52], [0x7f1c6e09, 0x288a9acd], [0xf9df649f, 0x587563cb], [0x0cd11214, 0xd259c2e6], [0xc6dd2c8f, 0xe263472a], [0x362614da, 0x61bf61f5], [0xb321dc08, 0x0dd3f0c7], [0x4176b58b, 0x982e27b8],
+    [0xf46058fd, 0x8bffc80e], [0x53aa9d96, 0x51a6b23a], [0xcac674ae, 0x3f5a48e6], [0x60890c5e, 0xecbc14dd], [0xa7a4e25f, 0xcb8a9c06], [0x14fcb544, 0x1c772564], [0x96d36c8c, 0x7db4c9f8], [0x27f4e9d5, 0xa2a2d677],
+    [0xa3d20934, 0x95b97168], [0x49130ccf, 0x1b183799], [0xceb9291b, 0x7bbd6653], [0x23f33fd7, 0xebef48af], [0xe9642684, 0xce3fa0a9], [0x12c1c333, 0x457fa9f7], [0x86369ae1, 0x2180ea5f], [0x6680747a, 0xa5db982c],
+    [0xc08e2576, 0xa97030d7], [0x6c8c0289, 0x75921127], [0xe5890e98, 0x12a748bc], [0x5982bcb2, 0xd52ee962], [0x9917318b, 0xe530cd62], [0x2e42a406, 0x32dc6faa], [0xad311042, 0x4e9fe081], [0x097f743d, 0x85317a3d],
+    [0xb1415f3f, 0xbb6f2b20], [0x743415f0, 0x0ed930c4], [0xdd6ea036, 0x560e7dfc], [0x11a6544a, 0xe1de69cf], [0xcddadc78, 0xc21201da], [0x0e695761, 0x6d04a1f3], [0x8ed492e2, 0x053ea835], [0x6942a1e7, 0x975e2a9d],
+    [0xd79dd7aa, 0x879c8922], [0x46ad5eb4, 0x7f08a1b3], [0xf75477a7, 0x2fa3d883], [0x6e01f76d, 0xc84c53ad], [0x94ec844e, 0xc6dc28a3], [0x1f91a984, 0x3dde8a6d], [0x9b8aab0d, 0x40833f47], [0x32dea823, 0x8810ecc0],
+    [0x851676a7, 0x821d4385], [0x5e0cdc51, 0x23618e27], [0xebec0607, 0x71b9d4c5], [0x35307d56, 0xd99ff821], [0xfadd9694, 0xfa718948], [0x209ae84b, 0x4a261796], [0xb87534b8, 0x2bb88b14], [0x4ba90416, 0xafae5efb],
+    [0xe7a213ff, 0xa1b13c18], [0x63c665e1, 0x4db9dd0a], [0xdad07857, 0x03ce151d], [0x4fd9fbf3, 0xe7063dc6], [0xae30f2c7, 0xeefd60f0], [0x24771288, 0x10c99a78], [0xa515921e, 0x774201b8], [0x04ddf9f2, 0xb2315bb8],
+    [0x9c64768b, 0xadc38cfd], [0x6b9f9203, 0x342c0a6b], [0xe1bcd15e, 0x48edf6dd], [0x184c872a, 0xc1d40b3b], [0xdf529831, 0xf764c47c], [0x2982b0b2, 0x739ef2c1], [0xa976a4ef, 0x1774f1a1], [0x5c009557, 0x815338e2],
+    [0xee5e74f5, 0x92b56954], [0x4d12091d, 0x43d4cc55], [0xd5b40ab2, 0x269e4415], [0x736bdd46, 0xfcc9d33b], [0xbfda8332, 0xdd419ef4], [0x068b3901, 0x00d11bfc], [0x8352603e, 0x6a702319], [0x3e033cf9, 0xbeed97b9],
+    [0xbbf10b2d, 0x8e9b6e4c], [0x56eb8566, 0x0616b90b], [0xd2f71604, 0x6ebce1c5], [0x3aef2f20, 0xf9790910], [0xf1cbea7f, 0xdb1b74fb], [0x00523c07, 0x54a44cfb], [0x91028b5d, 0x38e86a2e], [0x77aaa057, 0xb9b0c07b],
+    [0xd8066d5b, 0xb0f47eb7], [0x7931cf47, 0x6603ad4b], [0xfd003256, 0x0b4c322d], [0x45da774d, 0xc41a148f], [0x8baa7a98, 0xf3631be1], [0x311b68b7, 0x2da37658], [0xb6d6bb6d, 0x5f290f91], [0x1d764192, 0x9ff7c688],
+    [0xab0e9dea, 0xa6c134c6], [0x6411948b, 0x149a9973], [0xc4e6f2d4, 0x4661cd4b], [0x0246dd69, 0xf4271c2d], [0xd1aaa20a, 0xd128172a], [0x1bd319a8, 0x79a98fa3], [0x9fdc4ba8, 0x18e7c789], [0x7cee04a8, 0x8d1f936c],
+    [0xc80062cb, 0x9c722a30], [0x5a9f0c39, 0x682247ca], [0xece33824, 0x305c5781], [0x7b8c516f, 0xde68c710], [0x80e8a43f, 0xd6a047ad], [0x0bd348a9, 0x24884545], [0x89d0daf9, 0x53e6dcca], [0x2daa215a, 0x91efc52b],
+    [0x92a9f595, 0x9accfea8], [0x42ad8eaa, 0x3a0a660c], [0xf2b3af9f, 0x6294b515], [0x2bd0fe8d, 0xccd523c3], [0xe27b5f3a, 0xe8022555], [0x390440b0, 0x5aef66b8], [0xa1e680ff, 0x379a9d40], [0x54510f1f, 0xb56e9030],
+    [0xfe351401, 0xbce1c5a3], [0x70c791d7, 0x5c78cc3d], [0xc3d0d5d1, 0x1e4db17b], [0x50f52a9e, 0xf1f7ed40], [0xb5f03522, 0xff2f332e], [0x3cafcd58, 0x09fa2e53], [0xbc77e32d, 0x6540b0cd], [0x161c50bb, 0xaab8dff7],
+    [0x987b7489, 0xb1196344], [0x6d102875, 0x2ca7b6dc], [0xe421ff5e, 0x5e4d4910], [0x1c13970e, 0xd46a8772], [0xc1ac8adb, 0xf21ba5ea], [0x2f8e7a1e, 0x67ac37f2], [0xac31ea12, 0x0a369362], [0x44218252, 0x8453c14f],
+    [0xf00971dd, 0x94ecbc6c], [0x57452f0a, 0x4426457b], [0xd3802b91, 0x39611500], [0x76597729, 0xea2d5fc8], [0xba71caef, 0xcfb35402], [0x01de8d65, 0x1a975d40], [0x87ba0f9d, 0x7a55f117], [0x3bbba4cd, 0xa4c68341],
+    [0xa671497a, 0x8a6dd1c1], [0x4cbd84bb, 0x01760ac1], [0xcb37e85e, 0x6b7310ce], [0x26ef88d6, 0xfd4e4222], [0xefd8588e, 0xdcc6e5bc], [0x15470ecb, 0x50b312ed], [0x97ea6a26, 0x277e01a6], [0x610d92eb, 0xbf63ee5b],
+    [0xde9f015e, 0xacd60bf0], [0x7ec39f7d, 0x72630ecd], [0xf8af3e6b, 0x1660e424], [0x5d4a8383, 0xc083bb62], [0x8d3042d9, 0xe34c8e83], [0x37048c72, 0x352944f5], [0xb27bac82, 0x49273652], [0x0d80ecb6, 0x993dc33c],
+    [0xb479431c, 0xa02d0f87], [0x7145bb52, 0x11696eca], [0xdb57c47e, 0x419342b7], [0x054300e9, 0xe68dc215], [0xd65610bd, 0xc7f64f96], [0x0abeb960, 0x7e195beb], [0x88c9af86, 0x1f741177], [0x7a5f936e, 0x90a6d2c4],
+    [0xcc132603, 0x8320498a], [0x43fcbcf4, 0x6c933b07], [0xea7a5632, 0x2a27ed54], [0x689ddfb3, 0xd83fbaa9], [0x931cd93d, 0xd031a33c], [0x1ab4f1a9, 0x226c0ce1], [0x9e061dc5, 0x57a855a5], [0x2a6a9e75, 0x8c4bb375],
+    [0x8182ad07, 0x9dba01b9], [0x5b080c3b, 0x3c4c907d], [0xf6c08553, 0x76a45854], [0x3365dab7, 0xc99340a5], [0xff2bf5e3, 0xef00916b], [0x3d4f63da, 0x4cdb4f85], [0xbda7cd61, 0x313716b9], [0x515b412b, 0xab959411],
+    [0xe31c3795, 0xba06d1bb], [0x65c14ac7, 0x5b870386], [0xc5fb7793, 0x0478bf0d], [0x4a81ebb2, 0xf51667ab], [0xaa5922c9, 0xfb215a60], [0x21b3dc30, 0x0fc36632], [0xa0195e85, 0x63e99a02], [0x10eec00b, 0xb49b74dd],
+    [0x8a3d3573, 0xa86b4b87], [0x788d8579, 0x335707a1], [0xfc24b3e5, 0x4f5c5023], [0x08823dff, 0xc5c60273], [0xd944aeb2, 0xe425b152], [0x30399688, 0x74297652], [0xb747d421, 0x13ea32cb], [0x584788e2, 0x9ede535d],
+    [0xe836d1fe, 0x8f7610bc], [0x48ff7f1f, 0x5509e9f9], [0xcf5a78e8, 0x2089dd3e], [0x678e400d, 0xf8d9bff4], [0xa2bb9970, 0xdab109d4], [0x13693893, 0x07a85a5c], [0x909ff1d3, 0x6f879160], [0x224a7155, 0xb840b0f3],
+    [0xbeb777d9, 0x93a64233], [0x52346f28, 0x1d593f56], [0xd42c18fd, 0x7c84713d], [0x3f917971, 0xedba19bf], [0xf58cf96c, 0xca2b0cb0], [0x07ca732c, 0x422a1029], [0x8240eaef, 0x3e4f8120], [0x72d3175a, 0xa3b088c7],
+    [0xc74aa9c4, 0xb61c7341], [0x6a8e0cd0, 0x609c8b93], [0xe0f7dff3, 0x0cb7d4b3], [0x405cd0b4, 0xd31637ea], [0x9d084f42, 0xf62b3355], [0x28a9d058, 0x2903d480], [0xa8051e0b, 0x59639f76], [0x1934d83d, 0x80651430],
+    [0xaf64aabc, 0xbdb9bd07], [0x626101f8, 0x08ee5bcb], [0xc2e78330, 0x5205cc43], [0x17b268cd, 0xf07fa5a8], [0xc93fcb49, 0xd7d71e18], [0x1e4d608a, 0x698dc09d], [0x9ae6a66a, 0x02f47b08], [0x6fff1aeb, 0x89c8a6dd],
+    [0xd0f07916, 0x9b7ec284], [0x5f5c982c, 0x787be8e5], [0xf37d8888, 0x363d4ef6], [0x7dc338bd, 0xcd595dc8], [0x84cc5f92, 0xc310738c], [0x0fa2f62e, 0x3b097f9d], [0x8fecdd43, 0x47e84b7e], [0x34807252, 0x96719a3c],
+    [0x95ee6116, 0x860b027b], [0x47b0e594, 0x253a585a], [0xed587dae, 0x642a0b9c], [0x2c94fd97, 0xdf5a1ba7], [0xe6e188fa, 0xfe72d8d9], [0x2584af4a, 0x5d0191be], [0xa4cd1582, 0x2e696da2], [0x4e79f906, 0xb3bcc182],
+    [0xfbc4e070, 0xa7a1a2fd], [0x75f76782, 0x4b0c9228], [0xdc300c96, 0x193681eb], [0x55ff9320, 0xe0ba1848], [0xb0ca351e, 0xe9e69544], [0x38af40fa, 0x1507cf6e], [0xb9acea7a, 0x704fa55a], [0x03e44bb8, 0xae792f5c],
+    [0x86f65e31, 0xb96add68], [0x772fb6b7, 0x2165f99b], [0xf1786a19, 0x544cd3fd], [0x00af6345, 0xdbbd3f77], [0xce7a8e16, 0xeb27edcb], [0x3a778499, 0x6e6604b7], [0xbb4afc38, 0x06f28c8c], [0x49f6465f, 0x957ea18b],
+    [0xfdb23842, 0x85dac7b9], [0x5934bd27, 0x5fcb040f], [0xc0765b2e, 0x327bc006], [0x6c31d290, 0xe5d175f7], [0xad9f070a, 0xc4bcf71d], [0x1df80cf5, 0x12349c28], [0x99fb03a9, 0x754e5c30], [0x2ebe63e6, 0xa9c2d63a],
+    [0xa9de962b, 0x98a69fb1], [0x41cc4bdb, 0x17ea4550], [0xc6191e43, 0x7348d87a], [0x29144a4f, 0xe2efb4c3], [0xe13f8177, 0xc128c747], [0x188b052c, 0x4843e36f], [0x8ce9661a, 0x285ae698], [0x6b3af33f, 0xad6a1774],
+    [0xca145cf4, 0xa2358bdf], [0x603bc7e9, 0x7d06f347], [0xeee2e82d, 0x1cb5f21f], [0x531ce92d, 0xdda350f6], [0x961cdc50, 0xec094636], [0x27365fb0, 0x3ffc2237], [0xa7247d80, 0x437dbc67], [0x060963b4, 0x8b5298a5],
+    [0xb8ba8ac4, 0xb58ed447], [0x7c2aed7c, 0x05ee02d7], [0xd1167b4e, 0x5a21bd55], [0x1b7d1a81, 0xe8dc00c0], [0xc41a1c07, 0xcc306f56], [0x028f6417, 0x62549b13], [0x85f2f239, 0x0e125427], [0x64ce27e3, 0x9a0ae672],
+    [0xda039430, 0x88db414c], [0x4f0824a1, 0x77eadb7e], [0xfefa79b8, 0x24540b07], [0x637c0985, 0xc6701473], [0x9b04f3dc, 0xc893a2cd], [0x16de9c7a, 0x30e277c9], [0x942927ed, 0x4d3d2c3b], [0x3c1ac077, 0x8706997c],
+    [0x8e27e604, 0x8d956651], [0x54ad11a1, 0x2b32dce7], [0xe2a0393a, 0x79481236], [0x39dad9c7, 0xd1ebbc0a], [0xf20bbf71, 0xf4b66c58], [0x2b73060e, 0x4698052b], [0xb1ae9bc7, 0x23be73c6], [0x427c4eb6, 0xa6667b7d],
+    [0xec2997d1, 0xaa3309d0], [0x6eb1c913, 0x4076ccfd], [0xd704a9db, 0x094a8a45], [0x4626ca24, 0xee22a205], [0xa593319b, 0xe79c9afe], [0x2d0c16e0, 0x1ef50847], [0xaeba6220, 0x7f9db2b2], [0x0b649a90, 0xbc629ad7],
+    [0x91d175dc, 0xa52bbd83], [0x661b2e99, 0x38495c99], [0xe9d54bb1, 0x45a6b785], [0x122ca90b, 0xceab23eb], [0xd22b2c05, 0xf9ba0616], [0x2373b01b, 0x7b53e502], [0xa33bc1d0, 0x1bd4471c], [0x566f9960, 0x8e473890],
+    [0xe56899b8, 0x9f08867e], [0x450b418c, 0x4e371c5b], [0xd88860d8, 0x2d617f4c], [0x79f3c870, 0xf3d5de10], [0xb64d9089, 0xd5caa44b], [0x09cdf4de, 0x0bf46969], [0x8b285c16, 0x66b601fd], [0x3180339d, 0xb0751a18],
+    [0xb3836377, 0x8181c095], [0x5cbfe660, 0x0d04af4c], [0xdfed0c30, 0x614d62a1], [0x36e29cfd, 0xf79c8398], [0xf91ce0c6, 0xd2e7d582], [0x0c3d6f6b, 0x58cde0d7], [0x9c8e3812, 0x34a3c227], [0x7ff3c749, 0xb79edb0e],
+    [0xd556ba01, 0xbe74c307], [0x73f8f07c, 0x6ae54878], [0xf49ca1e8, 0x002504fb], [0x4d804ab0, 0xcb45b403], [0x8391f5d3, 0xfc0bc7c7], [0x3eb0b12b, 0x261672d4], [0xbf4ea95c, 0x5116ef16], [0x141b81dd, 0x923bbf26],
+    [0xa14352a2, 0xaf64bf37], [0x69a1d6e7, 0x1853f763], [0xcd3ef15d, 0x4ac2af21], [0x0ee41f4d, 0xfa970091], [0xdd8de44c, 0xd92dc9be], [0x115f3641, 0x7118087f], [0x92417462, 0x14050890], [0x74d4b310, 0x829e8797],
+    [0xc357fce4, 0x911420f1], [0x50556d08, 0x65816ed7], [0xe77eee5b, 0x3d7c9d60], [0x703249dd, 0xd626f304], [0x8958bfd3, 0xdeb06c95], [0x044b94fb, 0x2f279f0f], [0x800d6467, 0x5c894336], [0x24eb7301, 0x9ce39ee6],
+    [0x9f555361, 0x97d7e097], [0x4b180ff8, 0x37700649], [0xfa771022, 0x6def0977], [0x204d8d4d, 0xc2edb6dc], [0xeb13d04f, 0xe1152b47], [0x35da11f3, 0x56e9233b], [0xab8270be, 0x3aad4bd3], [0x5e82c2ff, 0xbbb9af7d],
+    [0xf7ea8168, 0xb2f8d2af], [0x7b4932f2, 0x535774f9], [0xc8b2d3c8, 0x104e349d], [0x5a10c0a0, 0xfff4e4ae], [0xbc84db8b, 0xf13e1828], [0x32290cef, 0x035625dc], [0xb52b7b5d, 0x68926c81], [0x1f21b0bf, 0xa104a313],
+    [0x9e738859, 0xbae1cf8e], [0x68c54eb8, 0x22d1be3d], [0xe34c75f9, 0x5744af06], [0x1ae4e8f3, 0xd89c33cf], [0xc5a6a39f, 0xfbcdb73b], [0x21322b9f, 0x639c48ae], [0xa0e8eca2, 0x0f8fc1b1], [0x4a64488d, 0x834476c7],
+    [0xffda88ba, 0x90f43295], [0x5bf236dd, 0x41d7b8d6], [0xd62bb457, 0x31f60a37], [0x7ae52b15, 0xef5e2072], [0xb4cb9629, 0xc9d9891b], [0x05012856, 0x1184de53], [0x88688b45, 0x7e5efba5], [0x33d27d66, 0xa06dbc63],
+    [0xa07137e8, 0x8394d9b6], [0x4aef7c47, 0x0f5a36e5], [0xcc73d9b4, 0x6321d418], [0x2af8f611, 0xfb6b400b], [0xe3b99f8a, 0xd87807b3], [0x10bb7cf1, 0x5b31d227], [0x9eda3a7e, 0x22084bfc], [0x658dbce5, 0xb45a1790],
+    [0xd6b40c81, 0xabd5301b], [0x71f9750a, 0x76c2126d], [0xff6b1287, 0x1ffe2c12], [0x51a66e70, 0xc7adcbaf], [0x814ff9ce, 0xe6d5d56d], [0x332ad4aa, 0x3c8386df], [0xbd21f2ed, 0x4cb4680f], [0x05f8c23a, 0x9ded2ea9],
+    [0xb20eb8c1, 0xac66801e], [0x7e24acd1, 0x162c16b7], [0xd35b8695, 0x44794775], [0x0d368b88, 0xe322f759], [0xd3e856bc, 0xcf2294e8], [0x0dee581b, 0x72bb2c28], [0x8737cf1c, 0x1adab1b1], [0x76e40365, 0x948ca135],
+    [0xcb775383, 0x8ae66f93], [0x4c667313, 0x6b249167], [0xe48e1e0d, 0x2cf9d7d3], [0x6d42c238, 0xd4afc1ae], [0x978e98b7, 0xdc45cfc5], [0x15df69fb, 0x2727c64b], [0x9704ee42, 0x50d014ac], [0x2604b7d8, 0x8a0556bb],
+    [0x8de5cbb1, 0x994a6fc8], [0x5d061e65, 0x35d98323], [0xf0698a19, 0x7a84141a], [0x37732745, 0xc054b8fa], [0xf8e607c2, 0xe3ea9afd], [0x37c2003f, 0x49404c9c], [0xb2cea661, 0x35597a5e], [0x5d9fc2f9, 0xac85795e],
+    [0xe4688b61, 0xb1eed97a], [0x6dc3fdd5, 0x5e335340], [0xc1dc4c89, 0x0aae2b88], [0x44bd2c4d, 0xf26b005e], [0xa6fafe57, 0xfd22edb5], [0x2f2d03aa, 0x0a54afb0], [0xa600ec3c, 0x6bd01e33], [0x1cdd85c7, 0xb152cd2b],
+    [0x8f9f75f6, 0xa71b4f7d], [0x7d88bee5, 0x3b8bdb85], [0xf3a03a76, 0x4b41cc62], [0x0fcae79e, 0xcdc4d26e], [0xdc5ff1cf, 0xe9656a4f], [0x34dde716, 0x78a93b72], [0xb082aa28, 0x199b1649], [0x5f07e5b1, 0x96b676b2],
+    [0xed2a7b09, 0x86e91dc5], [0x4756d328, 0x527d5888], [0xc221b4a8, 0x25564a6d], [0x621746f6, 0xf0b15eef], [0xa49cc6cb, 0xd779a7b7], [0x17cd70df, 0x084b3c37], [0x95ab0f0e, 0x648d7d02], [0x2c3ac9c6, 0xbdf856ca],
+    [0xb02f63d6, 0x960a354f], [0x5fc53534, 0x197848fa], [0xdce3dd12, 0x7830395d], [0x38e50fb9, 0xe034d736], [0xfb0d934e, 0xcd2054a7], [0x0f6a529a, 0x47b546cf], [0x8f4b88e5, 0x3b5e203a], [0x75b9e7b1, 0xaec2fa63],
+    [0xc2a788e5, 0xbd5ef523], [0x62b32cb6, 0x644e95f5], [0xedb259bf, 0x0888daad], [0x47fb62af, 0xdfe0ebd4], [0x95356e54, 0xf0161e1a], [0x25381a27, 0x2e0f6a97], [0xaf3822ae, 0x52a5087d], [0x17778aba, 0x86684e61],
+    [0xa2416c1a, 0xb8010bba], [0x6a5eaa43, 0x0cf37e48], [0xc71d34ca, 0x59c24ead], [0x19d2f7bd, 0xf67f9cde], [0xc7ba335f, 0xd35e963f], [0x194d579b, 0x605932fd], [0x900c8c38, 0x07f5a91a], [0x675f8082, 0x8f1f9ec9],
+    [0xd479d0ac, 0x934119f3], [0x580b45cf, 0x74cfaa06], [0xf5f23798, 0x3ed3cc44], [0x72566f07, 0xca698499], [0x8ae4a47c, 0xc5bdb47e], [0x08c85f0a, 0x33a3751f], [0x8a7f19d6, 0x4f81d9f6], [0x304fd1c6, 0x9e6b745f],
+    [0x90e182fd, 0x8ff1a24d], [0x485f62a3, 0x20f4c5e9], [0xe05b2e59, 0x60f9b320], [0x22a039b4, 0xdac12564], [0xe8abb9f7, 0xf8a0250f], [0x223b819d, 0x55b78dd2], [0xa2c149e5, 0x20692c52], [0x488ea81b, 0xb8ba0e75],
+    [0xfc59237c, 0xa8a25162], [0x7295a900, 0x428e41d3], [0xd4898306, 0x1d38f45f], [0x58ae5461, 0xe452f1f1], [0xbe5278ce, 0xedd7c574], [0x3089828c, 0x139b9828], [0xb7edcb19, 0x74665651], [0x07aadacd, 0xa369c466],
+    [0x8049ed65, 0xb27067de], [0x707398df, 0x2fea3872], [0xfe76381b, 0x5328d979], [0x0b8c97b6, 0xdece3815], [0xc8c04310, 0xe77d14dd], [0x32b5e886, 0x68d15b49], [0xb58bed9f, 0x030f04d0], [0x46df70c7, 0x91452966],
+    [0xfa16a0b7, 0x8d7e6b3f], [0x5ed8f270, 0x564da68f], [0xcd81df88, 0x37246e7f], [0x64581e90, 0xe140d0eb], [0xabd83235, 0xcca49436], [0x1b15b828, 0x189f02ac], [0x92cb77b0, 0x7173b59d], [0x20c7db33, 0xaf28d19f],
+    [0xaeeedca7, 0x918feb80], [0x467fd830, 0x1e2b9394], [0xc86139ad, 0x77bcc4bc], [0x242b8d21, 0xe7e3423a], [0xe7377247, 0xc827c744], [0x1652d99f, 0x4d43241c], [0x808b0cf9, 0x2f5b1700], [0x63b2456c, 0xaa71e387],
+    [0xc4a3022b, 0xa624b109], [0x64b06f2e, 0x71d20342], [0xe21ccc54, 0x1819565e], [0x54f810f3, 0xd174b008], [0x923d8918, 0xe182e7e6], [0x2b960702, 0x3ae47777], [0xab453409, 0x46e5bca8], [0x0e13519a, 0x8def8706],
+    [0xb69b41ce, 0xb03ac970], [0x79a9110e, 0x0b0b79af], [0xd53d25b7, 0x51eb57d0], [0x14a5d31c, 0xec5d00f9], [0xc0138d3f, 0xc47e0139], [0x06751262, 0x6a12ab52], [0x83dbaaec, 0x008dc50a], [0x6050839c, 0x92cfe62e],
+    [0xd2aa9c46, 0x8e08de96], [0x498f718c, 0x7bdad8f0], [0xf956c57f, 0x28c795cb], [0x6bdbd2dc, 0xc16bad49], [0x914b5dbc, 0xcee49504], [0x126f9a06, 0x38a50dbe], [0x9c062151, 0x48279c17], [0x367a5c8c, 0x81dcaeeb],
+    [0x833d46f6, 0x8b311bbc], [0x53589219, 0x26cf2540], [0xee28c3fa, 0x7d71df3b], [0x316c4b10, 0xd581c14d], [0xf4dcef03, 0xfc8f1602], [0x2ef83bf5, 0x4edab5e9], [0xbfba493e, 0x26678397], [0x45648006, 0xa91fcdfe],
+    [0xe9afb02f, 0xa58732b9], [0x66c2271c, 0x45faef92], [0xdf1642da, 0x0d72cc80], [0x419737a7, 0xe23e35a1], [0xa919ba22, 0xe28bd523], [0x29781b3e, 0x173b7e11], [0xa9bc52d9, 0x73c29a9a], [0x0c7cd02c, 0xb7312c35],
+    [0x9472531f, 0xaacd7ba3], [0x6e6bfcb7, 0x3d1ce3d8], [0xe7faf9f5, 0x402faa5c], [0x1fd0079f, 0xc8ecfbb3], [0xdab0d69a, 0xff83dc8f], [0x2dc8adbe, 0x7fc0c72e], [0xa5ec1e88, 0x10aea709], [0x5028ae9b, 0x87d26db6],
+    [0xeb98b848, 0x9a6545f0], [0x4bfba1d9, 0x4a9d35f6], [0xdddaac30, 0x232af97e], [0x7469a5e4, 0xf4c7c2bb], [0xb1dbb26e, 0xd9fba724], [0x0e854060, 0x05452091], [0x85538f7a, 0x623ef26b], [0x395915ca, 0xb5f77f34],
+    [0xb57fcdd1, 0x8878b7d9], [0x50aeef73, 0x09271204], [0xd7fc7443, 0x65ce793d], [0x3cc6d630, 0xf14e2fa7], [0xf71c5f54, 0xd67ac4a0], [0x0b28ac6f, 0x53b9a0c4], [0x9b42ddfb, 0x3db71d3b], [0x70b32e69, 0xb293bc13],
+    [0xdd093560, 0xbbf990e1], [0x74ad560b, 0x62d882ba], [0xf278145e, 0x0eb27e49], [0x4b7eaf12, 0xc25f1b07], [0x8e9c9d0f, 0xfaddb4bb], [0x357b4521, 0x23ecfc1b], [0xb125027e, 0x569cd485], [0x1b80cb23, 0x9799a75f],
+    [0xad5aa610, 0xa9b19847], [0x60f881a6, 0x1cdfd9eb], [0xc0e57c6e, 0x4e7109eb], [0x09932414, 0xf311c1ec], [0xd8f9ac8a, 0xd57d8939], [0x146d2048, 0x7de6ca52], [0x965dcf8c, 0x1c053f98], [0x79440f35, 0x859029e5],
+    [0xc6422cc2, 0x987fadde], [0x563710fc, 0x6ef867e7], [0xe16a082c, 0x3453eb24], [0x7f5d6c8b, 0xd2bf74c4], [0x865657b3, 0xdbdef808], [0x00fef4c6, 0x21f647a6], [0x8c1406d7, 0x589d6d7b], [0x29c04482, 0x98ebc5e4],
+    [0x96820667, 0x9278b28a], [0x4df200dd, 0x3f3256dd], [0xfde3ff64, 0x66690b82], [0x2765edc0, 0xcbe64080], [0xe5d72252, 0xe5914ebd], [0x31e12c3c, 0x5f6c54b7], [0xa7d52684, 0x3fa3a338], [0x53c82ea7, 0xbe0fc4fc],
+    [0xf114a688, 0xb9dc2f8f], [0x7fb4735e, 0x58244c81], [0xce1c1edf, 0x1b4f07cd], [0x5cd06b1e, 0xf72a16f7], [0xbb3caedd, 0xf90778a6], [0x3a8ff0fd, 0x06abc325], [0xb3df48f5, 0x61eec2cc], [0x18f2222a, 0xadb1cc5b],
+    [0x938d3f78, 0xb4c94d4b], [0x653f2ff6, 0x2a5e6823], [0xea8faa98, 0x5bca82b3], [0x105cdad9, 0xd053b77c], [0xccc26bfd, 0xf54dad9b], [0x2a11d068, 0x6c0a2eea], [0xaa093c4e, 0x04b96656], [0x438d27ee, 0x8cb1b755],
+    [0xf6a3f1fe, 0x9d5e0cde], [0x512db77a, 0x4c33cc8a], [0xdb92098e, 0x3c3573cb], [0x71385566, 0xe6001b07], [0xbdfdbc8a, 0xc71ec556], [0x0a31de42, 0x1f0a1bc6], [0x81f9fb85, 0x767ab2bb], [0x3d0b6315, 0xab121d5f],
+    [0xaadbf30d, 0x8c0a0fcd], [0x4341b95b, 0x043b9683], [0xc52fe3dd, 0x6cd59f58], [0x21dff1e2, 0xf5ee6efa], [0xea071e9a, 0xd0a18272], [0x1a09c52f, 0x57c9e6c8], [0x93500026, 0x2ab996e9], [0x6837c565, 0xba53ddb5],
+    [0xdb3372f1, 0xa0ff1463], [0x7a3910bc, 0x7e9a18c9], [0xf679572a, 0x11035fb6], [0x5b402acd, 0xc910a5e4], [0x889fbe8f, 0xef89f3fb], [0x3de643cf, 0x3148b689], [0xb430f392, 0x417156da], [0x0add5006, 0x901f0d8f],
+    [0xbae6c309, 0xa4a51809], [0x762704cf, 0x1a2d69e7], [0xdeea2c07, 0x49cb9e92], [0x01b37287, 0xeae9b71f], [0xde6eefc6, 0xc0e2c3b7], [0x0146ed05, 0x7a07c1cf], [0x8d476f99, 0x168bd8a1], [0x7e81d25f, 0x99a4142f],
+    [0xc120c466, 0x843c772d], [0x445ac592, 0x67417e26], [0xef85b409, 0x278f8252], [0x6191c371, 0xdc8213ab], [0x988edeaf, 0xd41c7d83], [0x1c42cefb, 0x2c7edd74], [0x9825b3f1, 0x5ef6d812], [0x2fe27beb, 0x84bbe0ae],
+    [0x87dd23c0, 0x94074415], [0x57ff6b00, 0x391b187b], [0xf835cf24, 0x72242cd3], [0x3b5281d2, 0xcfd65a22], [0xf0dc182d, 0xea44e5db], [0x3bd24e7f, 0x44fb4463], [0xba23befe, 0x39804159], [0x570c3967, 0xa4285604],
+    [0xef34664d, 0xbf07a69b], [0x617b62d6, 0x5059dac6], [0xcbb49500, 0x011c166d], [0x4ce2972a, 0xfdbcdb3f], [0xac5302c4, 0xf2ff9501], [0x2697cb80, 0x01d994d8], [0xaca35721, 0x67ffe52a], [0x152dde6e, 0xbfacb43d],
+    [0x8450b515, 0xae15051f], [0x753aa16e, 0x36701050], [0xfb8af998, 0x4765d1dd], [0x0330e205, 0xc367ae05], [0xd07718db, 0xe0f8f553], [0x384382fe, 0x7009a61d], [0xb97b7dc1, 0x156deffd], [0x55668808, 0x9b0acbff],
+    [0xe61fec4b, 0x8981e32f], [0x4e0c9b71, 0x5db5b97b], [0xc9783127, 0x2ed74cd4], [0x6f563e47, 0xfe3ce444], [0xaffdc89a, 0xdf1754f9], [0x1ecd4eb2, 0x02a119e0], [0x9a62f1a5, 0x69dbb06d], [0x25c08b00, 0xb344b47b],
+    [0xb9c7f981, 0x9bc757c9], [0x559546c6, 0x15ffa1c0], [0xd0b22e19, 0x70f589b5], [0x34576ef3, 0xe9a0aef2], [0xf31bad22, 0xc3ca36b9], [0x03bd70b1, 0x4bfce3ce], [0x84a93b6d, 0x36e3bbee], [0x7d2609ac, 0xa7ee1f6e],
+    [0xc9d2a67a, 0xb3d845b6], [0x6faacf23, 0x69772527], [0xe6a52b5a, 0x0223bf27], [0x4eeb9ef2, 0xd79dc5e4], [0x9aae7072, 0xfe89f270], [0x2cdb3675, 0x25d1ca64], [0xa411bd5c, 0x5d5c318e], [0x1e0acbdf, 0x89304012],
+    [0xa8671cf7, 0xb6ebfe56], [0x67fb5ee1, 0x0712ef63], [0xcfe50530, 0x5567dc47], [0x13245ea6, 0xf85976b0], [0xcf1d20af, 0xda4d81b7], [0x13e35048, 0x6fc12b7a], [0x9d606d47, 0x0c41855f], [0x6aed99b1, 0x80d3988e],
+    [0xd9848082, 0x9e9ec172], [0x52c3f169, 0x7cc886a5], [0xfcb3ea93, 0x331f7b5d], [0x78ec6950, 0xc50a0450], [0x82106aaa, 0xcaffef13], [0x07041c03, 0x3e2ab839], [0x82f84c78, 0x425f686d], [0x3f27154f, 0x93e50e92],
+    [0x9dac6810, 0x8012e39b], [0x4022d901, 0x29d06468], [0xe863e611, 0x6f19d9d6], [0x28d80209, 0xd3ad97b7], [0xe08589fb, 0xf6f73fd4], [0x2849ae0a, 0x592fdbff], [0xa8ae6c79, 0x2948168b], [0x40f517b2, 0xb665f283],
+    [0xf51b1e99, 0xa3d1eb2e], [0x7872397c, 0x4f0cd309], [0xd907a569, 0x135b6cf7], [0x527c28b7, 0xed42ada2], [0xb70723f4, 0xe4f8931f], [0x3fc62f61, 0x1da9c040], [0xbedbc4db, 0x7c784081], [0x082f5ca3, 0xa83cfaa6],
+    [0x8988fa0c, 0xbc01e809], [0x7bfac5a0, 0x24024063], [0xf7bb0ce8, 0x5c2e8723], [0x0424dc73, 0xd6db4246], [0xc3a941bb, 0xee4ca78e], [0x3c672382, 0x651e3765], [0xbcc3acdd, 0x09aee1e4], [0x4f7d5150, 0x9c3f4ce6],
+    [0xf2ff67b2, 0x82e6ac59], [0x54108643, 0x5a4451fa], [0xc449f272, 0x3a61dc64], [0x69ce9678, 0xe87772d0], [0xa192f632, 0xc28c6de4], [0x11f8e77b, 0x1455aca7], [0x9f03bfe1, 0x79f3a55b], [0x2b3cfd33, 0xa6965ba2],
+    [0xa57393db, 0x9cb3cba6], [0x4fa8d1f0, 0x102040be], [0xc325e1e5, 0x7f48cebd], [0x2d437348, 0xeeb5213d], [0xecb72114, 0xc63c7f28], [0x1f4ed0cb, 0x40d65f6b], [0x8932c514, 0x24dc4bf3], [0x6eef5b7d, 0xa1d17248],
+    [0xcd7b17a1, 0xafcd1a68], [0x69221cb6, 0x7933d8d3], [0xeb51e8cb, 0x14ff18bb], [0x5e6e81a3, 0xd95e1385], [0x9facfdee, 0xe8b9a2c6], [0x20355bff, 0x37fd9856], [0xa1286ff9, 0x4a51c431], [0x02fe56fa, 0x827d65aa],
+    [0xbf3ae8d7, 0xbebea304], [0x730d81fb, 0x00781291], [0xd8429595, 0x5f879914], [0x1da0abe2, 0xe5717142], [0xcaa31bdf, 0xcb20da7a], [0x09156fcb, 0x66e32a29], [0x8bc951b4, 0x0bbdc31b], [0x6cd592c0, 0x9f5f4efc],
+    [0xdfb4aab2, 0x8112b517], [0x413a91e5, 0x7306d933], [0xf1938c00, 0x2105cef9], [0x6656055b, 0xce467c7b], [0x9cd99db4, 0xc1b16b0a], [0x18289643, 0x34ef9300], [0x91836bd5, 0x4534f511], [0x3a30ffd5, 0x8ece452a],
+    [0x8b458718, 0x854ccb89], [0x59d49093, 0x2d2cb0bf], [0xe52529bf, 0x75d1501c], [0x3ec120b1, 0xdd00baf8], [0xfd5f3f36, 0xf382a322], [0x2790d270, 0x4318b688], [0xb61c1a86, 0x2df28582], [0x4d65a6dc, 0xa2791b74],
+    [0xe1efc5bb, 0xad0c9c12], [0x6b4540f1, 0x48937fe6], [0xd254271e, 0x066cdff4], [0x494a4a60, 0xeb6dc547], [0xa342c7f4, 0xeb87baaf], [0x23bcf4b0, 0x1ba8cf09], [0xa3bba640, 0x7b10bd2b], [0x002dddd0, 0xb9112dbc],
+    [0x9bfe986d, 0xa1778b42], [0x632d0251, 0x300e8774], [0xec70c5f9, 0x4df6e4d2], [0x16acdf2a, 0xc69e8300], [0xd759065a, 0xf18f4df9], [0x24a975d6, 0x770558fe], [0xae755dd9, 0x1ea7acb5], [0x5af793dc, 0x88900a0b],
+    [0xe2fee90d, 0x9732d91a], [0x4208bec1, 0x461283a4], [0xd1d914d9, 0x2b48b921], [0x7c794a4e, 0xfa159530], [0xb83b6f89, 0xd1ae4a69], [0x0202b8ac, 0x0e70e9f1], [0x8e5a98b0, 0x6d685ff4], [0x35843157, 0xbb228300],
+    [0xbc2a3daa, 0x87585031], [0x5a7e813e, 0x03badc65], [0xda53c276, 0x6846808b], [0x327b436d, 0xff459faf], [0xfeb2c98b, 0xde2fdeae], [0x04994c8f, 0x5ce68fb3], [0x94b28ce3, 0x30a56956], [0x7b2f02df, 0xbcae3de0],
+    [0xd16d8e3c, 0xb53f24a0], [0x7ca50609, 0x6d912b98], [0xfa8fb347, 0x058b0ddd], [0x42ce4cd2, 0xcc5d6b4b], [0x85909a4e, 0xf45717b5], [0x39bc83fc, 0x2bf9ece4], [0xb8d807b3, 0x5ab07c8c], [0x1123e42a, 0x9a8d73bd],
+    [0xa76b5554, 0xa2caf097], [0x6c6099f2, 0x12fa78bb], [0xca4b9573, 0x439baf82], [0x06dec82d, 0xfc6b55b3], [0xd5d806a4, 0xddf3d9b8], [0x1d0567f9, 0x752cb8f0], [0x994306b2, 0x124a5b0c], [0x73ab47f3, 0x8bae06f2],
+    [0xcedd5331, 0x952664e9], [0x5c7dbe83, 0x612b1c51], [0xe93ebac4, 0x383e0fdf], [0x7759a6fa, 0xdb4833fc], [0x8cae2e8e, 0xd21ef255], [0x0c9ba20c, 0x283c3ccc], [0x868626ea, 0x54e29a9a], [0x232490c2, 0x95e0c03d],
+    [0x99959c8f, 0x9fa4f2a4], [0x458a3402, 0x322011a9], [0xf4097791, 0x6ab9c842], [0x2e3b66e1, 0xc4f89b64], [0xee9332b3, 0xecfe8464], [0x3e71ab4f, 0x51646100], [0xadf4c195, 0x329f5008], [0x597a1408, 0xb0990af6],
+    [0xf9a30c80, 0xb7ca90b6], [0x77d47b54, 0x5436270b], [0xc6b0effb, 0x17bf0d6b], [0x5691a544, 0xf9ed24f9], [0xb3790148, 0xf7fa2dcb], [0x369f527c, 0x0dab7798], [0xbbae62de, 0x6e0ff7e0], [0x129b67ae, 0xa547e02a],
+    [0x1d0b14e4, 0xb3466f8a], [0x8953b27d, 0x1bb446d6], [0x78e0c348, 0x5f6d9138], [0xecb32d84, 0xed8f8b63], [0x4ac08452, 0xd6f4cf89], [0xc9cb321f, 0x63533175], [0x32d0d723, 0x3a38bb1a], [0xb56dbabf, 0x861d85f6],
+    [0x6373decc, 0x91ef58d0], [0xab1f6240, 0x4b97fa78], [0x56da6561, 0x03645eb7], [0xd60dc89d, 0xa5ea3f30], [0x2820c624, 0xfb4eda63], [0xf513e1b9, 0x292b443d], [0x00470706, 0x78b7fa76], [0x9af2f263, 0xcb264439],
+    [0x3c52eaa2, 0x8b52ff2c], [0xb92d2313, 0x355925b9], [0x42f5ee17, 0x6ab90af6], [0xc710a2f5, 0xdb528a83], [0x73015074, 0xe421a088], [0xe5c969cb, 0x53885a84], [0x1079e2e2, 0x147b2c1d], [0x82a842da, 0xb89f47e9],
+    [0x59bd2c99, 0xab2e4291], [0x9669b2f0, 0x7298fa89], [0x69c2d02c, 0x25bdf255], [0xf80dfeca, 0x9f4edee8], [0x098f64fa, 0xc7b61ec8], [0xdb16548a, 0x0ddf237b], [0x27847848, 0x46f177c6], [0xa71c92bf, 0xf359ffde],
+    [0x076b1342, 0x9940e864], [0x9d561d38, 0x22d52680], [0x5dca8c7e, 0x4dba3205], [0xdd7e65a2, 0xfdaaf5ca], [0x5115869a, 0xf76271d8], [0xd28b57a3, 0x43e0fb4c], [0x2d716569, 0x0925bc0c], [0x9216dc7e, 0x977aa494],
+    [0x7f6af4c9, 0xbe51ec72], [0xb309447c, 0x6c032245], [0x755db337, 0x1d8ed86e], [0xe2de56a0, 0xb7eaa302], [0x17c84b62, 0xea619b14], [0xe84b8f93, 0x13018ed8], [0x18b2e16d, 0x5754f93b], [0xbfa991c5, 0xd3e8e297],
+    [0x20ca1a49, 0xa3663e06], [0xa0abfbfe, 0x05594b26], [0x641b559a, 0x74642aad], [0xf0212b8a, 0xc0f9ed36], [0x6fa7ca74, 0xcd8f8bfc], [0xfc585d73, 0x7e3e4ab9], [0x0e640ac6, 0x2ea1b7af], [0xaf62d476, 0xadf2c64c],
+    [0x47002e2d, 0x804c8e8a], [0x8704192c, 0x58ca3d24], [0x4e7b3408, 0x314a764b], [0xccc73c69, 0x8d443fe1], [0x344b0f14, 0xde6ee248], [0xc2d262fa, 0x3ebb775d], [0x3a31629b, 0x657bd3fe], [0x8fb03781, 0xe0fa1adb],
+    [0x0d5beb79, 0xae4d1c68], [0x91499743, 0x0a97e291], [0x6d5961b2, 0x41650469], [0xff455009, 0xf498cd1a], [0x5f111ad1, 0xc3d3209a], [0xdf382875, 0x76bd6599], [0x228cd5d1, 0x21da9aa4], [0xa2d07c63, 0x9bb5dc55],
+    [0x76b0a9f5, 0x8f3492dd], [0xbc7bdd18, 0x540b5c1c], [0x44ebdcb2, 0x101eebbf], [0xc1df50a4, 0xbd8cb89b], [0x3811c75d, 0xe3884958], [0xe1275cf5, 0x32601449], [0x14c84f19, 0x6fb2fc6f], [0x85ee581b, 0xdda79a90],
+    [0x2e4d71f9, 0x95e6bd1e], [0xac2cbf59, 0x2d8c9167], [0x52562793, 0x7c460ead], [0xd0ad67c3, 0xcf0c850b], [0x670cd8ea, 0xff482f3e], [0xf2636609, 0x4fbe46e6], [0x059cea9c, 0x076aec21], [0x9f521ba5, 0xa06fe32d],
+    [0x4cb33b48, 0xb433bb06], [0x8c632e98, 0x66bbb9ee], [0x7c9b9161, 0x3dd62a93], [0xea49ea9e, 0x82204adf], [0x1b3740a0, 0xd09952a2], [0xce7cd52a, 0x1e5eb943], [0x3646ba41, 0x5b411c02], [0xb1623400, 0xe987dfe7],
+    [0x121c5f85, 0x851381e5], [0x819ec88b, 0x39d45ef4], [0x4895f5f1, 0x502cb9ff], [0xcaa29385, 0xe7555c55], [0x40417f0e, 0xef36ae33], [0xc463129b, 0x5c48df33], [0x3e944cb7, 0x181e49e8], [0x8a82cff7, 0x899186cd],
+    [0x6ac3a453, 0xa750dc90], [0xa4943dbf, 0x7b42fa2c], [0x60e2a184, 0x0e635405], [0xf6d08bc9, 0xa91e60c0], [0x03861017, 0xf06572bd], [0xfbfbaa80, 0x00396835], [0x0b8a21c3, 0x48372ef5], [0xa95d987c, 0xc527370e],
+    [0x30832896, 0xba47c77d], [0xb79ef718, 0x170a97f3], [0x70f28fd8, 0x61dc777f], [0xe7e0c5c0, 0xd55f4d69], [0x7b85667d, 0xd886a4f5], [0xef09472a, 0x68633079], [0x1ed54db3, 0x3732c02b], [0xba169fcc, 0xb0a2bc27],
+    [0x54052fea, 0x9dadf119], [0x99085718, 0x457b8028], [0x5aaed015, 0x2a55fad8], [0xd8b03c88, 0x93ed1cee], [0x25d21999, 0xc9771551], [0xd5c8a65c, 0x27fd73e7], [0x2bcb1215, 0x71bf101f], [0x95e2f66e, 0xf84645d9],
+    [0x15e04e72, 0xa487b29d], [0x9b28a072, 0x118a67ab], [0x62ca0c66, 0x559bcd4f], [0xf4aab954, 0xe2586de8], [0x57523095, 0xdcfe4bd9], [0xd708a0b7, 0x6e26f593], [0x39fae3ad, 0x28c48f8e], [0xbd968faf, 0x90f57777],
+    [0x793a705b, 0x9a43f7e4], [0xa3d7d203, 0x5e01c641], [0x4b1ddf8d, 0x0bbf6dcf], [0xdebe772b, 0xb2cb9e6a], [0x233becfa, 0xec9b886d], [0xedc4fcb2, 0x20c41748], [0x0c90515b, 0x62516b71], [0x88d4e0f1, 0xc2593ed3],
+    [0x37916e67, 0x9e38f5da], [0xb0fe7fd9, 0x2415ba6e], [0x581ae624, 0x67fa697e], [0xdac414b5, 0xd1fcaaf4], [0x68c4256d, 0xe81c335f], [0xf9c024c7, 0x5a086f8a], [0x08806b47, 0x1f8d5f71], [0x975f0272, 0xb57a8095],
+    [0x430e52fd, 0xa17de3aa], [0x8378c05a, 0x7d8409b2], [0x66a2363a, 0x34c01c4d], [0xf303357c, 0x8a01c54a], [0x111ea2dc, 0xcee64c2c], [0xc67cccba, 0x0610f6ef], [0x3d5c0e67, 0x4e7ad235], [0xad5e5f25, 0xe5cf9242],
+    [0x19bdca73, 0x92985f9d], [0x94549008, 0x30cea110], [0x5529cf5f, 0x590677d4], [0xd4a10555, 0xeb9e9bf8], [0x5b7c43b2, 0xe144a694], [0xcdb5a13b, 0x49982ede], [0x35f0a1c9, 0x019d72ef], [0x86c7bca7, 0x9c2ff0bd],
+    [0x65b930c2, 0xb11e0d22], [0xbb757bda, 0x75936449], [0x7a951a90, 0x04500a07], [0xfdf5512e, 0xbb57bb54], [0x1ffff2b2, 0xfc8209a2], [0xf1ec79b5, 0x19e86d95], [0x0630a0ef, 0x5d004510], [0xa1e1c565, 0xd9c9ef41],
+    [0x2a9d6427, 0xb6e90048], [0xbe215130, 0x0f37483c], [0x7e6dcfeb, 0x7ac9e7ae], [0xfa5f0b87, 0xdf97eca7], [0x7441fad5, 0xc48ef7f2], [0xe320a255, 0x70e1d982], [0x0293671e, 0x3f18dc44], [0xb2b8d952, 0xa6733f90],
+    [0x5c95aa46, 0x88f9b191], [0x8b112520, 0x4c9481a9], [0x41dd35ab, 0x2314345e], [0xd397c580, 0x84b56978], [0x2cfd88d1, 0xd4423464], [0xcb33e38f, 0x2f2039bf], [0x312430e9, 0x7ff72a85], [0x9c06bab2, 0xee3f9e1e],
+    [0x01255ccd, 0xbc703e66], [0x844f3bf7, 0x022815c1], [0x77c69429, 0x4a4d7a5e], [0xe0f874da, 0xfad92895], [0x456e8a14, 0xca6744c1], [0xc06c76f1, 0x79332a28], [0x29d3e781, 0x3359e2c6], [0xaaa7abb0, 0x8e204583],
+    [0x6c5260af, 0x87a4ae5e], [0xb41b0f6e, 0x40a35ddf], [0x5e0804b1, 0x1a5442b5], [0xc86523f9, 0xaf1f9d02], [0x331a527e, 0xf59854d8], [0xfef7fe5c, 0x3bfbe44d], [0x1c5866e7, 0x77a6b385], [0x90d5ddf3, 0xd74a66a6],
+    [0x26962849, 0x8396963c], [0xa625b2de, 0x3c8c306b], [0x4d6510c8, 0x73ee3d83], [0xcff6241b, 0xc61c40e6], [0x7d149206, 0xf24a10a5], [0xeb91dcec, 0x47c289a5], [0x1ad602ff, 0x0c23ef99], [0x8d8fcd60, 0xaa7b2344],
+    [0x53a2a7a4, 0xb9c2958d], [0x9e423f61, 0x6b9cc54a], [0x72dd7ca6, 0x2c114794], [0xe49f7152, 0x947faad2], [0x04d5485a, 0xdad03b89], [0xd13b5762, 0x15914b8f], [0x2f8f5196, 0x52a1c332], [0xb82b8f0f, 0xfe6abcd3],
+    [0x0aec64fc, 0x8c44a233], [0x8e8e5d4e, 0x2bc97d45], [0x46c2df04, 0x44434445], [0xc35497e8, 0xf1942b68], [0x4f8b38bd, 0xf96c7580], [0xd9f63629, 0x564d1db8], [0x24160d48, 0x12977b2c], [0x9859fcd0, 0x8196364e],
+    [0x71f5b8a9, 0xac4fdc14], [0xaea50da2, 0x60df3619], [0x6efc2f8c, 0x163e1795], [0xee79769a, 0xa28b4c57], [0x0feff6aa, 0xe6394b7a], [0xe6e70a84, 0x08355330], [0x13c67487, 0x42605334], [0xb64269ab, 0xcc8d7850],
+    [0x3bdd3eca, 0xa88d5c0b], [0xa8a6bcf1, 0x1cd6c0e5], [0x6bff2132, 0x6dcb4baf], [0xe9319b02, 0xc8f42f3a], [0x61c42763, 0xd23a87bb], [0xf7379f1d, 0x64aa249f], [0x162f2d05, 0x26dc966d], [0xa530db84, 0xbfdc91b1],
+    [0x49785230, 0x96c689be], [0x9377da3a, 0x513b4b2d], [0x502ad5e0, 0x38a61415], [0xc5498df0, 0x984812ff], [0x3ffc4579, 0xc1d1db9e], [0xdc84610d, 0x36bd82c9], [0x21ece715, 0x690fce10], [0x8073d889, 0xf6b5f8fc],
+    [0x10a6f61c, 0xb851ef74], [0x823d3515, 0x14dd0cfd], [0x7398e2aa, 0x5358a86a], [0xe515825e, 0xe4a5ca0d], [0x427d0adf, 0xdbb197a0], [0xc7bece85, 0x6a1d2e31], [0x3ce7d1b4, 0x35a65f12], [0xb9a4808b, 0x8bcb298e],
+    [0x690daa1f, 0x9fba477d], [0xa7dd609f, 0x46247443], [0x596f0a04, 0x0d38fbd7], [0xdbca6b55, 0xabff266e], [0x272f83a4, 0xf3eca11e], [0xf8b235ac, 0x25746f23], [0x0915d5f3, 0x724b3916], [0x96fb40d4, 0xc75fe083],
+    [0x327bdb37, 0x86bb7953], [0xb5928942, 0x3aa1f1e4], [0x4a678b08, 0x6381fd35], [0xc940e885, 0xd6604516], [0x780ee360, 0xed64edd0], [0xec2ba81d, 0x5fb4980c], [0x1da50b97, 0x1b5a35cf], [0x89be0685, 0xb3e16e26],
+    [0x5667cf0d, 0xa53b2616], [0x9a792cd5, 0x78145c23], [0x63b4ab6f, 0x29ee380f], [0xf5a087df, 0x9137478a], [0x00b6eed5, 0xcbc502aa], [0xd6fed58a, 0x03f818e6], [0x28a6180a, 0x4b2a36be], [0xabaa1429, 0xfbbfb3fd],
+    [0x0ebbf4e6, 0x97cbbe2c], [0x92853404, 0x2e21b657], [0x519ee3a7, 0x43640b4a], [0xd221a339, 0xf78eb4dc], [0x5d784e64, 0xfd3c3212], [0xdd83cf15, 0x4d2da1ab], [0x2073929b, 0x05df4be9], [0x9dc597a4, 0x99aaf3ca],
+    [0x7596995f, 0xb7592e01], [0xbf684ff1, 0x65ab2ab7], [0x7fe08403, 0x1391f640], [0xe8c61ec7, 0xbed7d007], [0x18522299, 0xe027b7b3], [0xe26ef114, 0x1d0d28f4], [0x1755f5f2, 0x5828badf], [0xb3dff1bd, 0xde8b1d4f],
+    [0x2dcc76db, 0xad7a7346], [0xaf9fe5dc, 0x09c427df], [0x6f47587b, 0x7ef15825], [0xfcf23858, 0xcd4bcd7b], [0x64ce73cd, 0xc0762e6f], [0xf091050f, 0x74ddf61f], [0x07a5fa58, 0x22037cfc], [0xa072daa7, 0xa3999106],
+    [0x4ec95cc6, 0x8de37e6a], [0x8f443aeb, 0x57964294], [0x479ec21d, 0x3e58439c], [0xc264867d, 0x80c11fee], [0x3a8c2a6e, 0xd30b2599], [0xcc5d8223, 0x31a0a93a], [0x34d01f7b, 0x6c9c5a4c], [0x878cba0f, 0xead556c9],
+    [0x053b70a2, 0xa0879faa], [0x9f8fb454, 0x07e33fc5], [0x67adbb6f, 0x4f7460d5], [0xf2c75e36, 0xffc45026], [0x52bd4883, 0xcfa8b1b7], [0xd06e1af6, 0x7cbe7a6f], [0x2ec266ad, 0x2d620d24], [0xaca3f5fd, 0x9500d7c1],
+    [0x7c22bfaf, 0x82bd0323], [0xb1aeccf7, 0x5bf5741b], [0x4c4067dc, 0x1eee32ad], [0xcee9f658, 0xb499ae6f], [0x36be2684, 0xe969c3c9], [0xead4fd5f, 0x3d518b53], [0x1bb8c40e, 0x660ce62c], [0x8cbff9ee, 0xd06a7639],
+    [0x2216cbc4, 0x9b37d951], [0xa202ba48, 0x210824f3], [0x5f98482e, 0x7648830d], [0xdfa1627d, 0xc3549c9a], [0x6dd1db47, 0xf43cbd18], [0xffde1747, 0x4199a2fd], [0x0da7b60c, 0x0a55243d], [0x91a2f0e1, 0xaede3e21],
+    [0x446ae51f, 0xbd034fcb], [0x85149fb4, 0x6f1a3ab7], [0x7655fd03, 0x3291374d], [0xe1bd033d, 0x8fd647c7], [0x146ee42a, 0xdd4d4a1e], [0xc10a7c38, 0x109be15e], [0x38a67c2c, 0x54e6fa79], [0xbcb596cc, 0xe30ad0c4],
+    [0x1e10132a, 0x893bed63], [0x8a038e55, 0x37a87c47], [0x40bc376b, 0x5cb3d545], [0xc4b28629, 0xef9d6bd6], [0x4826d9fb, 0xe7d693d9], [0xca40cf52, 0x50ab91e9], [0x302a7070, 0x17d3222e], [0x810b533c, 0x859af1fb],
+    [0x602a5561, 0xa98e0fa9], [0xa990a1c6, 0x71628f18], [0x6a77e91e, 0x00b83eeb], [0xfb3680be, 0xa78b7079], [0x0b4bf510, 0xf890dc5d], [0xf6489f4c, 0x0e87f25b], [0x03227f00, 0x45ab129c], [0xa444790f, 0xc9825df2],
+    [0x3e02543a, 0xb0150e97], [0xbaac5636, 0x1892fc31], [0x7b33e3d6, 0x68aa6d93], [0xefa69d7f, 0xd870bab6], [0x704d5589, 0xd5bd98a4], [0xe75ab261, 0x61587ef4], [0x128eed2c, 0x390fd70f], [0xb702e652, 0xbae920fd],
+    [0x5a2c2271, 0x93418f7e], [0x9567f8a2, 0x489b723f], [0x54b020ee, 0x274af8aa], [0xd5242b57, 0x9d488fe2], [0x2b4cdb82, 0xc5b12f0f], [0xd8767bef, 0x2ac68c2c], [0x252bf82a, 0x7bb1bf5b], [0x99fee421, 0xf0a07c44],
+    [0x1a05fb7a, 0xaae733d5], [0x97c2e81b, 0x1f1ecb85], [0x68700907, 0x5a8a2392], [0xf941f377, 0xe88aef3f], [0x58b90663, 0xd11077af], [0xda6c394d, 0x671aa96b], [0x37673377, 0x24939fcc], [0xb022a392, 0x9eda684f],
+    [0x72170865, 0x94b0c382], [0xadb26d1a, 0x520c07c4], [0x43f9d467, 0x06a4358e], [0xd1f5fe93, 0xb96909a1], [0x2f2b02b7, 0xe517b0a4], [0xe43b86b0, 0x2cbf44d1], [0x04235d54, 0x6b457e11], [0x83ed57da, 0xce4de322],
+    [0x391f14b1, 0x904d65fc], [0xbd6af8f6, 0x286a6bbd], [0x57a7d083, 0x6e9dd973], [0xd7f9db48, 0xdc1d150b], [0x6202b222, 0xe2db0262], [0xf41097b7, 0x55002680], [0x01d27bb6, 0x11371b8d], [0x9ba89f09, 0xbcbe841a],
+    [0x4bdea73e, 0xaf90c981], [0x880e52ef, 0x77034d55], [0x6c8225cc, 0x3b3c5702], [0xfe30ba58, 0x87220ada], [0x1ce9a73a, 0xc2abdef9], [0xc8d1a912, 0x0b63cb7e], [0x33a489f1, 0x40291095], [0xa3651782, 0xec111747],
+    [0x16b07b56, 0x9cf2ab60], [0x98c2a7c1, 0x3fcf322b], [0x5bb29f09, 0x569cfe05], [0xd91115b2, 0xe1b037f1], [0x55e0e486, 0xeb5d8ec6], [0xc3c4420f, 0x44dc9968], [0x3b772969, 0x0f8d4c75], [0x8e0c38b0, 0x9237c132],
+    [0x6e197924, 0xbbd2fa09], [0xb6d63f8b, 0x7f5110aa], [0x7123555a, 0x08aafe8f], [0xf124aa00, 0xb1e401b1], [0x1333171b, 0xf6430f57], [0xfd6c5f12, 0x16d89b0d], [0x0f167ea2, 0x51b1d5b4], [0xae71df19, 0xd4db83f0],
+    [0x2489448a, 0xbf7600f8], [0xb214ee7f, 0x010662be], [0x748730c7, 0x7040746d], [0xf79ab8f0, 0xd2b6a163], [0x7ef7f343, 0xc81f3feb], [0xe9b50846, 0x7a31790e], [0x0a245727, 0x302a3214], [0xbefd10e5, 0xa83405b0],
+    [0x50d4d0e1, 0x84025fad], [0x80cfc471, 0x428e1642], [0x49cb7660, 0x2fa48d9b], [0xdc63c7c1, 0x8800f951], [0x21012bf0, 0xd9119170], [0xc5fe496f, 0x23a3c85a], [0x3f611fd4, 0x751246d9], [0x939addc8, 0xe6e3965d],
+    [0x0868c50d, 0xb586157f], [0x8d4bf481, 0x0cb4de6c], [0x7df9d369, 0x4757fedc], [0xeb55c6d8, 0xf2ee05bf], [0x4dbab3c6, 0xc6c0b08f], [0xcf41aef1, 0x734c52df], [0x2612d34f, 0x3c0b56a8], [0xa6efaf4e, 0x834d7c4d],
+    [0x66328c6d, 0x8abb88ae], [0xb89d7ae9, 0x4e8a6de8], [0x531fbf38, 0x1549bfaa], [0xc6b67b04, 0xa1fca5ef], [0x3d9cb329, 0xfea7dc77], [0xf3ad6914, 0x3440f3a2], [0x11d52d7b, 0x7d585f15], [0x9eaf3cdd, 0xda065506],
+    [0x297de72b, 0x8e9057c3], [0xaa423cc1, 0x33f2fa28], [0x45c9ff46, 0x79ea6930], [0xc0959431, 0xca925584], [0x775db260, 0xfa7df090], [0xe005374d, 0x4aa37ab5], [0x1507bf9a, 0x02af7cac], [0x84dd93a5, 0xa420a13e],
+    [0x5eb6506d, 0xb25112f3], [0x9011f799, 0x62d29d5d], [0x7991b403, 0x204e95e4], [0xed498040, 0x9aa527cb], [0x0c35cf27, 0xd7e731fc], [0xde70844c, 0x1ab88389], [0x23a71de0, 0x5ee3af81], [0xb49a7188, 0xf529e3c7],
+    [0x0211ebd5, 0x8154d21c], [0x8624bcbe, 0x267d6879], [0x4f04dc19, 0x4926dbbc], [0xcd467e69, 0xf996c020], [0x465b00b0, 0xf12c2479], [0xd460b132, 0x59fd5b5a], [0x2a3eb7f6, 0x1c3a1da1], [0x94931880, 0x8cbcf435],
+    [0x7a09728b, 0xa231bc81], [0xa15e9016, 0x69f18a5c], [0x65271907, 0x192a4302], [0xe6163229, 0xac99207f], [0x06defcd0, 0xeed9c4c8], [0xee9e98b8, 0x04d36371], [0x1f1c44a4, 0x4c333500], [0xbb927cb0, 0xc142ce69],
+    [0x3504b085, 0xa6e6111c], [0xa5e4f8e1, 0x1200ed1d], [0x6134f848, 0x6435de68], [0xe3abb46e, 0xc42cd6f8], [0x6b017d99, 0xdf4fec96], [0xfac879f7, 0x6d406a6a], [0x1939a1ce, 0x2b7cdb49], [0xa8314d27, 0xb6558b98],
+    [0x417f774e, 0x98e450a6], [0x9c8f7ceb, 0x5d9b8dad], [0x5c366be5, 0x3613b11f], [0xcbd2ecb7, 0x965bd64f], [0x318a9856, 0xcc1cfdb5], [0xd3365dec, 0x3877c0b4], [0x2c6ffe26, 0x607d08e4], [0x8bfcd6a5, 0xfc63d213],
+    [0x18c3d3ee, 0xbeb5152f], [0x87cbd746, 0x1df0fb69], [0x7f30f5b1, 0x57c38af2], [0xe210f2ca, 0xea190c5b], [0x4e2b96e0, 0xdef75490], [0xc21ac7ba, 0x6520915e], [0x3ac28d48, 0x3ef923ff], [0xbf1cbf7b, 0x800b3a7c],
+    [0x64742647, 0x99da7332], [0xa0330078, 0x4dda8cd4], [0x51759601, 0x099d1070], [0xdde33702, 0xa32984e2], [0x200b54e7, 0xfde8d1e3], [0xfcb88c02, 0x2eece7cc], [0x0eeddba3, 0x7e7e1be1], [0x92e3d889, 0xcdcf60b4],
+    [0x34ae7038, 0x8d21e4d0], [0xbfcf51be, 0x3e0bdfde], [0x476b2b63, 0x65fc33c4], [0xcc2b08a1, 0xde1bcc51], [0x7f98e510, 0xe09bcd13], [0xe2a19fab, 0x584cd1d0], [0x182bd5f8, 0x136d7f32], [0x874f5d19, 0xb711b8d5],
+    [0x51e46a20, 0xad9a9b3a], [0x92503f25, 0x7e9b5c7e], [0x6ff900de, 0x2e6ecb51], [0xf0e46903, 0x99051e9f], [0x07d289ec, 0xc0a6c3be], [0xd26834bd, 0x09734135], [0x2dace318, 0x43ba5105], [0xafea6124, 0xf70c1751],
+    [0x09587fd4, 0x9f0fabbc], [0x9687f586, 0x25db263a], [0x5627bbbe, 0x4bf7690e], [0xdb458048, 0xf3aee2bd], [0x5900908f, 0xf33b4379], [0xdba9f42c, 0x46b6f3bc], [0x2876b476, 0x038c981a], [0x9a1f8b2f, 0x918a449c],
+    [0x78991453, 0xb3993fe0], [0xb9f2f023, 0x6aecc6be], [0x734fccb1, 0x14afc7f2], [0xe593cf8f, 0xb80814c9], [0x1de33696, 0xedf0d4ad], [0xe57b0cec, 0x1420018e], [0x1d699506, 0x5ff85916], [0xb5f6a933, 0xd6b97353],
+    [0x27c85610, 0xabb92272], [0xa76d81e5, 0x0d4139a6], [0x697c3a5e, 0x72d33b6d], [0xf57bfbb6, 0xcbaad73d], [0x63c0f82d, 0xcb7974fc], [0xf8432f4f, 0x721802f6], [0x09cc5f33, 0x25015d07], [0xab4071d4, 0xa56000e4],
+    [0x4a30fc7d, 0x865653e7], [0x82debdde, 0x531934e5], [0x4aad5e34, 0x3ad0bb26], [0xc7da28a0, 0x8b37cc33], [0x3281eb09, 0xd6073f37], [0xc74151aa, 0x35d705ec], [0x3c083724, 0x6a5dd132], [0x827858a6, 0xe45e60b1],
+    [0x0375e46a, 0xa97993f5], [0x99879664, 0x0e01f845], [0x6a9287a3, 0x48dc7d32], [0xfba15b03, 0xf8fd4f00], [0x54c60a3e, 0xc55dd85d], [0xd8fa8969, 0x7bf378d7], [0x2b0de292, 0x2780c2f2], [0xa4c8d4da, 0x933b706a],
+    [0x709d49d3, 0x85c3568f], [0xba60bbbe, 0x5cefd095], [0x40249a6b, 0x18eec375], [0xc4349b38, 0xb069efee], [0x30400c5b, 0xe732ba9e], [0xe79c4210, 0x395894f0], [0x124adde6, 0x610ff103], [0x81e5c5ec, 0xd5fd19e1],
+    [0x25491211, 0x93b079b7], [0xa43e05cc, 0x2a3d5b7a], [0x544eb8ac, 0x71245307], [0xd5a48dda, 0xc5e5e962], [0x6a2a519a, 0xf82e656a], [0xfb66e165, 0x4874b4ac], [0x03c72f00, 0x0ed92de8], [0x953cdcdb, 0xa72c4423],
+    [0x48eb45a1, 0xba8d57d2], [0x8170fa1b, 0x618e39a1], [0x7bd8f98b, 0x37ffde49], [0xef63774c, 0x894e1bd1], [0x1e8e109a, 0xd8044561], [0xcafdf061, 0x1794ae93], [0x30d86cef, 0x50d8c05c], [0xbad7f704, 0xef77d40c],
+    [0x14a1420e, 0x8f9d4465], [0x8cdaba84, 0x3da58dae], [0x4cc35ad6, 0x5bbf00bf], [0xce1beb14, 0xe915547b], [0x44836d59, 0xe3729aad], [0xc1ab6426, 0x5481c81a], [0x38440198, 0x10dbd1f8], [0x8c2a0e65, 0x82febfa6],
+    [0x6d1ea2c4, 0xae95f2fe], [0xacfde0d6, 0x7c03833d], [0x67464435, 0x078d24d3], [0xf23cac3f, 0xa0e1561a], [0x05c37d37, 0xffa0c868], [0xff05b246, 0x0a0b682b], [0x056ed2d1, 0x4ffe5ffe], [0xac7a24f7, 0xcfc65438],
+    [0x36196084, 0xb4e988c4], [0xb1123151, 0x1e8147e9], [0x76c6e761, 0x6f66d292], [0xe16da5b1, 0xdd0dd9c0], [0x76065680, 0xddf41c6c], [0xe1e11bfe, 0x6fd662d1], [0x143cd5bd, 0x321331d1], [0xbc28ab62, 0xbd414dbd],
+    [0x5223f0ea, 0x95475769], [0x91c23a2f, 0x412416bc], [0x52eb66ec, 0x2def1098], [0xd02dec7e, 0x95a5de97], [0x2eaaf483, 0xcf69dc2d], [0xd0e5a886, 0x2d2ff2c0], [0x2248b473, 0x76f2f313], [0x911dc7a5, 0xf459a2bb],
+    [0x1f58ed4f, 0xa2dbd47a], [0x9c790d6c, 0x196c77ff], [0x6e649404, 0x5143ae97], [0xf189db80, 0xeeb87753], [0x50441e7c, 0xd48f6fda], [0xd3cd6e8f, 0x6021f17a], [0x3fbbb0e5, 0x23f5be3a], [0xb6bf9d9a, 0x9695abe0],
+    [0x7e0a340a, 0x9275bf92], [0xa8499be0, 0x596c2104], [0x4fce283b, 0x0159a8bd], [0xd98fb4df, 0xbf32eee2], [0x24672733, 0xe1def737], [0xe9523cee, 0x2b1ffd9b], [0x026a339a, 0x64cd29ec], [0x865c572a, 0xc4d3843f],
+    [0x3f171474, 0x98374732], [0xbbdffd0a, 0x234035c3], [0x5cf8cf29, 0x69bf012b], [0xd340ef94, 0xd42a9922], [0x6e84eceb, 0xe68cb014], [0xf17907b3, 0x5d540dbe], [0x0fa65730, 0x16b42a2e], [0x931ad391, 0xbba2a15d],
+    [0x4f74491e, 0xa6035f1e], [0x86b29b66, 0x703d09b3], [0x6b49d009, 0x309e676e], [0xf76f2bc8, 0x811fdb6c], [0x194d9c02, 0xc8885b34], [0xc31321fd, 0x0fd45d50], [0x3b986371, 0x44a059a6], [0xa8e18658, 0xeb1c4eb2],
+    [0x1c11ed6f, 0x9ae2d74e], [0x9edaa50d, 0x348e9b13], [0x53d2d414, 0x524368b0], [0xde3885da, 0xecf0099e], [0x5360d598, 0xe59d5c68], [0xc8203aef, 0x404b20cd], [0x3dfc83d2, 0x06785360], [0x83186598, 0x94f63ba1],
+    [0x683f6e86, 0xb515a9bf], [0xb0680165, 0x738892c0], [0x77067359, 0x0251f22e], [0xf46eb2e4, 0xbc2fb073], [0x157f257c, 0xfa8866a0], [0xf4e190f5, 0x11724f60], [0x017f957d, 0x556ed88e], [0xa6711ba5, 0xd15d8e9c],
+    [0x23c4dea7, 0xb282e94c], [0xb86c585a, 0x06db14ee], [0x724771b2, 0x7ddef23e], [0xfeab4e2a, 0xd7b9a711], [0x79e83d1f, 0xc223e571], [0xed21187d, 0x77e6ff9b], [0x04aec898, 0x341cf611], [0xb8d05766, 0xa123dc0e],
+    [0x57dd5c37, 0x8e62e4d2], [0x8dd7b1a3, 0x471e884c], [0x451bdfee, 0x282541e7], [0xda34b91e, 0x83e190b5], [0x26664e5f, 0xd1b6bcf3], [0xcf9016f4, 0x24d125b5], [0x3954f37c, 0x794f4a92], [0x9b5c8644, 0xe28b4e6b],
+    [0x06618269, 0xb1a366de], [0x8b8a6ede, 0x043659a7], [0x71a53b88, 0x42e0c662], [0xe6b082ee, 0xf60d3e2f], [0x419aec57, 0xc12c9c52], [0xcb871ce3, 0x7f9fe9d2], [0x217a8243, 0x36ca290e], [0xaeef7462, 0x847dc1fc],
+    [0x6b8ed4a8, 0x8ce0271b], [0xbe954c8b, 0x44342cec], [0x55899726, 0x1cab40ee], [0xcdc0a55c, 0xa6b3d612], [0x3b1f0257, 0xf1d53f46], [0xfa1a2c76, 0x30555c08], [0x16e2449b, 0x70af86a1], [0x98aae2e4, 0xd25f6750],
+    [0x2c21d4a3, 0x84ed9a99], [0xa1985f71, 0x365e8e8d], [0x4912eed9, 0x7f3539b3], [0xcb4e8dcc, 0xcc52c2e2], [0x7ad3ee90, 0xfc2887ae], [0xe67b048c, 0x423b93b9], [0x137dfeee, 0x087051d8], [0x8b61026e, 0xa2781566],
+    [0x554b126a, 0xb612ef82], [0x94f79574, 0x6d8a1207], [0x7e8f511c, 0x2ba5a76e], [0xe9e1209d, 0x92d823b3], [0x0a503ff6, 0xdfd57277], [0xd94070eb, 0x12cd54d8], [0x2acde673, 0x5995930f], [0xbe452548, 0xf168d6b0],
+    [0x0cf6390b, 0x875cc6cd], [0x88a46eb6, 0x2023aecb], [0x4bbddb79, 0x40e2155c], [0xc89b4e06, 0xf5d567c9], [0x4b48e70c, 0xf56c93b2], [0xdeeb410b, 0x5e88ac1e], [0x2f5a494d, 0x15d6abfc], [0x9e07a541, 0x8ac3319c],
+    [0x7db9c57b, 0xaa2f4272], [0xaac51a49, 0x6ed99ad8], [0x624ba734, 0x11cfdce2], [0xebc32b62, 0xaa8bc6b3], [0x08d5d4b5, 0xe8f459d3], [0xeb1ebae5, 0x0c74731a], [0x15801e9d, 0x4aed7ff2], [0xb0a55871, 0xc6933b3f],
+    [0x3d3bb639, 0xa1a4d0a5], [0xa32ddc40, 0x1a152e5b], [0x6c365078, 0x62a5161a], [0xe45c1a94, 0xceb4f4c6], [0x66e29925, 0xda5f75b4], [0xfe6f710f, 0x62203906], [0x1170b7c1, 0x2cec8942], [0xa39571d4, 0xb2180734],
+    [0x45b39a5c, 0x9089c3ea], [0x97245e53, 0x5adc66cd], [0x572557dd, 0x33a54468], [0xc033a0ab, 0x90238a6d], [0x372196c9, 0xc6536006], [0xd7abc993, 0x331bf51c], [0x2937ad86, 0x6e4da5b1], [0x8426b890, 0xfa04c365],
+    [0x1719e3e7, 0xb78d342b], [0x8ffb50c3, 0x13cad118], [0x75ddd480, 0x58a8d327], [0xe832653b, 0xe0425ab4], [0x47f29758, 0xd3a51ae7], [0xccad08d8, 0x6cd707cc], [0x342fee93, 0x31e81e60], [0xb3575dc1, 0x8d997c80],
+    [0x6f1ff883, 0x973bb0da], [0xaf3bcbfb, 0x4318f9cb], [0x5d121424, 0x053327c5], [0xd2f068dd, 0xad0186ab], [0x2d117b35, 0xf7fc806f], [0xf0559d63, 0x2264634e], [0x0725b864, 0x748b159a], [0x9d34e75c, 0xc013212a],
+    [0x3a77f621, 0x80aed9b8], [0xb3a41e8c, 0x3124343c], [0x4ea00f8f, 0x6c655b2c], [0xc29bd949, 0xd3640dcf], [0x751b2c3f, 0xea809960], [0xe8983b4c, 0x57138fba], [0x17abb9b8, 0x1d7c8721], [0x8f35622a, 0xbe22a30f],
+    [0x5db418fb, 0xa3f5c68a], [0x9da791e9, 0x7404b26d], [0x64928bfd, 0x2295a00f], [0xfc026844, 0x97b21b14], [0x0e2646dc, 0xcd0b5de6], [0xdd11d4fc, 0x05b1738c], [0x20b237ea, 0x4d41f090], [0xa0e38e90, 0xfd6b4a68],
+    [0x00064527, 0x91724af0], [0x9a820e87, 0x29873a28], [0x59c1820d, 0x465b3854], [0xd690dda0, 0xfb027f5f], [0x569aa6e8, 0xfbfeb158], [0xd645eece, 0x4b4e2068], [0x27554981, 0x0da579c1], [0x963bcc86, 0x9fd2f118],
+    [0x73f3fb94, 0xb8e736b1], [0xb5274875, 0x63ec29c8], [0x786ffdde, 0x1bf330a3], [0xec4049f7, 0xb32a83ba], [0x101e5d83, 0xe4d698be], [0xecc0285c, 0x1b078104], [0x10dc9583, 0x53ea301f], [0xb957e27c, 0xdbefc76e],
+    [0x28c38710, 0xa5a69fea], [0xabef661d, 0x03386455], [0x6303125e, 0x78545b31], [0xf8d096bd, 0xc7e2e5cc], [0x69872ad7, 0xc7085497], [0xf5d5d7fe, 0x78f2b532], [0x00e18261, 0x29670913], [0xa7bd5ab3, 0xab5ca20d],
+    [0x4299a6b3, 0x8bab97f4], [0x89de2710, 0x5f37c15b], [0x420e7863, 0x350c2979], [0xc983f541, 0x86dae76c], [0x3ca0e2e3, 0xdb0c730b], [0xc9146975, 0x3a792577], [0x3227848f, 0x631d98fc], [0x8919809f, 0xed229539],
+    [0x0bfc0513, 0xa7dc876e], [0x95a85ac6, 0x00e37d6c], [0x6060dc21, 0x4532294f], [0xf62121b4, 0xf01abb99], [0x5af6ea25, 0xc9e2a074], [0xd56d3461, 0x71c09ed0], [0x25985759, 0x2a873e7f], [0xa9ddabac, 0x9dc65119],
+    [0x7b7d229e, 0x89f21fa5], [0xb75a9563, 0x507a9028], [0x487f1a07, 0x17622f6d], [0xca2c60e8, 0xba051e32], [0x3ed9398b, 0xefeb73cf], [0xefee62b5, 0x37746a05], [0x1e558d28, 0x68032f6c], [0x8a6ea2e2, 0xd8c9c533],
+    [0x2b8ddc79, 0x9d2cd4e5], [0xa903f4c9, 0x273ba45f], [0x5a7956d9, 0x7b2f5205], [0xd82c8e8b, 0xc918b6ae], [0x60a882a7, 0xf0e694b1], [0xf69cc6ed, 0x45d45ddc], [0x0b3048df, 0x005a189d], [0x995f621e, 0xa9ccc01e],
+    [0x40c1a826, 0xb0f2bc29], [0x8ae967b6, 0x68ca416b], [0x70020627, 0x39a8aece], [0xe73abe62, 0x85532225], [0x12cf86cd, 0xd518b973], [0xc4e8bf1e, 0x18474b12], [0x3e4b454a, 0x5c101240], [0xb7dc4662, 0xe783f0b8],
+    [0x1bf6bb8b, 0x8263ec7a], [0x858e104a, 0x32e82030], [0x44065d42, 0x546617b3], [0xc169e17b, 0xe3d38cf3], [0x4c19e133, 0xe9c9d269], [0xceb384ea, 0x5b2d60b5], [0x36f7fca8, 0x1e2851f3], [0x854a9fcc, 0x8f4add72],
+    [0x67cb17fa, 0xa0223068], [0xa2bd23fd, 0x762f51e5], [0x6db254e2, 0x0acb04f5], [0xff9faf5e, 0xae24215d], [0x0de2567f, 0xf4fc2034], [0xf2a7bd1d, 0x0729bdde], [0x0d06c77f, 0x41c2f300], [0xa2718ffc, 0xc3b8ef76],
+    [0x38c1e9db, 0xbdd536cf], [0xbcc373b9, 0x1064cc9a], [0x7c7ed0da, 0x66ec9712], [0xea83f78a, 0xd0fd8558], [0x7cd9b976, 0xd01acb4d], [0xea27dcea, 0x66655785], [0x1b4913b9, 0x3d0981e3], [0xb1c63e7c, 0xb4736411],
+    [0x5fd668c0, 0x9bd81087], [0x9f155dee, 0x4f2f197e], [0x5f7b95e2, 0x21702bd5], [0xdf5b3f8d, 0x9b490f2f], [0x22c8450b, 0xc33a45f9], [0xdfdbfb24, 0x21964faa], [0x2e21950d, 0x7ce5bf59], [0x9fc8a845, 0xff05c9fd],
+    [0x13ab1d04, 0xace7a4c6], [0x93ed2a6f, 0x16498001], [0x65de0a8d, 0x5ddc015d], [0xfd3d1383, 0xe647e979], [0x5c49a072, 0xd995943b], [0xdc28b261, 0x69552c80], [0x31ff9ff1, 0x2f7b2be0], [0xbb002cdd, 0x988ff921],
+    [0x74ec95d7, 0x9c542f17], [0xa55b2c84, 0x56f3dc09], [0x4614b873, 0x0f7de492], [0xd42b3c9f, 0xb6a79c5e], [0x2a56431d, 0xebe7c131], [0xe3ded29b, 0x26ad75ad], [0x0aa32201, 0x6d16c90b], [0x8ef87576, 0xc85d5657],
+    [0x3154b511, 0x9622bf02], [0xb6258005, 0x2feb837f], [0x5093697f, 0x60beef99], [0xdcee7b9c, 0xd96d31a0], [0x655272e5, 0xee52273f], [0xfdbce7c5, 0x51e078b0], [0x06909a3f, 0x198ea73a], [0x9cd4d318, 0xb17a1569],
+    [0x46a33b04, 0xa846f06d], [0x8e6fb539, 0x7ab289d0], [0x61a4a906, 0x3f988b0f], [0xfa80e3ef, 0x8c3abb9c], [0x164e4cba, 0xc4754a66], [0xcd34a3c2, 0x01e05b4f], [0x357553e6, 0x49fd03cb], [0xa5b39a3b, 0xe11a0e32],
+    [0x118e9eeb, 0x943c08d3], [0x90bdd2c6, 0x3b52e522], [0x5ee6d5a5, 0x5e73ecc2], [0xd1464879, 0xe5548fac], [0x5e72fdc0, 0xec43fadc], [0xc6e3778b, 0x4e30d0fe], [0x336e0b5b, 0x0b144100], [0x88693240, 0x9a055330],
+    [0x62a0818d, 0xbcf25ed1], [0xbdd070d8, 0x7986b2a9], [0x7d432c50, 0x0cfe2417], [0xf9b83979, 0xb5f1f0da], [0x1ab20fbf, 0xf29925ea], [0xf91a9baa, 0x1fcbd0c5], [0x081870a3, 0x5a52a177], [0xaa014712, 0xdc8b8289],
+    [0x2ffe39f2, 0xb93b98a5], [0xb4c7ee25, 0x0be1825d], [0x7964cf6c, 0x7753c30a], [0xf3f91fc4, 0xdabdf488], [0x72a94b8e, 0xce32ed4e], [0xe4d2fc79, 0x7d38f5f1], [0x0c591d12, 0x3bba2384], [0xb47a2fc7, 0xaffde979],
+    [0x585cf0b6, 0x8301cf1d], [0x84baadc2, 0x4a023744], [0x4dcca886, 0x245a17e6], [0xd7604e6a, 0x8ec04bfb], [0x29bc7d74, 0xdc6e149d], [0xc0f18296, 0x2893d7dd], [0x37e38231, 0x7309a16c], [0x979b6f28, 0xe847cc76],
+    [0x0f7b45e6, 0xbb0110c8], [0x802c695d, 0x08d9ca62], [0x7a69a523, 0x4cfbab21], [0xeed09071, 0xfce513dc], [0x49be82f6, 0xccc38d0a], [0xc5071bd8, 0x75413a58], [0x2c93dba2, 0x38001652], [0xa101c117, 0x88b471ef],
+    [0x614433cc, 0x81df7529], [0xb2d40670, 0x4976947a], [0x5b03f7bc, 0x1249ee83], [0xc3bb0717, 0xa8fe8fd6], [0x35956f53, 0xf9e2d648], [0xf7f5eb82, 0x3f700cc6], [0x19ccf88a, 0x7a4d3509], [0x9414ea84, 0xdf0e7a43],
+    [0x2194814d, 0x88670452], [0xae3c6c2c, 0x38fdd726], [0x41214b15, 0x75de21e6], [0xc5a92fb3, 0xc1b7a941], [0x714ad5b7, 0xf6e46a84], [0xee25c077, 0x4c7e4c69], [0x1f803f9c, 0x048c5f7e], [0x80b7cb20, 0xac029ac1],
+    [0x5bd03b43, 0xbfa5081f], [0x9802edf6, 0x646d9109], [0x741985d5, 0x260d0e6f], [0xe35fef56, 0x9c9af542], [0x02e5edb7, 0xd2e2ad67], [0xd4c41261, 0x1c60cff3], [0x24eba514, 0x560c25a6], [0xb255d2e1, 0xf906c4e2],
+    [0x046c84d6, 0x8a52feb1], [0x838b9908, 0x2c74387f], [0x434cffcf, 0x4eca4e41], [0xc61819fe, 0xfec22c9e], [0x43bfc15d, 0xfe2b381d], [0xd1a9144d, 0x52c1884d], [0x2361c7ba, 0x1adcd8bd], [0x907cef94, 0x87f6f678],
+    [0x77888114, 0xa45c38ae], [0xa6a71900, 0x67940368], [0x68b4a057, 0x1f57a5d2], [0xe04ce41f, 0xa4c6d776], [0x01bde01e, 0xe232d4b0], [0xe0901c35, 0x02c3f843], [0x1a7c8261, 0x479b9aad], [0xbd1983fb, 0xca13785a],
+    [0x33e085ad, 0xaf51a66f], [0xad31559e, 0x150e33e7], [0x66600751, 0x6bd87ecb], [0xedb16636, 0xc2d0f3e8], [0x6cc73642, 0xd7249925], [0xf378df1c, 0x6b10c4c8], [0x1c8ab8ab, 0x209c6db3], [0xadd951e5, 0xb9b1d1d5],
+    [0x4d341e6e, 0x9eb9d09c], [0x9bf22041, 0x55e98e89], [0x58c46fd9, 0x3cd0b65e], [0xcf0bc382, 0x9e7db89f], [0x399024b0, 0xcaf9ff61], [0xdab03948, 0x3c575364], [0x26e8c03c, 0x6760c5f0], [0x8d35c7cd, 0xf21a9db9],
+    [0x6e73e372, 0xe474c66a], [0xbef27586, 0x32819d0c], [0x1cb824f6, 0x4ba260c2], [0xf2d1ae2b, 0xac987fe4], [0x35c45b98, 0x9b3d3b5f], [0xd37389e4, 0x65685f1d], [0x444d09df, 0x0bb69e2c], [0x93a3829b, 0xc43f06f3],
+    [0x0906e8eb, 0xddea992a], [0xeef9e7d0, 0x10da5708], [0x2e30fe26, 0x2ddf3935], [0xad6e5445, 0x832ff8fe], [0x5fa6ff8c, 0xb00aa9d6], [0x8296725b, 0x58128108], [0x7712ba29, 0x7eb134c2], [0xcc426ce9, 0xf76ad5bf],
+    [0x4a1cac9e, 0xcdc5595f], [0x9f1a86cd, 0x056e0318], [0x395e738e, 0x6c53303c], [0xdd4b4e65, 0x90699b5d], [0x1187c9c5, 0xa17e07fb], [0xfddf0cb1, 0x4315712e], [0x61597914, 0x3af91ffe], [0xb00cca81, 0xe84468e3],
+    [0x26b95b41, 0xfbaa4a08], [0xc2f8e7a3, 0x2459b401], [0x02364955, 0x1e1ee706], [0x8ccc95f9, 0xbd9ab69b], [0x7c62c4d6, 0x8a7aef83], [0xa0cb1842, 0x77ac9185], [0x54eee202, 0x5363ce48], [0xe5032e19, 0xd01c4846],
+    [0x52223960, 0xf39bd6c2], [0xab1bfd1f, 0x18c1cf3a], [0x0ccf509a, 0x714b846a], [0xc43ea560, 0xb6c1a4c4], [0x283c8f74, 0xbaf2a1c2], [0xe3a31ab4, 0x78873783], [0x793089fa, 0x1433803b], [0x84ba5400, 0xfdf91e15],
+    [0x1a863811, 0xed6abff0], [0xdabae051, 0x0e872e1b], [0x31f95e30, 0x00f85008], [0xbaa72391, 0xa4acba21], [0x64278c5c, 0xaaaa0da3], [0x978796cc, 0x6b005149], [0x4f5dd407, 0x60660f7d], [0xd7acf753, 0xc8de6359],
+    [0x73b46c48, 0xd41c6ee5], [0x8a208e29, 0x2af4dad3], [0x223c2bda, 0x5c11e619], [0xeb9bf65e, 0x8f5c6f49], [0x07ac65f2, 0x871fff46], [0xcaba5871, 0x56aaa7d7], [0x58391f86, 0x207b0ac1], [0xa7e5fef6, 0xdb452667],
+    [0x3dd6d4e4, 0xc2ff5fbb], [0xf58e5300, 0x3db71334], [0x1468304c, 0x35cd0296], [0x98c45b77, 0x9c2079b2], [0x4299c2b4, 0x9722afd6], [0xb4071422, 0x4fc8060d], [0x69f8127c, 0x4534ee5c], [0xf9715d0c, 0xe096f048],
+    [0x7b5dfc31, 0xffc1df44], [0xa5d0d3ef, 0x23a119af], [0x04ca423e, 0x5514b995], [0xe13a0af4, 0xb859d4c8], [0x21f90440, 0x8c59bbf7], [0xc6ef74b8, 0x72164c6b], [0x51863168, 0x1b09f3d6], [0x889db53e, 0xd7d7b1e3],
+    [0x16cf8502, 0xca283a5d], [0xfbc2673d, 0x020c25e3], [0x3fe49d0c, 0x3e80e8f5], [0xb66038d7, 0x94d6278d], [0x4ccafe30, 0xa760108d], [0x9b656280, 0x46515b32], [0x67456729, 0x69e1bfc1], [0xd9e2cb00, 0xeff5f714],
+    [0x5a317624, 0xd9795619], [0x8790563e, 0x179e078d], [0x2a42c201, 0x7be76890], [0xc80655d6, 0x84ddb44b], [0x0ebb81c7, 0xb5e379dd], [0xe9bfe3fe, 0x5f5c12f5], [0x701ba566, 0x288cc12d], [0xa9d0cad2, 0xf0711983],
+    [0x32fd265c, 0xe23f3b7a], [0xd5f1b701, 0x36720285], [0x18945525, 0x0df97794], [0x9424046a, 0xa934eaf0], [0x6a0e046b, 0x9f96e02f], [0xb95a8b78, 0x63ebc18e], [0x40e87b46, 0x4d2c63ab], [0xf640fb1c, 0xc1ecb915],
+    [0x460fe401, 0xea1f7242], [0xb2fb5066, 0x08b9b13e], [0x12a5a0d5, 0x6683844c], [0xd0606583, 0xa33ee988], [0x3b4a8a0d, 0xaff66520], [0xf1f75e3f, 0x6ec7bed3], [0x6d293799, 0x06fc1f65], [0x9d741f56, 0xe7123f2d],
+    [0x003e811f, 0xf5dc78bd], [0xce331485, 0x1d08ceda], [0x24d00067, 0x138696c7], [0xa26f5716, 0xb2531b3b], [0x75bfda7e, 0xbec71de4], [0x8e60e56f, 0x7ccace36], [0x5cfd929b, 0x74ed468a], [0xc06fd519, 0xdf4ec810],
+    [0x62a1bed5, 0xc7e10560], [0x9014960f, 0x386440d2], [0x3624a9f1, 0x40eb5a31], [0xff867bf0, 0x98808b4f], [0x1fdef0c1, 0x93c2daba], [0xdef710bb, 0x485327ac], [0x482bf867, 0x31feeaa5], [0xbd98b9eb, 0xce475929],
+    [0x2ddb4557, 0xd30f93f8], [0xe605ca0f, 0x2ead413f], [0x0a4eebe3, 0x2746068a], [0x80fe7eaf, 0x88f6ae3c], [0x56de8fd1, 0x80b77689], [0xaf2ab414, 0x51575f58], [0x7ef78a62, 0x5a685868], [0xed6e343f, 0xf9ebc96f],
+    [0x76e6fe6b, 0xee0f0411], [0xacce4658, 0x3fc44fd2], [0x17a56f82, 0x59616d60], [0xfade01cc, 0xb13a8e02], [0x2f3b8bb8, 0x95dac8ae], [0xd8e048a8, 0x7f5e4beb], [0x5e486f59, 0x039e1acb], [0x83361ccc, 0xcb39c796],
+    [0x1da119f4, 0xd6c807ab], [0xe0c59329, 0x0ac0e0fa], [0x341ff216, 0x2253b6e2], [0xa454fec5, 0x9a7fcab0], [0x457d3c8b, 0xb9672c38], [0x927d7015, 0x54afece0], [0x7aa68653, 0x641acc17], [0xd2a3b609, 0xfe5149af],
+    [0x412bfb82, 0xd1b3ceab], [0x95a2661d, 0x1fcb3711], [0x33b40bf3, 0x765b0771], [0xc3ecfbff, 0x9ec8b57c], [0x038be183, 0xa84ddadc], [0xe4d65307, 0x4c6f393e], [0x6b60ca37, 0x25861ee8], [0xb8cd7b04, 0xfa0e2d8a],
+    [0x2bca26ad, 0xe93f5b83], [0xc91e346f, 0x3b59d435], [0x0fb47d45, 0x04c26294], [0x9e5d625a, 0xb45c9d2e], [0x60bd04a8, 0x8502cf98], [0xa893e11b, 0x6d97b7b2], [0x5bcfd4bd, 0x42e1d131], [0xfccf9f53, 0xd819bc32],
+    [0x5d8b8ad5, 0xe16d3e7e], [0xa3be8f28, 0x01cbd5c8], [0x1b7bcf59, 0x7dc050a7], [0xdb1b8f79, 0xbfe71756], [0x30d02797, 0xb321158f], [0xec4003f5, 0x6144e3c1], [0x65c214f4, 0x1ceda8fa], [0x96163b0d, 0xf43932a5],
+    [0x13cfc937, 0xfc81c032], [0xd138ba59, 0x154705ff], [0x3a618b16, 0x19725606], [0xaac62fe5, 0xaee09ab7], [0x6c91c3c3, 0xb767473b], [0x857836d2, 0x6793e202], [0x53e94ef4, 0x6f50e825], [0xdfde6ba5, 0xd537ab43],
+    [0x6823cb66, 0xdef86d94], [0x81f12819, 0x34b6db39], [0x2cdc2fdb, 0x4ec5a7e0], [0xe7a6877c, 0x9634771e], [0x15d1ca33, 0x8919d5e2], [0xc116e37b, 0x445e5d04], [0x576ef92b, 0x3c17ac6f], [0xaedfff6b, 0xc39f30d4],
+    [0x3753f8d0, 0xda7ccd39], [0xfefe8a50, 0x217db070], [0x1e2c094b, 0x2b177723], [0x91658c72, 0x86495df8], [0x593ac56e, 0x99a46514], [0xbc0f0f0c, 0x5dd21057], [0x72d01df9, 0x498d6f7a], [0xe2d10bec, 0xeb7cc354],
+    [0x66a167d9, 0xf63e2acd], [0xb79c906a, 0x2cfb7ab1], [0x082d06f5, 0x476b11b9], [0xefef9c21, 0xa6c0deee], [0x3e2f0089, 0x824a0b5e], [0xcdad31ca, 0x6803fe53], [0x4da2982b, 0x11b49549], [0x9a742656, 0xdcf41f45],
+    [0x05db3c9b, 0xc5fbf9ef], [0xf32d7372, 0x1a70343e], [0x20c2cde7, 0x33af5033], [0xbf918be4, 0x8df9f9cf], [0x50fdc674, 0xad83350a], [0x8952b9c7, 0x4ae2be26], [0x6fe9a98f, 0x731343f2], [0xc7f89ba5, 0xe5bedae3],
+    [0x5598ac5a, 0xc0669ab8], [0x8d713c68, 0x0c10e1b8], [0x2789f7a8, 0x625f6a71], [0xd48fa1b7, 0x8b5374ab], [0x1924395b, 0xbc6167ab], [0xf7e091e9, 0x52147f72], [0x7d2d88dc, 0x37f2409f], [0xa1016fd2, 0xe342758a],
+    [0x3866743a, 0xf1280500], [0xdc78ab95, 0x292edf0f], [0x10bfe2e9, 0x165b5b8b], [0x86ae3d64, 0xa0f5de13], [0x71e1a367, 0x913ceb3c], [0xb1a1f8f4, 0x7a934f2c], [0x4b59285e, 0x5e21422f], [0xe84a3e32, 0xcc0f5150],
+    [0x4eae1b80, 0xf8c2c962], [0xbba21865, 0x1279a33d], [0x01cffda4, 0x6a530476], [0xcfa488bc, 0xaba9db43], [0x25e68e38, 0xa568820a], [0xf815d406, 0x7587347b], [0x74d488db, 0x0f88c749], [0x8fcecb49, 0xeceb3b01],
+    [0x0d4ebc39, 0xe686825c], [0xc5f0dc51, 0x071e06f1], [0x29e69349, 0x097294c8], [0xb33b765e, 0xbb5f1674], [0x78d17331, 0xa2e2522f], [0x9c3965b5, 0x70e65593], [0x47e178aa, 0x79efa410], [0xcb814df9, 0xc6b4da5c],
+    [0x7f1bb62c, 0xc9a0f065], [0x99f4d846, 0x2668a715], [0x3cea7d0e, 0x5039b4a3], [0xf4f9a764, 0x81342eee], [0x0b8e94ef, 0x9dbf30f7], [0xd6794cc4, 0x5b9efe51], [0x4359f170, 0x2ff95c81], [0xb5c7fddd, 0xd21b5de4],
+    [0x23b9bbc6, 0xcf2e2ae1], [0xead283fb, 0x30522337], [0x0690dc10, 0x399003f2], [0x8b813bd6, 0x9275be84], [0x49a92114, 0x8e532336], [0xa67fccbb, 0x41ba591e], [0x63c68e54, 0x5718d166], [0xf0261302, 0xf208ec6b],
+    [0x61b5edf4, 0xe8e8e144], [0xb0ef6fdc, 0x3a3746c5], [0x1129961c, 0x43a2b058], [0xfd03ffdf, 0xa1f9c2e1], [0x398dfad0, 0x90d7296c], [0xddbc143c, 0x6cb180b8], [0x4ac57ebe, 0x05aeaa77], [0x9fbacd8c, 0xcd461c6e],
+    [0x02aac122, 0xd0c84bec], [0xe5943867, 0x1ede675d], [0x265c1d87, 0x24ac37dc], [0xa07597f1, 0x8aa6b23a], [0x541a98a0, 0xbd77cbe4], [0x8c255c75, 0x53c674ae], [0x7ce33c7d, 0x772e727b], [0xc211967b, 0xfb078c8c],
+    [0x44acc224, 0xc4cb63f8], [0x932f7a09, 0x0b2a5d5e], [0x356b6fad, 0x65af36c6], [0xd3aece4d, 0x9ba4b3f6], [0x1c2b89f2, 0xac4526e5], [0xf267366e, 0x4b77ecbe], [0x6e84c5a4, 0x324ea914], [0xbe669c41, 0xe4c62835],
+    [0x2eb78ca6, 0xf7e246aa], [0xccf7ded1, 0x2d119d53], [0x09ebec10, 0x1010069e], [0x82384aaf, 0xb0d457a8], [0x77db45f7, 0x83acdd36], [0xadb6eb9c, 0x7e4bf067], [0x5f19c79c, 0x58e77bfb], [0xee4ad82c, 0xdd0ca6fa],
+    [0x58b46da1, 0xfd1e891b], [0xa778cead, 0x14e10b1b], [0x072fb062, 0x7862dbf6], [0xca4647e7, 0xba3f0247], [0x22bbbecd, 0xb6588092], [0xeb08db2b, 0x71aac947], [0x733ab177, 0x184ed174], [0x8af35edd, 0xf341f196],
+    [0x14a450cb, 0xe06ef719], [0xd734ba17, 0x001d3b84], [0x3d744d14, 0x0e42750c], [0xb4ac5f92, 0xaa1596e2], [0x69342ce2, 0xa46c44d4], [0x984c6047, 0x60cf257b], [0x4205e69c, 0x6bfcb058], [0xda63fc10, 0xc20be8ce],
+    [0x7980747a, 0xdbb6d81f], [0x84423a3c, 0x2084d5d6], [0x28856065, 0x563eb811], [0xe3550f7d, 0x87a341f6], [0x0c01dd10, 0x8fa4d24d], [0xc4e1471f, 0x5cd66aae], [0x52dc24d1, 0x2a71681b], [0xabe612c8, 0xd4d8fdd2],
+    [0x316c63cc, 0xc85a7108], [0xf9f45c9e, 0x353cc5fc], [0x1a04af56, 0x3d68a8c6], [0x9777ebf1, 0x979ed3f2], [0x4f875ab1, 0x9cc510af], [0xba6685fb, 0x45b6d026], [0x64fb3587, 0x4f4daf05], [0xf5188555, 0xedc16c62],
+    [0x70d9fb0c, 0xf0d5ab08], [0xa97d1748, 0x285f53c6], [0x0e492164, 0x5f81ba78], [0xe95c22ef, 0xb56ccf62], [0x2a9ef92b, 0x8408beda], [0xc8ac70c4, 0x7b49dc62], [0x5ac7e806, 0x175d4c17], [0x873631be, 0xd98bd7e3],
+    [0x18399d14, 0xc150d4cc], [0xf6f2ac45, 0x0d407112], [0x3206d865, 0x36aafa35], [0xb9fe68dc, 0x9f676856], [0x40538b7d, 0xa99dd7ea], [0x94ea9bd7, 0x4de68066], [0x6ae6171b, 0x63765c13], [0xd5359be2, 0xe2a63830],
+    [0x514675d8, 0xd72e0d2b], [0x88317b79, 0x1bb2e3a7], [0x215304cb, 0x729f7e2b], [0xc67cb607, 0x8cafa41c], [0x042bf8a5, 0xb888671b], [0xe1ec06f7, 0x55c24500], [0x7be54e8b, 0x23396938], [0xa52083ee, 0xff3d5c3c],
+    [0x3f293119, 0xef055ed1], [0xd9675106, 0x3e33c53f], [0x167a0008, 0x02d8fbe8], [0x9bedda40, 0xa7d682e2], [0x67ec3478, 0x9451a104], [0xb688de92, 0x695b64d3], [0x4c413e9a, 0x46f39b36], [0xfb21f411, 0xcaf32024],
+    [0x48aa18c5, 0xe7b47c92], [0xbd0ddec6, 0x0608ed01], [0x1f72a577, 0x6e1ce7f6], [0xde350ea7, 0xaf3c27e2], [0x36f83cc2, 0xa3d4f720], [0xff1a25d0, 0x665a63a4], [0x62100737, 0x08756bf9], [0x90a6f859, 0xea861b60],
+    [0x0aab4972, 0xf954ed2b], [0xc0f61ebb, 0x1366b49f], [0x2d0bf3f4, 0x1daef7ad], [0xafad245f, 0xbe75e174], [0x7e387507, 0xb2daa6b7], [0x805e65e8, 0x743dbc2c], [0x564083a6, 0x7c13e458], [0xceb368dd, 0xd3c2de08],
+    [0x6da8057c, 0xcec182a7], [0x9ddba61b, 0x314be2ba], [0x3bda5ebd, 0x48eb950a], [0xf116d00d, 0x932f3b08], [0x1233334c, 0x9846db16], [0xd0c49222, 0x407b6ab7], [0x46e18e27, 0x3894da0d], [0xb20d0315, 0xc707a478],
+    [0x246c0607, 0xdfc9a4e9], [0xedf81212, 0x27ddaca8], [0x00c16d1b, 0x2e4a2d0b], [0x8ecb3b44, 0x80374f27], [0x5c370239, 0x880185ca], [0xa2a99c11, 0x5add9694], [0x7572abb4, 0x518300d2], [0xe68c992e, 0xf575e6de],
+    [0x7daa9446, 0xe3c759cd], [0xa1e5d474, 0x373986af], [0x19d8b1c5, 0x52d301f7], [0xf707b85a, 0xbc9b03ec], [0x274bde2d, 0x9e3c8a59], [0xd44f866e, 0x76e2a0ae], [0x557b4ddd, 0x0ccbdcfb], [0x8dc057c0, 0xc0ade828],
+    [0x107efc9a, 0xd89f286b], [0xe8d26ce9, 0x04543538], [0x38ca1d9e, 0x29fa9d8a], [0xa8034427, 0x91d0cc99], [0x4bae2fc0, 0xb4a1e70f], [0x9ee7e977, 0x5ebde3ae], [0x714804a9, 0x6d570047], [0xdcae2bbe, 0xf1f2c488],
+    [0x4d4c6084, 0xdc644d32], [0x9adfdecb, 0x11347879], [0x3ec05dfb, 0x7fe1aa0c], [0xcd2dd45f, 0x952ccc5c], [0x08a329da, 0xa649c928], [0xef79c814, 0x4785177e], [0x667247b4, 0x2c777437], [0xb71ff5d0, 0xf6b437d1],
+    [0x204bbf6f, 0xe5260714], [0xc7679948, 0x3353bd77], [0x0509cccf, 0x0a66beb2], [0x92ab0d85, 0xb9f929c9], [0x6f72c064, 0x8d4a79f8], [0xa4959539, 0x64f18a04], [0x5012c745, 0x4a751c74], [0xf3f814a0, 0xd65de008],
+    [0x57888378, 0xec797917], [0xae6bb961, 0x0f2484d9], [0x153cc54b, 0x7506368b], [0xd6d5b0c0, 0xb3e70dc2], [0x3c1af3c8, 0xbf43d611], [0xe745d65c, 0x6ab6acb0], [0x68cd6a27, 0x12ed7270], [0x9932658b, 0xf8695ae5],
+    [0x1ea2ba12, 0xf2de110b], [0xdf311aca, 0x19c43174], [0x37b383fe, 0x15eeedff], [0xa692a472, 0xa2685c70], [0x633052d5, 0xbbef4873], [0x8b732cc5, 0x6fd22b4e], [0x59e6ae6f, 0x6754fe1e], [0xd1b871ce, 0xdad4cc55],
+    [0x650584e4, 0xd2dd11da], [0x8f6d69e0, 0x3c9e4415], [0x2531e352, 0x44ec46a4], [0xecfa5579, 0x9d143b60], [0x1bbac316, 0x818b807c], [0xcf18babc, 0x4e0e8dd5], [0x5d086592, 0x341d15ee], [0xa362b92d, 0xc962f928],
+    [0x3ae05266, 0xd5f750b1], [0xf096f1f3, 0x2b876303], [0x13692962, 0x21f75352], [0x9c8c18c3, 0x8efddbc1], [0x53291ee3, 0x92b75599], [0xb3a9e0dd, 0x5798e790], [0x7869b6bf, 0x416c4013], [0xea18fcc5, 0xe649ca29],
+    [0x6bd5fb8e, 0xfa852ab1], [0xb808757f, 0x253ce1c5], [0x03287891, 0x4cc1fb75], [0xe4186a72, 0xa89b536a], [0x336def5f, 0x8b96edf8], [0xc3047147, 0x62ee33ba], [0x41e5f9ac, 0x1f16865f], [0x957ce268, 0xd1778ef3],
+    [0x0f1bdc2e, 0xcce7305e], [0xfc643fd9, 0x16a03015], [0x2b676c40, 0x3becf3d8], [0xb15e137c, 0x85bf09da], [0x5b611d1e, 0xa049f0a6], [0x861720e2, 0x426dea87], [0x6050f79a, 0x7a1f9b9e], [0xc9fb8602, 0xe9cf4f2f],
+    [0x5eef7eea, 0xcbc8498e], [0x83c9c667, 0x035ec7b6], [0x2fa779ce, 0x68853daf], [0xd84a2df9, 0x82dc7be6], [0x170ddf38, 0xb1a248b4], [0xfa30a70c, 0x59ba4d78], [0x763abc37, 0x3f3d995c], [0xac324a69, 0xee9eb5fb],
+    [0x34a6d988, 0xfebc0baa], [0xd22f4c46, 0x22b0bc72], [0x1d08302f, 0x1aa8ae78], [0x89ae66c4, 0xad1ce3a8], [0x7a4debd1, 0x9afe9723], [0xbf00607e, 0x73c33e49], [0x45c4f8aa, 0x54455e69], [0xe013974c, 0xc52a1f6c],
+    [0x43b48564, 0xf4ac2e17], [0xb56fb2f2, 0x1c1232f7], [0x0b205b3c, 0x619c6d9b], [0xc1943df9, 0xa588fa96], [0x2c4ace7b, 0xab7ffc34], [0xf404cb47, 0x7d626ceb], [0x7f92a16a, 0x015cea3b], [0x8159202e, 0xe1a708e6],
+    [0x061996ab, 0xebf50a90], [0xcb32707e, 0x09ff1e8f], [0x235ec068, 0x07a1f477], [0xbcd0adad, 0xb79d0caf], [0x7268e5d8, 0xae58d8c6], [0x9181fc0f, 0x793827ec], [0x495a2f85, 0x7056330b], [0xc51f1614, 0xcfe05ab2],
+    [0x746c098c, 0xc374d448], [0x96f9b8bb, 0x2f7f44f1], [0x305a7172, 0x5b074ca7], [0xf89aa44c, 0x89e8c710], [0x01186287, 0x96ff8139], [0xdbd97771, 0x50a047ad], [0x4e2f6943, 0x26a951eb], [0xbb20b686, 0xde084545],
+    [0x294a108c, 0xc6729b48], [0xe24461a5, 0x3919ede8], [0x0db7e918, 0x30c091cf], [0x85c5fc41, 0x9959f1c3], [0x471bd655, 0x86a56ba8], [0xaa66370d, 0x49604ff8], [0x6c774353, 0x5d3cd0b6], [0xfe1f8be9, 0xfc363130],
+    [0x698345c9, 0xedb6596f], [0xbad201df, 0x3d098f58], [0x14fadbfe, 0x4fb1aac6], [0xf98c40c0, 0xaae2487d], [0x3d3611a5, 0x9c5db9fb], [0xda1058e7, 0x6008a679], [0x4ffb15f5, 0x0ec642ba], [0x9815b34f, 0xc28097ad],
+    [0x0ca8c08d, 0xd4995227], [0xebcfc567, 0x18314712], [0x22fe5dcc, 0x2a8672ea], [0xab5f3d6c, 0x8fc2e20a], [0x586a01d0, 0xba68e420], [0x84ee4029, 0x566d9df9], [0x79f71a61, 0x78e21768], [0xc492752f, 0xf3e4a223],
+    [0x4f0dffcd, 0xc26f9c9f], [0x971d89fd, 0x0097bfa3], [0x3dafaf06, 0x608e3c1f], [0xd7687eb5, 0x97795e83], [0x14391652, 0xaa465e3e], [0xf547611d, 0x45432fd7], [0x6458d370, 0x35669b27], [0xba1d5baa, 0xed0ceaef],
+    [0x28d6dfd5, 0xfd9daa85], [0xca318ad0, 0x201b6f16], [0x07f8c790, 0x14a49434], [0x8a4a001c, 0xb62c8adb], [0x795b2e6e, 0x87f42b6e], [0xa785e81c, 0x7810a9e0], [0x525b026a, 0x5cbe2de9], [0xe3d97933, 0xdbe3ca29],
+    [0x549d63e6, 0xfb5f6cd2], [0xa000ba6d, 0x1e5a7e11], [0x02ef260b, 0x77d886f8], [0xccb8921f, 0xb04cbcec], [0x262ff7b8, 0xbde55fa3], [0xee3273c0, 0x7edea3ec], [0x7c39375d, 0x1e884878], [0x82ca0238, 0xf78b537f],
+    [0x11572f6e, 0xe83f2a64], [0xd3e476b3, 0x0bf02a99], [0x39e52792, 0x053f6c3c], [0xb0bbf9b2, 0xa107d294], [0x6114254a, 0xa1a00bd0], [0x93c88985, 0x65f1497a], [0x44ee2055, 0x652d89fa], [0xddc466be, 0xcd9035ac],
+    [0x775e2baa, 0xdd5b0cff], [0x826b5e2b, 0x2d9a63a5], [0x26d63eb3, 0x539342d0], [0xe5c02f5d, 0x8a0a26b7], [0x027a1db0, 0x8aeb54fd], [0xcc25536c, 0x58921de5], [0x5ffd9a8c, 0x2d55f780], [0xa0a61ccf, 0xd08920f4],
+    [0x350a55bf, 0xc47519f0], [0xfd584f1a, 0x3aba69d6], [0x1c7e5fb5, 0x32e8cd5d], [0x93704630, 0x9b6b9bf0], [0x4aae54d9, 0x90229c32], [0xbe149e73, 0x4bf74ee5], [0x61c800ea, 0x4348f931], [0xf2047e01, 0xe41f07f9],
+    [0x7e82fb7b, 0xf527dbb7], [0xa213aea4, 0x2e0e89d4], [0x0ad40df9, 0x51209bb8], [0xe6689e28, 0xb297b6b7], [0x24b40d88, 0x8048904e], [0xc03c8d4d, 0x7c5e772f], [0x56b35dd8, 0x131092d1], [0x8e372b21, 0xdf8bf4a1],
+    [0x1f0d3a14, 0xce255fab], [0xffc08cb1, 0x0828dbb6], [0x36aab6fd, 0x380dc215], [0xb2a57de2, 0x982e986b], [0x4864d1d5, 0xaf7b7912], [0x9d38a8fc, 0x48995beb], [0x6dde3b65, 0x6e955b0a], [0xd08b51e0, 0xea60442c],
+    [0x5c97c145, 0xd3abf163], [0x809d5817, 0x1dc0f778], [0x2d841550, 0x74713ef3], [0xc08198fc, 0x80d33b07], [0x009011c9, 0xb209d67d], [0xe6e8dc93, 0x5a057a1c], [0x75fae7db, 0x278b662c], [0xaf66da26, 0xf9020d50],
+    [0x3b90e355, 0xe76d1021], [0xde585054, 0x31a9ca68], [0x1fbb6853, 0x0644da67], [0x9069ee67, 0xa39a18b4], [0x62413dfc, 0x98e8f606], [0xb266f871, 0x66d647df], [0x467c38b1, 0x400184f1], [0xf1b6d9a9, 0xcebe848d],
+    [0x4c1e241b, 0xef9184e1], [0xb9a996af, 0x0d93047c], [0x18473382, 0x639ca49c], [0xd58d537d, 0xa9d73882], [0x32597a1b, 0xa9632e55], [0xfb751ef3, 0x69942bb8], [0x67a33f52, 0x0243837a], [0x9484ffa4, 0xe24b43b6],
+    [0x04aef5e5, 0xff61af00], [0xc605ab18, 0x1b6d8051], [0x2acf283d, 0x17e0394e], [0xa9091ad8, 0xb5a3c4a8], [0x7ba7496a, 0xb816bcd9], [0x8769a079, 0x7ba5fb8e], [0x5a528b73, 0x7b3990ff], [0xc6aeb96b, 0xd773c334],
+    [0x67057b77, 0xcaa1cf56], [0x946244de, 0x36ecd1d7], [0x32961e3d, 0x4d984f37], [0xfb9ee03d, 0x941f781c], [0x163134cb, 0x9480a8ef], [0xd99f9ecb, 0x46a26986], [0x4039d45d, 0x362bbd9c], [0xb6df9fa9, 0xca520c2d],
+    [0x2126c0c2, 0xd7a4b3e5], [0xe16d7e1f, 0x23771957], [0x0ec6a8ea, 0x2819c06e], [0x88454b38, 0x8c2eafc5], [0x51d8ce95, 0x8cdb86af], [0xa549381e, 0x55697652], [0x70865c69, 0x5f194cf2], [0xe9dbcfef, 0xf0b2a36a],
+    [0x780036e3, 0xeb29fee0], [0xa6d7faab, 0x39f58ea0], [0x1ec28f5f, 0x5da0fcfc], [0xf0efbc9e, 0xb72895d2], [0x29925dc0, 0x993facfa], [0xdf8206d2, 0x70233394], [0x5956641b, 0x09a56b77], [0x851c0a77, 0xc6261e7d],
+    [0x15563586, 0xd2408ec5], [0xe7fc00c9, 0x0125c6f4], [0x3c6e9b60, 0x2623b9a3], [0xae235bbe, 0x9dc4c763], [0x4e6fc30a, 0xbf818545], [0x99404da5, 0x507ed724], [0x7430700c, 0x6101a5c3], [0xd6299929, 0xf4c27576],
+    [0x47aa1b87, 0xda9fb97e], [0x9ce8295c, 0x191ac128], [0x3a8e7088, 0x70939c3a], [0xc588e7be, 0x92d3c187], [0x06d7497f, 0xa228eb34], [0xea61b4f8, 0x49d2d1ee], [0x636dcaa8, 0x2103deab], [0xb3c0118b, 0xfcd11045],
+    [0x2cb0ae4c, 0xe1c44108], [0xc1fad8a6, 0x3c78700a], [0x0bd0dcdf, 0x0f75b168], [0x99afbb0b, 0xbf0c03b8], [0x65909d6e, 0x899c27ab], [0xa3016aa4, 0x6a33415b], [0x5dcaba2f, 0x4e5d3a72], [0xf443ce5f, 0xde884f42],
+    [0x5053ac04, 0xe5e7ff19], [0xa43cf613, 0x0a2f03d5], [0x10036f48, 0x7aea521e], [0xd2552065, 0xb901399e], [0x38a601ec, 0xb40d7c28], [0xe070dfb3, 0x644bf773], [0x60e21b32, 0x16cac4d8], [0x9e8a8dba, 0xf16e5bcb],
+    [0x17ec4f5b, 0xf6d479f3], [0xd4fe99e0, 0x1f544bde], [0x332c7f93, 0x1f9bc682], [0xac87eb94, 0xa62642ba], [0x6b344ddf, 0xbcfd8462], [0x83546eb2, 0x68cdec55], [0x55c9ef89, 0x62991a2a], [0xd40d00f9, 0xd1e271ee],
+    [0x603a4092, 0xd848a6dd], [0x86ec39a3, 0x3bb53796], [0x2033af5a, 0x4aa62667], [0xe0aaa642, 0x9aa11cf7], [0x1d7634d5, 0x8d971117], [0xc999e33a, 0x42bbf33e], [0x5b38e8fc, 0x3b23f0d3], [0xa8c73ca7, 0xccb20b19],
+    [0x33f469ac, 0xd139a09b], [0xfa8978c1, 0x2c01cc07], [0x195dfa67, 0x255ff26b], [0x9aabffa5, 0x820be6c7], [0x553ff1df, 0x9e832bd9], [0xb742f08e, 0x590db8ce], [0x7dc06386, 0x4c3a5936], [0xef069121, 0xee589d31],
+    [0x6c231630, 0xf274cfaf], [0xb3513152, 0x2180c30a], [0x0676839d, 0x41eeb6b9], [0xe2049d6d, 0xa28c7a73], [0x37f38648, 0x86386669], [0xcbd446f7, 0x6728a040], [0x49dd6061, 0x1997d460], [0x91c45d86, 0xda128a1b],
+    [0x0b712670, 0xc3cdaf7d], [0xf8c4d340, 0x1c9d9302], [0x25b36b59, 0x3ccc0477], [0xbbe1e5fe, 0x81c1c32f], [0x5d643af9, 0xabeec938], [0x810d2a16, 0x4eb7b0ce], [0x654b3ee7, 0x75d3a0d8], [0xcf56afde, 0xe1253682],
+    [0x538ac4d6, 0xcf8037f9], [0x8bd2151e, 0x07c7cf6e], [0x29116da3, 0x67d21e8d], [0xdf5856bf, 0x8e1c5eeb], [0x1e6efa93, 0xb7ea8ab3], [0xf04759bb, 0x5d751ad2], [0x78ad39d1, 0x395c6170], [0xa61c6af6, 0xeb9917c7],
+    [0x3034b19d, 0xf47447f9], [0xdb6b60f9, 0x26f7ad2d], [0x159274d7, 0x1c627389], [0x8192469c, 0xab1c7630], [0x7499a6c5, 0x9d5468c0], [0xb58941e8, 0x757f8d46], [0x43f62594, 0x5be484c7], [0xe70f10bc, 0xc9d7cd99],
+    [0x45b84142, 0xfe1e9517], [0xb12b8139, 0x16329756], [0x0f532719, 0x6dccd75a], [0xc9777690, 0xa02ba9c1], [0x2b011d1a, 0xa0bf0dd5], [0xfcb4ade6, 0x7a6a22a4], [0x7196c60f, 0x040f6c82], [0x867b6c0a, 0xe945fe6f],
+    [0x03591379, 0xe3032184], [0xc3a6855a, 0x0caf7092], [0x2f755e28, 0x0300fbd1], [0xb7fd2257, 0xb1dffaa4], [0x76532d38, 0xa6b80847], [0x951386ce, 0x760634ff], [0x4d0a405e, 0x7f1b59b8], [0xc347474f, 0xc03ec174],
+    [0x7ad38ff1, 0xc56e4eaa], [0x9e216f59, 0x299f7364], [0x34e6582c, 0x54f29f39], [0xf34d790c, 0x8d39c643], [0x055b0866, 0x9a22afe5], [0xd2cfdf3c, 0x54357312], [0x4503f8b3, 0x22dbfefe], [0xbf5bc278, 0xd683af36],
+    [0x27c4869c, 0xc0c6a808], [0xe45fe854, 0x378d0807], [0x08f98fca, 0x3fbfb336], [0x8388f796, 0x95ac275a], [0x41b66c00, 0x8b21a4ce], [0xac79424a, 0x4723ee86], [0x6b8f8670, 0x5249d73b], [0xfa5079b5, 0xf64353c0],
+    [0x64acb8a9, 0xe0d10356], [0xb4c21e50, 0x35bde70a], [0x1ad30f85, 0x45e7007f], [0xf5ffd448, 0xa40a8a3b], [0x319744f6, 0x97c959c8], [0xd7dfca0a, 0x6b8db25d], [0x42f91016, 0x0070d3b1], [0x97d2bc16, 0xc81fbed6],
+    [0x0774085a, 0xdb1dc1c9], [0xe31ec907, 0x1444760b], [0x287fb249, 0x20ea4f46], [0xa71cec2f, 0x877baf60], [0x52a96c18, 0xb6ab9fe1], [0x8aa2efd8, 0x5c7bd723], [0x73f49489, 0x71ed6f02], [0xcacf1dfa, 0xfd6c8c02],
+    [0x426f2bf8, 0xc8ac608c], [0x98913d7e, 0x0e1c3e6a], [0x313b1284, 0x6b6e6199], [0xdaf83d2c, 0x9c94b73a], [0x1a778839, 0xa4f61b30], [0xf92eba32, 0x4f34d18d], [0x6952a350, 0x3df531d7], [0xb44dafae, 0xe003e131],
+    [0x2272ddcb, 0xf319110c], [0xc44d794a, 0x2a0ada2f], [0x0c467534, 0x18aa3c12], [0x84200ada, 0xbab0f5c5], [0x73481126, 0x8f22fb96], [0xab88250e, 0x71095527], [0x58c020ed, 0x56e5ebdd], [0xeb4a99c9, 0xd47851c6],
+    [0x5f6eacf5, 0xf735bf8e], [0xad246c80, 0x10477d32], [0x095c213c, 0x7e094fcd], [0xc2bd7501, 0xbd1f9295], [0x2e657f43, 0xb0ab91fa], [0xe57aa301, 0x7744c51d], [0x77babf99, 0x10ab97f9], [0x8c59722e, 0xfbf0b98a],
+    [0x1cebba20, 0xe4b44922], [0xdd246b6c, 0x05cc33f7], [0x35a91966, 0x0b40a41d], [0xbebd9e8f, 0xac0aec68], [0x6ed85018, 0xacda73f7], [0x9fd5858c, 0x6c283ef0], [0x4a584503, 0x6ccc9117], [0xd3280147, 0xc4890438],
+    [0x7c840da8, 0xd06c83ca], [0x8c8c7924, 0x24d6d08f], [0x2ed02102, 0x5877e7f8], [0xee8c009a, 0x83ee9bc7], [0x09bc8101, 0x83550a02], [0xc25f8ca0, 0x533c4eb6], [0x54432afe, 0x24001918], [0xadd5d327, 0xdd87b7ec],
+    [0x392c8517, 0xcd023684], [0xf2bc8966, 0x3217fd2b], [0x11c27880, 0x3a58de03], [0x9f4fb20a, 0x909791e0], [0x441635c8, 0x9bca178f], [0xb073dc69, 0x43ccc845], [0x6e2e4d88, 0x4b1f5ef5], [0xfd9dab47, 0xe8b865ad],
+    [0x7501b334, 0xf9a9410b], [0xafed0f72, 0x27314af2], [0x006a19c4, 0x5a84dc91], [0xedba186b, 0xbeb91ec2], [0x2d6ef3d0, 0x88b76405], [0xceec30fc, 0x749bcfc1], [0x5c4aca8f, 0x1d664c78], [0x80206db3, 0xd3600367],
+    [0x12d0f195, 0xc74a4ec4], [0xf171f422, 0x06b4d072], [0x3b2fd40a, 0x3138dce4], [0xbd5ed699, 0x93aed440], [0x46a6b785, 0xa34250d8], [0x90c26c29, 0x40aca90b], [0x62d3b2b6, 0x6619b0fc], [0xdeb4c856, 0xe7d06017],
+    [0x56151b92, 0xdf2f351f], [0x8e8356ca, 0x13d79385], [0x24233f61, 0x7caf7a27], [0xce760579, 0x8879356e], [0x0a396eeb, 0xbe3f6d91], [0xed28c59a, 0x51c7f0a8], [0x7e5cbc99, 0x2ec99539], [0xa2fe8386, 0xf5b698a0],
+    [0x3656307a, 0xeafed5ff], [0xd03bf15b, 0x38c13cbd], [0x12429084, 0x08e45dc3], [0x9dab986b, 0xafa266e3], [0x6d427e5f, 0x93478213], [0xbdebf560, 0x6e45d0df], [0x48f847f4, 0x482cb43b], [0xff4206e8, 0xc799b113],
+    [0x40a7e1a6, 0xe2cf0729], [0xb617ddba, 0x02a276a5], [0x16a8ffa2, 0x693a3565], [0xd90c3fd6, 0xa7064fe2], [0x3f87f4a0, 0xa7bb8f42], [0xf60c5150, 0x631c8028], [0x6a71f5cf, 0x0d17fe22], [0x9b2e31f4, 0xef5d4690],
+    [0x0e3e4fad, 0xf024a402], [0xc85d7be6, 0x1702a037], [0x21bc19fe, 0x1bf00d5d], [0xa58a8f61, 0xb8f1cbc8], [0x70743891, 0xb5273ef4], [0x88cc2e3b, 0x72d3ee36], [0x51196149, 0x72405a0c], [0xc8f42f11, 0xd93e2573],
+    [0x6aa7d582, 0xc1a5f34f], [0x9ba41e18, 0x3ef73635], [0x3f7314e9, 0x46229008], [0xf69f5dc7, 0x9fdf6c3f], [0x18fd4ab6, 0x9f1442f2], [0xd561b595, 0x4d73c749], [0x4c9edb0e, 0x3e45e5bd], [0xb91301b4, 0xc13d3172],
+    [0x2a36ce7d, 0xd9f1c459], [0xe91861fc, 0x28f7ad1e], [0x04588519, 0x23ef872c], [0x87e09dd1, 0x84548735], [0x5a9616c4, 0x84bb2fc2], [0xa9b23195, 0x5fdf64c8], [0x7b05b403, 0x55bc0edb], [0xe1a9c379, 0xffbf954e],
+    [0x7297734a, 0xe63a08a9], [0xaaa700fe, 0x30b8bee6], [0x139672d4, 0x57f72b80], [0xfea8ffda, 0xbb8ea95c], [0x2315abb8, 0x9211dcc2], [0xd1fa6e92, 0x7980b5ef], [0x53658cbc, 0x0764b6ff], [0x8b04645b, 0xcf5cdb31],
+    [0x1b0ae9c5, 0xde6adafd], [0xeca84485, 0x0fc50f8f], [0x309e8606, 0x2f2b73a4], [0xa3c1a4c2, 0x96afd70a], [0x4327e333, 0xb3ae3958], [0x966ea5cf, 0x5b5638b1], [0x7f6ab482, 0x6afdfa9b], [0xdbbecb6e, 0xf887c0e6],
+    [0x490e74c7, 0xd5650efe], [0x911940a1, 0x15b11bf6], [0x372fb08b, 0x795cc98c], [0xcb6543fa, 0x99e3a3c7], [0x0ddc13af, 0xae8ec55d], [0xe2ad68e2, 0x4119a1dd], [0x6cc02484, 0x2bfa846d], [0xbc6c5a51, 0xf2a05fa5],
+    [0x255828a2, 0xec86f39f], [0xcffefb74, 0x345da11e], [0x01552cad, 0x01bc1176], [0x96a7c8e2, 0xb3483f75], [0x68813538, 0x8142f134], [0xaea35c0d, 0x61c89e5c], [0x57ef3438, 0x44330d19], [0xf871a3e8, 0xd290b384],
+    [0x5bb1ea9a, 0xe9b285ef], [0xa843b678, 0x0495021c], [0x1df2cb2d, 0x73b5974f], [0xdc14f5e7, 0xb4cf9f1b], [0x34521bce, 0xb999b925], [0xe83bb1f6, 0x6d0f7017], [0x6f219c4d, 0x1a0ad825], [0x9230a233, 0xfef0a113],
+    [0x19b74aec, 0xfa7e1855], [0xd827d658, 0x11cedcc0], [0x3e71e8db, 0x117dc579], [0xa191db68, 0xa81098b1], [0x66143d8a, 0xb15bf45a], [0x8d911945, 0x620d8d4d], [0x5e8d40ad, 0x684a8b41], [0xd8bb6993, 0xdc390461],
+    [0x6faa05b9, 0xd615847a], [0x89e2ec01, 0x33e5bd76], [0x2bb0ea00, 0x420ccd87], [0xe896ad1d, 0x914416a0], [0x10f0c8b6, 0x85dbd151], [0xc7a9b2e3, 0x4a31ece8], [0x50855b00, 0x331461cf], [0xa4d31033, 0xc5b727db],
+    [0x3e847e32, 0xdcb08a63], [0xf776d839, 0x25c932f2], [0x175774f9, 0x2c9a9a30], [0x95cdd1af, 0x8bd807db], [0x5e20d21b, 0x955ccdaf], [0xb880ae56, 0x5290c0a0], [0x7699e25e, 0x47fbe0f4], [0xe486f2f2, 0xe39e641b],
+    [0x638e3c14, 0xfc69fb93], [0xbcaebe1b, 0x2b67f9bd], [0x0d248be9, 0x491afab7], [0xea94c18d, 0xae07a4f5], [0x3a37a093, 0x8e9804de], [0xc56afdae, 0x6f189885], [0x475e2ba3, 0x151bfe44], [0x9c5218c6, 0xd5a7c9fb],
+    [0x01809942, 0xc907af4b], [0xf486bfba, 0x12b39616], [0x2c037a30, 0x34d64283], [0xb50d66b5, 0x8940f2a1], [0x5711a5b7, 0xa5d3917b], [0x8f9547a6, 0x449f4b62], [0x684302df, 0x7d339848], [0xc144f61f, 0xec2c2958],
+    [0x598f6a74, 0xc6c7e142], [0x85bb2179, 0x0938c34a], [0x23f2aff7, 0x6fb5d83c], [0xd14c25a4, 0x86f84cd4], [0x13316479, 0xbb2665f6], [0xfe41091d, 0x577c8c35], [0x721c2b72, 0x303389ce], [0xaa00b7a4, 0xe6e1ad70],
+    [0x3c8f8991, 0xf817352c], [0xd6834a75, 0x2fbab15c], [0x1bc427f0, 0x120efefe], [0x8f0b93ba, 0xa5154a5a], [0x7fdfc7fe, 0x964c9b3e], [0xbb7db57c, 0x7da5a7d8], [0x4eebf237, 0x50dc8a31], [0xec20ba7f, 0xc304c65e],
+    [0x4b2b4e24, 0xf1b856f3], [0xbfd567e4, 0x1ad7e7ed], [0x05bd6018, 0x64bb096d], [0xc71d3225, 0xade597bf], [0x20b476f9, 0xad541e6d], [0xf3bd4e9c, 0x735423ee], [0x7a1e882d, 0x0ab02f4e], [0x891cfd1e, 0xe5660176],
+    [0x0846f932, 0xeef91a32], [0xcd7a89d0, 0x03faff8c], [0x271d86be, 0x0c530f2a], [0xb856d136, 0xbc1a49c7], [0x7d61a128, 0xa8f2a051], [0x9a1c32b0, 0x7f9330cf], [0x41632b5c, 0x76b617e6], [0xcdf8f5d5, 0xcbbd66a6],
+    [0x7124facc, 0xcc503cab], [0x92feb8f1, 0x221e96af], [0x380740e3, 0x5ece6877], [0xfc3b7c7b, 0x8569b36c], [0x0ff19605, 0x91baa9db], [0xdcff48a2, 0x5e442ca2], [0x4bd78c34, 0x295b32a5], [0xb1cda59b, 0xd8d92faf],
+    [0x2fe64a2c, 0xcb6a012c], [0xefba5ba3, 0x3f6ae943], [0x03e2375f, 0x3765fae1], [0x8d107273, 0x9e5dfbb6], [0x4dd99492, 0x82ad0e29], [0xa14de261, 0x4caaf6e6], [0x66eecc09, 0x59fb8bc5], [0xf78c23b5, 0xfad0a0d0],
+];
@@ -0,0 +1,285 @@
+use crate::film::RgbFilmPixels;
+use crate::output::exr::{ExrAttribute, ExrImage, ExrPixelType};
+use anyhow::{anyhow, bail, Result};
+use glam::UVec2;
+use log::info;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProgressiveSettings {
+    pub resolution: UVec2,
+    pub samples_per_pass: u32,
+    pub target_sample_count: u32,
+    pub max_depth: u32,
+    // Identifies the scene so a checkpoint is not resumed against a different one
+    pub scene: String,
+}
+
+impl ProgressiveSettings {
+    pub fn new(scene: impl Into<String>, resolution: UVec2) -> Self {
+        Self {
+            resolution,
+            samples_per_pass: 16,
+            target_sample_count: 1024,
+            max_depth: 16,
+            scene: scene.into(),
+        }
+    }
+
+    pub fn samples_per_pass(mut self, samples_per_pass: u32) -> Self {
+        self.samples_per_pass = samples_per_pass.max(1);
+        self
+    }
+
+    pub fn target_sample_count(mut self, target_sample_count: u32) -> Self {
+        self.target_sample_count = target_sample_count;
+        self
+    }
+
+    pub fn max_depth(mut self, max_depth: u32) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    // Only the target may change between a checkpoint and the run resuming it, the pass size fixes the sample indices of every pass
+    pub fn check_resumable(&self, checkpoint: &ProgressiveSettings) -> Result<()> {
+        if self.samples_per_pass != checkpoint.samples_per_pass {
+            bail!("Checkpoint samples per pass {} does not match {}", checkpoint.samples_per_pass, self.samples_per_pass);
+        }
+
+        if self.resolution != checkpoint.resolution {
+            bail!("Checkpoint resolution {} does not match {}", checkpoint.resolution, self.resolution);
+        }
+
+        if self.max_depth != checkpoint.max_depth {
+            bail!("Checkpoint max depth {} does not match {}", checkpoint.max_depth, self.max_depth);
+        }
+
+        if self.scene != checkpoint.scene {
+            bail!("Checkpoint of scene {} cannot resume {}", checkpoint.scene, self.scene);
+        }
+
+        Ok(())
+    }
+}
+
+// The film is the state of the `RgbFilm` that `integrator::adaptive::main_cs` accumulates into
+#[derive(Clone, Debug)]
+pub struct Checkpoint {
+    pub settings: ProgressiveSettings,
+    pub sample_index: u32,
+    // The next pass starts at sample index `pass_index * samples_per_pass` of every pixel
+    pub pass_index: u32,
+    pub elapsed: Duration,
+    pub film: RgbFilmPixels,
+}
+
+impl Checkpoint {
+    // Stored as an EXR so the film can be inspected, the progress and settings are attributes
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let mut image = ExrImage::new(self.settings.resolution.x, self.settings.resolution.y);
+
+        image.add_layer("", "RGBA", ExrPixelType::Float, &self.film.variance_mean)?;
+        image.add_layer("m2", "RGB", ExrPixelType::Float, &self.film.variance_m2)?;
+        image.add_layer("weightSum", "RGBA", ExrPixelType::Float, &self.film.rgb_weight_sum)?;
+
+        image.set_attribute("progressive.scene", ExrAttribute::String(self.settings.scene.clone()));
+        image.set_attribute("progressive.samplesPerPass", ExrAttribute::Int(self.settings.samples_per_pass as i32));
+        image.set_attribute("progressive.targetSampleCount", ExrAttribute::Int(self.settings.target_sample_count as i32));
+        image.set_attribute("progressive.maxDepth", ExrAttribute::Int(self.settings.max_depth as i32));
+        image.set_attribute("progressive.sampleIndex", ExrAttribute::Int(self.sample_index as i32));
+        image.set_attribute("progressive.passIndex", ExrAttribute::Int(self.pass_index as i32));
+        image.set_attribute("renderTimeSeconds", ExrAttribute::Float(self.elapsed.as_secs_f32()));
+
+        image.write(path)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let image = ExrImage::read(path)?;
+
+        let int = |name: &str| match image.attribute(name) {
+            Some(&ExrAttribute::Int(value)) if value >= 0 => Ok(value as u32),
+            _ => Err(anyhow!("Checkpoint {} has no valid {} attribute", path.display(), name)),
+        };
+
+        let scene = match image.attribute("progressive.scene") {
+            Some(ExrAttribute::String(scene)) => scene.clone(),
+            _ => bail!("Checkpoint {} has no scene attribute", path.display()),
+        };
+
+        let elapsed = match image.attribute("renderTimeSeconds") {
+            Some(&ExrAttribute::Float(seconds)) => Duration::from_secs_f32(seconds.max(0.0)),
+            _ => Duration::ZERO,
+        };
+
+        let settings = ProgressiveSettings {
+            resolution: UVec2::new(image.data_window.width() as u32, image.data_window.height() as u32),
+            samples_per_pass: int("progressive.samplesPerPass")?,
+            target_sample_count: int("progressive.targetSampleCount")?,
+            max_depth: int("progressive.maxDepth")?,
+            scene,
+        };
+
+        let film = RgbFilmPixels {
+            rgb_weight_sum: image.layer_pixels("weightSum", "RGBA"),
+            variance_mean: image.layer_pixels("", "RGBA"),
+            variance_m2: image.layer_pixels("m2", "RGB").into_iter().map(|[r, g, b, _]| [r, g, b, 0.0]).collect(),
+        };
+
+        Ok(Self {
+            settings,
+            sample_index: int("progressive.sampleIndex")?,
+            pass_index: int("progressive.passIndex")?,
+            elapsed,
+            film,
+        })
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct ProgressivePass {
+    pub pass_index: u32,
+    pub first_sample: u32,
+    pub sample_count: u32,
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct Progress {
+    pub pass_index: u32,
+    pub sample_index: u32,
+    pub target_sample_count: u32,
+    pub elapsed: Duration,
+}
+
+impl Progress {
+    pub fn fraction(&self) -> f32 {
+        if self.target_sample_count == 0 {
+            1.0
+        } else {
+            (self.sample_index as f32 / self.target_sample_count as f32).min(1.0)
+        }
+    }
+
+    pub fn remaining(&self) -> Option<Duration> {
+        let fraction = self.fraction();
+
+        if fraction <= 0.0 {
+            None
+        } else {
+            Some(self.elapsed.mul_f32((1.0 - fraction) / fraction))
+        }
+    }
+}
+
+// Hands out passes until the target sample count is reached or the render is cancelled; the caller dispatches them and accumulates into persistent film images
+pub struct ProgressiveRender {
+    pub settings: ProgressiveSettings,
+    sample_index: u32,
+    pass_index: u32,
+    previous_elapsed: Duration,
+    start: Instant,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl ProgressiveRender {
+    pub fn new(settings: ProgressiveSettings) -> Self {
+        Self {
+            settings,
+            sample_index: 0,
+            pass_index: 0,
+            previous_elapsed: Duration::ZERO,
+            start: Instant::now(),
+            cancelled: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    pub fn resume(settings: ProgressiveSettings, checkpoint: &Checkpoint) -> Result<Self> {
+        settings.check_resumable(&checkpoint.settings)?;
+
+        info!("Resuming {} at {} samples per pixel", settings.scene, checkpoint.sample_index);
+
+        Ok(Self {
+            sample_index: checkpoint.sample_index,
+            pass_index: checkpoint.pass_index,
+            previous_elapsed: checkpoint.elapsed,
+            ..Self::new(settings)
+        })
+    }
+
+    // Set from another thread to stop handing out passes, the film keeps everything accumulated so far
+    pub fn cancel_flag(&self) -> Arc<AtomicBool> {
+        self.cancelled.clone()
+    }
+
+    // Cancels the render once this run has taken `time_limit`, the passes already handed out still finish
+    pub fn cancel_after(&self, time_limit: Duration) {
+        let cancelled = self.cancel_flag();
+
+        thread::spawn(move || {
+            thread::sleep(time_limit);
+            cancelled.store(true, Ordering::Relaxed);
+        });
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.sample_index >= self.settings.target_sample_count
+    }
+
+    pub fn next_pass(&self) -> Option<ProgressivePass> {
+        if self.is_complete() || self.is_cancelled() {
+            return None;
+        }
+
+        Some(ProgressivePass {
+            pass_index: self.pass_index,
+            first_sample: self.sample_index,
+            sample_count: self.settings.samples_per_pass.min(self.settings.target_sample_count - self.sample_index),
+        })
+    }
+
+    pub fn finish_pass(&mut self, pass: ProgressivePass) -> Progress {
+        self.sample_index = pass.first_sample + pass.sample_count;
+        self.pass_index = pass.pass_index + 1;
+
+        let progress = self.progress();
+
+        info!(
+            "Pass {} done, {} / {} samples per pixel ({:.1}%), {:.1?} remaining",
+            pass.pass_index,
+            progress.sample_index,
+            progress.target_sample_count,
+            100.0 * progress.fraction(),
+            progress.remaining().unwrap_or_default()
+        );
+
+        progress
+    }
+
+    pub fn progress(&self) -> Progress {
+        Progress {
+            pass_index: self.pass_index,
+            sample_index: self.sample_index,
+            target_sample_count: self.settings.target_sample_count,
+            elapsed: self.previous_elapsed + self.start.elapsed(),
+        }
+    }
+
+    pub fn checkpoint(&self, film: RgbFilmPixels) -> Checkpoint {
+        Checkpoint {
+            settings: self.settings.clone(),
+            sample_index: self.sample_index,
+            pass_index: self.pass_index,
+            elapsed: self.progress().elapsed,
+            film,
+        }
+    }
+}
@@ -0,0 +1,21 @@
+pub mod subsurface;
+
+pub struct ReferenceRng {
+    state: u64,
+}
+
+impl ReferenceRng {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407) | 1,
+        }
+    }
+
+    pub fn next_f32(&mut self) -> f32 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+
+        (self.state >> 40) as f32 / (1u64 << 24) as f32
+    }
+}
@@ -0,0 +1,174 @@
+use crate::reference::ReferenceRng;
+use glam::{UVec2, Vec2, Vec3};
+
+pub const MAX_WALK_STEPS: u32 = 4096;
+
+#[derive(Copy, Clone, Debug)]
+pub struct SlabParameters {
+    pub albedo: Vec3,
+    pub mean_free_path: Vec3,
+    pub eta: f32,
+    pub thickness: f32,
+}
+
+#[derive(Copy, Clone, Debug, Default)]
+pub struct SlabEstimate {
+    pub reflectance: Vec3,
+    pub transmittance: Vec3,
+}
+
+impl SlabEstimate {
+    // Largest difference to the GPU estimate in units of their combined standard error, every reference walk is a Bernoulli trial
+    pub fn standard_score(&self, sample_count: u64, other: &SlabWalkEstimate) -> f32 {
+        let score = |p: f32, other: f32, other_error: f32| {
+            let variance = p * (1.0 - p) / sample_count as f32 + other_error * other_error;
+
+            (p - other).abs() / variance.sqrt().max(1.0e-6)
+        };
+
+        (0..3)
+            .flat_map(|channel| {
+                [
+                    score(self.reflectance[channel], other.estimate.reflectance[channel], other.standard_error.reflectance[channel]),
+                    score(self.transmittance[channel], other.estimate.transmittance[channel], other.standard_error.transmittance[channel]),
+                ]
+            })
+            .fold(0.0, f32::max)
+    }
+}
+
+// The integrator's walks carry weights rather than hit or miss tallies, so their error comes from the spread of the per invocation averages
+#[derive(Copy, Clone, Debug, Default)]
+pub struct SlabWalkEstimate {
+    pub estimate: SlabEstimate,
+    pub standard_error: SlabEstimate,
+}
+
+// Mirrors `test::subsurface_slab::SlabWalkParameters` in the shaders, every invocation of the `grid_size` grid traces `walk_count` paths into
+// a slab mesh spanning [0, thickness] in y. The material is a gray subsurface one, so a single spectral estimate stands for every channel
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct SlabWalkParameters {
+    pub thickness: f32,
+    pub walk_count: u32,
+    pub seed: u32,
+    pub grid_size: UVec2,
+}
+
+impl SlabWalkParameters {
+    pub fn new(thickness: f32, walk_count: u32, grid_size: UVec2, seed: u32) -> Self {
+        Self {
+            thickness,
+            walk_count,
+            seed,
+            grid_size,
+        }
+    }
+
+    // Averages the per invocation (reflectance, transmittance, 0, 0) tallies written by the shader
+    pub fn estimate(&self, tallies: &[[f32; 4]]) -> SlabWalkEstimate {
+        let invocation_count = (self.grid_size.x * self.grid_size.y) as usize;
+        let tallies = &tallies[..invocation_count];
+
+        let mean = tallies.iter().fold(Vec2::ZERO, |sum, tally| sum + Vec2::new(tally[0], tally[1])) / invocation_count as f32;
+        let variance = tallies.iter().fold(Vec2::ZERO, |sum, tally| {
+            let delta = Vec2::new(tally[0], tally[1]) - mean;
+            sum + delta * delta
+        }) / (invocation_count - 1) as f32;
+        let standard_error = (variance / invocation_count as f32).powf(0.5);
+
+        SlabWalkEstimate {
+            estimate: SlabEstimate {
+                reflectance: Vec3::splat(mean.x),
+                transmittance: Vec3::splat(mean.y),
+            },
+            standard_error: SlabEstimate {
+                reflectance: Vec3::splat(standard_error.x),
+                transmittance: Vec3::splat(standard_error.y),
+            },
+        }
+    }
+}
+
+pub fn single_scattering_albedo(multiple_scattering_albedo: f32) -> f32 {
+    let albedo = multiple_scattering_albedo.clamp(0.0, 1.0);
+    1.0 - (4.09712 + 4.20863 * albedo - (9.59217 + 41.6808 * albedo + 17.7126 * albedo * albedo).sqrt()).powi(2)
+}
+
+pub fn fresnel_dielectric(cos_theta_i: f32, eta: f32) -> f32 {
+    let (cos_theta_i, eta) = if cos_theta_i < 0.0 { (-cos_theta_i, 1.0 / eta) } else { (cos_theta_i, eta) };
+
+    let sin2_theta_t = (1.0 - cos_theta_i * cos_theta_i) / (eta * eta);
+
+    if sin2_theta_t >= 1.0 {
+        return 1.0;
+    }
+
+    let cos_theta_t = (1.0 - sin2_theta_t).max(0.0).sqrt();
+    let r_parl = (eta * cos_theta_i - cos_theta_t) / (eta * cos_theta_i + cos_theta_t);
+    let r_perp = (cos_theta_i - eta * cos_theta_t) / (cos_theta_i + eta * cos_theta_t);
+
+    (r_parl * r_parl + r_perp * r_perp) / 2.0
+}
+
+fn walk_channel(albedo: f32, mean_free_path: f32, eta: f32, thickness: f32, rng: &mut ReferenceRng) -> (f32, f32) {
+    let sigma_t = 1.0 / mean_free_path;
+    let single_albedo = single_scattering_albedo(albedo);
+
+    let entry_reflectance = fresnel_dielectric(1.0, eta);
+
+    if rng.next_f32() < entry_reflectance {
+        return (1.0, 0.0);
+    }
+
+    let mut depth = 0.0;
+    let mut cos_theta = 1.0;
+
+    for _ in 0..MAX_WALK_STEPS {
+        let t = -(1.0 - rng.next_f32()).ln() / sigma_t;
+        let next_depth = depth + t * cos_theta;
+
+        if next_depth < 0.0 || next_depth > thickness {
+            let exit_top = next_depth < 0.0;
+            depth = if exit_top { 0.0 } else { thickness };
+
+            if rng.next_f32() < fresnel_dielectric(cos_theta.abs(), 1.0 / eta) {
+                cos_theta = -cos_theta;
+                continue;
+            }
+
+            return if exit_top { (1.0, 0.0) } else { (0.0, 1.0) };
+        }
+
+        depth = next_depth;
+
+        if rng.next_f32() >= single_albedo {
+            return (0.0, 0.0);
+        }
+
+        cos_theta = 1.0 - 2.0 * rng.next_f32();
+    }
+
+    (0.0, 0.0)
+}
+
+pub fn random_walk_slab(params: &SlabParameters, sample_count: u32, seed: u64) -> SlabEstimate {
+    let mut rng = ReferenceRng::new(seed);
+    let mut estimate = SlabEstimate::default();
+
+    for channel in 0..3 {
+        let mut reflected = 0.0;
+        let mut transmitted = 0.0;
+
+        for _ in 0..sample_count {
+            let (r, t) = walk_channel(params.albedo[channel], params.mean_free_path[channel], params.eta, params.thickness, &mut rng);
+            reflected += r;
+            transmitted += t;
+        }
+
+        estimate.reflectance[channel] = reflected / sample_count as f32;
+        estimate.transmittance[channel] = transmitted / sample_count as f32;
+    }
+
+    estimate
+}
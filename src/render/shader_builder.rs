@@ -52,6 +52,7 @@ pub fn compile_spirv_shaders() -> HashMap<String, SpirvShader> {
         .multimodule(true)
         .capability(Capability::RayQueryKHR)
         .capability(Capability::RayTracingKHR)
+        .capability(Capability::Int64)
         .extension("SPV_KHR_ray_query")
         .extension("SPV_KHR_ray_tracing")
         .build()
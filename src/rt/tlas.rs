@@ -19,6 +19,8 @@ use std::{mem, slice};
 pub struct InstanceMetadata {
     pub transform: Mat4,
     pub index_offset: u32,
+    pub medium_inside: u32,
+    pub medium_outside: u32,
 }
 
 pub struct Tlas {
@@ -37,12 +39,12 @@ impl Drop for Tlas {
     }
 }
 
-pub fn create_acceleration_instance(device: &WrappedDevice, blas: &[Blas], models: &[RenderModel]) -> Result<Vec<AccelerationStructureInstanceKHR>> {
+pub fn create_acceleration_instance(device: &WrappedDevice, blas: &[Blas], models: &[RenderModel], time: Option<f32>) -> Result<Vec<AccelerationStructureInstanceKHR>> {
     let mut acceleration_instances: Vec<AccelerationStructureInstanceKHR> = Vec::with_capacity(blas.len());
     let mut blas_idx = 0;
 
     for model in models {
-        for &(_, mesh_transform) in model.meshes.iter() {
+        for mesh_transform in model.mesh_transforms(time) {
             let affine_transform = Affine3A::from_mat4(mesh_transform).to_cols_array_2d();
 
             let transform = TransformMatrixKHR {
@@ -85,7 +87,11 @@ pub fn create_acceleration_instance(device: &WrappedDevice, blas: &[Blas], model
 }
 
 pub fn create_tlas(device: WrappedDeviceRef, allocator: &RenderBufferAllocator, blas: &[Blas], models: &[RenderModel]) -> Result<Tlas> {
-    let acceleration_instances = create_acceleration_instance(&device, blas, models)?;
+    create_tlas_at(device, allocator, blas, models, None)
+}
+
+pub fn create_tlas_at(device: WrappedDeviceRef, allocator: &RenderBufferAllocator, blas: &[Blas], models: &[RenderModel], time: Option<f32>) -> Result<Tlas> {
+    let acceleration_instances = create_acceleration_instance(&device, blas, models, time)?;
 
     let instance_buffer = allocator.allocate(
         (acceleration_instances.len() * mem::size_of::<AccelerationStructureInstanceKHR>()) as DeviceSize,
@@ -0,0 +1,238 @@
+use crate::memory::render_buffer::{RenderBuffer, RenderBufferAllocator};
+use crate::pmj02bn_table::PMJ02BN_SAMPLES;
+use anyhow::{bail, Result};
+use ash::vk::BufferUsageFlags;
+use gpu_allocator::MemoryLocation;
+use std::mem;
+
+// Runtime sized distributions are flattened into one `&[u32]` storage buffer and read in the shaders through the slice views in `util::sampling`,
+// values are stored as their bits so sizes and alias indices never pass through a float
+pub trait FlattenedDistribution {
+    fn flatten_into(&self, data: &mut Vec<u32>);
+}
+
+#[derive(Clone, Debug)]
+pub struct PiecewiseConstant1D {
+    pub func: Vec<f32>,
+    pub cdf: Vec<f32>,
+    pub func_integral: f32,
+}
+
+impl PiecewiseConstant1D {
+    pub fn new(func: &[f32]) -> Self {
+        assert!(!func.is_empty());
+
+        let func = func.iter().map(|value| value.abs()).collect::<Vec<_>>();
+        let mut total = 0.0;
+
+        let mut cdf = func
+            .iter()
+            .map(|value| {
+                total += value / func.len() as f32;
+                total
+            })
+            .collect::<Vec<_>>();
+
+        if total > 0.0 {
+            cdf.iter_mut().for_each(|value| *value /= total);
+        }
+
+        Self { func, cdf, func_integral: total }
+    }
+}
+
+impl FlattenedDistribution for PiecewiseConstant1D {
+    fn flatten_into(&self, data: &mut Vec<u32>) {
+        data.push(self.func.len() as u32);
+        data.push(self.func_integral.to_bits());
+        data.extend(self.func.iter().map(|value| value.to_bits()));
+        data.extend(self.cdf.iter().map(|value| value.to_bits()));
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PiecewiseConstant2D {
+    pub conditional: Vec<PiecewiseConstant1D>,
+    pub marginal: PiecewiseConstant1D,
+}
+
+impl PiecewiseConstant2D {
+    // `func` is row major with `nu` values per row, e.g. the luminance of an environment map
+    pub fn new(func: &[f32], nu: usize, nv: usize) -> Self {
+        assert_eq!(func.len(), nu * nv);
+
+        let conditional = func.chunks(nu).map(PiecewiseConstant1D::new).collect::<Vec<_>>();
+        let marginal = PiecewiseConstant1D::new(&conditional.iter().map(|row| row.func_integral).collect::<Vec<_>>());
+
+        Self { conditional, marginal }
+    }
+}
+
+impl FlattenedDistribution for PiecewiseConstant2D {
+    fn flatten_into(&self, data: &mut Vec<u32>) {
+        data.push(self.conditional[0].func.len() as u32);
+        data.push(self.conditional.len() as u32);
+
+        self.marginal.flatten_into(data);
+        self.conditional.iter().for_each(|row| row.flatten_into(data));
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct AliasBin {
+    // Probability of keeping this bin instead of jumping to `alias`
+    pub q: f32,
+    pub p: f32,
+    pub alias: u32,
+}
+
+#[derive(Clone, Debug)]
+pub struct AliasTable {
+    pub bins: Vec<AliasBin>,
+}
+
+impl AliasTable {
+    // Vose's construction, bins below the average probability are topped up from ones above it
+    pub fn new(weights: &[f32]) -> Result<Self> {
+        if weights.is_empty() || weights.iter().any(|weight| !weight.is_finite() || *weight < 0.0) {
+            bail!("Alias table weights must be finite and non-negative");
+        }
+
+        let sum = weights.iter().map(|&weight| weight as f64).sum::<f64>();
+
+        if sum == 0.0 {
+            bail!("Alias table weights sum to zero");
+        }
+
+        let count = weights.len();
+        let mut bins = weights
+            .iter()
+            .enumerate()
+            .map(|(index, &weight)| AliasBin {
+                q: 1.0,
+                p: (weight as f64 / sum) as f32,
+                alias: index as u32,
+            })
+            .collect::<Vec<_>>();
+
+        let (mut under, mut over): (Vec<_>, Vec<_>) = bins.iter().enumerate().map(|(index, bin)| (index, bin.p as f64 * count as f64)).partition(|&(_, p_hat)| p_hat < 1.0);
+
+        while !under.is_empty() && !over.is_empty() {
+            let (under_index, under_p_hat) = under.pop().unwrap();
+            let (over_index, over_p_hat) = over.pop().unwrap();
+
+            bins[under_index].q = under_p_hat as f32;
+            bins[under_index].alias = over_index as u32;
+
+            let excess = under_p_hat + over_p_hat - 1.0;
+
+            if excess < 1.0 {
+                under.push((over_index, excess));
+            } else {
+                over.push((over_index, excess));
+            }
+        }
+
+        // Whatever is left is within rounding of the average, those bins always keep their own outcome
+        for (index, _) in under.into_iter().chain(over) {
+            bins[index].q = 1.0;
+            bins[index].alias = index as u32;
+        }
+
+        Ok(Self { bins })
+    }
+}
+
+impl FlattenedDistribution for AliasTable {
+    fn flatten_into(&self, data: &mut Vec<u32>) {
+        data.push(self.bins.len() as u32);
+
+        for bin in &self.bins {
+            data.extend([bin.q.to_bits(), bin.p.to_bits(), bin.alias]);
+        }
+    }
+}
+
+// `values` are the density at evenly spaced points over [0, 1], linearly interpolated in between
+#[derive(Clone, Debug)]
+pub struct PiecewiseLinear {
+    pub values: Vec<f32>,
+    pub cdf: Vec<f32>,
+    pub integral: f32,
+}
+
+impl PiecewiseLinear {
+    pub fn new(values: &[f32]) -> Self {
+        assert!(values.len() >= 2);
+
+        let values = values.iter().map(|value| value.abs()).collect::<Vec<_>>();
+        let segment_count = values.len() - 1;
+        let mut total = 0.0;
+
+        let mut cdf = values
+            .windows(2)
+            .map(|segment| {
+                total += 0.5 * (segment[0] + segment[1]) / segment_count as f32;
+                total
+            })
+            .collect::<Vec<_>>();
+
+        if total > 0.0 {
+            cdf.iter_mut().for_each(|value| *value /= total);
+        }
+
+        Self { values, cdf, integral: total }
+    }
+}
+
+impl FlattenedDistribution for PiecewiseLinear {
+    fn flatten_into(&self, data: &mut Vec<u32>) {
+        data.push(self.cdf.len() as u32);
+        data.push(self.integral.to_bits());
+        data.extend(self.values.iter().map(|value| value.to_bits()));
+        data.extend(self.cdf.iter().map(|value| value.to_bits()));
+    }
+}
+
+// Offsets returned by `push` are passed to the shaders to find each distribution in the buffer
+#[derive(Clone, Debug, Default)]
+pub struct DistributionBuffer {
+    pub data: Vec<u32>,
+}
+
+impl DistributionBuffer {
+    pub fn push(&mut self, distribution: &impl FlattenedDistribution) -> u32 {
+        let offset = self.data.len() as u32;
+        distribution.flatten_into(&mut self.data);
+
+        offset
+    }
+
+    pub fn write_to_buffer(&self, allocator: &RenderBufferAllocator) -> Result<RenderBuffer> {
+        // Vulkan does not allow empty buffers
+        let data = if self.data.is_empty() { &[0][..] } else { &self.data };
+
+        let buffer = allocator.allocate(
+            (data.len() * mem::size_of::<u32>()) as _,
+            BufferUsageFlags::STORAGE_BUFFER | BufferUsageFlags::TRANSFER_DST,
+            MemoryLocation::GpuOnly,
+        )?;
+
+        allocator.upload_data(&buffer, data)?;
+
+        Ok(buffer)
+    }
+}
+
+// Bound as the `&[UVec2]` read by `sampler::Pmj02bnSampler`
+pub fn write_pmj02bn_table_to_buffer(allocator: &RenderBufferAllocator) -> Result<RenderBuffer> {
+    let table_buffer = allocator.allocate(
+        (PMJ02BN_SAMPLES.len() * mem::size_of::<[u32; 2]>()) as _,
+        BufferUsageFlags::STORAGE_BUFFER | BufferUsageFlags::TRANSFER_DST,
+        MemoryLocation::GpuOnly,
+    )?;
+
+    allocator.upload_data(&table_buffer, &PMJ02BN_SAMPLES)?;
+
+    Ok(table_buffer)
+}
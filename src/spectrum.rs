@@ -0,0 +1,26 @@
+use crate::memory::render_buffer::{RenderBuffer, RenderBufferAllocator};
+use anyhow::Result;
+use ash::vk::BufferUsageFlags;
+use gpu_allocator::MemoryLocation;
+use log::info;
+use spectrum_table::Gamut;
+use std::mem;
+
+pub const RGB_TO_SPECTRUM_TABLE_RES: usize = 64;
+
+pub fn write_rgb_to_spectrum_table_to_buffer(allocator: &RenderBufferAllocator, gamut: Gamut) -> Result<RenderBuffer> {
+    info!("Generating RGB to spectrum table");
+
+    let (scale, mut table) = spectrum_table::generate_spectrum_tables(gamut, RGB_TO_SPECTRUM_TABLE_RES);
+    table.extend(scale);
+
+    let table_buffer = allocator.allocate(
+        (table.len() * mem::size_of::<f32>()) as _,
+        BufferUsageFlags::STORAGE_BUFFER | BufferUsageFlags::TRANSFER_DST,
+        MemoryLocation::GpuOnly,
+    )?;
+
+    allocator.upload_data(&table_buffer, &table)?;
+
+    Ok(table_buffer)
+}
@@ -0,0 +1,194 @@
+use anyhow::{bail, Result};
+use glam::{UVec2, Vec2};
+use optics_common::sampler;
+
+// Region of the image to render as fractions of the resolution, like pbrt's crop window
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CropWindow {
+    pub min: Vec2,
+    pub max: Vec2,
+}
+
+impl Default for CropWindow {
+    fn default() -> Self {
+        Self { min: Vec2::ZERO, max: Vec2::ONE }
+    }
+}
+
+impl CropWindow {
+    pub fn new(min: Vec2, max: Vec2) -> Result<Self> {
+        if min.cmplt(Vec2::ZERO).any() || max.cmpgt(Vec2::ONE).any() || min.cmpge(max).any() {
+            bail!("Invalid crop window {} - {}, expected 0 <= min < max <= 1", min, max);
+        }
+
+        Ok(Self { min, max })
+    }
+
+    // For re-rendering a region picked in pixels
+    pub fn from_pixels(resolution: UVec2, min: UVec2, max: UVec2) -> Result<Self> {
+        Self::new(min.as_vec2() / resolution.as_vec2(), max.as_vec2() / resolution.as_vec2())
+    }
+
+    pub fn pixel_bounds(&self, resolution: UVec2) -> PixelBounds {
+        let min = (self.min * resolution.as_vec2()).ceil().as_uvec2();
+        let max = (self.max * resolution.as_vec2()).ceil().as_uvec2();
+
+        PixelBounds::new(min, max.min(resolution))
+    }
+}
+
+// Half-open range of pixels, `max` is excluded
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PixelBounds {
+    pub min: UVec2,
+    pub max: UVec2,
+}
+
+impl PixelBounds {
+    pub fn new(min: UVec2, max: UVec2) -> Self {
+        Self { min, max: max.max(min) }
+    }
+
+    pub fn full(resolution: UVec2) -> Self {
+        Self::new(UVec2::ZERO, resolution)
+    }
+
+    pub fn size(&self) -> UVec2 {
+        self.max - self.min
+    }
+
+    pub fn area(&self) -> u32 {
+        let size = self.size();
+        size.x * size.y
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.area() == 0
+    }
+
+    pub fn contains(&self, pixel: UVec2) -> bool {
+        pixel.cmpge(self.min).all() && pixel.cmplt(self.max).all()
+    }
+
+    pub fn intersect(&self, other: &PixelBounds) -> PixelBounds {
+        PixelBounds::new(self.min.max(other.min), self.max.min(other.max))
+    }
+
+    // Pixels outside of the full image are clipped, so a filter wider than a pixel never reaches past the image edge
+    pub fn filter_footprint(&self, filter_radius: Vec2, resolution: UVec2) -> PixelBounds {
+        let radius = (filter_radius - Vec2::splat(0.5)).max(Vec2::ZERO).ceil().as_uvec2();
+
+        PixelBounds::new(self.min.saturating_sub(radius), (self.max + radius).min(resolution))
+    }
+
+    // Copies the region out of a full image `width` pixels wide
+    pub fn crop(&self, pixels: &[[f32; 4]], width: u32) -> Vec<[f32; 4]> {
+        (self.min.y..self.max.y)
+            .flat_map(|y| pixels[(y * width + self.min.x) as usize..(y * width + self.max.x) as usize].iter().copied())
+            .collect()
+    }
+
+    // Writes a region produced by `crop` back into a full image, so a re-rendered region can replace the old pixels
+    pub fn paste(&self, region: &[[f32; 4]], pixels: &mut [[f32; 4]], width: u32) {
+        let size = self.size();
+
+        for y in 0..size.y {
+            let source = &region[(y * size.x) as usize..((y + 1) * size.x) as usize];
+            let start = ((self.min.y + y) * width + self.min.x) as usize;
+
+            pixels[start..start + size.x as usize].copy_from_slice(source);
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TileOrder {
+    Scanline,
+    // Z-order curve, neighbouring tiles are rendered close in time
+    Morton,
+    // Rings of tiles from the center out, the subject usually shows up first
+    Spiral,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Tile {
+    pub index: u32,
+    pub bounds: PixelBounds,
+}
+
+// Each tile is its own submission, so a large render never keeps the GPU busy long enough to hit the driver timeout
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TileSchedule {
+    pub tile_size: UVec2,
+    pub order: TileOrder,
+}
+
+impl Default for TileSchedule {
+    fn default() -> Self {
+        Self {
+            tile_size: UVec2::new(128, 128),
+            order: TileOrder::Spiral,
+        }
+    }
+}
+
+impl TileSchedule {
+    pub fn tile_size(mut self, tile_size: UVec2) -> Self {
+        self.tile_size = tile_size.max(UVec2::ONE);
+        self
+    }
+
+    pub fn order(mut self, order: TileOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    // Tiles of a film that splats: samples taken within the filter radius outside the film bounds still reach the pixels at its edge
+    pub fn film_tiles(&self, film_bounds: PixelBounds, filter_radius: Vec2, resolution: UVec2) -> Vec<Tile> {
+        self.tiles(film_bounds.filter_footprint(filter_radius, resolution))
+    }
+
+    // Tiles are aligned to `bounds.min` and clipped to `bounds.max`
+    pub fn tiles(&self, bounds: PixelBounds) -> Vec<Tile> {
+        if bounds.is_empty() {
+            return Vec::new();
+        }
+
+        let tile_count = (bounds.size() + self.tile_size - UVec2::ONE) / self.tile_size;
+
+        let mut coordinates = (0..tile_count.y).flat_map(|y| (0..tile_count.x).map(move |x| UVec2::new(x, y))).collect::<Vec<_>>();
+
+        match self.order {
+            TileOrder::Scanline => {}
+            TileOrder::Morton => coordinates.sort_by_key(|coordinate| sampler::encode_morton2(coordinate.x, coordinate.y)),
+            TileOrder::Spiral => {
+                let center = (tile_count.as_vec2() - Vec2::ONE) * 0.5;
+
+                coordinates.sort_by(|a, b| {
+                    let key = |coordinate: &UVec2| {
+                        let offset = coordinate.as_vec2() - center;
+                        (offset.abs().max_element().round(), offset.y.atan2(offset.x))
+                    };
+
+                    let (ring_a, angle_a) = key(a);
+                    let (ring_b, angle_b) = key(b);
+
+                    ring_a.total_cmp(&ring_b).then(angle_a.total_cmp(&angle_b))
+                });
+            }
+        }
+
+        coordinates
+            .into_iter()
+            .enumerate()
+            .map(|(index, coordinate)| {
+                let min = bounds.min + coordinate * self.tile_size;
+
+                Tile {
+                    index: index as u32,
+                    bounds: PixelBounds::new(min, (min + self.tile_size).min(bounds.max)),
+                }
+            })
+            .collect()
+    }
+}
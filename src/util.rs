@@ -1,3 +1,6 @@
+use crate::output::display::DisplayTransform;
+use crate::output::exr::{ExrImage, ExrPixelType};
+use crate::output::hdr::HdrDisplayOutput;
 use anyhow::anyhow;
 use anyhow::{bail, Result};
 use bytemuck::Pod;
@@ -31,6 +34,8 @@ pub fn cstr_to_str(vk_str: &[c_char]) -> Result<&str> {
 pub enum OutputFormat {
     Png,
     Hdr,
+    Exr,
+    HdrDisplay(HdrDisplayOutput),
 }
 
 pub fn output_image<T: Pod>(path: &impl AsRef<Path>, width: u32, height: u32, pixels: &[T], output_format: OutputFormat) -> Result<()> {
@@ -42,18 +47,7 @@ pub fn output_image<T: Pod>(path: &impl AsRef<Path>, width: u32, height: u32, pi
 
     match output_format {
         OutputFormat::Png => {
-            let image = ImageBuffer::from_fn(width, height, |x, y| {
-                let idx = (y * width + x) as usize;
-
-                let r: u8 = (pixels[idx][0] * 255.0) as _;
-                let g: u8 = (pixels[idx][1] * 255.0) as _;
-                let b: u8 = (pixels[idx][2] * 255.0) as _;
-                let a: u8 = (pixels[idx][3] * 255.0) as _;
-
-                image::Rgba([r, g, b, a])
-            });
-
-            image.save_with_format(path, ImageFormat::Png)?;
+            output_display_image(path, width, height, pixels, &DisplayTransform::default())?;
         }
         OutputFormat::Hdr => {
             let pixels = pixels.iter().map(|pixel| image::Rgb([pixel[0], pixel[1], pixel[2]])).collect::<Vec<_>>();
@@ -61,7 +55,26 @@ pub fn output_image<T: Pod>(path: &impl AsRef<Path>, width: u32, height: u32, pi
             let encoder = HdrEncoder::new(&mut file);
             encoder.encode(&pixels, width as usize, height as usize)?;
         }
+        OutputFormat::Exr => {
+            let mut image = ExrImage::new(width, height);
+            image.add_layer("", "RGBA", ExrPixelType::Float, pixels)?;
+            image.write(path)?;
+        }
+        OutputFormat::HdrDisplay(hdr_output) => hdr_output.write(path, width, height, pixels)?,
+    }
+
+    Ok(())
+}
+
+pub fn output_display_image(path: &impl AsRef<Path>, width: u32, height: u32, pixels: &[[f32; 4]], transform: &DisplayTransform) -> Result<()> {
+    if (width * height) as usize != pixels.len() {
+        bail!("Image dimensions does not match pixels length: {}", pixels.len());
     }
 
+    let encoded = transform.apply(pixels, width, height);
+    let image = ImageBuffer::from_fn(width, height, |x, y| image::Rgba(encoded[(y * width + x) as usize]));
+
+    image.save_with_format(path, ImageFormat::Png)?;
+
     Ok(())
 }